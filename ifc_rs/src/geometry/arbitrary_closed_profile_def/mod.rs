@@ -2,6 +2,7 @@ mod deserialize;
 mod serialize;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::IdOr,
@@ -10,7 +11,10 @@ use crate::{
     prelude::*,
 };
 
-use super::{indexed_poly_curve::Curve, rectangle_profile_def::ProfileDef};
+use super::{
+    curve::Curve,
+    profile_def::{curve_points, signed_area, ProfileDef, ProfilePolygon},
+};
 
 /// The closed profile IfcArbitraryClosedProfileDef defines an arbitrary
 /// two-dimensional profile for the use within the swept surface geometry,
@@ -18,7 +22,7 @@ use super::{indexed_poly_curve::Curve, rectangle_profile_def::ProfileDef};
 /// boundary from which the surface or solid can be constructed.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcprofileresource/lexical/ifcarbitraryclosedprofiledef.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct ArbitraryClosedProfileDef {
     /// Defines the type of geometry into which this profile definition shall be resolved, either a
     /// curve or a surface area. In case of curve the profile should be referenced by a swept
@@ -29,7 +33,7 @@ pub struct ArbitraryClosedProfileDef {
     /// IfcExternalReference.ItemReference.
     pub profile_name: OptionalParameter<Label>,
     /// `IfcCurve` Bounded curve, defining the outer boundaries of the arbitrary profile.
-    #[ifc_types(IndexedPolyCurve, PolyLine)]
+    #[ifc_types(IndexedPolyCurve, PolyLine, CompositeCurve, Circle, Ellipse)]
     pub outer_curve: Id,
 }
 
@@ -52,8 +56,23 @@ impl ArbitraryClosedProfileDef {
     }
 }
 
-impl IfcType for ArbitraryClosedProfileDef {}
-impl ProfileDef for ArbitraryClosedProfileDef {}
+#[typetag::serde(name = "IfcArbitraryClosedProfileDef")]
+impl IfcType for ArbitraryClosedProfileDef {
+    fn to_profile_def(&self) -> Option<&dyn ProfileDef> {
+        Some(self)
+    }
+}
+
+impl ProfileDef for ArbitraryClosedProfileDef {
+    fn polygons(&self, ifc: &IFC) -> Vec<ProfilePolygon> {
+        let mut outer = curve_points(self.outer_curve, ifc);
+        if signed_area(&outer) < 0.0 {
+            outer.reverse();
+        }
+
+        vec![ProfilePolygon::new(outer)]
+    }
+}
 
 #[cfg(test)]
 mod test {
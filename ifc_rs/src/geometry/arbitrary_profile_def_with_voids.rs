@@ -0,0 +1,184 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{
+        curve::Curve,
+        profile_def::{curve_points, signed_area, ProfileDef, ProfilePolygon},
+        profile_type::ProfileType,
+    },
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// The IfcArbitraryProfileDefWithVoids defines an arbitrary closed two-dimensional profile with
+/// holes defined for the use for the swept area solid or a sectioned spine. It is given by an
+/// outer boundary and inner boundaries from which the solid can be constructed.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcprofileresource/lexical/ifcarbitraryprofiledefwithvoids.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct ArbitraryProfileDefWithVoids {
+    /// Defines the type of geometry into which this profile definition shall be resolved, either a
+    /// curve or a surface area. In case of curve the profile should be referenced by a swept
+    /// surface, in case of area the profile should be referenced by a swept area solid.
+    pub profile_type: ProfileType,
+    /// Human-readable name of the profile, for example according to a standard profile table.
+    pub profile_name: OptionalParameter<Label>,
+    /// `IfcCurve` Bounded curve, defining the outer boundaries of the arbitrary profile.
+    #[ifc_types(IndexedPolyCurve, PolyLine, CompositeCurve, Circle, Ellipse)]
+    pub outer_curve: Id,
+    /// Set of bounded curves, defining the inner boundaries of the arbitrary profile.
+    #[ifc_types(IndexedPolyCurve, PolyLine, CompositeCurve, Circle, Ellipse)]
+    pub inner_curves: IfcList<Id>,
+}
+
+impl ArbitraryProfileDefWithVoids {
+    pub fn new<C: Curve>(
+        profile_type: ProfileType,
+        outer_curve: impl Into<IdOr<C>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            profile_type,
+            profile_name: OptionalParameter::omitted(),
+            outer_curve: outer_curve.into().or_insert(ifc).id(),
+            inner_curves: IfcList::empty(),
+        }
+    }
+
+    pub fn profile_name(mut self, name: impl Into<Label>) -> Self {
+        self.profile_name = name.into().into();
+        self
+    }
+
+    pub fn add_inner_curve<C: Curve>(mut self, curve: impl Into<IdOr<C>>, ifc: &mut IFC) -> Self {
+        self.inner_curves.0.push(curve.into().or_insert(ifc).id());
+        self
+    }
+}
+
+impl IFCParse for ArbitraryProfileDefWithVoids {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCARBITRARYPROFILEDEFWITHVOIDS("),
+
+                profile_type: ProfileType::parse(),
+                _: Comma::parse(),
+                profile_name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                outer_curve: Id::parse(),
+                _: Comma::parse(),
+                inner_curves: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for ArbitraryProfileDefWithVoids {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCARBITRARYPROFILEDEFWITHVOIDS({},{},{},{});",
+            self.profile_type, self.profile_name, self.outer_curve, self.inner_curves,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcArbitraryProfileDefWithVoids")]
+impl IfcType for ArbitraryProfileDefWithVoids {
+    fn to_profile_def(&self) -> Option<&dyn ProfileDef> {
+        Some(self)
+    }
+}
+
+impl ProfileDef for ArbitraryProfileDefWithVoids {
+    fn polygons(&self, ifc: &IFC) -> Vec<ProfilePolygon> {
+        let mut outer = curve_points(self.outer_curve, ifc);
+        if signed_area(&outer) < 0.0 {
+            outer.reverse();
+        }
+
+        let polygon = self
+            .inner_curves
+            .iter()
+            .fold(ProfilePolygon::new(outer), |polygon, inner| {
+                polygon.with_void(curve_points(*inner, ifc))
+            });
+
+        vec![polygon]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::DVec2;
+    use winnow::Parser;
+
+    use super::ArbitraryProfileDefWithVoids;
+    use crate::parser::IFCParse;
+    use crate::{
+        geometry::{
+            indexed_poly_curve::IndexedPolyCurve, point_list::PointList2D, profile_def::ProfileDef,
+            profile_type::ProfileType,
+        },
+        IFC,
+    };
+
+    #[test]
+    fn arbitrary_profile_def_with_voids_round_trip() {
+        let example = "IFCARBITRARYPROFILEDEFWITHVOIDS(.AREA.,$,#25,(#31,#37));";
+
+        let parsed = ArbitraryProfileDefWithVoids::parse()
+            .parse(example)
+            .unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn arbitrary_profile_def_with_voids_polygon() {
+        let mut ifc = IFC::default();
+
+        let outer = IndexedPolyCurve::new(
+            PointList2D::new(
+                [
+                    DVec2::new(0.0, 0.0),
+                    DVec2::new(4.0, 0.0),
+                    DVec2::new(4.0, 4.0),
+                    DVec2::new(0.0, 4.0),
+                ]
+                .into_iter(),
+            ),
+            &mut ifc,
+        );
+        let inner = IndexedPolyCurve::new(
+            PointList2D::new(
+                [
+                    DVec2::new(1.0, 1.0),
+                    DVec2::new(3.0, 1.0),
+                    DVec2::new(3.0, 3.0),
+                    DVec2::new(1.0, 3.0),
+                ]
+                .into_iter(),
+            ),
+            &mut ifc,
+        );
+
+        let profile = ArbitraryProfileDefWithVoids::new(ProfileType::Area, outer, &mut ifc)
+            .add_inner_curve(inner, &mut ifc);
+        let polygons = profile.polygons(&ifc);
+
+        assert_eq!(polygons.len(), 1);
+        assert_eq!(polygons[0].inner.len(), 1);
+        assert_eq!(polygons[0].area(), 12.0);
+    }
+}
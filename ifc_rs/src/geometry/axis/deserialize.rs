@@ -3,12 +3,23 @@ use winnow::{
     Parser,
 };
 
-use super::{Axis2D, Axis3D};
+use super::{Axis1D, Axis2D, Axis3D};
 use crate::{
     id::TypedId,
     parser::{comma::Comma, optional::OptionalParameter, IFCParse, IFCParser},
 };
 
+impl IFCParse for Axis1D {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        delimited(
+            "IFCAXIS1PLACEMENT(",
+            separated_pair(TypedId::parse(), Comma::parse(), OptionalParameter::parse()),
+            ");",
+        )
+        .map(|(location, axis)| Self { location, axis })
+    }
+}
+
 impl IFCParse for Axis2D {
     fn parse<'a>() -> impl IFCParser<'a, Self> {
         delimited(
@@ -41,6 +52,13 @@ impl IFCParse for Axis3D {
     }
 }
 
+#[test]
+fn parse_axis_1d_works() {
+    let data = "IFCAXIS1PLACEMENT(#6,#9);";
+    let parsed = Axis1D::parse().parse(data).unwrap();
+    assert_eq!(data, parsed.to_string());
+}
+
 #[test]
 fn parse_axis_2d_works() {
     let data = "IFCAXIS2PLACEMENT2D(#248,#23);";
@@ -1,10 +1,12 @@
 mod deserialize;
 mod serialize;
 
+use glam::{DAffine2, DAffine3, DMat3, DVec2, DVec3};
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    id::TypedId,
+    id::{Id, IdOr, TypedId},
     ifc_type::{IfcType, IfcVerify},
     parser::optional::OptionalParameter,
     prelude::*,
@@ -12,6 +14,51 @@ use crate::{
 
 pub trait AxisPlacement: IfcType {}
 
+/// The IfcAxis1Placement represents the location and orientation of a single axis in
+/// three-dimensional space, e.g. the axis of revolution of a revolved solid.
+///
+/// If the attribute Axis is not given, the axis defaults to the z axis [0.,0.,1.].
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcaxis1placement.htm
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
+pub struct Axis1D {
+    /// The geometric position of a reference point on the axis.
+    pub location: TypedId<Point3D>,
+    /// The direction of the local Z axis. If a value is omited that it defaults to [0.0, 0.0, 1.0]
+    pub axis: OptionalParameter<TypedId<Direction3D>>,
+}
+
+impl Axis1D {
+    pub fn new(point: Point3D, ifc: &mut IFC) -> Self {
+        let id = ifc.data.insert_new(point);
+
+        Self {
+            location: id,
+            axis: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn axis(mut self, axis: impl Into<IdOr<Direction3D>>, ifc: &mut IFC) -> Self {
+        self.axis = axis.into().or_insert(ifc).into();
+        self
+    }
+
+    /// Location and normalized direction of the axis.
+    pub fn line(&self, ifc: &IFC) -> (DVec3, DVec3) {
+        let location = ***ifc.data.get(self.location);
+        let axis = self
+            .axis
+            .custom()
+            .map(|id| ifc.data.get(*id).normalize())
+            .unwrap_or(DVec3::Z);
+
+        (location, axis)
+    }
+}
+
+#[typetag::serde(name = "IfcAxis1Placement")]
+impl IfcType for Axis1D {}
+
 /// The IfcAxis2Placement2D provides location and orientation to place items in a two-dimensional
 /// space. The attribute RefDirection defines the x axis, the y axis is derived.
 ///
@@ -19,7 +66,7 @@ pub trait AxisPlacement: IfcType {}
 /// and P[2] (y-axis) as [0.,1.].
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcaxis2placement2d.htm
-#[derive(Debug, Clone, IfcVerify)]
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
 pub struct Axis2D {
     /// The geometric position of a reference point, such as the center of a circle, of the item to
     /// be located.
@@ -38,8 +85,22 @@ impl Axis2D {
             local_x: OptionalParameter::omitted(),
         }
     }
+
+    /// Transformation from the placed coordinate system into the coordinate
+    /// system the placement is given in.
+    pub fn transform(&self, ifc: &IFC) -> DAffine2 {
+        let location = ***ifc.data.get(self.location);
+        let local_x = self
+            .local_x
+            .custom()
+            .map(|id| ifc.data.get(*id).normalize())
+            .unwrap_or(DVec2::X);
+
+        DAffine2::from_cols(local_x, local_x.perp(), location)
+    }
 }
 
+#[typetag::serde(name = "IfcAxis2Placement2D")]
 impl IfcType for Axis2D {}
 impl AxisPlacement for Axis2D {}
 
@@ -51,7 +112,7 @@ impl AxisPlacement for Axis2D {}
 /// (x-axis) as [1.,0.,0.], P[2] (y-axis) as [0.,1.,0.] and P[3] (z-axis) as [0.,0.,1.].
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcaxis2placement3d.htm
-#[derive(Debug, Clone, IfcVerify)]
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
 pub struct Axis3D {
     /// The geometric position of a reference point, such as the center of a circle, of the item to
     /// be located.
@@ -74,7 +135,67 @@ impl Axis3D {
             local_x: OptionalParameter::omitted(),
         }
     }
+
+    pub fn local_z(mut self, local_z: impl Into<IdOr<Direction3D>>, ifc: &mut IFC) -> Self {
+        self.local_z = local_z.into().or_insert(ifc).into();
+        self
+    }
+
+    pub fn local_x(mut self, local_x: impl Into<IdOr<Direction3D>>, ifc: &mut IFC) -> Self {
+        self.local_x = local_x.into().or_insert(ifc).into();
+        self
+    }
+
+    /// Transformation from the placed coordinate system into the coordinate
+    /// system the placement is given in. The x axis is adjusted to be
+    /// orthogonal to the z axis.
+    pub fn transform(&self, ifc: &IFC) -> DAffine3 {
+        let location = ***ifc.data.get(self.location);
+        let local_z = self
+            .local_z
+            .custom()
+            .map(|id| ifc.data.get(*id).normalize())
+            .unwrap_or(DVec3::Z);
+        let local_x = self
+            .local_x
+            .custom()
+            .map(|id| ***ifc.data.get(*id))
+            .unwrap_or(DVec3::X);
+
+        let mut local_x = (local_x - local_x.dot(local_z) * local_z).normalize_or_zero();
+        if local_x == DVec3::ZERO {
+            local_x = local_z.any_orthonormal_vector();
+        }
+
+        DAffine3::from_mat3_translation(
+            DMat3::from_cols(local_x, local_z.cross(local_x), local_z),
+            location,
+        )
+    }
+}
+
+/// Transformation of an `Axis2D` or `Axis3D` placement given by its id, two
+/// dimensional placements are embedded into the xy plane. Other entities
+/// result in the identity.
+pub fn axis_placement_transform(placement: Id, ifc: &IFC) -> DAffine3 {
+    let placement = ifc.data.get_untyped(placement);
+
+    if let Some(axis) = placement.downcast_ref::<Axis2D>() {
+        let transform = axis.transform(ifc);
+
+        DAffine3::from_cols(
+            transform.matrix2.x_axis.extend(0.0),
+            transform.matrix2.y_axis.extend(0.0),
+            DVec3::Z,
+            transform.translation.extend(0.0),
+        )
+    } else if let Some(axis) = placement.downcast_ref::<Axis3D>() {
+        axis.transform(ifc)
+    } else {
+        DAffine3::IDENTITY
+    }
 }
 
+#[typetag::serde(name = "IfcAxis2Placement3D")]
 impl IfcType for Axis3D {}
 impl AxisPlacement for Axis3D {}
@@ -1,6 +1,17 @@
 use std::fmt::Display;
 
-use super::{Axis2D, Axis3D};
+use super::{Axis1D, Axis2D, Axis3D};
+
+impl Display for Axis1D {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCAXIS1PLACEMENT({loc_id},{axis_id});",
+            loc_id = self.location,
+            axis_id = self.axis
+        )
+    }
+}
 
 impl Display for Axis2D {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -0,0 +1,243 @@
+use std::{fmt::Display, str::FromStr};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::{
+    combinator::{alt, delimited},
+    Parser,
+};
+
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, p_space_or_comment, p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// This type defines the three Boolean operators used in the definition of
+/// CSG solids.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcbooleanoperator.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BooleanOperator {
+    /// The operation of constructing the regularized set theoretic union of
+    /// the volumes defined by two solids.
+    #[strum(to_string = ".UNION.")]
+    Union,
+
+    /// The operation of constructing the regularised set theoretic
+    /// intersection of the volumes defined by two solids.
+    #[strum(to_string = ".INTERSECTION.")]
+    Intersection,
+
+    /// The regularised set theoretic difference between the volumes defined
+    /// by two solids.
+    #[strum(to_string = ".DIFFERENCE.")]
+    Difference,
+}
+
+impl IFCParse for BooleanOperator {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid BooleanOperator")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+/// The IfcBooleanResult is the result of applying a Boolean operation to
+/// two operands being solids.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcbooleanresult.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct BooleanResult {
+    /// The Boolean operator used in the operation to create the result.
+    pub operator: BooleanOperator,
+    /// The first operand to be operated upon by the Boolean operation.
+    #[ifc_types(
+        ExtrudedAreaSolid,
+        ExtrudedAreaSolidTapered,
+        RevolvedAreaSolid,
+        SweptDiskSolid,
+        SurfaceCurveSweptAreaSolid,
+        FixedReferenceSweptAreaSolid,
+        FacetedBrep,
+        TriangulatedFaceSet,
+        PolygonalFaceSet,
+        BooleanResult,
+        BooleanClippingResult,
+        HalfSpaceSolid,
+        PolygonalBoundedHalfSpace
+    )]
+    pub first_operand: Id,
+    /// The second operand specified for the operation.
+    #[ifc_types(
+        ExtrudedAreaSolid,
+        ExtrudedAreaSolidTapered,
+        RevolvedAreaSolid,
+        SweptDiskSolid,
+        SurfaceCurveSweptAreaSolid,
+        FixedReferenceSweptAreaSolid,
+        FacetedBrep,
+        TriangulatedFaceSet,
+        PolygonalFaceSet,
+        BooleanResult,
+        BooleanClippingResult,
+        HalfSpaceSolid,
+        PolygonalBoundedHalfSpace
+    )]
+    pub second_operand: Id,
+}
+
+impl BooleanResult {
+    pub fn new<A: IfcType, B: IfcType>(
+        operator: BooleanOperator,
+        first_operand: impl Into<IdOr<A>>,
+        second_operand: impl Into<IdOr<B>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            operator,
+            first_operand: first_operand.into().or_insert(ifc).id(),
+            second_operand: second_operand.into().or_insert(ifc).id(),
+        }
+    }
+}
+
+impl IFCParse for BooleanResult {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCBOOLEANRESULT("),
+
+                operator: BooleanOperator::parse(),
+                _: Comma::parse(),
+                first_operand: Id::parse(),
+                _: Comma::parse(),
+                second_operand: Id::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for BooleanResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCBOOLEANRESULT({},{},{});",
+            self.operator, self.first_operand, self.second_operand
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcBooleanResult")]
+impl IfcType for BooleanResult {}
+impl ShapeItem for BooleanResult {}
+
+/// The IfcBooleanClippingResult is a special subtype of IfcBooleanResult.
+/// It is used to trim a solid by a half space solid, the operator is always
+/// a difference and the second operand a half space.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcbooleanclippingresult.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct BooleanClippingResult {
+    /// The Boolean operator used in the operation, always a difference.
+    pub operator: BooleanOperator,
+    /// The solid to be clipped, either a swept area solid or the result of a
+    /// previous clipping.
+    #[ifc_types(
+        ExtrudedAreaSolid,
+        ExtrudedAreaSolidTapered,
+        RevolvedAreaSolid,
+        SweptDiskSolid,
+        SurfaceCurveSweptAreaSolid,
+        FixedReferenceSweptAreaSolid,
+        FacetedBrep,
+        BooleanClippingResult
+    )]
+    pub first_operand: Id,
+    /// The half space solid clipping the first operand.
+    #[ifc_types(HalfSpaceSolid, PolygonalBoundedHalfSpace)]
+    pub second_operand: Id,
+}
+
+impl BooleanClippingResult {
+    pub fn new<A: IfcType, B: IfcType>(
+        first_operand: impl Into<IdOr<A>>,
+        second_operand: impl Into<IdOr<B>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            operator: BooleanOperator::Difference,
+            first_operand: first_operand.into().or_insert(ifc).id(),
+            second_operand: second_operand.into().or_insert(ifc).id(),
+        }
+    }
+}
+
+impl IFCParse for BooleanClippingResult {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCBOOLEANCLIPPINGRESULT("),
+
+                operator: BooleanOperator::parse(),
+                _: Comma::parse(),
+                first_operand: Id::parse(),
+                _: Comma::parse(),
+                second_operand: Id::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for BooleanClippingResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCBOOLEANCLIPPINGRESULT({},{},{});",
+            self.operator, self.first_operand, self.second_operand
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcBooleanClippingResult")]
+impl IfcType for BooleanClippingResult {}
+impl ShapeItem for BooleanClippingResult {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::{BooleanClippingResult, BooleanResult};
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn boolean_result_round_trip() {
+        let example = "IFCBOOLEANRESULT(.UNION.,#24,#31);";
+
+        let parsed: BooleanResult = BooleanResult::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn boolean_clipping_result_round_trip() {
+        let example = "IFCBOOLEANCLIPPINGRESULT(.DIFFERENCE.,#24,#31);";
+
+        let parsed: BooleanClippingResult = BooleanClippingResult::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt::Display;
+
+use glam::DVec2;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{
+        profile_def::{position_transform, ProfileDef, ProfilePolygon},
+        profile_type::ProfileType,
+    },
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcCShapeProfileDef defines a section profile that provides the defining parameters of a
+/// C-shaped section (lipped channel) for use by the swept area solid. It is based on a U-shape with
+/// constant wall thickness, whose flanges end in lips (girth) pointing inwards. The origin of the
+/// profile is the center of its bounding box.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcprofileresource/lexical/ifccshapeprofiledef.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct CShapeProfileDef {
+    /// Defines the type of geometry into which this profile definition shall be resolved, either a
+    /// curve or a surface area. In case of curve the profile should be referenced by a swept
+    /// surface, in case of area the profile should be referenced by a swept area solid.
+    pub profile_type: ProfileType,
+    /// Human-readable name of the profile, for example according to a standard profile table. As
+    /// noted above, machine-readable standardized profile designations should be provided in
+    /// IfcExternalReference.ItemReference.
+    pub profile_name: OptionalParameter<Label>,
+    /// Position coordinate system of the parameterized profile definition. If unspecified, no
+    /// translation and no rotation is applied.
+    #[ifc_types(Axis2D, Axis3D)]
+    pub position: OptionalParameter<Id>,
+    /// Profile depth (= h).
+    pub depth: IfcFloat,
+    /// Profile width (= b).
+    pub width: IfcFloat,
+    /// Constant wall thickness of profile (= ts).
+    pub wall_thickness: IfcFloat,
+    /// Lengths of girth (= c).
+    pub girth: IfcFloat,
+    /// Internal fillet radius (= r1).
+    pub internal_fillet_radius: OptionalParameter<IfcFloat>,
+}
+
+impl CShapeProfileDef {
+    pub fn new(
+        profile_type: ProfileType,
+        depth: f64,
+        width: f64,
+        wall_thickness: f64,
+        girth: f64,
+    ) -> Self {
+        Self {
+            profile_type,
+            profile_name: OptionalParameter::omitted(),
+            position: OptionalParameter::omitted(),
+            depth: depth.into(),
+            width: width.into(),
+            wall_thickness: wall_thickness.into(),
+            girth: girth.into(),
+            internal_fillet_radius: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn profile_name(mut self, name: impl Into<Label>) -> Self {
+        self.profile_name = name.into().into();
+        self
+    }
+
+    pub fn position<A: AxisPlacement>(
+        mut self,
+        position: impl Into<IdOr<A>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.position = position.into().or_insert(ifc).id().into();
+        self
+    }
+
+    pub fn internal_fillet_radius(mut self, internal_fillet_radius: f64) -> Self {
+        self.internal_fillet_radius = IfcFloat(internal_fillet_radius).into();
+        self
+    }
+}
+
+impl IFCParse for CShapeProfileDef {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCSHAPEPROFILEDEF("),
+
+                profile_type: ProfileType::parse(),
+                _: Comma::parse(),
+                profile_name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                position: OptionalParameter::parse(),
+                _: Comma::parse(),
+                depth: IfcFloat::parse(),
+                _: Comma::parse(),
+                width: IfcFloat::parse(),
+                _: Comma::parse(),
+                wall_thickness: IfcFloat::parse(),
+                _: Comma::parse(),
+                girth: IfcFloat::parse(),
+                _: Comma::parse(),
+                internal_fillet_radius: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for CShapeProfileDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCSHAPEPROFILEDEF({},{},{},{},{},{},{},{});",
+            self.profile_type,
+            self.profile_name,
+            self.position,
+            self.depth,
+            self.width,
+            self.wall_thickness,
+            self.girth,
+            self.internal_fillet_radius,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcCShapeProfileDef")]
+impl IfcType for CShapeProfileDef {
+    fn to_profile_def(&self) -> Option<&dyn ProfileDef> {
+        Some(self)
+    }
+}
+
+impl ProfileDef for CShapeProfileDef {
+    fn polygons(&self, ifc: &IFC) -> Vec<ProfilePolygon> {
+        let (w, d) = (self.width.0 * 0.5, self.depth.0 * 0.5);
+        let (t, girth) = (self.wall_thickness.0, self.girth.0);
+
+        let polygon = ProfilePolygon::new(vec![
+            DVec2::new(-w, -d),
+            DVec2::new(w, -d),
+            DVec2::new(w, -d + girth),
+            DVec2::new(w - t, -d + girth),
+            DVec2::new(w - t, -d + t),
+            DVec2::new(-w + t, -d + t),
+            DVec2::new(-w + t, d - t),
+            DVec2::new(w - t, d - t),
+            DVec2::new(w - t, d - girth),
+            DVec2::new(w, d - girth),
+            DVec2::new(w, d),
+            DVec2::new(-w, d),
+        ]);
+
+        vec![polygon.transformed(position_transform(&self.position, ifc))]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::CShapeProfileDef;
+    use crate::parser::IFCParse;
+    use crate::{
+        geometry::{profile_def::ProfileDef, profile_type::ProfileType},
+        IFC,
+    };
+
+    #[test]
+    fn c_shape_profile_def_round_trip() {
+        let examples = ["IFCCSHAPEPROFILEDEF(.AREA.,'C 150x50x20x2',$,0.15,0.05,0.002,0.02,$);"];
+
+        for example in examples {
+            let parsed: CShapeProfileDef = CShapeProfileDef::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn c_shape_profile_def_polygon() {
+        let ifc = IFC::default();
+        let polygons =
+            CShapeProfileDef::new(ProfileType::Area, 0.2, 0.1, 0.01, 0.03).polygons(&ifc);
+
+        // web, two flanges and two lips
+        let area = 0.2 * 0.01 + 2.0 * 0.09 * 0.01 + 2.0 * 0.02 * 0.01;
+        assert!((polygons[0].area() - area).abs() < 1e-9);
+    }
+}
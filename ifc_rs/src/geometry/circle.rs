@@ -0,0 +1,161 @@
+use std::{f64::consts::TAU, fmt::Display};
+
+use glam::DVec3;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{
+        axis::axis_placement_transform,
+        curve::{conic_arc, Curve},
+    },
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// An IfcCircle is defined by a radius and the location and orientation of
+/// the circle. The circle is parameterized by the angle from the x axis of
+/// its position, running counter-clockwise around its z axis.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccircle.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Circle {
+    /// The location and orientation of the circle, the center is at the
+    /// location of the placement.
+    #[ifc_types(Axis2D, Axis3D)]
+    pub position: Id,
+    /// The radius of the circle, which shall be greater than zero.
+    pub radius: IfcFloat,
+}
+
+impl Circle {
+    pub fn new<A: AxisPlacement>(position: impl Into<IdOr<A>>, radius: f64, ifc: &mut IFC) -> Self {
+        Self {
+            position: position.into().or_insert(ifc).id(),
+            radius: radius.into(),
+        }
+    }
+
+    /// Point of the circle at the given angle in radians.
+    pub fn point_at(&self, angle: f64, ifc: &IFC) -> DVec3 {
+        axis_placement_transform(self.position, ifc).transform_point3(DVec3::new(
+            self.radius.0 * angle.cos(),
+            self.radius.0 * angle.sin(),
+            0.0,
+        ))
+    }
+
+    /// Angle in radians of the given point projected onto the circle.
+    pub fn angle_of(&self, point: DVec3, ifc: &IFC) -> f64 {
+        let local = axis_placement_transform(self.position, ifc)
+            .inverse()
+            .transform_point3(point);
+
+        local.y.atan2(local.x)
+    }
+
+    /// Arc from the angle `start` sweeping `sweep` radians, negative sweeps
+    /// run clockwise.
+    pub fn arc(&self, start: f64, sweep: f64, tolerance: f64, ifc: &IFC) -> Vec<DVec3> {
+        conic_arc(
+            axis_placement_transform(self.position, ifc),
+            self.radius.0,
+            self.radius.0,
+            start,
+            sweep,
+            tolerance,
+        )
+    }
+}
+
+impl IFCParse for Circle {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCIRCLE("),
+
+                position: Id::parse(),
+                _: Comma::parse(),
+                radius: IfcFloat::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for Circle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCCIRCLE({},{});", self.position, self.radius)
+    }
+}
+
+#[typetag::serde(name = "IfcCircle")]
+impl IfcType for Circle {
+    fn to_curve(&self) -> Option<&dyn Curve> {
+        Some(self)
+    }
+}
+
+impl ShapeItem for Circle {}
+
+impl Curve for Circle {
+    fn discretize(&self, tolerance: f64, ifc: &IFC) -> Vec<DVec3> {
+        let mut points = self.arc(0.0, TAU, tolerance, ifc);
+        if let (Some(first), Some(last)) = (points.first().copied(), points.last_mut()) {
+            *last = first;
+        }
+
+        points
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::{DVec2, DVec3};
+    use winnow::Parser;
+
+    use super::Circle;
+    use crate::{
+        geometry::{axis::Axis2D, curve::Curve},
+        parser::IFCParse,
+        IFC,
+    };
+
+    #[test]
+    fn circle_round_trip() {
+        let example = "IFCCIRCLE(#36,2.5);";
+
+        let parsed: Circle = Circle::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn circle_discretization() {
+        let mut ifc = IFC::default();
+
+        let center = DVec2::new(1.0, 2.0);
+        let position = Axis2D::new(center.into(), &mut ifc);
+        let circle = Circle::new(position, 2.0, &mut ifc);
+
+        let points = circle.discretize(0.01, &ifc);
+        assert_eq!(points.first(), points.last());
+        assert!(points
+            .iter()
+            .all(|point| (point.distance(center.extend(0.0)) - 2.0).abs() < 1e-9));
+
+        // the sagitta of each chord stays within the tolerance
+        let max_sagitta = points
+            .windows(2)
+            .map(|chord| 2.0 - ((chord[0] + chord[1]) * 0.5).distance(center.extend(0.0)))
+            .fold(0.0, f64::max);
+        assert!(max_sagitta <= 0.01);
+
+        let quarter = circle.point_at(std::f64::consts::FRAC_PI_2, &ifc);
+        assert!(quarter.distance(DVec3::new(1.0, 4.0, 0.0)) < 1e-9);
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt::Display;
+
+use glam::DVec2;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{
+        profile_def::{circle, position_transform, ProfileDef, ProfilePolygon},
+        profile_type::ProfileType,
+    },
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcCircleHollowProfileDef defines a section profile that provides the defining parameters of a
+/// circular hollow section (tube) to be used by the swept area solid. Its parameters and
+/// orientation relative to the position coordinate system are according to the circle profile
+/// definition, the wall thickness is measured inwards from the outer radius.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcprofileresource/lexical/ifccirclehollowprofiledef.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct CircleHollowProfileDef {
+    /// Defines the type of geometry into which this profile definition shall be resolved, either a
+    /// curve or a surface area. In case of curve the profile should be referenced by a swept
+    /// surface, in case of area the profile should be referenced by a swept area solid.
+    pub profile_type: ProfileType,
+    /// Human-readable name of the profile, for example according to a standard profile table. As
+    /// noted above, machine-readable standardized profile designations should be provided in
+    /// IfcExternalReference.ItemReference.
+    pub profile_name: OptionalParameter<Label>,
+    /// Position coordinate system of the parameterized profile definition. If unspecified, no
+    /// translation and no rotation is applied.
+    #[ifc_types(Axis2D, Axis3D)]
+    pub position: OptionalParameter<Id>,
+    /// The radius of the circle.
+    pub radius: IfcFloat,
+    /// Thickness of the material, it is the difference between the outer and inner radius.
+    pub wall_thickness: IfcFloat,
+}
+
+impl CircleHollowProfileDef {
+    pub fn new(profile_type: ProfileType, radius: f64, wall_thickness: f64) -> Self {
+        Self {
+            profile_type,
+            profile_name: OptionalParameter::omitted(),
+            position: OptionalParameter::omitted(),
+            radius: radius.into(),
+            wall_thickness: wall_thickness.into(),
+        }
+    }
+
+    pub fn profile_name(mut self, name: impl Into<Label>) -> Self {
+        self.profile_name = name.into().into();
+        self
+    }
+
+    pub fn position<A: AxisPlacement>(
+        mut self,
+        position: impl Into<IdOr<A>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.position = position.into().or_insert(ifc).id().into();
+        self
+    }
+}
+
+impl IFCParse for CircleHollowProfileDef {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCIRCLEHOLLOWPROFILEDEF("),
+
+                profile_type: ProfileType::parse(),
+                _: Comma::parse(),
+                profile_name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                position: OptionalParameter::parse(),
+                _: Comma::parse(),
+                radius: IfcFloat::parse(),
+                _: Comma::parse(),
+                wall_thickness: IfcFloat::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for CircleHollowProfileDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCIRCLEHOLLOWPROFILEDEF({},{},{},{},{});",
+            self.profile_type, self.profile_name, self.position, self.radius, self.wall_thickness,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcCircleHollowProfileDef")]
+impl IfcType for CircleHollowProfileDef {
+    fn to_profile_def(&self) -> Option<&dyn ProfileDef> {
+        Some(self)
+    }
+}
+
+impl ProfileDef for CircleHollowProfileDef {
+    fn polygons(&self, ifc: &IFC) -> Vec<ProfilePolygon> {
+        let polygon = ProfilePolygon::new(circle(DVec2::ZERO, self.radius.0))
+            .with_void(circle(DVec2::ZERO, self.radius.0 - self.wall_thickness.0));
+
+        vec![polygon.transformed(position_transform(&self.position, ifc))]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::CircleHollowProfileDef;
+    use crate::parser::IFCParse;
+    use crate::{
+        geometry::{profile_def::ProfileDef, profile_type::ProfileType},
+        IFC,
+    };
+
+    #[test]
+    fn circle_hollow_profile_def_round_trip() {
+        let examples = ["IFCCIRCLEHOLLOWPROFILEDEF(.AREA.,'CHS 168.3x5',#58,0.08415,0.005);"];
+
+        for example in examples {
+            let parsed: CircleHollowProfileDef =
+                CircleHollowProfileDef::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn circle_hollow_profile_def_polygon() {
+        let ifc = IFC::default();
+        let polygons = CircleHollowProfileDef::new(ProfileType::Area, 0.5, 0.1).polygons(&ifc);
+
+        assert_eq!(polygons[0].inner.len(), 1);
+        assert!(polygons[0].inner[0]
+            .iter()
+            .all(|point| (point.length() - 0.4).abs() < 1e-9));
+
+        let full = std::f64::consts::PI * (0.5 * 0.5 - 0.4 * 0.4);
+        assert!((polygons[0].area() - full).abs() < 0.01);
+    }
+}
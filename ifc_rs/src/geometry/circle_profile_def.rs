@@ -0,0 +1,150 @@
+use std::fmt::Display;
+
+use glam::DVec2;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{
+        profile_def::{circle, position_transform, ProfileDef, ProfilePolygon},
+        profile_type::ProfileType,
+    },
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcCircleProfileDef defines a circle as the profile definition used by the swept surface
+/// geometry or by the swept area solid. It is given by its Radius attribute and placed within the
+/// 2D position coordinate system, established by the Position attribute.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcprofileresource/lexical/ifccircleprofiledef.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct CircleProfileDef {
+    /// Defines the type of geometry into which this profile definition shall be resolved, either a
+    /// curve or a surface area. In case of curve the profile should be referenced by a swept
+    /// surface, in case of area the profile should be referenced by a swept area solid.
+    pub profile_type: ProfileType,
+    /// Human-readable name of the profile, for example according to a standard profile table. As
+    /// noted above, machine-readable standardized profile designations should be provided in
+    /// IfcExternalReference.ItemReference.
+    pub profile_name: OptionalParameter<Label>,
+    /// Position coordinate system of the parameterized profile definition. If unspecified, no
+    /// translation and no rotation is applied.
+    #[ifc_types(Axis2D, Axis3D)]
+    pub position: OptionalParameter<Id>,
+    /// The radius of the circle.
+    pub radius: IfcFloat,
+}
+
+impl CircleProfileDef {
+    pub fn new(profile_type: ProfileType, radius: f64) -> Self {
+        Self {
+            profile_type,
+            profile_name: OptionalParameter::omitted(),
+            position: OptionalParameter::omitted(),
+            radius: radius.into(),
+        }
+    }
+
+    pub fn profile_name(mut self, name: impl Into<Label>) -> Self {
+        self.profile_name = name.into().into();
+        self
+    }
+
+    pub fn position<A: AxisPlacement>(
+        mut self,
+        position: impl Into<IdOr<A>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.position = position.into().or_insert(ifc).id().into();
+        self
+    }
+}
+
+impl IFCParse for CircleProfileDef {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCIRCLEPROFILEDEF("),
+
+                profile_type: ProfileType::parse(),
+                _: Comma::parse(),
+                profile_name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                position: OptionalParameter::parse(),
+                _: Comma::parse(),
+                radius: IfcFloat::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for CircleProfileDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCIRCLEPROFILEDEF({},{},{},{});",
+            self.profile_type, self.profile_name, self.position, self.radius,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcCircleProfileDef")]
+impl IfcType for CircleProfileDef {
+    fn to_profile_def(&self) -> Option<&dyn ProfileDef> {
+        Some(self)
+    }
+}
+
+impl ProfileDef for CircleProfileDef {
+    fn polygons(&self, ifc: &IFC) -> Vec<ProfilePolygon> {
+        let polygon = ProfilePolygon::new(circle(DVec2::ZERO, self.radius.0));
+
+        vec![polygon.transformed(position_transform(&self.position, ifc))]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::CircleProfileDef;
+    use crate::parser::IFCParse;
+    use crate::{
+        geometry::{profile_def::ProfileDef, profile_type::ProfileType},
+        IFC,
+    };
+
+    #[test]
+    fn circle_profile_def_round_trip() {
+        let examples = [
+            "IFCCIRCLEPROFILEDEF(.AREA.,'Round 200',#58,0.1);",
+            "IFCCIRCLEPROFILEDEF(.AREA.,$,$,0.25);",
+        ];
+
+        for example in examples {
+            let parsed: CircleProfileDef = CircleProfileDef::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn circle_profile_def_polygon() {
+        let ifc = IFC::default();
+        let polygons = CircleProfileDef::new(ProfileType::Area, 0.5).polygons(&ifc);
+
+        assert_eq!(polygons.len(), 1);
+        assert!(polygons[0]
+            .outer
+            .iter()
+            .all(|point| (point.length() - 0.5).abs() < 1e-9));
+        assert!(polygons[0].area() > 0.0);
+    }
+}
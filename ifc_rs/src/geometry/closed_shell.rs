@@ -0,0 +1,82 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::face::Face,
+    id::{IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{list::IfcList, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+};
+
+/// An IfcClosedShell is a shell of the dimensionality 2 which typically
+/// serves as a bound for a region in R3. A closed shell has no boundary, and
+/// has non-zero finite extent.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcclosedshell.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct ClosedShell {
+    /// Set of faces arcwise connected along their edges, constituting the
+    /// shell.
+    pub cfs_faces: IfcList<TypedId<Face>>,
+}
+
+impl ClosedShell {
+    pub fn new() -> Self {
+        Self {
+            cfs_faces: IfcList::empty(),
+        }
+    }
+
+    pub fn add_face(mut self, face: impl Into<IdOr<Face>>, ifc: &mut IFC) -> Self {
+        self.cfs_faces.0.push(face.into().or_insert(ifc));
+        self
+    }
+}
+
+impl Default for ClosedShell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IFCParse for ClosedShell {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCLOSEDSHELL("),
+
+                cfs_faces: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for ClosedShell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCCLOSEDSHELL({});", self.cfs_faces)
+    }
+}
+
+#[typetag::serde(name = "IfcClosedShell")]
+impl IfcType for ClosedShell {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::ClosedShell;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn closed_shell_round_trip() {
+        let example = "IFCCLOSEDSHELL((#110,#115,#120,#125));";
+
+        let parsed: ClosedShell = ClosedShell::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
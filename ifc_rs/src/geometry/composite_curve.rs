@@ -0,0 +1,315 @@
+use std::{fmt::Display, str::FromStr};
+
+use glam::DVec3;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::{
+    combinator::{alt, delimited},
+    Parser,
+};
+
+use crate::{
+    geometry::curve::{append_points, discretize_curve, Curve},
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        bool::IfcBool, comma::Comma, list::IfcList, p_space_or_comment,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// This type conveys the continuity properties of a composite curve or
+/// surface. The continuity referred to is that between the segments of the
+/// composite curve.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifctransitioncode.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
+pub enum TransitionCode {
+    /// The segments do not join. This is permitted only at the boundary of
+    /// the curve or surface to indicate that it is not closed.
+    #[strum(to_string = ".DISCONTINUOUS.")]
+    Discontinuous,
+
+    /// The segments join but no condition on their tangents is implied.
+    #[strum(to_string = ".CONTINUOUS.")]
+    Continuous,
+
+    /// The segments join and their tangent vectors or tangent planes are
+    /// parallel and have the same direction at the joint.
+    #[strum(to_string = ".CONTSAMEGRADIENT.")]
+    ContSameGradient,
+
+    /// For a curve, the segments join, their tangent vectors are parallel
+    /// and in the same direction and their curvatures are equal at the joint.
+    #[strum(to_string = ".CONTSAMEGRADIENTSAMECURVATURE.")]
+    ContSameGradientSameCurvature,
+}
+
+impl IFCParse for TransitionCode {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid TransitionCode")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+/// An IfcCompositeCurveSegment is a bounded curve together with transition
+/// information which is used to construct an IfcCompositeCurve.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccompositecurvesegment.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct CompositeCurveSegment {
+    /// The state of transition (i.e., geometric continuity from the last
+    /// point of this segment to the first point of the next segment) in a
+    /// composite curve.
+    pub transition: TransitionCode,
+    /// An indicator of whether or not the sense of the segment agrees with,
+    /// or opposes, that of the parent curve.
+    pub same_sense: IfcBool,
+    /// The bounded curve which defines the geometry of the segment.
+    #[ifc_types(PolyLine, IndexedPolyCurve, TrimmedCurve, CompositeCurve)]
+    pub parent_curve: Id,
+}
+
+impl CompositeCurveSegment {
+    pub fn new<C: Curve>(
+        transition: TransitionCode,
+        parent_curve: impl Into<IdOr<C>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            transition,
+            same_sense: IfcBool::True,
+            parent_curve: parent_curve.into().or_insert(ifc).id(),
+        }
+    }
+
+    pub fn same_sense(mut self, same_sense: bool) -> Self {
+        self.same_sense = same_sense.into();
+        self
+    }
+
+    /// Discretized parent curve, reversed if the sense doesn't agree.
+    pub fn discretize(&self, tolerance: f64, ifc: &IFC) -> Vec<DVec3> {
+        let mut points = discretize_curve(self.parent_curve, tolerance, ifc).unwrap_or_default();
+        if self.same_sense == IfcBool::False {
+            points.reverse();
+        }
+
+        points
+    }
+}
+
+impl IFCParse for CompositeCurveSegment {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCOMPOSITECURVESEGMENT("),
+
+                transition: TransitionCode::parse(),
+                _: Comma::parse(),
+                same_sense: IfcBool::parse(),
+                _: Comma::parse(),
+                parent_curve: Id::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for CompositeCurveSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCOMPOSITECURVESEGMENT({},{},{});",
+            self.transition, self.same_sense, self.parent_curve
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcCompositeCurveSegment")]
+impl IfcType for CompositeCurveSegment {}
+
+/// An IfcCompositeCurve is a continuous curve composed of curve segments.
+/// Each segment is defined by a bounded curve, the sense of the segments
+/// determines in which direction they are traversed.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccompositecurve.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct CompositeCurve {
+    /// The component bounded curves, their transitions and senses.
+    pub segments: IfcList<TypedId<CompositeCurveSegment>>,
+    /// Indication of whether the curve intersects itself or not; this is for
+    /// information only.
+    pub self_intersect: IfcBool,
+}
+
+impl CompositeCurve {
+    pub fn new() -> Self {
+        Self {
+            segments: IfcList::empty(),
+            self_intersect: IfcBool::False,
+        }
+    }
+
+    pub fn add_segment(
+        mut self,
+        segment: impl Into<IdOr<CompositeCurveSegment>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.segments.0.push(segment.into().or_insert(ifc));
+        self
+    }
+}
+
+impl Default for CompositeCurve {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IFCParse for CompositeCurve {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCOMPOSITECURVE("),
+
+                segments: IfcList::parse(),
+                _: Comma::parse(),
+                self_intersect: IfcBool::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for CompositeCurve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCOMPOSITECURVE({},{});",
+            self.segments, self.self_intersect
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcCompositeCurve")]
+impl IfcType for CompositeCurve {
+    fn to_curve(&self) -> Option<&dyn Curve> {
+        Some(self)
+    }
+}
+
+impl ShapeItem for CompositeCurve {}
+
+impl Curve for CompositeCurve {
+    fn discretize(&self, tolerance: f64, ifc: &IFC) -> Vec<DVec3> {
+        let mut points = Vec::new();
+
+        for segment in self.segments.iter() {
+            append_points(
+                &mut points,
+                ifc.data.get(*segment).discretize(tolerance, ifc),
+            );
+        }
+
+        points
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::{DVec2, DVec3};
+    use winnow::Parser;
+
+    use super::{CompositeCurve, CompositeCurveSegment, TransitionCode};
+    use crate::{
+        geometry::{
+            axis::Axis2D,
+            circle::Circle,
+            curve::Curve,
+            point::Point2D,
+            polyline::PolyLine,
+            trimmed_curve::{TrimmedCurve, TrimmingSelect},
+        },
+        parser::IFCParse,
+        IFC,
+    };
+
+    #[test]
+    fn composite_curve_round_trip() {
+        let example = "IFCCOMPOSITECURVE((#41,#43),.FALSE.);";
+
+        let parsed: CompositeCurve = CompositeCurve::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn composite_curve_segment_round_trip() {
+        let example = "IFCCOMPOSITECURVESEGMENT(.CONTINUOUS.,.TRUE.,#40);";
+
+        let parsed: CompositeCurveSegment = CompositeCurveSegment::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn composite_curve_discretization() {
+        let mut ifc = IFC::default();
+
+        // a semicircular arched opening: straight sides and bottom with an
+        // arc on top
+        let points: Vec<Point2D> = vec![
+            DVec2::new(1.0, 2.0).into(),
+            DVec2::new(1.0, 0.0).into(),
+            DVec2::new(-1.0, 0.0).into(),
+            DVec2::new(-1.0, 2.0).into(),
+        ];
+        let sides = PolyLine::from(points.into_iter(), &mut ifc);
+
+        let position = Axis2D::new(DVec2::new(0.0, 2.0).into(), &mut ifc);
+        let circle = Circle::new(position, 1.0, &mut ifc);
+        let arc = TrimmedCurve::new(
+            circle,
+            TrimmingSelect::Parameter(0.0.into()),
+            TrimmingSelect::Parameter(std::f64::consts::PI.into()),
+            &mut ifc,
+        );
+
+        // the arc runs counter-clockwise from the right to the left side,
+        // closing the outline needs it reversed
+        let composite = CompositeCurve::new()
+            .add_segment(
+                CompositeCurveSegment::new(TransitionCode::Continuous, sides, &mut ifc),
+                &mut ifc,
+            )
+            .add_segment(
+                CompositeCurveSegment::new(TransitionCode::Continuous, arc, &mut ifc)
+                    .same_sense(false),
+                &mut ifc,
+            );
+
+        let points = composite.discretize(0.01, &ifc);
+        assert!(points[0].distance(*points.last().unwrap()) < 1e-9);
+        assert_eq!(
+            points
+                .iter()
+                .filter(|point| point.distance(DVec3::new(-1.0, 2.0, 0.0)) < 1e-9)
+                .count(),
+            1
+        );
+        assert!(points
+            .iter()
+            .any(|point| point.distance(DVec3::new(0.0, 3.0, 0.0)) < 0.01));
+    }
+}
@@ -0,0 +1,176 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{
+        profile_def::{ProfileDef, ProfilePolygon},
+        profile_type::ProfileType,
+    },
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// The IfcCompositeProfileDef is the profile definition composed of
+/// multiple profile definitions, used by swept surfaces or swept area solids.
+/// The contained profiles are interpreted in the same coordinate system and
+/// may not intersect each other.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcprofileresource/lexical/ifccompositeprofiledef.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct CompositeProfileDef {
+    /// Defines the type of geometry into which this profile definition shall be resolved, either a
+    /// curve or a surface area. In case of curve the profile should be referenced by a swept
+    /// surface, in case of area the profile should be referenced by a swept area solid.
+    pub profile_type: ProfileType,
+    /// Human-readable name of the profile, for example according to a standard profile table.
+    pub profile_name: OptionalParameter<Label>,
+    /// The profiles which are used to define the composite profile, all of
+    /// them have the same profile type.
+    #[ifc_types(
+        ArbitraryClosedProfileDef,
+        ArbitraryProfileDefWithVoids,
+        CircleProfileDef,
+        CircleHollowProfileDef,
+        RectangleProfileDef,
+        RectangleHollowProfileDef,
+        IShapeProfileDef,
+        LShapeProfileDef,
+        TShapeProfileDef,
+        UShapeProfileDef,
+        CShapeProfileDef,
+        ZShapeProfileDef
+    )]
+    pub profiles: IfcList<Id>,
+    /// The label of the composite profile, e.g. a name of a built-up section.
+    pub label: OptionalParameter<Label>,
+}
+
+impl CompositeProfileDef {
+    pub fn new(profile_type: ProfileType) -> Self {
+        Self {
+            profile_type,
+            profile_name: OptionalParameter::omitted(),
+            profiles: IfcList::empty(),
+            label: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn profile_name(mut self, name: impl Into<Label>) -> Self {
+        self.profile_name = name.into().into();
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<Label>) -> Self {
+        self.label = label.into().into();
+        self
+    }
+
+    pub fn add_profile<P: ProfileDef>(
+        mut self,
+        profile: impl Into<IdOr<P>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.profiles.0.push(profile.into().or_insert(ifc).id());
+        self
+    }
+}
+
+impl IFCParse for CompositeProfileDef {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCOMPOSITEPROFILEDEF("),
+
+                profile_type: ProfileType::parse(),
+                _: Comma::parse(),
+                profile_name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                profiles: IfcList::parse(),
+                _: Comma::parse(),
+                label: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for CompositeProfileDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCOMPOSITEPROFILEDEF({},{},{},{});",
+            self.profile_type, self.profile_name, self.profiles, self.label,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcCompositeProfileDef")]
+impl IfcType for CompositeProfileDef {
+    fn to_profile_def(&self) -> Option<&dyn ProfileDef> {
+        Some(self)
+    }
+}
+
+impl ProfileDef for CompositeProfileDef {
+    fn polygons(&self, ifc: &IFC) -> Vec<ProfilePolygon> {
+        self.profiles
+            .iter()
+            .filter_map(|profile| ifc.data.get_untyped(*profile).to_profile_def())
+            .flat_map(|profile| profile.polygons(ifc))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::DVec2;
+    use winnow::Parser;
+
+    use super::CompositeProfileDef;
+    use crate::parser::IFCParse;
+    use crate::{
+        geometry::{
+            circle_profile_def::CircleProfileDef, profile_def::ProfileDef,
+            profile_type::ProfileType, rectangle_profile_def::RectangleProfileDef,
+        },
+        prelude::Axis2D,
+        IFC,
+    };
+
+    #[test]
+    fn composite_profile_def_round_trip() {
+        let example = "IFCCOMPOSITEPROFILEDEF(.AREA.,'Double Angle',(#1,#2),'2L');";
+
+        let parsed = CompositeProfileDef::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn composite_profile_def_polygons() {
+        let mut ifc = IFC::default();
+
+        let position = Axis2D::new(DVec2::new(2.0, 0.0).into(), &mut ifc);
+        let circle = CircleProfileDef::new(ProfileType::Area, 0.5).position(position, &mut ifc);
+        let rectangle = RectangleProfileDef::new(ProfileType::Area, 1.0, 2.0);
+
+        let profile = CompositeProfileDef::new(ProfileType::Area)
+            .add_profile(circle, &mut ifc)
+            .add_profile(rectangle, &mut ifc);
+        let polygons = profile.polygons(&ifc);
+
+        assert_eq!(polygons.len(), 2);
+        assert!(polygons[0]
+            .outer
+            .iter()
+            .all(|point| (point.distance(DVec2::new(2.0, 0.0)) - 0.5).abs() < 1e-9));
+        assert_eq!(polygons[1].area(), 2.0);
+    }
+}
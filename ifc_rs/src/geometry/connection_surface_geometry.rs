@@ -0,0 +1,101 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, optional::OptionalParameter, p_space_or_comment_surrounded, IFCParse,
+        IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcConnectionSurfaceGeometry is used to describe the geometric
+/// constraints that facilitate the physical connection of two objects at a
+/// surface or at a face with surface geometry associated, e.g. the face of a
+/// space bounded by a wall.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcconnectionsurfacegeometry.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct ConnectionSurfaceGeometry {
+    /// Surface at which related object is connected, given in the
+    /// coordinate system of the relating object.
+    #[ifc_types(CurveBoundedPlane, Plane)]
+    pub surface_on_relating_element: Id,
+    /// Surface at which the relating element is connected, given in the
+    /// coordinate system of the related object.
+    #[ifc_types(CurveBoundedPlane, Plane)]
+    pub surface_on_related_element: OptionalParameter<Id>,
+}
+
+impl ConnectionSurfaceGeometry {
+    pub fn new<S: IfcType>(surface_on_relating_element: impl Into<IdOr<S>>, ifc: &mut IFC) -> Self {
+        Self {
+            surface_on_relating_element: surface_on_relating_element.into().or_insert(ifc).id(),
+            surface_on_related_element: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn surface_on_related_element<S: IfcType>(
+        mut self,
+        surface: impl Into<IdOr<S>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.surface_on_related_element = surface.into().or_insert(ifc).id().into();
+        self
+    }
+}
+
+impl IFCParse for ConnectionSurfaceGeometry {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCONNECTIONSURFACEGEOMETRY("),
+
+                surface_on_relating_element: Id::parse(),
+                _: Comma::parse(),
+                surface_on_related_element: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for ConnectionSurfaceGeometry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCONNECTIONSURFACEGEOMETRY({},{});",
+            self.surface_on_relating_element, self.surface_on_related_element
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcConnectionSurfaceGeometry")]
+impl IfcType for ConnectionSurfaceGeometry {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::ConnectionSurfaceGeometry;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn connection_surface_geometry_round_trip() {
+        let examples = [
+            "IFCCONNECTIONSURFACEGEOMETRY(#76508,$);",
+            "IFCCONNECTIONSURFACEGEOMETRY(#76508,#76509);",
+        ];
+
+        for example in examples {
+            let parsed: ConnectionSurfaceGeometry =
+                ConnectionSurfaceGeometry::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use glam::{DAffine3, DVec3};
+
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::IfcType,
+    prelude::*,
+};
+
+/// Chord tolerance used when curves are evaluated without an explicit
+/// tolerance, e.g. for the boundaries of arbitrary profiles. Given in model
+/// length units.
+pub const DEFAULT_CHORD_TOLERANCE: f64 = 0.001;
+
+/// Curves which can be approximated by polylines, e.g. to draw them or to use
+/// them as boundaries of profiles.
+pub trait Curve: IfcType {
+    /// Approximates the curve by a polyline whose chords deviate at most
+    /// `tolerance` from the curve. Closed curves repeat their first point at
+    /// the end, two dimensional curves lie on the xy plane.
+    fn discretize(&self, tolerance: f64, ifc: &IFC) -> Vec<DVec3>;
+}
+
+/// Discretizes any curve given by its id, `None` if the entity isn't a known
+/// curve.
+pub fn discretize_curve(curve: Id, tolerance: f64, ifc: &IFC) -> Option<Vec<DVec3>> {
+    ifc.data
+        .get_untyped(curve)
+        .to_curve()
+        .map(|curve| curve.discretize(tolerance, ifc))
+}
+
+/// Number of chords needed to approximate an arc with the given radius and
+/// angle in radians, arcs are split at least every quarter circle.
+pub(crate) fn arc_segment_count(radius: f64, angle: f64, tolerance: f64) -> usize {
+    let max_angle = if tolerance < radius {
+        (2.0 * (1.0 - tolerance / radius).acos()).min(FRAC_PI_2)
+    } else {
+        FRAC_PI_2
+    };
+
+    ((angle.abs() / max_angle).ceil() as usize).max(1)
+}
+
+/// Circular arc from `start` through `middle` to `end`, including both end
+/// points. Collinear points result in straight segments.
+pub(crate) fn arc_through_points(
+    start: DVec3,
+    middle: DVec3,
+    end: DVec3,
+    tolerance: f64,
+) -> Vec<DVec3> {
+    let u = middle - start;
+    let v = end - start;
+    let normal = u.cross(v);
+
+    if normal.length_squared() <= f64::EPSILON * u.length_squared() * v.length_squared() {
+        return vec![start, middle, end];
+    }
+
+    let center = start
+        + (u.length_squared() * v - v.length_squared() * u).cross(normal)
+            / (2.0 * normal.length_squared());
+    let radius = start.distance(center);
+
+    let x_axis = (start - center) / radius;
+    let y_axis = normal.normalize().cross(x_axis);
+
+    let to_end = end - center;
+    let mut sweep = to_end.dot(y_axis).atan2(to_end.dot(x_axis));
+    if sweep <= 0.0 {
+        sweep += TAU;
+    }
+
+    let segments = arc_segment_count(radius, sweep, tolerance);
+    let mut points: Vec<DVec3> = (0..segments)
+        .map(|segment| {
+            let angle = sweep * segment as f64 / segments as f64;
+            center + radius * (angle.cos() * x_axis + angle.sin() * y_axis)
+        })
+        .collect();
+    points.push(end);
+
+    points
+}
+
+/// Arc of a conic with the given semi axes in the xy plane of `transform`,
+/// starting at the angle `start` and sweeping `sweep` radians, negative
+/// sweeps run clockwise. Both end points are included.
+pub(crate) fn conic_arc(
+    transform: DAffine3,
+    semi_axis1: f64,
+    semi_axis2: f64,
+    start: f64,
+    sweep: f64,
+    tolerance: f64,
+) -> Vec<DVec3> {
+    let segments = arc_segment_count(semi_axis1.max(semi_axis2), sweep, tolerance);
+
+    (0..=segments)
+        .map(|segment| {
+            let angle = start + sweep * segment as f64 / segments as f64;
+            transform.transform_point3(DVec3::new(
+                semi_axis1 * angle.cos(),
+                semi_axis2 * angle.sin(),
+                0.0,
+            ))
+        })
+        .collect()
+}
+
+/// Appends `other` to `points`, skipping its first point if it coincides
+/// with the last point of `points`.
+pub(crate) fn append_points(points: &mut Vec<DVec3>, other: impl IntoIterator<Item = DVec3>) {
+    let mut other = other.into_iter().peekable();
+
+    if let (Some(last), Some(first)) = (points.last(), other.peek()) {
+        if last.distance_squared(*first) <= f64::EPSILON {
+            other.next();
+        }
+    }
+
+    points.extend(other);
+}
+
+/// Coordinates of a `Point2D` or `Point3D` given by its id.
+pub(crate) fn point_coordinates(point: Id, ifc: &IFC) -> DVec3 {
+    let point = ifc.data.get_untyped(point);
+
+    if let Some(point) = point.downcast_ref::<Point2D>() {
+        PointType::D2(point).to_dvec3()
+    } else if let Some(point) = point.downcast_ref::<Point3D>() {
+        PointType::D3(point).to_dvec3()
+    } else {
+        DVec3::ZERO
+    }
+}
+
+/// Normalized direction of a `Direction2D` or `Direction3D` given by its
+/// id.
+pub(crate) fn direction_coordinates(direction: Id, ifc: &IFC) -> DVec3 {
+    let direction = ifc.data.get_untyped(direction);
+
+    if let Some(direction) = direction.downcast_ref::<Direction2D>() {
+        direction.extend(0.0).normalize()
+    } else if let Some(direction) = direction.downcast_ref::<Direction3D>() {
+        direction.normalize()
+    } else {
+        DVec3::X
+    }
+}
+
+/// Factor converting plane angles of the model into radians. The plane
+/// angle unit is looked up in the unit assignment of the project, models
+/// using degrees define it by a conversion based unit. Radians are assumed
+/// if the project assigns no plane angle unit.
+pub(crate) fn plane_angle_factor(ifc: &IFC) -> f64 {
+    ifc.data
+        .find_all_of_type::<Project>()
+        .filter_map(|(_, project)| project.units_in_context.custom())
+        .flat_map(|unit_assignment| ifc.data.get(*unit_assignment).units.iter())
+        .filter_map(|unit| {
+            ifc.data
+                .get_untyped(*unit)
+                .downcast_ref::<ConversionBasedUnit>()
+        })
+        .find(|unit| matches!(unit.unit_type.custom(), Some(IfcUnitEnum::PlaneAngleUnit)))
+        .and_then(|unit| unit.conversion_factor.custom())
+        .map(|factor| {
+            let factor = match factor {
+                IdOr::Id(id) => ifc.data.get(*id),
+                IdOr::Custom(factor) => factor,
+            };
+
+            match &factor.value {
+                IdOr::Id(id) => ifc.data.get(*id).value.0,
+                IdOr::Custom(measure) => measure.value.0,
+            }
+        })
+        .unwrap_or(1.0)
+}
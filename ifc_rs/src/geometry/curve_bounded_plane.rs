@@ -0,0 +1,136 @@
+use std::fmt::Display;
+
+use glam::DVec3;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, list::IfcList, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+};
+
+/// The curve bounded planar surface is a planar surface bounded by an outer
+/// boundary and optional inner boundaries. The boundaries are curves lying
+/// within the two dimensional parameter space of the basis plane.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccurveboundedplane.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct CurveBoundedPlane {
+    /// The surface to be bound.
+    pub basis_surface: TypedId<Plane>,
+    /// The outer boundary of the surface.
+    #[ifc_types(
+        PolyLine,
+        IndexedPolyCurve,
+        CompositeCurve,
+        Circle,
+        Ellipse,
+        TrimmedCurve
+    )]
+    pub outer_boundary: Id,
+    /// An optional set of inner boundaries. They shall not intersect each
+    /// other or the outer boundary.
+    #[ifc_types(
+        PolyLine,
+        IndexedPolyCurve,
+        CompositeCurve,
+        Circle,
+        Ellipse,
+        TrimmedCurve
+    )]
+    pub inner_boundaries: IfcList<Id>,
+}
+
+impl CurveBoundedPlane {
+    pub fn new<C: Curve>(
+        basis_surface: impl Into<IdOr<Plane>>,
+        outer_boundary: impl Into<IdOr<C>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            basis_surface: basis_surface.into().or_insert(ifc),
+            outer_boundary: outer_boundary.into().or_insert(ifc).id(),
+            inner_boundaries: IfcList::empty(),
+        }
+    }
+
+    pub fn add_inner_boundary<C: Curve>(
+        mut self,
+        inner_boundary: impl Into<IdOr<C>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.inner_boundaries
+            .0
+            .push(inner_boundary.into().or_insert(ifc).id());
+        self
+    }
+
+    /// Points of the outer boundary within the coordinate system the basis
+    /// plane is positioned in. The first point is not repeated at the end.
+    pub fn outer_points(&self, ifc: &IFC) -> Vec<DVec3> {
+        let transform =
+            axis_placement_transform(ifc.data.get(self.basis_surface).position.id(), ifc);
+
+        let mut points: Vec<DVec3> =
+            discretize_curve(self.outer_boundary, DEFAULT_CHORD_TOLERANCE, ifc)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|point| transform.transform_point3(point))
+                .collect();
+
+        if points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+
+        points
+    }
+}
+
+impl IFCParse for CurveBoundedPlane {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCURVEBOUNDEDPLANE("),
+
+                basis_surface: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                outer_boundary: Id::parse(),
+                _: Comma::parse(),
+                inner_boundaries: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for CurveBoundedPlane {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCURVEBOUNDEDPLANE({},{},{});",
+            self.basis_surface, self.outer_boundary, self.inner_boundaries
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcCurveBoundedPlane")]
+impl IfcType for CurveBoundedPlane {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::CurveBoundedPlane;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn curve_bounded_plane_round_trip() {
+        let example = "IFCCURVEBOUNDEDPLANE(#76490,#76505,());";
+
+        let parsed: CurveBoundedPlane = CurveBoundedPlane::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
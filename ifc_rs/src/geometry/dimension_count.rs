@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use optional::OptionalParameter;
 use place_holder::Inherited;
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, VariantNames};
 use winnow::ascii::dec_int;
 use winnow::combinator::{alt, delimited};
@@ -11,13 +12,26 @@ use crate::parser::*;
 
 /// The IfcDimensionCount is restricted to have the dimensionality of either 1, 2, or 3
 /// - the WR1 had been added as an addition to the STEP P42 entity dimension_count.
+///
 /// In contrary to the STEP P42 constraint, that all geometric representation items
 /// within a geometric representation context are forced to have the same dimension count,
 /// the IFC geometry allows mixed dimensions, particularly when defining the boundary of planar surfaces.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcgeometryresource/lexical/ifcdimensioncount.htm
 #[derive(
-    Debug, EnumString, VariantNames, Display, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord,
+    Debug,
+    EnumString,
+    VariantNames,
+    Display,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
 )]
 pub enum DimensionCount {
     #[default]
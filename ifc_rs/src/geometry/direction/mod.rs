@@ -1,8 +1,11 @@
 mod deserialize;
 mod serialize;
 
+use std::ops::Deref;
+
 use glam::{DVec2, DVec3};
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     ifc_type::{IfcType, IfcVerify},
@@ -17,7 +20,7 @@ use crate::{
 /// The components in the direction of X axis (DirectionRatios[1]), of Y axis (DirectionRatios[2])
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcdirection.htm
-#[derive(Debug, Clone, Copy, PartialEq, IfcVerify)]
+#[derive(Debug, Clone, Copy, PartialEq, IfcVerify, Serialize, Deserialize)]
 pub struct Direction2D(IfcDVec2);
 
 impl From<DVec2> for Direction2D {
@@ -26,6 +29,15 @@ impl From<DVec2> for Direction2D {
     }
 }
 
+impl Deref for Direction2D {
+    type Target = IfcDVec2;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[typetag::serde(name = "IfcDirection2D")]
 impl IfcType for Direction2D {}
 
 /// The IfcDirection provides a direction in two or three dimensional space depending on the number
@@ -36,7 +48,7 @@ impl IfcType for Direction2D {}
 /// and of Z axis (DirectionRatios[3])
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcdirection.htm
-#[derive(Debug, Clone, Copy, PartialEq, IfcVerify)]
+#[derive(Debug, Clone, Copy, PartialEq, IfcVerify, Serialize, Deserialize)]
 pub struct Direction3D(IfcDVec3);
 
 impl From<DVec3> for Direction3D {
@@ -45,4 +57,13 @@ impl From<DVec3> for Direction3D {
     }
 }
 
+impl Deref for Direction3D {
+    type Target = IfcDVec3;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[typetag::serde(name = "IfcDirection3D")]
 impl IfcType for Direction3D {}
@@ -0,0 +1,167 @@
+use std::{f64::consts::TAU, fmt::Display};
+
+use glam::DVec3;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{
+        axis::axis_placement_transform,
+        curve::{conic_arc, Curve},
+    },
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// An IfcEllipse is a curve consisting of a set of points whose distances to
+/// two fixed points add to the same constant. The ellipse is parameterized
+/// as `Position + SemiAxis1 * cos(t) * x + SemiAxis2 * sin(t) * y`.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcellipse.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Ellipse {
+    /// The location and orientation of the ellipse, the center is at the
+    /// location of the placement.
+    #[ifc_types(Axis2D, Axis3D)]
+    pub position: Id,
+    /// The first radius of the ellipse, measured along the x axis of the
+    /// position.
+    pub semi_axis1: IfcFloat,
+    /// The second radius of the ellipse, measured along the y axis of the
+    /// position.
+    pub semi_axis2: IfcFloat,
+}
+
+impl Ellipse {
+    pub fn new<A: AxisPlacement>(
+        position: impl Into<IdOr<A>>,
+        semi_axis1: f64,
+        semi_axis2: f64,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            position: position.into().or_insert(ifc).id(),
+            semi_axis1: semi_axis1.into(),
+            semi_axis2: semi_axis2.into(),
+        }
+    }
+
+    /// Point of the ellipse at the given parameter angle in radians.
+    pub fn point_at(&self, angle: f64, ifc: &IFC) -> DVec3 {
+        axis_placement_transform(self.position, ifc).transform_point3(DVec3::new(
+            self.semi_axis1.0 * angle.cos(),
+            self.semi_axis2.0 * angle.sin(),
+            0.0,
+        ))
+    }
+
+    /// Parameter angle in radians of the given point projected onto the
+    /// ellipse.
+    pub fn angle_of(&self, point: DVec3, ifc: &IFC) -> f64 {
+        let local = axis_placement_transform(self.position, ifc)
+            .inverse()
+            .transform_point3(point);
+
+        (local.y / self.semi_axis2.0).atan2(local.x / self.semi_axis1.0)
+    }
+
+    /// Arc from the parameter angle `start` sweeping `sweep` radians,
+    /// negative sweeps run clockwise.
+    pub fn arc(&self, start: f64, sweep: f64, tolerance: f64, ifc: &IFC) -> Vec<DVec3> {
+        conic_arc(
+            axis_placement_transform(self.position, ifc),
+            self.semi_axis1.0,
+            self.semi_axis2.0,
+            start,
+            sweep,
+            tolerance,
+        )
+    }
+}
+
+impl IFCParse for Ellipse {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCELLIPSE("),
+
+                position: Id::parse(),
+                _: Comma::parse(),
+                semi_axis1: IfcFloat::parse(),
+                _: Comma::parse(),
+                semi_axis2: IfcFloat::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for Ellipse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCELLIPSE({},{},{});",
+            self.position, self.semi_axis1, self.semi_axis2
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcEllipse")]
+impl IfcType for Ellipse {
+    fn to_curve(&self) -> Option<&dyn Curve> {
+        Some(self)
+    }
+}
+
+impl ShapeItem for Ellipse {}
+
+impl Curve for Ellipse {
+    fn discretize(&self, tolerance: f64, ifc: &IFC) -> Vec<DVec3> {
+        let mut points = self.arc(0.0, TAU, tolerance, ifc);
+        if let (Some(first), Some(last)) = (points.first().copied(), points.last_mut()) {
+            *last = first;
+        }
+
+        points
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::DVec2;
+    use winnow::Parser;
+
+    use super::Ellipse;
+    use crate::{
+        geometry::{axis::Axis2D, curve::Curve},
+        parser::IFCParse,
+        IFC,
+    };
+
+    #[test]
+    fn ellipse_round_trip() {
+        let example = "IFCELLIPSE(#36,3.,1.5);";
+
+        let parsed: Ellipse = Ellipse::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn ellipse_discretization() {
+        let mut ifc = IFC::default();
+
+        let position = Axis2D::new(DVec2::ZERO.into(), &mut ifc);
+        let ellipse = Ellipse::new(position, 3.0, 1.5, &mut ifc);
+
+        let points = ellipse.discretize(0.01, &ifc);
+        assert_eq!(points.first(), points.last());
+        assert!(points.iter().all(|point| {
+            ((point.x / 3.0).powi(2) + (point.y / 1.5).powi(2) - 1.0).abs() < 1e-9
+        }));
+    }
+}
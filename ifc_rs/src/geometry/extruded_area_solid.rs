@@ -1,4 +1,4 @@
-use crate::geometry::rectangle_profile_def::ProfileDef;
+use crate::geometry::profile_def::ProfileDef;
 use crate::id::{IdOr, TypedId};
 use crate::ifc_type::{IfcType, IfcVerify};
 use crate::prelude::*;
@@ -8,6 +8,7 @@ use comma::Comma;
 use ifc_float::IfcFloat;
 use ifc_rs_verify_derive::IfcVerify;
 use optional::OptionalParameter;
+use serde::{Deserialize, Serialize};
 
 use std::fmt::Display;
 
@@ -21,11 +22,25 @@ use super::shape_representation::ShapeItem;
 /// into holes of the solid.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcextrudedareasolid.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct ExtrudedAreaSolid {
     /// The surface defining the area to be swept. It is given as a
     /// profile definition within the xy plane of the position coordinate system.
-    #[ifc_types(RectangleProfileDef, ArbitraryClosedProfileDef<C: Curve>)]
+    #[ifc_types(
+        ArbitraryClosedProfileDef,
+        ArbitraryProfileDefWithVoids,
+        CircleProfileDef,
+        CircleHollowProfileDef,
+        CompositeProfileDef,
+        RectangleProfileDef,
+        RectangleHollowProfileDef,
+        IShapeProfileDef,
+        LShapeProfileDef,
+        TShapeProfileDef,
+        UShapeProfileDef,
+        CShapeProfileDef,
+        ZShapeProfileDef
+    )]
     pub swept_area: Id,
 
     /// Position coordinate system for the resulting swept solid of the sweeping
@@ -93,6 +108,7 @@ impl Display for ExtrudedAreaSolid {
     }
 }
 
+#[typetag::serde(name = "IfcExtrudedAreaSolid")]
 impl IfcType for ExtrudedAreaSolid {}
 impl ShapeItem for ExtrudedAreaSolid {}
 
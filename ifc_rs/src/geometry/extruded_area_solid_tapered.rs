@@ -0,0 +1,159 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::profile_def::ProfileDef,
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// The IfcExtrudedAreaSolidTapered is defined by sweeping a cross section
+/// provided by a profile definition between two profiles. The end profile
+/// is placed at the distance Depth along the extruded direction and shall be
+/// of the same type as the start profile, e.g. a derived profile scaling the
+/// start profile.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcextrudedareasolidtapered.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct ExtrudedAreaSolidTapered {
+    /// The surface defining the area to be swept. It is given as a
+    /// profile definition within the xy plane of the position coordinate system.
+    #[ifc_types(
+        ArbitraryClosedProfileDef,
+        ArbitraryProfileDefWithVoids,
+        CircleProfileDef,
+        CircleHollowProfileDef,
+        CompositeProfileDef,
+        RectangleProfileDef,
+        RectangleHollowProfileDef,
+        IShapeProfileDef,
+        LShapeProfileDef,
+        TShapeProfileDef,
+        UShapeProfileDef,
+        CShapeProfileDef,
+        ZShapeProfileDef
+    )]
+    pub swept_area: Id,
+
+    /// Position coordinate system for the resulting swept solid of the sweeping
+    /// operation. The position coordinate system allows for re-positioning of
+    /// the swept solid. If not provided, the swept solid remains within the
+    /// position as determined by the cross section or by the directrix used
+    /// for the sweeping operation.
+    pub position: OptionalParameter<TypedId<Axis3D>>,
+
+    /// The direction in which the surface, provided by SweptArea is to be swept.
+    pub extruded_direction: TypedId<Direction3D>,
+
+    /// The distance the surface is to be swept along the ExtrudedDirection.
+    pub depth: IfcFloat,
+
+    /// The surface defining the end of the swept area, it is given within the
+    /// xy plane of the position coordinate system moved by the depth along the
+    /// extruded direction.
+    #[ifc_types(
+        ArbitraryClosedProfileDef,
+        ArbitraryProfileDefWithVoids,
+        CircleProfileDef,
+        CircleHollowProfileDef,
+        CompositeProfileDef,
+        RectangleProfileDef,
+        RectangleHollowProfileDef,
+        IShapeProfileDef,
+        LShapeProfileDef,
+        TShapeProfileDef,
+        UShapeProfileDef,
+        CShapeProfileDef,
+        ZShapeProfileDef
+    )]
+    pub end_swept_area: Id,
+}
+
+impl ExtrudedAreaSolidTapered {
+    pub fn new<P: ProfileDef>(
+        swept_area: impl Into<IdOr<P>>,
+        end_swept_area: impl Into<IdOr<P>>,
+        extruded_direction: impl Into<IdOr<Direction3D>>,
+        depth: f64,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            swept_area: swept_area.into().or_insert(ifc).id(),
+            position: OptionalParameter::omitted(),
+            extruded_direction: extruded_direction.into().or_insert(ifc),
+            depth: depth.into(),
+            end_swept_area: end_swept_area.into().or_insert(ifc).id(),
+        }
+    }
+
+    pub fn position(mut self, position: impl Into<IdOr<Axis3D>>, ifc: &mut IFC) -> Self {
+        self.position = position.into().or_insert(ifc).into();
+        self
+    }
+}
+
+impl IFCParse for ExtrudedAreaSolidTapered {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCEXTRUDEDAREASOLIDTAPERED("),
+
+                swept_area: Id::parse(),
+                _: Comma::parse(),
+                position: OptionalParameter::parse(),
+                _: Comma::parse(),
+                extruded_direction: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                depth: IfcFloat::parse(),
+                _: Comma::parse(),
+                end_swept_area: Id::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for ExtrudedAreaSolidTapered {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCEXTRUDEDAREASOLIDTAPERED({},{},{},{},{});",
+            self.swept_area,
+            self.position,
+            self.extruded_direction,
+            self.depth,
+            self.end_swept_area,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcExtrudedAreaSolidTapered")]
+impl IfcType for ExtrudedAreaSolidTapered {}
+impl ShapeItem for ExtrudedAreaSolidTapered {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::ExtrudedAreaSolidTapered;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn extruded_area_solid_tapered_round_trip() {
+        let examples = ["IFCEXTRUDEDAREASOLIDTAPERED(#38,#42,#21,3.,#40);"];
+
+        for example in examples {
+            let parsed: ExtrudedAreaSolidTapered =
+                ExtrudedAreaSolidTapered::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::poly_loop::PolyLoop,
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        bool::IfcBool, comma::Comma, list::IfcList, p_space_or_comment_surrounded, IFCParse,
+        IFCParser,
+    },
+    prelude::*,
+};
+
+/// An IfcFaceBound is a loop which is intended to be used for bounding a
+/// face. The orientation indicates whether the loop is used in its original
+/// direction or reversed.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcfacebound.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct FaceBound {
+    /// The loop which will be used as a face boundary.
+    pub bound: TypedId<PolyLoop>,
+    /// This indicated whether (TRUE) or not (FALSE) the loop has the same
+    /// sense when used to bound the face as when first defined.
+    pub orientation: IfcBool,
+}
+
+impl FaceBound {
+    pub fn new(bound: impl Into<IdOr<PolyLoop>>, ifc: &mut IFC) -> Self {
+        Self {
+            bound: bound.into().or_insert(ifc),
+            orientation: IfcBool::True,
+        }
+    }
+
+    pub fn orientation(mut self, orientation: bool) -> Self {
+        self.orientation = orientation.into();
+        self
+    }
+}
+
+impl IFCParse for FaceBound {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCFACEBOUND("),
+
+                bound: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                orientation: IfcBool::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for FaceBound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCFACEBOUND({},{});", self.bound, self.orientation)
+    }
+}
+
+#[typetag::serde(name = "IfcFaceBound")]
+impl IfcType for FaceBound {}
+
+/// The IfcFaceOuterBound is a special subtype of IfcFaceBound which carries
+/// the additional semantics of defining an outer boundary on the face. No
+/// more than one boundary of a face shall be of this type.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcfaceouterbound.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct FaceOuterBound {
+    /// The loop which will be used as the outer face boundary.
+    pub bound: TypedId<PolyLoop>,
+    /// This indicated whether (TRUE) or not (FALSE) the loop has the same
+    /// sense when used to bound the face as when first defined.
+    pub orientation: IfcBool,
+}
+
+impl FaceOuterBound {
+    pub fn new(bound: impl Into<IdOr<PolyLoop>>, ifc: &mut IFC) -> Self {
+        Self {
+            bound: bound.into().or_insert(ifc),
+            orientation: IfcBool::True,
+        }
+    }
+
+    pub fn orientation(mut self, orientation: bool) -> Self {
+        self.orientation = orientation.into();
+        self
+    }
+}
+
+impl IFCParse for FaceOuterBound {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCFACEOUTERBOUND("),
+
+                bound: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                orientation: IfcBool::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for FaceOuterBound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCFACEOUTERBOUND({},{});", self.bound, self.orientation)
+    }
+}
+
+#[typetag::serde(name = "IfcFaceOuterBound")]
+impl IfcType for FaceOuterBound {}
+
+/// An IfcFace is topological entity of dimensionality 2 corresponding to the
+/// intuitive notion of a piece of surface bounded by loops. Its domain, if
+/// present, is an oriented, connected, finite 2-manifold in Rm. A face
+/// domain shall not have handles but it may have holes, each hole bounded
+/// by a loop.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcface.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Face {
+    /// Boundaries of the face, at most one of them is a `FaceOuterBound`.
+    #[ifc_types(FaceBound, FaceOuterBound)]
+    pub bounds: IfcList<Id>,
+}
+
+impl Face {
+    /// Face bounded by the given outer loop.
+    pub fn new(outer: impl Into<IdOr<PolyLoop>>, ifc: &mut IFC) -> Self {
+        let outer = FaceOuterBound::new(outer, ifc);
+
+        Self {
+            bounds: IfcList(vec![ifc.data.insert_new(outer).id()]),
+        }
+    }
+
+    /// Adds an inner boundary, i.e. a hole of the face.
+    pub fn add_inner_bound(mut self, inner: impl Into<IdOr<PolyLoop>>, ifc: &mut IFC) -> Self {
+        let inner = FaceBound::new(inner, ifc);
+        self.bounds.0.push(ifc.data.insert_new(inner).id());
+        self
+    }
+}
+
+impl IFCParse for Face {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCFACE("),
+
+                bounds: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for Face {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCFACE({});", self.bounds)
+    }
+}
+
+#[typetag::serde(name = "IfcFace")]
+impl IfcType for Face {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::{Face, FaceBound, FaceOuterBound};
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn face_round_trip() {
+        let example = "IFCFACE((#105,#108));";
+
+        let parsed: Face = Face::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn face_bound_round_trip() {
+        let example = "IFCFACEBOUND(#107,.FALSE.);";
+        let parsed: FaceBound = FaceBound::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+
+        let example = "IFCFACEOUTERBOUND(#104,.TRUE.);";
+        let parsed: FaceOuterBound = FaceOuterBound::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
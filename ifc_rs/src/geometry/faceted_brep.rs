@@ -0,0 +1,116 @@
+use std::fmt::Display;
+
+use glam::DVec3;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{closed_shell::ClosedShell, face::Face, poly_loop::PolyLoop},
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+};
+
+/// The IfcFacetedBrep is a manifold solid brep with the restriction that all
+/// faces are planar and bounded by polygons.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcfacetedbrep.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct FacetedBrep {
+    /// A closed shell defining the exterior boundary of the solid. The shell
+    /// normal shall point away from the interior of the solid.
+    pub outer: TypedId<ClosedShell>,
+}
+
+impl FacetedBrep {
+    pub fn new(outer: impl Into<IdOr<ClosedShell>>, ifc: &mut IFC) -> Self {
+        Self {
+            outer: outer.into().or_insert(ifc),
+        }
+    }
+
+    /// Builds the brep of a closed mesh. Faces are given by zero based
+    /// indices into `vertices` and are ordered counter-clockwise when seen
+    /// from outside.
+    pub fn from_mesh(
+        vertices: impl IntoIterator<Item = DVec3>,
+        faces: impl IntoIterator<Item = impl IntoIterator<Item = usize>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        let points: Vec<TypedId<Point3D>> = vertices
+            .into_iter()
+            .map(|vertex| ifc.data.insert_new(Point3D::from(vertex)))
+            .collect();
+
+        let mut shell = ClosedShell::new();
+        for face in faces {
+            let poly_loop = PolyLoop::new(face.into_iter().map(|index| points[index]));
+            let face = Face::new(poly_loop, ifc);
+            shell = shell.add_face(face, ifc);
+        }
+
+        Self::new(shell, ifc)
+    }
+}
+
+impl IFCParse for FacetedBrep {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCFACETEDBREP("),
+
+                outer: Id::parse().map(TypedId::new),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for FacetedBrep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCFACETEDBREP({});", self.outer)
+    }
+}
+
+#[typetag::serde(name = "IfcFacetedBrep")]
+impl IfcType for FacetedBrep {}
+impl ShapeItem for FacetedBrep {}
+
+#[cfg(test)]
+mod test {
+    use glam::DVec3;
+    use winnow::Parser;
+
+    use super::FacetedBrep;
+    use crate::{parser::IFCParse, IFC};
+
+    #[test]
+    fn faceted_brep_round_trip() {
+        let example = "IFCFACETEDBREP(#130);";
+
+        let parsed: FacetedBrep = FacetedBrep::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn faceted_brep_from_mesh() {
+        let mut ifc = IFC::default();
+
+        let vertices = [DVec3::ZERO, DVec3::X, DVec3::Y, DVec3::Z];
+        let faces = [[0, 2, 1], [0, 1, 3], [1, 2, 3], [2, 0, 3]];
+
+        let brep = FacetedBrep::from_mesh(vertices, faces, &mut ifc);
+        let shell = ifc.data.get(brep.outer);
+        assert_eq!(shell.cfs_faces.0.len(), 4);
+
+        // vertices are shared between the faces
+        assert_eq!(
+            ifc.data
+                .find_all_of_type::<crate::geometry::point::Point3D>()
+                .count(),
+            4
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{curve::Curve, profile_def::ProfileDef},
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// The IfcFixedReferenceSweptAreaSolid is the result of sweeping a profile
+/// along a directrix. The orientation of the profile is given by a fixed
+/// reference direction whose projection onto the normal plane of the
+/// directrix defines the x axis of the profile.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcfixedreferencesweptareasolid.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct FixedReferenceSweptAreaSolid {
+    /// The surface defining the area to be swept. It is given as a
+    /// profile definition within the xy plane of the position coordinate system.
+    #[ifc_types(
+        ArbitraryClosedProfileDef,
+        ArbitraryProfileDefWithVoids,
+        CircleProfileDef,
+        CircleHollowProfileDef,
+        CompositeProfileDef,
+        RectangleProfileDef,
+        RectangleHollowProfileDef,
+        IShapeProfileDef,
+        LShapeProfileDef,
+        TShapeProfileDef,
+        UShapeProfileDef,
+        CShapeProfileDef,
+        ZShapeProfileDef
+    )]
+    pub swept_area: Id,
+
+    /// Position coordinate system for the resulting swept solid of the sweeping
+    /// operation. The position coordinate system allows for re-positioning of
+    /// the swept solid. If not provided, the swept solid remains within the
+    /// position as determined by the cross section or by the directrix used
+    /// for the sweeping operation.
+    pub position: OptionalParameter<TypedId<Axis3D>>,
+
+    /// The curve used to define the sweeping operation. The solid is generated
+    /// by sweeping the SweptArea along the directrix.
+    #[ifc_types(
+        PolyLine,
+        IndexedPolyCurve,
+        TrimmedCurve,
+        CompositeCurve,
+        Circle,
+        Ellipse,
+        Line
+    )]
+    pub directrix: Id,
+
+    /// The parameter value on the directrix at which the sweeping operation
+    /// commences. If no value is provided the start of the sweeping operation
+    /// is at the start of the directrix.
+    pub start_param: OptionalParameter<IfcFloat>,
+
+    /// The parameter value on the directrix at which the sweeping operation
+    /// ends. If no value is provided the end of the sweeping operation is at
+    /// the end of the directrix.
+    pub end_param: OptionalParameter<IfcFloat>,
+
+    /// The direction providing the orientation of the profile x axis along
+    /// the directrix.
+    pub fixed_reference: TypedId<Direction3D>,
+}
+
+impl FixedReferenceSweptAreaSolid {
+    pub fn new<P: ProfileDef, C: Curve>(
+        swept_area: impl Into<IdOr<P>>,
+        directrix: impl Into<IdOr<C>>,
+        fixed_reference: impl Into<IdOr<Direction3D>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            swept_area: swept_area.into().or_insert(ifc).id(),
+            position: OptionalParameter::omitted(),
+            directrix: directrix.into().or_insert(ifc).id(),
+            start_param: OptionalParameter::omitted(),
+            end_param: OptionalParameter::omitted(),
+            fixed_reference: fixed_reference.into().or_insert(ifc),
+        }
+    }
+
+    pub fn position(mut self, position: impl Into<IdOr<Axis3D>>, ifc: &mut IFC) -> Self {
+        self.position = position.into().or_insert(ifc).into();
+        self
+    }
+
+    pub fn start_param(mut self, start_param: f64) -> Self {
+        self.start_param = IfcFloat(start_param).into();
+        self
+    }
+
+    pub fn end_param(mut self, end_param: f64) -> Self {
+        self.end_param = IfcFloat(end_param).into();
+        self
+    }
+}
+
+impl IFCParse for FixedReferenceSweptAreaSolid {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCFIXEDREFERENCESWEPTAREASOLID("),
+
+                swept_area: Id::parse(),
+                _: Comma::parse(),
+                position: OptionalParameter::parse(),
+                _: Comma::parse(),
+                directrix: Id::parse(),
+                _: Comma::parse(),
+                start_param: OptionalParameter::parse(),
+                _: Comma::parse(),
+                end_param: OptionalParameter::parse(),
+                _: Comma::parse(),
+                fixed_reference: Id::parse().map(TypedId::new),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for FixedReferenceSweptAreaSolid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCFIXEDREFERENCESWEPTAREASOLID({},{},{},{},{},{});",
+            self.swept_area,
+            self.position,
+            self.directrix,
+            self.start_param,
+            self.end_param,
+            self.fixed_reference,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcFixedReferenceSweptAreaSolid")]
+impl IfcType for FixedReferenceSweptAreaSolid {}
+impl ShapeItem for FixedReferenceSweptAreaSolid {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::FixedReferenceSweptAreaSolid;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn fixed_reference_swept_area_solid_round_trip() {
+        let examples = [
+            "IFCFIXEDREFERENCESWEPTAREASOLID(#60,#61,#70,$,$,#80);",
+            "IFCFIXEDREFERENCESWEPTAREASOLID(#60,$,#70,0.,1.,#80);",
+        ];
+
+        for example in examples {
+            let parsed: FixedReferenceSweptAreaSolid = FixedReferenceSweptAreaSolid::parse()
+                .parse(example)
+                .unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+}
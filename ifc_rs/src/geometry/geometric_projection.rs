@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, VariantNames};
 use winnow::combinator::{alt, delimited};
 use winnow::Parser;
@@ -11,7 +12,7 @@ use crate::parser::*;
 /// of the shape representation are controlled by the representation type.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcrepresentationresource/lexical/ifcgeometricprojectionenum.htm
-#[derive(Debug, EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(Debug, EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
 pub enum GeometricProjection {
     /// Geometric display representation that shows an abstract, often 1D element
     /// representation, e.g. representing a wall by its axis line.
@@ -0,0 +1,119 @@
+use std::fmt::Display;
+
+use glam::DVec3;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::curve::Curve,
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        bool::IfcBool, comma::Comma, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// An individual axis, the IfcGridAxis, is defined in the context of a
+/// design grid. The axis definition is based on a curve of dimensionality
+/// 2 given in the xy plane of the grid.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcgridaxis.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct GridAxis {
+    /// The tag or name for this grid axis.
+    pub axis_tag: OptionalParameter<Label>,
+    /// Underlying curve which provides the geometry for this grid axis.
+    #[ifc_types(PolyLine, IndexedPolyCurve, Line, Circle, TrimmedCurve, CompositeCurve)]
+    pub axis_curve: Id,
+    /// Defines whether the original sense of curve is used or whether it is
+    /// reversed in the context of the grid axis.
+    pub same_sense: IfcBool,
+}
+
+impl GridAxis {
+    pub fn new<C: Curve>(
+        axis_tag: impl Into<Label>,
+        axis_curve: impl Into<IdOr<C>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            axis_tag: axis_tag.into().into(),
+            axis_curve: axis_curve.into().or_insert(ifc).id(),
+            same_sense: true.into(),
+        }
+    }
+
+    pub fn same_sense(mut self, same_sense: bool) -> Self {
+        self.same_sense = same_sense.into();
+        self
+    }
+
+    /// Whether the axis is tagged with `tag`.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.axis_tag
+            .custom()
+            .is_some_and(|axis_tag| axis_tag.0 == tag)
+    }
+
+    /// Polyline approximation of the axis in the grid coordinate system,
+    /// following the sense of the axis.
+    pub fn points(&self, ifc: &IFC) -> Vec<DVec3> {
+        let mut points =
+            discretize_curve(self.axis_curve, DEFAULT_CHORD_TOLERANCE, ifc).unwrap_or_default();
+
+        if self.same_sense == IfcBool::False {
+            points.reverse();
+        }
+
+        points
+    }
+}
+
+impl IFCParse for GridAxis {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCGRIDAXIS("),
+
+                axis_tag: OptionalParameter::parse(),
+                _: Comma::parse(),
+                axis_curve: Id::parse(),
+                _: Comma::parse(),
+                same_sense: IfcBool::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for GridAxis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCGRIDAXIS({},{},{});",
+            self.axis_tag, self.axis_curve, self.same_sense
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcGridAxis")]
+impl IfcType for GridAxis {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::GridAxis;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn grid_axis_round_trip() {
+        let example = "IFCGRIDAXIS('A',#42,.TRUE.);";
+
+        let grid_axis: GridAxis = GridAxis::parse().parse(example).unwrap();
+        assert_eq!(example, grid_axis.to_string());
+    }
+}
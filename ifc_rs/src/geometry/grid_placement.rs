@@ -0,0 +1,184 @@
+use std::fmt::Display;
+
+use glam::{DAffine3, DMat3, DVec3};
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, optional::OptionalParameter, p_space_or_comment_surrounded, IFCParse,
+        IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcGridPlacement provides a specialization of IfcObjectPlacement in which
+/// the placement and axis direction of the object coordinate system is
+/// defined by a reference to the design grid as defined in IfcGrid.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcgridplacement.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct GridPlacement {
+    /// Placement of the object coordinate system defined by the
+    /// intersection of two grid axes.
+    pub placement_location: TypedId<VirtualGridIntersection>,
+    /// Reference to either an explicit direction, or a second grid axis
+    /// intersection, which defines the orientation of the object coordinate
+    /// system. If omitted, the x axis of the object is parallel to the x axis
+    /// of the grid.
+    #[ifc_types(VirtualGridIntersection, Direction3D)]
+    pub placement_ref_direction: OptionalParameter<Id>,
+}
+
+impl GridPlacement {
+    pub fn new(
+        placement_location: impl Into<IdOr<VirtualGridIntersection>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            placement_location: placement_location.into().or_insert(ifc),
+            placement_ref_direction: OptionalParameter::omitted(),
+        }
+    }
+
+    /// Orients the x axis of the object towards another grid intersection.
+    pub fn ref_intersection(
+        mut self,
+        intersection: impl Into<IdOr<VirtualGridIntersection>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.placement_ref_direction = intersection.into().or_insert(ifc).id().into();
+        self
+    }
+
+    /// Orients the x axis of the object along `direction`, given in the
+    /// coordinate system of the grid.
+    pub fn ref_direction(mut self, direction: impl Into<IdOr<Direction3D>>, ifc: &mut IFC) -> Self {
+        self.placement_ref_direction = direction.into().or_insert(ifc).id().into();
+        self
+    }
+
+    /// Grid the intersecting axes of the placement belong to.
+    pub fn grid(&self, ifc: &IFC) -> Option<TypedId<Grid>> {
+        let axis = *ifc
+            .data
+            .get(self.placement_location)
+            .intersecting_axes
+            .0
+            .first()?;
+
+        ifc.data
+            .find_all_of_type::<Grid>()
+            .find(|(_, grid)| grid.axes().any(|grid_axis| *grid_axis == axis))
+            .map(|(grid_id, _)| grid_id)
+    }
+
+    /// Transformation from the placed coordinate system into the coordinate
+    /// system of the grid.
+    pub fn grid_transform(&self, ifc: &IFC) -> DAffine3 {
+        let location = ifc
+            .data
+            .get(self.placement_location)
+            .point(ifc)
+            .unwrap_or_default();
+
+        let ref_direction = self.placement_ref_direction.custom().and_then(|id| {
+            let ref_direction = ifc.data.get_untyped(*id);
+
+            if let Some(intersection) = ref_direction.downcast_ref::<VirtualGridIntersection>() {
+                intersection.point(ifc).map(|point| point - location)
+            } else {
+                ref_direction
+                    .downcast_ref::<Direction3D>()
+                    .map(|direction| ***direction)
+            }
+        });
+
+        let local_x = ref_direction
+            .map(|direction| DVec3::new(direction.x, direction.y, 0.0).normalize_or_zero())
+            .filter(|direction| *direction != DVec3::ZERO)
+            .unwrap_or(DVec3::X);
+
+        DAffine3::from_mat3_translation(
+            DMat3::from_cols(local_x, DVec3::Z.cross(local_x), DVec3::Z),
+            location,
+        )
+    }
+
+    /// Transformation from the placed coordinate system into the world
+    /// coordinate system, the grid intersection is resolved in the placement
+    /// of the grid.
+    pub fn transform(&self, ifc: &IFC) -> DAffine3 {
+        let grid_placement = self
+            .grid(ifc)
+            .and_then(|grid| ifc.data.get(grid).object_placement.custom().copied())
+            .map(|placement| object_placement_transform(placement, ifc))
+            .unwrap_or(DAffine3::IDENTITY);
+
+        grid_placement * self.grid_transform(ifc)
+    }
+}
+
+/// Transformation of an object placement (`LocalPlacement` or
+/// `GridPlacement`) given by its id into the world coordinate system. Other
+/// entities result in the identity.
+pub fn object_placement_transform(placement: Id, ifc: &IFC) -> DAffine3 {
+    let placement = ifc.data.get_untyped(placement);
+
+    if let Some(local_placement) = placement.downcast_ref::<LocalPlacement>() {
+        local_placement.transform(ifc)
+    } else if let Some(grid_placement) = placement.downcast_ref::<GridPlacement>() {
+        grid_placement.transform(ifc)
+    } else {
+        DAffine3::IDENTITY
+    }
+}
+
+impl IFCParse for GridPlacement {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCGRIDPLACEMENT("),
+
+                placement_location: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                placement_ref_direction: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for GridPlacement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCGRIDPLACEMENT({},{});",
+            self.placement_location, self.placement_ref_direction
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcGridPlacement")]
+impl IfcType for GridPlacement {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::GridPlacement;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn grid_placement_round_trip() {
+        let examples = ["IFCGRIDPLACEMENT(#56,$);", "IFCGRIDPLACEMENT(#56,#58);"];
+
+        for example in examples {
+            let grid_placement: GridPlacement = GridPlacement::parse().parse(example).unwrap();
+            assert_eq!(example, grid_placement.to_string());
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::curve::Curve,
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{bool::IfcBool, comma::Comma, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+};
+
+/// A half space solid is defined by the half space which is the regular
+/// subset of the domain which lies on one side of an unbounded surface.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifchalfspacesolid.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct HalfSpaceSolid {
+    /// Surface defining side of half space.
+    #[ifc_types(Plane)]
+    pub base_surface: Id,
+    /// The agreement flag is TRUE if the normal to the base surface points
+    /// away from the material of the half space solid. Otherwise it is
+    /// FALSE.
+    pub agreement_flag: IfcBool,
+}
+
+impl HalfSpaceSolid {
+    pub fn new(base_surface: impl Into<IdOr<Plane>>, agreement_flag: bool, ifc: &mut IFC) -> Self {
+        Self {
+            base_surface: base_surface.into().or_insert(ifc).id(),
+            agreement_flag: agreement_flag.into(),
+        }
+    }
+}
+
+impl IFCParse for HalfSpaceSolid {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCHALFSPACESOLID("),
+
+                base_surface: Id::parse(),
+                _: Comma::parse(),
+                agreement_flag: IfcBool::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for HalfSpaceSolid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCHALFSPACESOLID({},{});",
+            self.base_surface, self.agreement_flag
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcHalfSpaceSolid")]
+impl IfcType for HalfSpaceSolid {}
+
+/// The polygonal bounded half space is a special subtype of a half space
+/// solid, where the material of the half space used in Boolean expressions
+/// is bounded by a polygonal boundary. The polygonal boundary is given in the
+/// xy plane of its position and extruded along the z axis.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpolygonalboundedhalfspace.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PolygonalBoundedHalfSpace {
+    /// Surface defining side of half space.
+    #[ifc_types(Plane)]
+    pub base_surface: Id,
+    /// The agreement flag is TRUE if the normal to the base surface points
+    /// away from the material of the half space solid. Otherwise it is
+    /// FALSE.
+    pub agreement_flag: IfcBool,
+    /// Definition of the position coordinate system for the bounding
+    /// polyline and the base surface.
+    pub position: TypedId<Axis3D>,
+    /// Two-dimensional closed bounding polyline in the xy plane of the
+    /// position.
+    #[ifc_types(PolyLine, IndexedPolyCurve, CompositeCurve)]
+    pub polygonal_boundary: Id,
+}
+
+impl PolygonalBoundedHalfSpace {
+    pub fn new<C: Curve>(
+        base_surface: impl Into<IdOr<Plane>>,
+        agreement_flag: bool,
+        position: impl Into<IdOr<Axis3D>>,
+        polygonal_boundary: impl Into<IdOr<C>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            base_surface: base_surface.into().or_insert(ifc).id(),
+            agreement_flag: agreement_flag.into(),
+            position: position.into().or_insert(ifc),
+            polygonal_boundary: polygonal_boundary.into().or_insert(ifc).id(),
+        }
+    }
+}
+
+impl IFCParse for PolygonalBoundedHalfSpace {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPOLYGONALBOUNDEDHALFSPACE("),
+
+                base_surface: Id::parse(),
+                _: Comma::parse(),
+                agreement_flag: IfcBool::parse(),
+                _: Comma::parse(),
+                position: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                polygonal_boundary: Id::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PolygonalBoundedHalfSpace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCPOLYGONALBOUNDEDHALFSPACE({},{},{},{});",
+            self.base_surface, self.agreement_flag, self.position, self.polygonal_boundary
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcPolygonalBoundedHalfSpace")]
+impl IfcType for PolygonalBoundedHalfSpace {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::{HalfSpaceSolid, PolygonalBoundedHalfSpace};
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn half_space_solid_round_trip() {
+        let example = "IFCHALFSPACESOLID(#40,.FALSE.);";
+
+        let parsed: HalfSpaceSolid = HalfSpaceSolid::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn polygonal_bounded_half_space_round_trip() {
+        let example = "IFCPOLYGONALBOUNDEDHALFSPACE(#40,.FALSE.,#41,#48);";
+
+        let parsed: PolygonalBoundedHalfSpace =
+            PolygonalBoundedHalfSpace::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,222 @@
+use std::fmt::Display;
+
+use glam::DVec2;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{
+        profile_def::{position_transform, ProfileDef, ProfilePolygon},
+        profile_type::ProfileType,
+    },
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcIShapeProfileDef defines a section profile that provides the defining parameters of a
+/// symmetrical 'I' or 'H' shaped section. The I-shape profile has two equal flanges, its origin is
+/// in the center of its bounding box.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcprofileresource/lexical/ifcishapeprofiledef.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct IShapeProfileDef {
+    /// Defines the type of geometry into which this profile definition shall be resolved, either a
+    /// curve or a surface area. In case of curve the profile should be referenced by a swept
+    /// surface, in case of area the profile should be referenced by a swept area solid.
+    pub profile_type: ProfileType,
+    /// Human-readable name of the profile, for example according to a standard profile table. As
+    /// noted above, machine-readable standardized profile designations should be provided in
+    /// IfcExternalReference.ItemReference.
+    pub profile_name: OptionalParameter<Label>,
+    /// Position coordinate system of the parameterized profile definition. If unspecified, no
+    /// translation and no rotation is applied.
+    #[ifc_types(Axis2D, Axis3D)]
+    pub position: OptionalParameter<Id>,
+    /// Total extent of the width, defined parallel to the x axis of the position coordinate system.
+    pub overall_width: IfcFloat,
+    /// Total extent of the depth, defined parallel to the y axis of the position coordinate system.
+    pub overall_depth: IfcFloat,
+    /// Thickness of the web of the I-shape. The web is centred on the x-axis and the y-axis of the
+    /// position coordinate system.
+    pub web_thickness: IfcFloat,
+    /// Flange thickness of the I-shape.
+    pub flange_thickness: IfcFloat,
+    /// The fillet between the web and the flange.
+    pub fillet_radius: OptionalParameter<IfcFloat>,
+    /// Radius of the lower edges of the top flange and the upper edges of the bottom flange.
+    pub flange_edge_radius: OptionalParameter<IfcFloat>,
+    /// Slope of the inner faces of the flanges.
+    pub flange_slope: OptionalParameter<IfcFloat>,
+}
+
+impl IShapeProfileDef {
+    pub fn new(
+        profile_type: ProfileType,
+        overall_width: f64,
+        overall_depth: f64,
+        web_thickness: f64,
+        flange_thickness: f64,
+    ) -> Self {
+        Self {
+            profile_type,
+            profile_name: OptionalParameter::omitted(),
+            position: OptionalParameter::omitted(),
+            overall_width: overall_width.into(),
+            overall_depth: overall_depth.into(),
+            web_thickness: web_thickness.into(),
+            flange_thickness: flange_thickness.into(),
+            fillet_radius: OptionalParameter::omitted(),
+            flange_edge_radius: OptionalParameter::omitted(),
+            flange_slope: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn profile_name(mut self, name: impl Into<Label>) -> Self {
+        self.profile_name = name.into().into();
+        self
+    }
+
+    pub fn position<A: AxisPlacement>(
+        mut self,
+        position: impl Into<IdOr<A>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.position = position.into().or_insert(ifc).id().into();
+        self
+    }
+
+    pub fn fillet_radius(mut self, fillet_radius: f64) -> Self {
+        self.fillet_radius = IfcFloat(fillet_radius).into();
+        self
+    }
+
+    pub fn flange_edge_radius(mut self, flange_edge_radius: f64) -> Self {
+        self.flange_edge_radius = IfcFloat(flange_edge_radius).into();
+        self
+    }
+
+    pub fn flange_slope(mut self, flange_slope: f64) -> Self {
+        self.flange_slope = IfcFloat(flange_slope).into();
+        self
+    }
+}
+
+impl IFCParse for IShapeProfileDef {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCISHAPEPROFILEDEF("),
+
+                profile_type: ProfileType::parse(),
+                _: Comma::parse(),
+                profile_name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                position: OptionalParameter::parse(),
+                _: Comma::parse(),
+                overall_width: IfcFloat::parse(),
+                _: Comma::parse(),
+                overall_depth: IfcFloat::parse(),
+                _: Comma::parse(),
+                web_thickness: IfcFloat::parse(),
+                _: Comma::parse(),
+                flange_thickness: IfcFloat::parse(),
+                _: Comma::parse(),
+                fillet_radius: OptionalParameter::parse(),
+                _: Comma::parse(),
+                flange_edge_radius: OptionalParameter::parse(),
+                _: Comma::parse(),
+                flange_slope: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for IShapeProfileDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCISHAPEPROFILEDEF({},{},{},{},{},{},{},{},{},{});",
+            self.profile_type,
+            self.profile_name,
+            self.position,
+            self.overall_width,
+            self.overall_depth,
+            self.web_thickness,
+            self.flange_thickness,
+            self.fillet_radius,
+            self.flange_edge_radius,
+            self.flange_slope,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcIShapeProfileDef")]
+impl IfcType for IShapeProfileDef {
+    fn to_profile_def(&self) -> Option<&dyn ProfileDef> {
+        Some(self)
+    }
+}
+
+impl ProfileDef for IShapeProfileDef {
+    fn polygons(&self, ifc: &IFC) -> Vec<ProfilePolygon> {
+        let (w, d) = (self.overall_width.0 * 0.5, self.overall_depth.0 * 0.5);
+        let (web, flange) = (self.web_thickness.0 * 0.5, self.flange_thickness.0);
+
+        let polygon = ProfilePolygon::new(vec![
+            DVec2::new(-w, -d),
+            DVec2::new(w, -d),
+            DVec2::new(w, -d + flange),
+            DVec2::new(web, -d + flange),
+            DVec2::new(web, d - flange),
+            DVec2::new(w, d - flange),
+            DVec2::new(w, d),
+            DVec2::new(-w, d),
+            DVec2::new(-w, d - flange),
+            DVec2::new(-web, d - flange),
+            DVec2::new(-web, -d + flange),
+            DVec2::new(-w, -d + flange),
+        ]);
+
+        vec![polygon.transformed(position_transform(&self.position, ifc))]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::IShapeProfileDef;
+    use crate::parser::IFCParse;
+    use crate::{
+        geometry::{profile_def::ProfileDef, profile_type::ProfileType},
+        IFC,
+    };
+
+    #[test]
+    fn i_shape_profile_def_round_trip() {
+        let examples =
+            ["IFCISHAPEPROFILEDEF(.AREA.,'IPE200',#58,0.1,0.2,0.0056,0.0085,0.012,$,$);"];
+
+        for example in examples {
+            let parsed: IShapeProfileDef = IShapeProfileDef::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn i_shape_profile_def_polygon() {
+        let ifc = IFC::default();
+        let polygons =
+            IShapeProfileDef::new(ProfileType::Area, 0.2, 0.4, 0.02, 0.05).polygons(&ifc);
+
+        let area = 2.0 * 0.2 * 0.05 + 0.02 * 0.3;
+        assert!((polygons[0].area() - area).abs() < 1e-9);
+    }
+}
@@ -33,9 +33,7 @@ fn parse_indexed_poly_curve_works() {
     let parsed = IndexedPolyCurve::parse().parse(data).unwrap();
     assert_eq!(data, parsed.to_string());
 
-    // TODO: When IfcLineIndex/IfcArcIndex is implemented
-    //
-    // let data = "IFCINDEXEDPOLYCURVE(#28,(IfcLineIndex((1,2)),IfcArcIndex((2,3,4)),IfcLineIndex((4,5)),IfcArcIndex((5,6,1))),.FALSE.);";
-    // let parsed = IndexedPolyCurve::parse().parse(data).unwrap();
-    // assert_eq!(data, parsed.to_string());
+    let data = "IFCINDEXEDPOLYCURVE(#28,(IFCLINEINDEX((1,2)),IFCARCINDEX((2,3,4)),IFCLINEINDEX((4,5)),IFCARCINDEX((5,6,1))),.FALSE.);";
+    let parsed = IndexedPolyCurve::parse().parse(data).unwrap();
+    assert_eq!(data, parsed.to_string());
 }
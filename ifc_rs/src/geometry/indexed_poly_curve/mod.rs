@@ -1,22 +1,28 @@
+use glam::DVec3;
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
+    geometry::curve::{append_points, arc_through_points, Curve},
     id::Id,
     ifc_type::{IfcType, IfcVerify},
-    parser::{bool::IfcBool, label::Label, optional::OptionalParameter},
+    parser::{bool::IfcBool, ifc_integer::IfcInteger, list::IfcList, optional::OptionalParameter},
     prelude::*,
 };
 
 mod deserialize;
+mod segment_index;
 mod serialize;
 
+pub use segment_index::SegmentIndex;
+
 /// The IfcIndexedPolyCurve is a bounded curve with only linear and circular
 /// arc segments defined by a Cartesian point list and an optional list of
 /// segments, providing indices into the Cartesian point list. In the case
 /// that the list of Segments is not provided, all points in the
 /// IfcCartesianPointList are connected by straight line segments in the
 /// order they appear in the IfcCartesianPointList.
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct IndexedPolyCurve {
     /// A list of points, provided by a point list of either two, or three
     /// dimensions, that is used to define the poly curve. If the attribute
@@ -32,7 +38,7 @@ pub struct IndexedPolyCurve {
     /// preserve consecutive connectivity between the segments, the start
     /// index of the next segment shall be identical with the end index
     /// of the previous segment.
-    pub segments: OptionalParameter<Label>, // TODO: this is either `LineIndex` or `ArcIndex`
+    pub segments: OptionalParameter<IfcList<SegmentIndex>>,
     /// Indication of whether the curve intersects itself or not; this is for
     /// information only.
     pub self_intersect: OptionalParameter<IfcBool>,
@@ -48,10 +54,131 @@ impl IndexedPolyCurve {
             self_intersect: OptionalParameter::omitted(),
         }
     }
+
+    pub fn add_segment(mut self, segment: SegmentIndex) -> Self {
+        match self.segments.custom_mut() {
+            Some(segments) => segments.0.push(segment),
+            None => self.segments = IfcList(vec![segment]).into(),
+        }
+        self
+    }
+
+    /// Coordinates of the point list, two dimensional points lie on the xy
+    /// plane.
+    pub fn coordinates(&self, ifc: &IFC) -> Vec<DVec3> {
+        let point_list = ifc.data.get_untyped(self.points);
+
+        if let Some(point_list) = point_list.downcast_ref::<PointList2D>() {
+            point_list
+                .coord_list
+                .iter()
+                .map(|point| point.extend(0.0))
+                .collect()
+        } else if let Some(point_list) = point_list.downcast_ref::<PointList3D>() {
+            point_list.coord_list.iter().map(|point| **point).collect()
+        } else {
+            Vec::new()
+        }
+    }
 }
 
-impl IfcType for IndexedPolyCurve {}
+#[typetag::serde(name = "IfcIndexedPolyCurve")]
+impl IfcType for IndexedPolyCurve {
+    fn to_curve(&self) -> Option<&dyn Curve> {
+        Some(self)
+    }
+}
+
+impl ShapeItem for IndexedPolyCurve {}
+
+impl Curve for IndexedPolyCurve {
+    fn discretize(&self, tolerance: f64, ifc: &IFC) -> Vec<DVec3> {
+        let coordinates = self.coordinates(ifc);
 
-// TODO: move this trait to a more general module for curves
-pub trait Curve: IfcType {}
-impl Curve for IndexedPolyCurve {}
+        let Some(segments) = self.segments.custom() else {
+            return coordinates;
+        };
+
+        let point = |index: &IfcInteger| {
+            usize::try_from(index.0 - 1)
+                .ok()
+                .and_then(|index| coordinates.get(index))
+                .copied()
+        };
+
+        // segments with indices outside of the point list are skipped
+        let mut points = Vec::new();
+        for segment in segments.iter() {
+            match segment {
+                SegmentIndex::Line(indices) => {
+                    if let Some(line) = indices.iter().map(point).collect::<Option<Vec<_>>>() {
+                        append_points(&mut points, line);
+                    }
+                }
+                SegmentIndex::Arc(indices) => {
+                    if let [Some(start), Some(middle), Some(end)] =
+                        indices.0.iter().map(point).collect::<Vec<_>>().as_slice()
+                    {
+                        append_points(
+                            &mut points,
+                            arc_through_points(*start, *middle, *end, tolerance),
+                        );
+                    }
+                }
+            }
+        }
+
+        points
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::{DVec2, DVec3};
+
+    use super::{IndexedPolyCurve, SegmentIndex};
+    use crate::{
+        geometry::{curve::Curve, point_list::PointList2D},
+        IFC,
+    };
+
+    #[test]
+    fn indexed_poly_curve_arc_discretization() {
+        let mut ifc = IFC::default();
+
+        let points = PointList2D::new(
+            [
+                DVec2::new(0.0, 0.0),
+                DVec2::new(1.0, 0.0),
+                DVec2::new(2.0, 1.0),
+                DVec2::new(1.0, 2.0),
+            ]
+            .into_iter(),
+        );
+        let curve = IndexedPolyCurve::new(points, &mut ifc)
+            .add_segment(SegmentIndex::line([1, 2]))
+            .add_segment(SegmentIndex::arc(2, 3, 4));
+
+        let points = curve.discretize(0.001, &ifc);
+        assert_eq!(points[0], DVec3::ZERO);
+        assert_eq!(points[1], DVec3::X);
+        assert_eq!(*points.last().unwrap(), DVec3::new(1.0, 2.0, 0.0));
+        assert!(points[1..]
+            .iter()
+            .all(|point| (point.distance(DVec3::new(1.0, 1.0, 0.0)) - 1.0).abs() < 1e-9));
+        assert!(points.iter().any(|point| point.x > 1.99));
+    }
+
+    #[test]
+    fn indexed_poly_curve_skips_segments_outside_of_the_points() {
+        let mut ifc = IFC::default();
+
+        let points = PointList2D::new([DVec2::new(0.0, 0.0), DVec2::new(1.0, 0.0)].into_iter());
+        let curve = IndexedPolyCurve::new(points, &mut ifc)
+            .add_segment(SegmentIndex::line([1, 2]))
+            .add_segment(SegmentIndex::line([2, 3]))
+            .add_segment(SegmentIndex::arc(2, 0, 1));
+
+        assert_eq!(curve.discretize(0.001, &ifc), vec![DVec3::ZERO, DVec3::X]);
+    }
+}
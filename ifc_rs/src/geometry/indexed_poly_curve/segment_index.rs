@@ -0,0 +1,73 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use winnow::{
+    combinator::{alt, delimited},
+    Parser,
+};
+
+use crate::parser::{
+    ifc_integer::IfcInteger, list::IfcList, p_space_or_comment_surrounded, IFCParse, IFCParser,
+};
+
+/// A segment of an `IndexedPolyCurve`, given by one based indices into the
+/// point list of the curve.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcsegmentindexselect.htm
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SegmentIndex {
+    /// `IfcLineIndex`, a polyline through two or more points.
+    Line(IfcList<IfcInteger>),
+    /// `IfcArcIndex`, a circular arc from the first through the second to
+    /// the third point.
+    Arc(IfcList<IfcInteger>),
+}
+
+impl SegmentIndex {
+    pub fn line(indices: impl IntoIterator<Item = i64>) -> Self {
+        Self::Line(IfcList(indices.into_iter().map(IfcInteger).collect()))
+    }
+
+    pub fn arc(start: i64, middle: i64, end: i64) -> Self {
+        Self::Arc(IfcList(vec![
+            IfcInteger(start),
+            IfcInteger(middle),
+            IfcInteger(end),
+        ]))
+    }
+
+    /// The one based indices of the segment.
+    pub fn indices(&self) -> &IfcList<IfcInteger> {
+        match self {
+            SegmentIndex::Line(indices) | SegmentIndex::Arc(indices) => indices,
+        }
+    }
+}
+
+impl IFCParse for SegmentIndex {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        alt((
+            delimited(
+                p_space_or_comment_surrounded("IFCLINEINDEX("),
+                IfcList::parse(),
+                p_space_or_comment_surrounded(")"),
+            )
+            .map(Self::Line),
+            delimited(
+                p_space_or_comment_surrounded("IFCARCINDEX("),
+                IfcList::parse(),
+                p_space_or_comment_surrounded(")"),
+            )
+            .map(Self::Arc),
+        ))
+    }
+}
+
+impl Display for SegmentIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SegmentIndex::Line(indices) => write!(f, "IFCLINEINDEX({indices})"),
+            SegmentIndex::Arc(indices) => write!(f, "IFCARCINDEX({indices})"),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_integer::IfcInteger, list::IfcList, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// The IfcIndexedPolygonalFace is a compact representation of a planar face
+/// being part of a face set. The vertices of the polygonal planar face are
+/// provided by one based indices into the coordinate list of the face set.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcindexedpolygonalface.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct IndexedPolygonalFace {
+    /// One based indices into the coordinate list of the face set, the
+    /// polygon is implicitly closed.
+    pub coord_index: IfcList<IfcInteger>,
+}
+
+impl IndexedPolygonalFace {
+    /// Face from zero based indices into the coordinate list.
+    pub fn new(indices: impl IntoIterator<Item = usize>) -> Self {
+        Self {
+            coord_index: one_based(indices),
+        }
+    }
+}
+
+impl IFCParse for IndexedPolygonalFace {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCINDEXEDPOLYGONALFACE("),
+
+                coord_index: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for IndexedPolygonalFace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCINDEXEDPOLYGONALFACE({});", self.coord_index)
+    }
+}
+
+#[typetag::serde(name = "IfcIndexedPolygonalFace")]
+impl IfcType for IndexedPolygonalFace {}
+
+/// The IfcIndexedPolygonalFaceWithVoids is a planar face with inner loops,
+/// i.e. holes, given by one based indices into the coordinate list of the
+/// face set.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcindexedpolygonalfacewithvoids.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct IndexedPolygonalFaceWithVoids {
+    /// One based indices of the outer loop of the face.
+    pub coord_index: IfcList<IfcInteger>,
+    /// One based indices of the inner loops of the face.
+    pub inner_coord_indices: IfcList<IfcList<IfcInteger>>,
+}
+
+impl IndexedPolygonalFaceWithVoids {
+    /// Face from zero based indices into the coordinate list.
+    pub fn new(indices: impl IntoIterator<Item = usize>) -> Self {
+        Self {
+            coord_index: one_based(indices),
+            inner_coord_indices: IfcList::empty(),
+        }
+    }
+
+    /// Adds an inner loop from zero based indices.
+    pub fn add_void(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        self.inner_coord_indices.0.push(one_based(indices));
+        self
+    }
+}
+
+impl IFCParse for IndexedPolygonalFaceWithVoids {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCINDEXEDPOLYGONALFACEWITHVOIDS("),
+
+                coord_index: IfcList::parse(),
+                _: Comma::parse(),
+                inner_coord_indices: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for IndexedPolygonalFaceWithVoids {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCINDEXEDPOLYGONALFACEWITHVOIDS({},{});",
+            self.coord_index, self.inner_coord_indices
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcIndexedPolygonalFaceWithVoids")]
+impl IfcType for IndexedPolygonalFaceWithVoids {}
+
+/// Converts zero based indices into the one based indices used by IFC.
+pub(crate) fn one_based(indices: impl IntoIterator<Item = usize>) -> IfcList<IfcInteger> {
+    IfcList(
+        indices
+            .into_iter()
+            .map(|index| IfcInteger(index as i64 + 1))
+            .collect(),
+    )
+}
+
+/// Converts one based IFC indices into zero based indices.
+pub(crate) fn zero_based(indices: &IfcList<IfcInteger>) -> Vec<usize> {
+    indices
+        .iter()
+        .map(|index| (index.0 - 1).max(0) as usize)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::{IndexedPolygonalFace, IndexedPolygonalFaceWithVoids};
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn indexed_polygonal_face_round_trip() {
+        let example = "IFCINDEXEDPOLYGONALFACE((1,2,3,4));";
+
+        let parsed: IndexedPolygonalFace = IndexedPolygonalFace::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn indexed_polygonal_face_with_voids_round_trip() {
+        let example = "IFCINDEXEDPOLYGONALFACEWITHVOIDS((1,2,3,4),((5,6,7),(8,9,10)));";
+
+        let parsed: IndexedPolygonalFaceWithVoids = IndexedPolygonalFaceWithVoids::parse()
+            .parse(example)
+            .unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,212 @@
+use std::fmt::Display;
+
+use glam::DVec2;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{
+        profile_def::{position_transform, ProfileDef, ProfilePolygon},
+        profile_type::ProfileType,
+    },
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcLShapeProfileDef defines a section profile that provides the defining parameters of an
+/// L-shaped section (equilateral L profiles are also covered by this entity) for use by the swept
+/// area solid. The vertical leg runs along the negative x side, the horizontal leg along the
+/// negative y side of the bounding box, whose center is the origin of the profile.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcprofileresource/lexical/ifclshapeprofiledef.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct LShapeProfileDef {
+    /// Defines the type of geometry into which this profile definition shall be resolved, either a
+    /// curve or a surface area. In case of curve the profile should be referenced by a swept
+    /// surface, in case of area the profile should be referenced by a swept area solid.
+    pub profile_type: ProfileType,
+    /// Human-readable name of the profile, for example according to a standard profile table. As
+    /// noted above, machine-readable standardized profile designations should be provided in
+    /// IfcExternalReference.ItemReference.
+    pub profile_name: OptionalParameter<Label>,
+    /// Position coordinate system of the parameterized profile definition. If unspecified, no
+    /// translation and no rotation is applied.
+    #[ifc_types(Axis2D, Axis3D)]
+    pub position: OptionalParameter<Id>,
+    /// Leg length (= h). Same as the overall depth.
+    pub depth: IfcFloat,
+    /// Leg length (= b). Same as the overall width. If not given, the value
+    /// of the Depth attribute is applied to Width.
+    pub width: OptionalParameter<IfcFloat>,
+    /// Constant wall thickness of profile (= ts).
+    pub thickness: IfcFloat,
+    /// Fillet radius (= r1).
+    pub fillet_radius: OptionalParameter<IfcFloat>,
+    /// Edge radius (= r2).
+    pub edge_radius: OptionalParameter<IfcFloat>,
+    /// Slope of the inner face of each leg of the profile.
+    pub leg_slope: OptionalParameter<IfcFloat>,
+}
+
+impl LShapeProfileDef {
+    pub fn new(profile_type: ProfileType, depth: f64, thickness: f64) -> Self {
+        Self {
+            profile_type,
+            profile_name: OptionalParameter::omitted(),
+            position: OptionalParameter::omitted(),
+            depth: depth.into(),
+            width: OptionalParameter::omitted(),
+            thickness: thickness.into(),
+            fillet_radius: OptionalParameter::omitted(),
+            edge_radius: OptionalParameter::omitted(),
+            leg_slope: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn profile_name(mut self, name: impl Into<Label>) -> Self {
+        self.profile_name = name.into().into();
+        self
+    }
+
+    pub fn position<A: AxisPlacement>(
+        mut self,
+        position: impl Into<IdOr<A>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.position = position.into().or_insert(ifc).id().into();
+        self
+    }
+
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = IfcFloat(width).into();
+        self
+    }
+
+    pub fn fillet_radius(mut self, fillet_radius: f64) -> Self {
+        self.fillet_radius = IfcFloat(fillet_radius).into();
+        self
+    }
+
+    pub fn edge_radius(mut self, edge_radius: f64) -> Self {
+        self.edge_radius = IfcFloat(edge_radius).into();
+        self
+    }
+
+    pub fn leg_slope(mut self, leg_slope: f64) -> Self {
+        self.leg_slope = IfcFloat(leg_slope).into();
+        self
+    }
+}
+
+impl IFCParse for LShapeProfileDef {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCLSHAPEPROFILEDEF("),
+
+                profile_type: ProfileType::parse(),
+                _: Comma::parse(),
+                profile_name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                position: OptionalParameter::parse(),
+                _: Comma::parse(),
+                depth: IfcFloat::parse(),
+                _: Comma::parse(),
+                width: OptionalParameter::parse(),
+                _: Comma::parse(),
+                thickness: IfcFloat::parse(),
+                _: Comma::parse(),
+                fillet_radius: OptionalParameter::parse(),
+                _: Comma::parse(),
+                edge_radius: OptionalParameter::parse(),
+                _: Comma::parse(),
+                leg_slope: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for LShapeProfileDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCLSHAPEPROFILEDEF({},{},{},{},{},{},{},{},{});",
+            self.profile_type,
+            self.profile_name,
+            self.position,
+            self.depth,
+            self.width,
+            self.thickness,
+            self.fillet_radius,
+            self.edge_radius,
+            self.leg_slope,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcLShapeProfileDef")]
+impl IfcType for LShapeProfileDef {
+    fn to_profile_def(&self) -> Option<&dyn ProfileDef> {
+        Some(self)
+    }
+}
+
+impl ProfileDef for LShapeProfileDef {
+    fn polygons(&self, ifc: &IFC) -> Vec<ProfilePolygon> {
+        let depth = self.depth.0;
+        let width = self.width.custom().map_or(depth, |width| width.0);
+        let (w, d, t) = (width * 0.5, depth * 0.5, self.thickness.0);
+
+        let polygon = ProfilePolygon::new(vec![
+            DVec2::new(-w, -d),
+            DVec2::new(w, -d),
+            DVec2::new(w, -d + t),
+            DVec2::new(-w + t, -d + t),
+            DVec2::new(-w + t, d),
+            DVec2::new(-w, d),
+        ]);
+
+        vec![polygon.transformed(position_transform(&self.position, ifc))]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::LShapeProfileDef;
+    use crate::parser::IFCParse;
+    use crate::{
+        geometry::{profile_def::ProfileDef, profile_type::ProfileType},
+        IFC,
+    };
+
+    #[test]
+    fn l_shape_profile_def_round_trip() {
+        let examples = [
+            "IFCLSHAPEPROFILEDEF(.AREA.,'L 100x10',$,0.1,$,0.01,0.012,0.006,$);",
+            "IFCLSHAPEPROFILEDEF(.AREA.,$,#58,0.15,0.1,0.012,$,$,$);",
+        ];
+
+        for example in examples {
+            let parsed: LShapeProfileDef = LShapeProfileDef::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn l_shape_profile_def_polygon() {
+        let ifc = IFC::default();
+        let polygons = LShapeProfileDef::new(ProfileType::Area, 0.1, 0.01).polygons(&ifc);
+
+        let area = 0.1 * 0.01 + 0.09 * 0.01;
+        assert!((polygons[0].area() - area).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt::Display;
+
+use glam::DVec3;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{
+        curve::{point_coordinates, Curve},
+        vector::Vector,
+    },
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+};
+
+/// The line is an unbounded curve with constant tangent direction. A line is
+/// defined by a point and a direction. The positive direction of the line is
+/// in the direction of the Dir vector. The curve is parameterized as
+/// `Pnt + t * Dir`.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcline.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Line {
+    /// The location of the line.
+    #[ifc_types(Point2D, Point3D)]
+    pub pnt: Id,
+    /// The direction of the line, the magnitude and units of Dir affect the
+    /// parameterization of the line.
+    pub dir: TypedId<Vector>,
+}
+
+impl Line {
+    pub fn new<P: CartesianPoint>(
+        pnt: impl Into<IdOr<P>>,
+        dir: impl Into<IdOr<Vector>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            pnt: pnt.into().or_insert(ifc).id(),
+            dir: dir.into().or_insert(ifc),
+        }
+    }
+
+    /// Point of the line at the given parameter.
+    pub fn point_at(&self, parameter: f64, ifc: &IFC) -> DVec3 {
+        point_coordinates(self.pnt, ifc) + parameter * ifc.data.get(self.dir).value(ifc)
+    }
+
+    /// Parameter of the point on the line closest to the given point.
+    pub fn parameter_of(&self, point: DVec3, ifc: &IFC) -> f64 {
+        let dir = ifc.data.get(self.dir).value(ifc);
+
+        (point - point_coordinates(self.pnt, ifc)).dot(dir) / dir.length_squared()
+    }
+}
+
+impl IFCParse for Line {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCLINE("),
+
+                pnt: Id::parse(),
+                _: Comma::parse(),
+                dir: Id::parse().map(TypedId::new),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCLINE({},{});", self.pnt, self.dir)
+    }
+}
+
+#[typetag::serde(name = "IfcLine")]
+impl IfcType for Line {
+    fn to_curve(&self) -> Option<&dyn Curve> {
+        Some(self)
+    }
+}
+
+impl ShapeItem for Line {}
+
+impl Curve for Line {
+    /// Lines are unbounded, the segment between the parameters 0 and 1 is
+    /// returned. Use a `TrimmedCurve` to bound them.
+    fn discretize(&self, _tolerance: f64, ifc: &IFC) -> Vec<DVec3> {
+        vec![self.point_at(0.0, ifc), self.point_at(1.0, ifc)]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Line;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn line_round_trip() {
+        let example = "IFCLINE(#32,#34);";
+
+        let parsed: Line = Line::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -1,6 +1,8 @@
 use std::fmt::Display;
 
+use glam::DAffine3;
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{Id, IdOr},
@@ -19,7 +21,7 @@ use super::axis::AxisPlacement;
 /// of a product within the geometric representation context of the project.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifclocalplacement.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct LocalPlacement {
     /// Reference to object placement that provides the relative placement with
     /// its placement in a grid, local coordinate system or linear referenced placement.
@@ -52,6 +54,19 @@ impl LocalPlacement {
         self.placement_rel_to = placement_rel_to.into().or_insert(ifc).into();
         self
     }
+
+    /// Transformation from the placed coordinate system into the world
+    /// coordinate system, following the chain of relative placements.
+    pub fn transform(&self, ifc: &IFC) -> DAffine3 {
+        let relative_placement = axis_placement_transform(self.relative_placement, ifc);
+
+        match self.placement_rel_to.custom() {
+            Some(placement_rel_to) => {
+                ifc.data.get(*placement_rel_to).transform(ifc) * relative_placement
+            }
+            None => relative_placement,
+        }
+    }
 }
 
 impl IFCParse for LocalPlacement {
@@ -80,6 +95,7 @@ impl Display for LocalPlacement {
     }
 }
 
+#[typetag::serde(name = "IfcLocalPlacement")]
 impl IfcType for LocalPlacement {}
 
 #[cfg(test)]
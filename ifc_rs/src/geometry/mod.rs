@@ -6,49 +6,148 @@ use crate::{
 };
 
 pub mod arbitrary_closed_profile_def;
+pub mod arbitrary_profile_def_with_voids;
 pub mod axis;
+pub mod boolean_result;
+pub mod c_shape_profile_def;
+pub mod circle;
+pub mod circle_hollow_profile_def;
+pub mod circle_profile_def;
+pub mod closed_shell;
+pub mod composite_curve;
+pub mod composite_profile_def;
+pub mod connection_surface_geometry;
+pub mod curve;
+pub mod curve_bounded_plane;
 pub mod dimension_count;
 pub mod direction;
+pub mod ellipse;
 pub mod extruded_area_solid;
+pub mod extruded_area_solid_tapered;
+pub mod face;
+pub mod faceted_brep;
+pub mod fixed_reference_swept_area_solid;
 pub mod geometric_projection;
+pub mod grid_axis;
+pub mod grid_placement;
+pub mod half_space_solid;
+pub mod i_shape_profile_def;
 pub mod indexed_poly_curve;
+pub mod indexed_polygonal_face;
+pub mod l_shape_profile_def;
+pub mod line;
 pub mod local_placement;
+pub mod plane;
 pub mod point;
 pub mod point_list;
+pub mod poly_loop;
+pub mod polygonal_face_set;
 pub mod polyline;
 pub mod prelude;
 pub mod product_definition_shape;
+pub mod profile_def;
 pub mod profile_type;
+pub mod rectangle_hollow_profile_def;
 pub mod rectangle_profile_def;
 pub mod representation_context;
 pub mod representation_subcontext;
+pub mod revolved_area_solid;
 pub mod shape_representation;
+pub mod surface_curve_swept_area_solid;
+pub mod swept_disk_solid;
+pub mod t_shape_profile_def;
 pub mod transformations;
+pub mod triangulated_face_set;
+pub mod trimmed_curve;
+pub mod u_shape_profile_def;
+pub mod vector;
+pub mod virtual_grid_intersection;
+pub mod z_shape_profile_def;
 
 pub struct Geometry;
 
 impl Geometry {
     pub fn parse<'a>() -> impl IFCParser<'a, Box<dyn IfcType>> {
         alt((
-            arbitrary_closed_profile_def::ArbitraryClosedProfileDef::parse_any(),
-            axis::Axis2D::parse_any(),
-            axis::Axis3D::parse_any(),
-            direction::Direction2D::parse_any(),
-            direction::Direction3D::parse_any(),
-            extruded_area_solid::ExtrudedAreaSolid::parse_any(),
-            indexed_poly_curve::IndexedPolyCurve::parse_any(),
-            point::Point2D::parse_any(),
-            point::Point3D::parse_any(),
-            point_list::PointList2D::parse_any(),
-            point_list::PointList3D::parse_any(),
-            polyline::PolyLine::parse_any(),
-            product_definition_shape::ProductDefinitionShape::parse_any(),
-            rectangle_profile_def::RectangleProfileDef::parse_any(),
-            representation_context::GeometricRepresentationContext::parse_any(),
-            representation_subcontext::GeometricRepresentationSubContext::parse_any(),
-            shape_representation::ShapeRepresentation::parse_any(),
-            local_placement::LocalPlacement::parse_any(),
-            transformations::CartesianTransformationOperator3DnonUniform::parse_any(),
+            alt((
+                arbitrary_closed_profile_def::ArbitraryClosedProfileDef::parse_any(),
+                axis::Axis2D::parse_any(),
+                axis::Axis3D::parse_any(),
+                direction::Direction2D::parse_any(),
+                direction::Direction3D::parse_any(),
+                extruded_area_solid::ExtrudedAreaSolid::parse_any(),
+                indexed_poly_curve::IndexedPolyCurve::parse_any(),
+                point::Point2D::parse_any(),
+                point::Point3D::parse_any(),
+                point_list::PointList2D::parse_any(),
+                point_list::PointList3D::parse_any(),
+                polyline::PolyLine::parse_any(),
+                product_definition_shape::ProductDefinitionShape::parse_any(),
+                rectangle_profile_def::RectangleProfileDef::parse_any(),
+                representation_context::GeometricRepresentationContext::parse_any(),
+                representation_subcontext::GeometricRepresentationSubContext::parse_any(),
+                shape_representation::ShapeRepresentation::parse_any(),
+                local_placement::LocalPlacement::parse_any(),
+                transformations::CartesianTransformationOperator3DnonUniform::parse_any(),
+            )),
+            alt((
+                arbitrary_profile_def_with_voids::ArbitraryProfileDefWithVoids::parse_any(),
+                c_shape_profile_def::CShapeProfileDef::parse_any(),
+                circle_hollow_profile_def::CircleHollowProfileDef::parse_any(),
+                circle_profile_def::CircleProfileDef::parse_any(),
+                composite_profile_def::CompositeProfileDef::parse_any(),
+                i_shape_profile_def::IShapeProfileDef::parse_any(),
+                l_shape_profile_def::LShapeProfileDef::parse_any(),
+                rectangle_hollow_profile_def::RectangleHollowProfileDef::parse_any(),
+                t_shape_profile_def::TShapeProfileDef::parse_any(),
+                u_shape_profile_def::UShapeProfileDef::parse_any(),
+                z_shape_profile_def::ZShapeProfileDef::parse_any(),
+            )),
+            alt((
+                circle::Circle::parse_any(),
+                composite_curve::CompositeCurve::parse_any(),
+                composite_curve::CompositeCurveSegment::parse_any(),
+                ellipse::Ellipse::parse_any(),
+                line::Line::parse_any(),
+                trimmed_curve::TrimmedCurve::parse_any(),
+                vector::Vector::parse_any(),
+            )),
+            alt((
+                axis::Axis1D::parse_any(),
+                extruded_area_solid_tapered::ExtrudedAreaSolidTapered::parse_any(),
+                fixed_reference_swept_area_solid::FixedReferenceSweptAreaSolid::parse_any(),
+                plane::Plane::parse_any(),
+                revolved_area_solid::RevolvedAreaSolid::parse_any(),
+                surface_curve_swept_area_solid::SurfaceCurveSweptAreaSolid::parse_any(),
+                swept_disk_solid::SweptDiskSolid::parse_any(),
+            )),
+            alt((
+                closed_shell::ClosedShell::parse_any(),
+                face::Face::parse_any(),
+                face::FaceBound::parse_any(),
+                face::FaceOuterBound::parse_any(),
+                faceted_brep::FacetedBrep::parse_any(),
+                indexed_polygonal_face::IndexedPolygonalFace::parse_any(),
+                indexed_polygonal_face::IndexedPolygonalFaceWithVoids::parse_any(),
+                poly_loop::PolyLoop::parse_any(),
+                polygonal_face_set::PolygonalFaceSet::parse_any(),
+                triangulated_face_set::TriangulatedFaceSet::parse_any(),
+            )),
+            alt((
+                boolean_result::BooleanClippingResult::parse_any(),
+                boolean_result::BooleanResult::parse_any(),
+                half_space_solid::HalfSpaceSolid::parse_any(),
+                half_space_solid::PolygonalBoundedHalfSpace::parse_any(),
+            )),
+            alt((
+                grid_axis::GridAxis::parse_any(),
+                grid_placement::GridPlacement::parse_any(),
+                virtual_grid_intersection::VirtualGridIntersection::parse_any(),
+            )),
+            alt((
+                connection_surface_geometry::ConnectionSurfaceGeometry::parse_any(),
+                curve_bounded_plane::CurveBoundedPlane::parse_any(),
+            )),
         ))
     }
 }
@@ -0,0 +1,70 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+};
+
+/// The plane is an unbounded surface with a constant normal. A plane is
+/// defined by a point on the plane and the normal direction to the plane,
+/// given by the location and the z axis of its position.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcplane.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Plane {
+    /// The location and orientation of the surface. The xy plane of the
+    /// position is the plane.
+    pub position: TypedId<Axis3D>,
+}
+
+impl Plane {
+    pub fn new(position: impl Into<IdOr<Axis3D>>, ifc: &mut IFC) -> Self {
+        Self {
+            position: position.into().or_insert(ifc),
+        }
+    }
+}
+
+impl IFCParse for Plane {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPLANE("),
+
+                position: Id::parse().map(TypedId::new),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for Plane {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCPLANE({});", self.position)
+    }
+}
+
+#[typetag::serde(name = "IfcPlane")]
+impl IfcType for Plane {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Plane;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn plane_round_trip() {
+        let example = "IFCPLANE(#42);";
+
+        let parsed: Plane = Plane::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
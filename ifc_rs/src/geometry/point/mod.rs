@@ -8,6 +8,7 @@ use std::{
 
 use glam::{DVec2, DVec3};
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     ifc_type::{IfcType, IfcVerify},
@@ -20,6 +21,17 @@ pub enum PointType<'a> {
     D3(&'a Point3D),
 }
 
+impl<'a> PointType<'a> {
+    /// Coordinates of the point, two dimensional points are placed on the xy
+    /// plane.
+    pub fn to_dvec3(&self) -> DVec3 {
+        match self {
+            PointType::D2(d2) => d2.extend(0.0),
+            PointType::D3(d3) => ****d3,
+        }
+    }
+}
+
 impl<'a> Display for PointType<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -40,7 +52,7 @@ pub trait CartesianPoint: IfcType {}
 /// Coordinates[2] is the Y coordinate.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccartesianpoint.htm
-#[derive(Debug, Clone, Copy, PartialEq, IfcVerify)]
+#[derive(Debug, Clone, Copy, PartialEq, IfcVerify, Serialize, Deserialize)]
 pub struct Point2D(IfcDVec2);
 
 impl Deref for Point2D {
@@ -63,6 +75,7 @@ impl From<DVec2> for Point2D {
     }
 }
 
+#[typetag::serde(name = "IfcCartesianPoint2D")]
 impl IfcType for Point2D {}
 impl CartesianPoint for Point2D {}
 
@@ -75,7 +88,7 @@ impl CartesianPoint for Point2D {}
 /// Coordinates[2] is the Y coordinate, and Coordinates[3] is the Z coordinate.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccartesianpoint.htm
-#[derive(Debug, Clone, Copy, PartialEq, IfcVerify)]
+#[derive(Debug, Clone, Copy, PartialEq, IfcVerify, Serialize, Deserialize)]
 pub struct Point3D(IfcDVec3);
 
 impl Deref for Point3D {
@@ -98,5 +111,6 @@ impl From<DVec3> for Point3D {
     }
 }
 
+#[typetag::serde(name = "IfcCartesianPoint3D")]
 impl IfcType for Point3D {}
 impl CartesianPoint for Point3D {}
@@ -2,6 +2,7 @@ mod deserialize;
 mod serialize;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     ifc_type::{IfcType, IfcVerify},
@@ -21,10 +22,10 @@ use crate::{
 ///
 /// * first dimension is an unbounded list representing each 2D Cartesian point;
 /// * second dimension is a fixed list of two list members, where [1] is the
-/// x-coordinate, and [2] the y-coordinate of the Cartesian point.
+///   x-coordinate, and [2] the y-coordinate of the Cartesian point.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcgeometricmodelresource/lexical/ifccartesianpointlist2d.htm
-#[derive(Debug, Clone, IfcVerify)]
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
 pub struct PointList2D {
     pub coord_list: IfcList<IfcDVec2>,
     pub tag_list: OptionalParameter<IfcList<Label>>,
@@ -39,6 +40,7 @@ impl PointList2D {
     }
 }
 
+#[typetag::serde(name = "IfcCartesianPointList2D")]
 impl IfcType for PointList2D {}
 
 /// The IfcCartesianPointList3D defines an ordered collection of two-dimentional
@@ -48,10 +50,10 @@ impl IfcType for PointList2D {}
 ///
 /// * first dimension is an unbounded list representing each 3D Cartesian point;
 /// * second dimension is a fixed list of two list members, where [1] is the
-/// x-coordinate, [2] is the y-coord, and [3] the z-coordinate of the Cartesian point.
+///   x-coordinate, [2] is the y-coord, and [3] the z-coordinate of the Cartesian point.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcgeometricmodelresource/lexical/ifccartesianpointlist3d.htm
-#[derive(Debug, Clone, IfcVerify)]
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
 pub struct PointList3D {
     pub coord_list: IfcList<IfcDVec3>,
     pub tag_list: OptionalParameter<IfcList<Label>>,
@@ -66,6 +68,7 @@ impl PointList3D {
     }
 }
 
+#[typetag::serde(name = "IfcCartesianPointList3D")]
 impl IfcType for PointList3D {}
 
 pub trait PointList: IfcType {}
@@ -0,0 +1,82 @@
+use std::fmt::Display;
+
+use glam::DVec3;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{list::IfcList, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+};
+
+/// The IfcPolyLoop is a loop with straight edges bounding a planar region in
+/// space. A poly loop is a loop of genus 1 where the loop is represented by
+/// an ordered coplanar collection of points forming the vertices of the
+/// loop. The loop is composed of straight line segments joining a point in
+/// the collection to the succeeding point in the collection. The closing
+/// segment is from the last to the first point in the collection.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpolyloop.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PolyLoop {
+    /// List of points defining the loop. There are no repeated points in the
+    /// list.
+    pub polygon: IfcList<TypedId<Point3D>>,
+}
+
+impl PolyLoop {
+    pub fn new(polygon: impl IntoIterator<Item = TypedId<Point3D>>) -> Self {
+        Self {
+            polygon: IfcList(polygon.into_iter().collect()),
+        }
+    }
+
+    /// Coordinates of the loop vertices.
+    pub fn points(&self, ifc: &IFC) -> Vec<DVec3> {
+        self.polygon
+            .iter()
+            .map(|point| ***ifc.data.get(*point))
+            .collect()
+    }
+}
+
+impl IFCParse for PolyLoop {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPOLYLOOP("),
+
+                polygon: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PolyLoop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCPOLYLOOP({});", self.polygon)
+    }
+}
+
+#[typetag::serde(name = "IfcPolyLoop")]
+impl IfcType for PolyLoop {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::PolyLoop;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn poly_loop_round_trip() {
+        let example = "IFCPOLYLOOP((#101,#102,#103));";
+
+        let parsed: PolyLoop = PolyLoop::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,191 @@
+use std::fmt::Display;
+
+use glam::DVec3;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::indexed_polygonal_face::{
+        zero_based, IndexedPolygonalFace, IndexedPolygonalFaceWithVoids,
+    },
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        bool::IfcBool, comma::Comma, ifc_integer::IfcInteger, list::IfcList,
+        optional::OptionalParameter, p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// The IfcPolygonalFaceSet is a tessellated face set with all faces being
+/// bound by polygons. The planar faces are constructed by implicit polylines
+/// defined by three or more Cartesian points, given by indices into the
+/// coordinate list.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpolygonalfaceset.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PolygonalFaceSet {
+    /// An ordered list of Cartesian points used by the coordinate indices
+    /// of the faces.
+    pub coordinates: TypedId<PointList3D>,
+    /// Indication whether the face set is a closed shell or not. If omitted
+    /// no such information can be provided.
+    pub closed: OptionalParameter<IfcBool>,
+    /// The list of polygonal faces of the face set.
+    #[ifc_types(IndexedPolygonalFace, IndexedPolygonalFaceWithVoids)]
+    pub faces: IfcList<Id>,
+    /// Optional indirection, if given the coordinate indices of the faces
+    /// refer to this list which refers to the coordinate list.
+    pub pn_index: OptionalParameter<IfcList<IfcInteger>>,
+}
+
+impl PolygonalFaceSet {
+    pub fn new(coordinates: impl Into<IdOr<PointList3D>>, ifc: &mut IFC) -> Self {
+        Self {
+            coordinates: coordinates.into().or_insert(ifc),
+            closed: OptionalParameter::omitted(),
+            faces: IfcList::empty(),
+            pn_index: OptionalParameter::omitted(),
+        }
+    }
+
+    /// Builds the face set of a polygon mesh. Faces are given by zero based
+    /// indices into `vertices`.
+    pub fn from_mesh(
+        vertices: impl IntoIterator<Item = DVec3>,
+        faces: impl IntoIterator<Item = impl IntoIterator<Item = usize>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        let coordinates = PointList3D::new(vertices.into_iter());
+
+        faces
+            .into_iter()
+            .fold(Self::new(coordinates, ifc), |face_set, face| {
+                face_set.add_face(IndexedPolygonalFace::new(face), ifc)
+            })
+    }
+
+    pub fn add_face<F: IfcType>(mut self, face: impl Into<IdOr<F>>, ifc: &mut IFC) -> Self {
+        self.faces.0.push(face.into().or_insert(ifc).id());
+        self
+    }
+
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.closed = IfcBool::from(closed).into();
+        self
+    }
+
+    /// Vertices and zero based outer loops of the faces, resolving the pn
+    /// index if given. Voids of the faces are ignored.
+    pub fn mesh(&self, ifc: &IFC) -> (Vec<DVec3>, Vec<Vec<usize>>) {
+        let vertices = ifc
+            .data
+            .get(self.coordinates)
+            .coord_list
+            .iter()
+            .map(|point| **point)
+            .collect();
+
+        let pn_index = self.pn_index.custom().map(zero_based);
+        let faces = self
+            .faces
+            .iter()
+            .filter_map(|face| {
+                let face = ifc.data.get_untyped(*face);
+
+                if let Some(face) = face.downcast_ref::<IndexedPolygonalFace>() {
+                    Some(zero_based(&face.coord_index))
+                } else {
+                    face.downcast_ref::<IndexedPolygonalFaceWithVoids>()
+                        .map(|face| zero_based(&face.coord_index))
+                }
+            })
+            .map(|indices| {
+                indices
+                    .into_iter()
+                    .map(|index| match &pn_index {
+                        Some(pn_index) => pn_index.get(index).copied().unwrap_or_default(),
+                        None => index,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        (vertices, faces)
+    }
+}
+
+impl IFCParse for PolygonalFaceSet {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPOLYGONALFACESET("),
+
+                coordinates: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                closed: OptionalParameter::parse(),
+                _: Comma::parse(),
+                faces: IfcList::parse(),
+                _: Comma::parse(),
+                pn_index: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PolygonalFaceSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCPOLYGONALFACESET({},{},{},{});",
+            self.coordinates, self.closed, self.faces, self.pn_index,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcPolygonalFaceSet")]
+impl IfcType for PolygonalFaceSet {}
+impl ShapeItem for PolygonalFaceSet {}
+
+#[cfg(test)]
+mod test {
+    use glam::DVec3;
+    use winnow::Parser;
+
+    use super::PolygonalFaceSet;
+    use crate::{parser::IFCParse, IFC};
+
+    #[test]
+    fn polygonal_face_set_round_trip() {
+        let example = "IFCPOLYGONALFACESET(#150,.TRUE.,(#151,#152,#153,#154,#155,#156),$);";
+
+        let parsed: PolygonalFaceSet = PolygonalFaceSet::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn polygonal_face_set_from_mesh() {
+        let mut ifc = IFC::default();
+
+        // unit cube
+        let vertices =
+            (0..8).map(|i| DVec3::new((i & 1) as f64, (i >> 1 & 1) as f64, (i >> 2) as f64));
+        let faces = vec![
+            vec![0, 2, 3, 1],
+            vec![4, 5, 7, 6],
+            vec![0, 1, 5, 4],
+            vec![2, 6, 7, 3],
+            vec![0, 4, 6, 2],
+            vec![1, 3, 7, 5],
+        ];
+
+        let face_set = PolygonalFaceSet::from_mesh(vertices, faces.clone(), &mut ifc).closed(true);
+        assert_eq!(face_set.faces.0.len(), 6);
+
+        let (mesh_vertices, mesh_faces) = face_set.mesh(&ifc);
+        assert_eq!(mesh_vertices.len(), 8);
+        assert_eq!(mesh_faces, faces);
+    }
+}
@@ -1,4 +1,6 @@
+use glam::DVec3;
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::Id,
@@ -7,7 +9,7 @@ use crate::{
     prelude::*,
 };
 
-use super::indexed_poly_curve::Curve;
+use super::curve::Curve;
 
 mod deserialize;
 mod serialize;
@@ -17,7 +19,7 @@ mod serialize;
 /// is a closed curve, otherwise it is an open curve.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpolyline.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct PolyLine {
     /// The points defining the polyline.
     #[ifc_types(Point2D, Point3D)]
@@ -55,8 +57,18 @@ impl PolyLine {
     }
 }
 
-impl IfcType for PolyLine {}
-impl Curve for PolyLine {}
+#[typetag::serde(name = "IfcPolyline")]
+impl IfcType for PolyLine {
+    fn to_curve(&self) -> Option<&dyn Curve> {
+        Some(self)
+    }
+}
+
+impl Curve for PolyLine {
+    fn discretize(&self, _tolerance: f64, ifc: &IFC) -> Vec<DVec3> {
+        self.points(ifc).iter().map(PointType::to_dvec3).collect()
+    }
+}
 impl ShapeItem for PolyLine {}
 
 #[cfg(test)]
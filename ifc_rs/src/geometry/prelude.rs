@@ -1,18 +1,57 @@
 pub use super::arbitrary_closed_profile_def::ArbitraryClosedProfileDef;
-pub use super::axis::{Axis2D, Axis3D, AxisPlacement};
+pub use super::arbitrary_profile_def_with_voids::ArbitraryProfileDefWithVoids;
+pub use super::axis::{axis_placement_transform, Axis1D, Axis2D, Axis3D, AxisPlacement};
+pub use super::boolean_result::{BooleanClippingResult, BooleanOperator, BooleanResult};
+pub use super::c_shape_profile_def::CShapeProfileDef;
+pub use super::circle::Circle;
+pub use super::circle_hollow_profile_def::CircleHollowProfileDef;
+pub use super::circle_profile_def::CircleProfileDef;
+pub use super::closed_shell::ClosedShell;
+pub use super::composite_curve::{CompositeCurve, CompositeCurveSegment, TransitionCode};
+pub use super::composite_profile_def::CompositeProfileDef;
+pub use super::connection_surface_geometry::ConnectionSurfaceGeometry;
+pub use super::curve::{discretize_curve, Curve, DEFAULT_CHORD_TOLERANCE};
+pub use super::curve_bounded_plane::CurveBoundedPlane;
 pub use super::dimension_count::DimensionCount;
 pub use super::direction::{Direction2D, Direction3D};
+pub use super::ellipse::Ellipse;
 pub use super::extruded_area_solid::ExtrudedAreaSolid;
+pub use super::extruded_area_solid_tapered::ExtrudedAreaSolidTapered;
+pub use super::face::{Face, FaceBound, FaceOuterBound};
+pub use super::faceted_brep::FacetedBrep;
+pub use super::fixed_reference_swept_area_solid::FixedReferenceSweptAreaSolid;
 pub use super::geometric_projection::GeometricProjection;
-pub use super::indexed_poly_curve::IndexedPolyCurve;
+pub use super::grid_axis::GridAxis;
+pub use super::grid_placement::{object_placement_transform, GridPlacement};
+pub use super::half_space_solid::{HalfSpaceSolid, PolygonalBoundedHalfSpace};
+pub use super::i_shape_profile_def::IShapeProfileDef;
+pub use super::indexed_poly_curve::{IndexedPolyCurve, SegmentIndex};
+pub use super::indexed_polygonal_face::{IndexedPolygonalFace, IndexedPolygonalFaceWithVoids};
+pub use super::l_shape_profile_def::LShapeProfileDef;
+pub use super::line::Line;
 pub use super::local_placement::LocalPlacement;
+pub use super::plane::Plane;
 pub use super::point::{CartesianPoint, Point2D, Point3D, PointType};
 pub use super::point_list::{PointList, PointList2D, PointList3D};
+pub use super::poly_loop::PolyLoop;
+pub use super::polygonal_face_set::PolygonalFaceSet;
 pub use super::polyline::PolyLine;
 pub use super::product_definition_shape::ProductDefinitionShape;
+pub use super::profile_def::{profile_polygons, ProfileDef, ProfilePolygon};
 pub use super::profile_type::ProfileType;
+pub use super::rectangle_hollow_profile_def::RectangleHollowProfileDef;
 pub use super::rectangle_profile_def::RectangleProfileDef;
 pub use super::representation_context::GeometricRepresentationContext;
 pub use super::representation_subcontext::GeometricRepresentationSubContext;
+pub use super::revolved_area_solid::RevolvedAreaSolid;
 pub use super::shape_representation::{ShapeItem, ShapeItemEnum, ShapeRepresentation};
+pub use super::surface_curve_swept_area_solid::SurfaceCurveSweptAreaSolid;
+pub use super::swept_disk_solid::SweptDiskSolid;
+pub use super::t_shape_profile_def::TShapeProfileDef;
 pub use super::transformations::CartesianTransformationOperator3DnonUniform;
+pub use super::triangulated_face_set::TriangulatedFaceSet;
+pub use super::trimmed_curve::{TrimmedCurve, TrimmingPreference, TrimmingSelect};
+pub use super::u_shape_profile_def::UShapeProfileDef;
+pub use super::vector::Vector;
+pub use super::virtual_grid_intersection::VirtualGridIntersection;
+pub use super::z_shape_profile_def::ZShapeProfileDef;
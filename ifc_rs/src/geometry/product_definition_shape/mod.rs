@@ -2,6 +2,7 @@ pub mod deserialize;
 pub mod serialize;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{IdOr, TypedId},
@@ -21,7 +22,7 @@ use crate::{
 /// - or the topological representation items for connectivity systems (vertex, edge, face
 ///   representations) that may include geometric representation items (vertex points, edge curves,
 ///   face surfaces)
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct ProductDefinitionShape {
     // from IfcProductRepresentation https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcproductrepresentation.htm
     //
@@ -74,6 +75,7 @@ impl ProductDefinitionShape {
     }
 }
 
+#[typetag::serde(name = "IfcProductDefinitionShape")]
 impl IfcType for ProductDefinitionShape {}
 
 #[cfg(test)]
@@ -0,0 +1,141 @@
+use glam::{DAffine2, DVec2};
+
+use crate::{id::Id, ifc_type::IfcType, parser::optional::OptionalParameter, prelude::*};
+
+/// Number of line segments circles of profiles are approximated with.
+pub const CIRCLE_SEGMENTS: usize = 32;
+
+/// Profile definitions which can be used as swept areas, e.g. by
+/// `ExtrudedAreaSolid`.
+pub trait ProfileDef: IfcType {
+    /// Evaluates the profile to polygons within the position coordinate
+    /// system of the profile, i.e. the position of parameterized profiles is
+    /// already applied. Composite profiles result in one polygon per
+    /// contained profile, all others in exactly one.
+    ///
+    /// Curved edges are approximated by line segments, fillet and edge radii
+    /// as well as flange slopes of the shape profiles are ignored.
+    fn polygons(&self, ifc: &IFC) -> Vec<ProfilePolygon>;
+}
+
+/// The area of a profile bounded by an outer boundary and optional inner
+/// boundaries (voids). The outer boundary runs counter-clockwise, inner
+/// boundaries clockwise. The boundaries are implicitly closed, the first
+/// point is not repeated at the end.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProfilePolygon {
+    pub outer: Vec<DVec2>,
+    pub inner: Vec<Vec<DVec2>>,
+}
+
+impl ProfilePolygon {
+    pub fn new(outer: Vec<DVec2>) -> Self {
+        Self {
+            outer,
+            inner: Vec::new(),
+        }
+    }
+
+    /// Adds an inner boundary, its orientation is made clockwise.
+    pub fn with_void(mut self, mut void: Vec<DVec2>) -> Self {
+        if signed_area(&void) > 0.0 {
+            void.reverse();
+        }
+        self.inner.push(void);
+        self
+    }
+
+    /// Area enclosed by the outer boundary minus the area of the voids.
+    pub fn area(&self) -> f64 {
+        signed_area(&self.outer) + self.inner.iter().map(|void| signed_area(void)).sum::<f64>()
+    }
+
+    pub fn transformed(mut self, transform: DAffine2) -> Self {
+        for point in self.outer.iter_mut().chain(self.inner.iter_mut().flatten()) {
+            *point = transform.transform_point2(*point);
+        }
+        self
+    }
+}
+
+/// Signed area of a closed polygon, positive for counter-clockwise polygons.
+pub(crate) fn signed_area(points: &[DVec2]) -> f64 {
+    let doubled: f64 = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(a, b)| a.perp_dot(*b))
+        .sum();
+
+    doubled * 0.5
+}
+
+/// Counter-clockwise polygon approximating a circle.
+pub(crate) fn circle(center: DVec2, radius: f64) -> Vec<DVec2> {
+    (0..CIRCLE_SEGMENTS)
+        .map(|segment| {
+            let angle = std::f64::consts::TAU * segment as f64 / CIRCLE_SEGMENTS as f64;
+            center + radius * DVec2::from_angle(angle)
+        })
+        .collect()
+}
+
+/// Counter-clockwise rectangle centered at the origin.
+pub(crate) fn rectangle(x_dim: f64, y_dim: f64) -> Vec<DVec2> {
+    let half = DVec2::new(x_dim, y_dim) * 0.5;
+
+    vec![
+        DVec2::new(-half.x, -half.y),
+        DVec2::new(half.x, -half.y),
+        DVec2::new(half.x, half.y),
+        DVec2::new(-half.x, half.y),
+    ]
+}
+
+/// Transformation of the position of parameterized profiles, the identity if
+/// no position is given. Three dimensional positions are projected onto
+/// their xy plane.
+pub(crate) fn position_transform(position: &OptionalParameter<Id>, ifc: &IFC) -> DAffine2 {
+    let Some(position) = position.custom().map(|id| ifc.data.get_untyped(*id)) else {
+        return DAffine2::IDENTITY;
+    };
+
+    if let Some(axis) = position.downcast_ref::<Axis2D>() {
+        axis.transform(ifc)
+    } else if let Some(axis) = position.downcast_ref::<Axis3D>() {
+        let transform = axis.transform(ifc);
+
+        DAffine2::from_cols(
+            transform.matrix3.x_axis.truncate(),
+            transform.matrix3.y_axis.truncate(),
+            transform.translation.truncate(),
+        )
+    } else {
+        DAffine2::IDENTITY
+    }
+}
+
+/// Points of a bounded curve used as profile boundary, without repeating the
+/// first point at the end of closed curves. Arcs are approximated with the
+/// default chord tolerance.
+pub(crate) fn curve_points(curve: Id, ifc: &IFC) -> Vec<DVec2> {
+    let mut points: Vec<DVec2> = discretize_curve(curve, DEFAULT_CHORD_TOLERANCE, ifc)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|point| point.truncate())
+        .collect();
+
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+
+    points
+}
+
+/// Evaluates any profile definition given by its id, `None` if the entity
+/// isn't a known profile definition.
+pub fn profile_polygons(profile: Id, ifc: &IFC) -> Option<Vec<ProfilePolygon>> {
+    ifc.data
+        .get_untyped(profile)
+        .to_profile_def()
+        .map(|profile| profile.polygons(ifc))
+}
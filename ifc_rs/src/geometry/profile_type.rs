@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, VariantNames};
 use winnow::combinator::{alt, delimited};
 use winnow::Parser;
@@ -10,7 +11,7 @@ use crate::parser::*;
 /// geometrically resolved into a curve or into a surface.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcprofileresource/lexical/ifcprofiletypeenum.htm
-#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
 pub enum ProfileType {
     #[strum(to_string = ".CURVE.")]
     Curve,
@@ -0,0 +1,188 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{
+        profile_def::{position_transform, rectangle, ProfileDef, ProfilePolygon},
+        profile_type::ProfileType,
+    },
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcRectangleHollowProfileDef defines a section profile that provides the defining parameters of
+/// a rectangular (or square) hollow section to be included in the swept area solid. The hollow is
+/// given by the wall thickness, which is constant on all sides. It is placed centric within the
+/// position coordinate system.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcprofileresource/lexical/ifcrectanglehollowprofiledef.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RectangleHollowProfileDef {
+    /// Defines the type of geometry into which this profile definition shall be resolved, either a
+    /// curve or a surface area. In case of curve the profile should be referenced by a swept
+    /// surface, in case of area the profile should be referenced by a swept area solid.
+    pub profile_type: ProfileType,
+    /// Human-readable name of the profile, for example according to a standard profile table. As
+    /// noted above, machine-readable standardized profile designations should be provided in
+    /// IfcExternalReference.ItemReference.
+    pub profile_name: OptionalParameter<Label>,
+    /// Position coordinate system of the parameterized profile definition. If unspecified, no
+    /// translation and no rotation is applied.
+    #[ifc_types(Axis2D, Axis3D)]
+    pub position: OptionalParameter<Id>,
+    /// The extent of the rectangle in the direction of the x-axis.
+    pub x_dim: IfcFloat,
+    /// The extent of the rectangle in the direction of the y-axis.
+    pub y_dim: IfcFloat,
+    /// Thickness of the material.
+    pub wall_thickness: IfcFloat,
+    /// Inner corner radius.
+    pub inner_fillet_radius: OptionalParameter<IfcFloat>,
+    /// Outer corner radius.
+    pub outer_fillet_radius: OptionalParameter<IfcFloat>,
+}
+
+impl RectangleHollowProfileDef {
+    pub fn new(profile_type: ProfileType, x_dim: f64, y_dim: f64, wall_thickness: f64) -> Self {
+        Self {
+            profile_type,
+            profile_name: OptionalParameter::omitted(),
+            position: OptionalParameter::omitted(),
+            x_dim: x_dim.into(),
+            y_dim: y_dim.into(),
+            wall_thickness: wall_thickness.into(),
+            inner_fillet_radius: OptionalParameter::omitted(),
+            outer_fillet_radius: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn profile_name(mut self, name: impl Into<Label>) -> Self {
+        self.profile_name = name.into().into();
+        self
+    }
+
+    pub fn position<A: AxisPlacement>(
+        mut self,
+        position: impl Into<IdOr<A>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.position = position.into().or_insert(ifc).id().into();
+        self
+    }
+
+    pub fn inner_fillet_radius(mut self, inner_fillet_radius: f64) -> Self {
+        self.inner_fillet_radius = IfcFloat(inner_fillet_radius).into();
+        self
+    }
+
+    pub fn outer_fillet_radius(mut self, outer_fillet_radius: f64) -> Self {
+        self.outer_fillet_radius = IfcFloat(outer_fillet_radius).into();
+        self
+    }
+}
+
+impl IFCParse for RectangleHollowProfileDef {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRECTANGLEHOLLOWPROFILEDEF("),
+
+                profile_type: ProfileType::parse(),
+                _: Comma::parse(),
+                profile_name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                position: OptionalParameter::parse(),
+                _: Comma::parse(),
+                x_dim: IfcFloat::parse(),
+                _: Comma::parse(),
+                y_dim: IfcFloat::parse(),
+                _: Comma::parse(),
+                wall_thickness: IfcFloat::parse(),
+                _: Comma::parse(),
+                inner_fillet_radius: OptionalParameter::parse(),
+                _: Comma::parse(),
+                outer_fillet_radius: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RectangleHollowProfileDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCRECTANGLEHOLLOWPROFILEDEF({},{},{},{},{},{},{},{});",
+            self.profile_type,
+            self.profile_name,
+            self.position,
+            self.x_dim,
+            self.y_dim,
+            self.wall_thickness,
+            self.inner_fillet_radius,
+            self.outer_fillet_radius,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRectangleHollowProfileDef")]
+impl IfcType for RectangleHollowProfileDef {
+    fn to_profile_def(&self) -> Option<&dyn ProfileDef> {
+        Some(self)
+    }
+}
+
+impl ProfileDef for RectangleHollowProfileDef {
+    fn polygons(&self, ifc: &IFC) -> Vec<ProfilePolygon> {
+        let polygon =
+            ProfilePolygon::new(rectangle(self.x_dim.0, self.y_dim.0)).with_void(rectangle(
+                self.x_dim.0 - 2.0 * self.wall_thickness.0,
+                self.y_dim.0 - 2.0 * self.wall_thickness.0,
+            ));
+
+        vec![polygon.transformed(position_transform(&self.position, ifc))]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::RectangleHollowProfileDef;
+    use crate::parser::IFCParse;
+    use crate::{
+        geometry::{profile_def::ProfileDef, profile_type::ProfileType},
+        IFC,
+    };
+
+    #[test]
+    fn rectangle_hollow_profile_def_round_trip() {
+        let examples = [
+            "IFCRECTANGLEHOLLOWPROFILEDEF(.AREA.,'RHS 200x100x8',$,0.2,0.1,0.008,0.008,0.016);",
+            "IFCRECTANGLEHOLLOWPROFILEDEF(.AREA.,$,#60,0.4,0.4,0.02,$,$);",
+        ];
+
+        for example in examples {
+            let parsed: RectangleHollowProfileDef =
+                RectangleHollowProfileDef::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn rectangle_hollow_profile_def_polygon() {
+        let ifc = IFC::default();
+        let polygons =
+            RectangleHollowProfileDef::new(ProfileType::Area, 0.4, 0.2, 0.05).polygons(&ifc);
+
+        assert!((polygons[0].area() - (0.4 * 0.2 - 0.3 * 0.1)).abs() < 1e-9);
+    }
+}
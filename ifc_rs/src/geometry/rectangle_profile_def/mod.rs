@@ -2,6 +2,7 @@ mod deserialize;
 mod serialize;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::id::IdOr;
 use crate::ifc_type::{IfcType, IfcVerify};
@@ -10,14 +11,16 @@ use crate::parser::label::Label;
 use crate::prelude::*;
 use crate::{id::Id, parser::optional::OptionalParameter};
 
+use super::profile_def::{position_transform, rectangle, ProfileDef, ProfilePolygon};
 use super::profile_type::ProfileType;
+
 /// IfcRectangleProfileDef defines a rectangle as the profile definition used by the swept surface
 /// geometry or the swept area solid. It is given by its X extent and its Y extent, and placed within
 /// the 2D position coordinate system, established by the Position attribute. It is placed centric
 /// within the position coordinate system.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcprofileresource/lexical/ifcrectangleprofiledef.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct RectangleProfileDef {
     /// Defines the type of geometry into which this profile definition shall be resolved, either a
     /// curve or a surface area. In case of curve the profile should be referenced by a swept
@@ -63,11 +66,20 @@ impl RectangleProfileDef {
     }
 }
 
-impl IfcType for RectangleProfileDef {}
+#[typetag::serde(name = "IfcRectangleProfileDef")]
+impl IfcType for RectangleProfileDef {
+    fn to_profile_def(&self) -> Option<&dyn ProfileDef> {
+        Some(self)
+    }
+}
+
+impl ProfileDef for RectangleProfileDef {
+    fn polygons(&self, ifc: &IFC) -> Vec<ProfilePolygon> {
+        let polygon = ProfilePolygon::new(rectangle(self.x_dim.0, self.y_dim.0));
 
-// TODO: move trait somewhere more general
-pub trait ProfileDef: IfcType {}
-impl ProfileDef for RectangleProfileDef {}
+        vec![polygon.transformed(position_transform(&self.position, ifc))]
+    }
+}
 
 #[cfg(test)]
 mod test {
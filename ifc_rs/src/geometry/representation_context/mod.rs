@@ -2,6 +2,7 @@ mod deserialize;
 mod serialize;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::geometry::dimension_count::DimensionCount;
 use crate::id::{Id, IdOr};
@@ -21,7 +22,7 @@ use super::axis::AxisPlacement;
 /// The TrueNorth attribute can be given, if the y axis of the WorldCoordinateSystem does not point to the global northing.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcrepresentationresource/lexical/ifcgeometricrepresentationcontext.htm
-#[derive(Debug, Clone, IfcVerify)]
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
 pub struct GeometricRepresentationContext {
     // NOTE: from IfcRepresentationContext
     //
@@ -93,4 +94,5 @@ impl GeometricRepresentationContext {
     }
 }
 
+#[typetag::serde(name = "IfcGeometricRepresentationContext")]
 impl IfcType for GeometricRepresentationContext {}
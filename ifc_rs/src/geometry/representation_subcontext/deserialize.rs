@@ -89,7 +89,7 @@ fn parse_geometric_representation_subcontext_works() {
         .unwrap();
     assert_eq!(data, parsed.to_string());
 
-    "IFCGEOMETRICREPRESENTATIONSUBCONTEXT('Axis','Model',0,$,$,$,#12,$,.MODEL_VIEW.,$);";
+    let data = "IFCGEOMETRICREPRESENTATIONSUBCONTEXT('Axis','Model',0,$,$,$,#12,$,.MODEL_VIEW.,$);";
     GeometricRepresentationSubContext::parse()
         .parse(data)
         .unwrap();
@@ -2,6 +2,7 @@ mod deserialize;
 mod serialize;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::geometry::dimension_count::DimensionCount;
 use crate::geometry::geometric_projection::GeometricProjection;
@@ -33,7 +34,7 @@ use super::representation_context::GeometricRepresentationContext;
 /// showing the geometric shape of the product, depending on the target view and scale.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcrepresentationresource/lexical/ifcgeometricrepresentationsubcontext.htm
-#[derive(Debug, Clone, IfcVerify)]
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
 pub struct GeometricRepresentationSubContext {
     // first six fields inherited from IfcGeometricRepresentationContext
     //
@@ -102,8 +103,8 @@ impl GeometricRepresentationSubContext {
         ifc: &mut IFC,
     ) -> Self {
         Self {
-            context_identifier: OptionalParameter::inherited(),
-            context_type: OptionalParameter::inherited(),
+            context_identifier: OptionalParameter::omitted(),
+            context_type: OptionalParameter::omitted(),
             coord_space_dimension: OptionalParameter::inherited(),
             precision: OptionalParameter::inherited(),
             world_coord_system: OptionalParameter::inherited(),
@@ -116,6 +117,16 @@ impl GeometricRepresentationSubContext {
         }
     }
 
+    pub fn context_identifier(mut self, id: impl Into<Label>) -> Self {
+        self.context_identifier = id.into().into();
+        self
+    }
+
+    pub fn context_type(mut self, context_type: impl Into<Label>) -> Self {
+        self.context_type = context_type.into().into();
+        self
+    }
+
     pub fn target_scale(mut self, scale: f64) -> Self {
         self.target_scale = IfcFloat(scale).into();
         self
@@ -127,4 +138,5 @@ impl GeometricRepresentationSubContext {
     }
 }
 
+#[typetag::serde(name = "IfcGeometricRepresentationSubContext")]
 impl IfcType for GeometricRepresentationSubContext {}
@@ -0,0 +1,134 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::profile_def::ProfileDef,
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// The IfcRevolvedAreaSolid is a solid constructed by revolving a cross
+/// section provided by a profile definition about an axis. The axis lies in
+/// the plane of the profile and shall not intersect the interior of the
+/// profile.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrevolvedareasolid.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RevolvedAreaSolid {
+    /// The surface defining the area to be swept. It is given as a
+    /// profile definition within the xy plane of the position coordinate system.
+    #[ifc_types(
+        ArbitraryClosedProfileDef,
+        ArbitraryProfileDefWithVoids,
+        CircleProfileDef,
+        CircleHollowProfileDef,
+        CompositeProfileDef,
+        RectangleProfileDef,
+        RectangleHollowProfileDef,
+        IShapeProfileDef,
+        LShapeProfileDef,
+        TShapeProfileDef,
+        UShapeProfileDef,
+        CShapeProfileDef,
+        ZShapeProfileDef
+    )]
+    pub swept_area: Id,
+
+    /// Position coordinate system for the resulting swept solid of the sweeping
+    /// operation. The position coordinate system allows for re-positioning of
+    /// the swept solid. If not provided, the swept solid remains within the
+    /// position as determined by the cross section or by the directrix used
+    /// for the sweeping operation.
+    pub position: OptionalParameter<TypedId<Axis3D>>,
+
+    /// Axis about which revolution will take place, given within the position
+    /// coordinate system of the swept area.
+    pub axis: TypedId<Axis1D>,
+
+    /// The angle through which the sweep will be made, measured in plane angle
+    /// units of the model.
+    pub angle: IfcFloat,
+}
+
+impl RevolvedAreaSolid {
+    pub fn new<P: ProfileDef>(
+        swept_area: impl Into<IdOr<P>>,
+        axis: impl Into<IdOr<Axis1D>>,
+        angle: f64,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            swept_area: swept_area.into().or_insert(ifc).id(),
+            position: OptionalParameter::omitted(),
+            axis: axis.into().or_insert(ifc),
+            angle: angle.into(),
+        }
+    }
+
+    pub fn position(mut self, position: impl Into<IdOr<Axis3D>>, ifc: &mut IFC) -> Self {
+        self.position = position.into().or_insert(ifc).into();
+        self
+    }
+}
+
+impl IFCParse for RevolvedAreaSolid {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCREVOLVEDAREASOLID("),
+
+                swept_area: Id::parse(),
+                _: Comma::parse(),
+                position: OptionalParameter::parse(),
+                _: Comma::parse(),
+                axis: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                angle: IfcFloat::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RevolvedAreaSolid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCREVOLVEDAREASOLID({},{},{},{});",
+            self.swept_area, self.position, self.axis, self.angle,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRevolvedAreaSolid")]
+impl IfcType for RevolvedAreaSolid {}
+impl ShapeItem for RevolvedAreaSolid {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::RevolvedAreaSolid;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn revolved_area_solid_round_trip() {
+        let examples = [
+            "IFCREVOLVEDAREASOLID(#38,#42,#46,90.);",
+            "IFCREVOLVEDAREASOLID(#38,$,#46,180.);",
+        ];
+
+        for example in examples {
+            let parsed: RevolvedAreaSolid = RevolvedAreaSolid::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+}
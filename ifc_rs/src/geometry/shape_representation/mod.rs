@@ -4,6 +4,7 @@ pub mod serialize;
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{Id, IdOr, TypedId},
@@ -17,7 +18,10 @@ pub trait ShapeItem: IfcType {}
 pub enum ShapeItemEnum<'a> {
     PolyLine(IfcList<PointType<'a>>),
     ExtrudedAreaSolid(&'a ExtrudedAreaSolid),
+    Curve(&'a dyn Curve),
     Dummy(&'a Dummy),
+    /// Items without a dedicated variant, e.g. the other swept solids.
+    Other(&'a dyn IfcType),
 }
 
 impl<'a> Display for ShapeItemEnum<'a> {
@@ -25,7 +29,9 @@ impl<'a> Display for ShapeItemEnum<'a> {
         match self {
             ShapeItemEnum::PolyLine(list) => write!(f, "{list}"),
             ShapeItemEnum::ExtrudedAreaSolid(solid) => write!(f, "{solid}"),
+            ShapeItemEnum::Curve(curve) => write!(f, "{curve}"),
             ShapeItemEnum::Dummy(dummy) => write!(f, "{dummy}"),
+            ShapeItemEnum::Other(item) => write!(f, "{item}"),
         }
     }
 }
@@ -42,7 +48,7 @@ impl<'a> Display for ShapeItemEnum<'a> {
 /// RepresentationIdentifier.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcshaperepresentation.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct ShapeRepresentation {
     // All fields from IfcRepresentation https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrepresentation.htm
     //
@@ -56,7 +62,27 @@ pub struct ShapeRepresentation {
     /// The supported values for context type are to be specified by implementers agreements.
     pub representation_type: OptionalParameter<Label>,
     /// Set of geometric representation items that are defined for this representation.
-    #[ifc_types(ExtrudedAreaSolid, PolyLine, MappedItem)]
+    #[ifc_types(
+        ExtrudedAreaSolid,
+        ExtrudedAreaSolidTapered,
+        RevolvedAreaSolid,
+        SweptDiskSolid,
+        SurfaceCurveSweptAreaSolid,
+        FixedReferenceSweptAreaSolid,
+        FacetedBrep,
+        TriangulatedFaceSet,
+        PolygonalFaceSet,
+        BooleanResult,
+        BooleanClippingResult,
+        PolyLine,
+        IndexedPolyCurve,
+        Line,
+        Circle,
+        Ellipse,
+        TrimmedCurve,
+        CompositeCurve,
+        MappedItem
+    )]
     pub items: IfcList<Id>,
 }
 
@@ -95,13 +121,16 @@ impl ShapeRepresentation {
                 ShapeItemEnum::PolyLine(poly_line.points(ifc))
             } else if let Some(extruded_area_solid) = item.downcast_ref::<ExtrudedAreaSolid>() {
                 ShapeItemEnum::ExtrudedAreaSolid(extruded_area_solid)
+            } else if let Some(curve) = item.to_curve() {
+                ShapeItemEnum::Curve(curve)
             } else if let Some(dummy) = item.downcast_ref::<Dummy>() {
                 ShapeItemEnum::Dummy(dummy)
             } else {
-                todo!()
+                ShapeItemEnum::Other(item)
             }
         })
     }
 }
 
+#[typetag::serde(name = "IfcShapeRepresentation")]
 impl IfcType for ShapeRepresentation {}
@@ -0,0 +1,175 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{curve::Curve, plane::Plane, profile_def::ProfileDef},
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// The IfcSurfaceCurveSweptAreaSolid is the result of sweeping a curve along
+/// a directrix which lies on a reference surface. The orientation of the
+/// curve during the sweeping operation is controlled by the reference
+/// surface, e.g. for vaulted roofs swept along an arc.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcsurfacecurvesweptareasolid.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct SurfaceCurveSweptAreaSolid {
+    /// The surface defining the area to be swept. It is given as a
+    /// profile definition within the xy plane of the position coordinate system.
+    #[ifc_types(
+        ArbitraryClosedProfileDef,
+        ArbitraryProfileDefWithVoids,
+        CircleProfileDef,
+        CircleHollowProfileDef,
+        CompositeProfileDef,
+        RectangleProfileDef,
+        RectangleHollowProfileDef,
+        IShapeProfileDef,
+        LShapeProfileDef,
+        TShapeProfileDef,
+        UShapeProfileDef,
+        CShapeProfileDef,
+        ZShapeProfileDef
+    )]
+    pub swept_area: Id,
+
+    /// Position coordinate system for the resulting swept solid of the sweeping
+    /// operation. The position coordinate system allows for re-positioning of
+    /// the swept solid. If not provided, the swept solid remains within the
+    /// position as determined by the cross section or by the directrix used
+    /// for the sweeping operation.
+    pub position: OptionalParameter<TypedId<Axis3D>>,
+
+    /// The curve used to define the sweeping operation. The solid is generated
+    /// by sweeping the SweptArea along the directrix.
+    #[ifc_types(
+        PolyLine,
+        IndexedPolyCurve,
+        TrimmedCurve,
+        CompositeCurve,
+        Circle,
+        Ellipse,
+        Line
+    )]
+    pub directrix: Id,
+
+    /// The parameter value on the directrix at which the sweeping operation
+    /// commences. If no value is provided the start of the sweeping operation
+    /// is at the start of the directrix.
+    pub start_param: OptionalParameter<IfcFloat>,
+
+    /// The parameter value on the directrix at which the sweeping operation
+    /// ends. If no value is provided the end of the sweeping operation is at
+    /// the end of the directrix.
+    pub end_param: OptionalParameter<IfcFloat>,
+
+    /// The surface containing the directrix.
+    #[ifc_types(Plane)]
+    pub reference_surface: Id,
+}
+
+impl SurfaceCurveSweptAreaSolid {
+    pub fn new<P: ProfileDef, C: Curve>(
+        swept_area: impl Into<IdOr<P>>,
+        directrix: impl Into<IdOr<C>>,
+        reference_surface: impl Into<IdOr<Plane>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            swept_area: swept_area.into().or_insert(ifc).id(),
+            position: OptionalParameter::omitted(),
+            directrix: directrix.into().or_insert(ifc).id(),
+            start_param: OptionalParameter::omitted(),
+            end_param: OptionalParameter::omitted(),
+            reference_surface: reference_surface.into().or_insert(ifc).id(),
+        }
+    }
+
+    pub fn position(mut self, position: impl Into<IdOr<Axis3D>>, ifc: &mut IFC) -> Self {
+        self.position = position.into().or_insert(ifc).into();
+        self
+    }
+
+    pub fn start_param(mut self, start_param: f64) -> Self {
+        self.start_param = IfcFloat(start_param).into();
+        self
+    }
+
+    pub fn end_param(mut self, end_param: f64) -> Self {
+        self.end_param = IfcFloat(end_param).into();
+        self
+    }
+}
+
+impl IFCParse for SurfaceCurveSweptAreaSolid {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCSURFACECURVESWEPTAREASOLID("),
+
+                swept_area: Id::parse(),
+                _: Comma::parse(),
+                position: OptionalParameter::parse(),
+                _: Comma::parse(),
+                directrix: Id::parse(),
+                _: Comma::parse(),
+                start_param: OptionalParameter::parse(),
+                _: Comma::parse(),
+                end_param: OptionalParameter::parse(),
+                _: Comma::parse(),
+                reference_surface: Id::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for SurfaceCurveSweptAreaSolid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCSURFACECURVESWEPTAREASOLID({},{},{},{},{},{});",
+            self.swept_area,
+            self.position,
+            self.directrix,
+            self.start_param,
+            self.end_param,
+            self.reference_surface,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcSurfaceCurveSweptAreaSolid")]
+impl IfcType for SurfaceCurveSweptAreaSolid {}
+impl ShapeItem for SurfaceCurveSweptAreaSolid {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::SurfaceCurveSweptAreaSolid;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn surface_curve_swept_area_solid_round_trip() {
+        let examples = [
+            "IFCSURFACECURVESWEPTAREASOLID(#60,#61,#70,$,$,#75);",
+            "IFCSURFACECURVESWEPTAREASOLID(#60,$,#70,0.,90.,#75);",
+        ];
+
+        for example in examples {
+            let parsed: SurfaceCurveSweptAreaSolid =
+                SurfaceCurveSweptAreaSolid::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::curve::Curve,
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// The IfcSweptDiskSolid represents the 3D shape by a sweeping operation of
+/// a disk along a curved path, e.g. pipes and handrails. The disk may have an
+/// inner radius resulting in a hollow tube.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcsweptdisksolid.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct SweptDiskSolid {
+    /// The curve used to define the sweeping operation. The solid is generated
+    /// by sweeping the disk along the directrix.
+    #[ifc_types(
+        PolyLine,
+        IndexedPolyCurve,
+        TrimmedCurve,
+        CompositeCurve,
+        Circle,
+        Ellipse,
+        Line
+    )]
+    pub directrix: Id,
+
+    /// The radius of the circular disk to be swept along the directrix.
+    pub radius: IfcFloat,
+
+    /// This attribute is optional, if present it defines the radius of a
+    /// circular hole in the centre of the disk.
+    pub inner_radius: OptionalParameter<IfcFloat>,
+
+    /// The parameter value on the directrix at which the sweeping operation
+    /// commences. If no value is provided the start of the sweeping operation
+    /// is at the start of the directrix.
+    pub start_param: OptionalParameter<IfcFloat>,
+
+    /// The parameter value on the directrix at which the sweeping operation
+    /// ends. If no value is provided the end of the sweeping operation is at
+    /// the end of the directrix.
+    pub end_param: OptionalParameter<IfcFloat>,
+}
+
+impl SweptDiskSolid {
+    pub fn new<C: Curve>(directrix: impl Into<IdOr<C>>, radius: f64, ifc: &mut IFC) -> Self {
+        Self {
+            directrix: directrix.into().or_insert(ifc).id(),
+            radius: radius.into(),
+            inner_radius: OptionalParameter::omitted(),
+            start_param: OptionalParameter::omitted(),
+            end_param: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn inner_radius(mut self, inner_radius: f64) -> Self {
+        self.inner_radius = IfcFloat(inner_radius).into();
+        self
+    }
+
+    pub fn start_param(mut self, start_param: f64) -> Self {
+        self.start_param = IfcFloat(start_param).into();
+        self
+    }
+
+    pub fn end_param(mut self, end_param: f64) -> Self {
+        self.end_param = IfcFloat(end_param).into();
+        self
+    }
+}
+
+impl IFCParse for SweptDiskSolid {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCSWEPTDISKSOLID("),
+
+                directrix: Id::parse(),
+                _: Comma::parse(),
+                radius: IfcFloat::parse(),
+                _: Comma::parse(),
+                inner_radius: OptionalParameter::parse(),
+                _: Comma::parse(),
+                start_param: OptionalParameter::parse(),
+                _: Comma::parse(),
+                end_param: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for SweptDiskSolid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCSWEPTDISKSOLID({},{},{},{},{});",
+            self.directrix, self.radius, self.inner_radius, self.start_param, self.end_param,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcSweptDiskSolid")]
+impl IfcType for SweptDiskSolid {}
+impl ShapeItem for SweptDiskSolid {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::SweptDiskSolid;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn swept_disk_solid_round_trip() {
+        let examples = [
+            "IFCSWEPTDISKSOLID(#52,0.025,$,$,$);",
+            "IFCSWEPTDISKSOLID(#52,0.05,0.045,0.,1.);",
+        ];
+
+        for example in examples {
+            let parsed: SweptDiskSolid = SweptDiskSolid::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::IdOr,
@@ -14,12 +15,12 @@ use crate::{
 /// Non uniform scaling is given by three different scaling factors:
 ///
 /// - Scale: the x axis scale factor - Scale2: the y axis scale factor - Scale3: the z axis scale
-/// factor
+///   factor
 ///
 /// If the Scale factor (at supertype IfcCartesianTransformationOperator) is omitted, it defaults
 /// to 1.0. If the Scale2 or the Scale3 factor is omitted, it defaults to the value of Scale (the x
 /// axis scale factor).
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct CartesianTransformationOperator3DnonUniform {
     /// The direction used to determine U[1], the derived X axis direction.
     pub axis_x: IdOr<Direction3D>,
@@ -63,6 +64,7 @@ impl CartesianTransformationOperator3DnonUniform {
     }
 }
 
+#[typetag::serde(name = "IfcCartesianTransformationOperator3DnonUniform")]
 impl IfcType for CartesianTransformationOperator3DnonUniform {}
 
 impl Display for CartesianTransformationOperator3DnonUniform {
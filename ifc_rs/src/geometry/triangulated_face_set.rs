@@ -0,0 +1,187 @@
+use std::fmt::Display;
+
+use glam::DVec3;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::indexed_polygonal_face::{one_based, zero_based},
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        bool::IfcBool, comma::Comma, ifc_float::IfcDVec3, ifc_integer::IfcInteger, list::IfcList,
+        optional::OptionalParameter, p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// The IfcTriangulatedFaceSet is a tessellated face set with all faces being
+/// bound by triangles. The faces are constructed by implicit polylines
+/// defined by three Cartesian points. Each triangle is given by three one
+/// based indices into the coordinate list.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifctriangulatedfaceset.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct TriangulatedFaceSet {
+    /// An ordered list of Cartesian points used by the coordinate index
+    /// defined at the triangles.
+    pub coordinates: TypedId<PointList3D>,
+    /// An ordered list of three-dimensional normals, one per point of the
+    /// coordinate list or of the pn index list.
+    pub normals: OptionalParameter<IfcList<IfcDVec3>>,
+    /// Indication whether the face set is a closed shell or not. If omitted
+    /// no such information can be provided.
+    pub closed: OptionalParameter<IfcBool>,
+    /// The list of triangles, each given by three indices into the
+    /// coordinate list, counter-clockwise when seen from outside.
+    pub coord_index: IfcList<IfcList<IfcInteger>>,
+    /// Optional indirection, if given the coordinate index refers to this
+    /// list which refers to the coordinate list.
+    pub pn_index: OptionalParameter<IfcList<IfcInteger>>,
+}
+
+impl TriangulatedFaceSet {
+    pub fn new(coordinates: impl Into<IdOr<PointList3D>>, ifc: &mut IFC) -> Self {
+        Self {
+            coordinates: coordinates.into().or_insert(ifc),
+            normals: OptionalParameter::omitted(),
+            closed: OptionalParameter::omitted(),
+            coord_index: IfcList::empty(),
+            pn_index: OptionalParameter::omitted(),
+        }
+    }
+
+    /// Builds the face set of a triangle mesh. Triangles are given by zero
+    /// based indices into `vertices`.
+    pub fn from_mesh(
+        vertices: impl IntoIterator<Item = DVec3>,
+        triangles: impl IntoIterator<Item = [usize; 3]>,
+        ifc: &mut IFC,
+    ) -> Self {
+        let coordinates = PointList3D::new(vertices.into_iter());
+
+        triangles
+            .into_iter()
+            .fold(Self::new(coordinates, ifc), |face_set, triangle| {
+                face_set.add_triangle(triangle)
+            })
+    }
+
+    /// Adds a triangle given by zero based indices.
+    pub fn add_triangle(mut self, triangle: [usize; 3]) -> Self {
+        self.coord_index.0.push(one_based(triangle));
+        self
+    }
+
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.closed = IfcBool::from(closed).into();
+        self
+    }
+
+    /// Vertices and zero based triangles of the face set, resolving the pn
+    /// index if given.
+    pub fn mesh(&self, ifc: &IFC) -> (Vec<DVec3>, Vec<[usize; 3]>) {
+        let vertices = ifc
+            .data
+            .get(self.coordinates)
+            .coord_list
+            .iter()
+            .map(|point| **point)
+            .collect();
+
+        let pn_index = self.pn_index.custom().map(zero_based);
+        let triangles = self
+            .coord_index
+            .iter()
+            .filter_map(|triangle| {
+                let indices: Vec<usize> = zero_based(triangle)
+                    .into_iter()
+                    .map(|index| match &pn_index {
+                        Some(pn_index) => pn_index.get(index).copied().unwrap_or_default(),
+                        None => index,
+                    })
+                    .collect();
+
+                indices.try_into().ok()
+            })
+            .collect();
+
+        (vertices, triangles)
+    }
+}
+
+impl IFCParse for TriangulatedFaceSet {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCTRIANGULATEDFACESET("),
+
+                coordinates: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                normals: OptionalParameter::parse(),
+                _: Comma::parse(),
+                closed: OptionalParameter::parse(),
+                _: Comma::parse(),
+                coord_index: IfcList::parse(),
+                _: Comma::parse(),
+                pn_index: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for TriangulatedFaceSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCTRIANGULATEDFACESET({},{},{},{},{});",
+            self.coordinates, self.normals, self.closed, self.coord_index, self.pn_index,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcTriangulatedFaceSet")]
+impl IfcType for TriangulatedFaceSet {}
+impl ShapeItem for TriangulatedFaceSet {}
+
+#[cfg(test)]
+mod test {
+    use glam::DVec3;
+    use winnow::Parser;
+
+    use super::TriangulatedFaceSet;
+    use crate::{parser::IFCParse, IFC};
+
+    #[test]
+    fn triangulated_face_set_round_trip() {
+        let examples = [
+            "IFCTRIANGULATEDFACESET(#140,$,.TRUE.,((1,2,3),(1,3,4),(1,4,2),(2,4,3)),$);",
+            "IFCTRIANGULATEDFACESET(#140,((0.,0.,1.),(0.,1.,0.)),$,((1,2,3)),(1,2,1));",
+        ];
+
+        for example in examples {
+            let parsed: TriangulatedFaceSet = TriangulatedFaceSet::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn triangulated_face_set_from_mesh() {
+        let mut ifc = IFC::default();
+
+        let vertices = [DVec3::ZERO, DVec3::X, DVec3::Y, DVec3::Z];
+        let triangles = [[0, 2, 1], [0, 1, 3], [1, 2, 3], [2, 0, 3]];
+
+        let face_set = TriangulatedFaceSet::from_mesh(vertices, triangles, &mut ifc).closed(true);
+        assert_eq!(
+            face_set.coord_index.to_string(),
+            "((1,3,2),(1,2,4),(2,3,4),(3,1,4))"
+        );
+
+        let (mesh_vertices, mesh_triangles) = face_set.mesh(&ifc);
+        assert_eq!(mesh_vertices, vertices);
+        assert_eq!(mesh_triangles, triangles);
+    }
+}
@@ -0,0 +1,344 @@
+use std::{f64::consts::TAU, fmt::Display, str::FromStr};
+
+use glam::DVec3;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::{
+    combinator::{alt, delimited},
+    Parser,
+};
+
+use crate::{
+    geometry::{
+        circle::Circle,
+        curve::{plane_angle_factor, point_coordinates, Curve},
+        ellipse::Ellipse,
+        line::Line,
+    },
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        bool::IfcBool, comma::Comma, ifc_float::IfcFloat, list::IfcList, p_space_or_comment,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// Trims a curve either at a point on the curve or at a parameter value of
+/// the curve. Parameters of conics are plane angles, of lines lengths along
+/// the line direction.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifctrimmingselect.htm
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TrimmingSelect {
+    /// `IfcCartesianPoint` on the basis curve.
+    Point(Id),
+    /// `IfcParameterValue` of the basis curve.
+    Parameter(IfcFloat),
+}
+
+impl IFCParse for TrimmingSelect {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        alt((
+            Id::parse().map(Self::Point),
+            delimited(
+                p_space_or_comment_surrounded("IFCPARAMETERVALUE("),
+                IfcFloat::parse(),
+                p_space_or_comment_surrounded(")"),
+            )
+            .map(Self::Parameter),
+        ))
+    }
+}
+
+impl Display for TrimmingSelect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrimmingSelect::Point(id) => write!(f, "{id}"),
+            TrimmingSelect::Parameter(value) => write!(f, "IFCPARAMETERVALUE({value})"),
+        }
+    }
+}
+
+/// Indicates the preferred form of representation for the trimming of a
+/// trimmed curve if both a point and a parameter value are given.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifctrimmingpreference.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
+pub enum TrimmingPreference {
+    #[strum(to_string = ".CARTESIAN.")]
+    Cartesian,
+
+    #[strum(to_string = ".PARAMETER.")]
+    Parameter,
+
+    #[strum(to_string = ".UNSPECIFIED.")]
+    Unspecified,
+}
+
+impl IFCParse for TrimmingPreference {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid TrimmingPreference")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+/// An IfcTrimmedCurve is a bounded curve which is created by taking a
+/// selected portion, between two identified points, of an associated basis
+/// curve. The basis curve itself is unaltered and more than one trimmed curve
+/// may reference the same basis curve.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifctrimmedcurve.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct TrimmedCurve {
+    /// The curve to be trimmed. For curves with multiple representations any
+    /// parameter values given as Trim1 or Trim2 refer to the master
+    /// representation of the BasisCurve only.
+    #[ifc_types(Line, Circle, Ellipse)]
+    pub basis_curve: Id,
+    /// The first trimming point which may be specified as a Cartesian point,
+    /// as a real parameter or both.
+    pub trim1: IfcList<TrimmingSelect>,
+    /// The second trimming point which may be specified as a Cartesian point,
+    /// as a real parameter or both.
+    pub trim2: IfcList<TrimmingSelect>,
+    /// Flag to indicate whether the direction of the trimmed curve agrees
+    /// with or is opposed to the direction of the basis curve.
+    pub sense_agreement: IfcBool,
+    /// Where both parameter and point are present at either end of the
+    /// curve this indicates the preferred form.
+    pub master_representation: TrimmingPreference,
+}
+
+impl TrimmedCurve {
+    pub fn new<C: Curve>(
+        basis_curve: impl Into<IdOr<C>>,
+        trim1: TrimmingSelect,
+        trim2: TrimmingSelect,
+        ifc: &mut IFC,
+    ) -> Self {
+        let master_representation = match trim1 {
+            TrimmingSelect::Point(_) => TrimmingPreference::Cartesian,
+            TrimmingSelect::Parameter(_) => TrimmingPreference::Parameter,
+        };
+
+        Self {
+            basis_curve: basis_curve.into().or_insert(ifc).id(),
+            trim1: IfcList(vec![trim1]),
+            trim2: IfcList(vec![trim2]),
+            sense_agreement: IfcBool::True,
+            master_representation,
+        }
+    }
+
+    pub fn sense_agreement(mut self, sense_agreement: bool) -> Self {
+        self.sense_agreement = sense_agreement.into();
+        self
+    }
+
+    /// Parameter of a trim, points are projected onto the basis curve by
+    /// `parameter_of`. Parameter values are multiplied by `factor`.
+    fn trim_parameter(
+        &self,
+        trim: &IfcList<TrimmingSelect>,
+        parameter_of: impl Fn(DVec3) -> f64,
+        factor: f64,
+        ifc: &IFC,
+    ) -> f64 {
+        let parameter = trim.iter().find_map(|trim| match trim {
+            TrimmingSelect::Parameter(value) => Some(value.0 * factor),
+            TrimmingSelect::Point(_) => None,
+        });
+        let point = trim.iter().find_map(|trim| match trim {
+            TrimmingSelect::Point(id) => Some(parameter_of(point_coordinates(*id, ifc))),
+            TrimmingSelect::Parameter(_) => None,
+        });
+
+        match self.master_representation {
+            TrimmingPreference::Cartesian => point.or(parameter),
+            TrimmingPreference::Parameter | TrimmingPreference::Unspecified => parameter.or(point),
+        }
+        .unwrap_or_default()
+    }
+
+    /// Signed angle swept from `start` to `end` on a conic, following the
+    /// sense agreement.
+    fn conic_sweep(&self, start: f64, end: f64) -> f64 {
+        let sweep = match self.sense_agreement {
+            IfcBool::False => -(start - end).rem_euclid(TAU),
+            IfcBool::True | IfcBool::Unknown => (end - start).rem_euclid(TAU),
+        };
+
+        if sweep == 0.0 {
+            match self.sense_agreement {
+                IfcBool::False => -TAU,
+                IfcBool::True | IfcBool::Unknown => TAU,
+            }
+        } else {
+            sweep
+        }
+    }
+}
+
+impl IFCParse for TrimmedCurve {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCTRIMMEDCURVE("),
+
+                basis_curve: Id::parse(),
+                _: Comma::parse(),
+                trim1: IfcList::parse(),
+                _: Comma::parse(),
+                trim2: IfcList::parse(),
+                _: Comma::parse(),
+                sense_agreement: IfcBool::parse(),
+                _: Comma::parse(),
+                master_representation: TrimmingPreference::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for TrimmedCurve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCTRIMMEDCURVE({},{},{},{},{});",
+            self.basis_curve,
+            self.trim1,
+            self.trim2,
+            self.sense_agreement,
+            self.master_representation,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcTrimmedCurve")]
+impl IfcType for TrimmedCurve {
+    fn to_curve(&self) -> Option<&dyn Curve> {
+        Some(self)
+    }
+}
+
+impl ShapeItem for TrimmedCurve {}
+
+impl Curve for TrimmedCurve {
+    fn discretize(&self, tolerance: f64, ifc: &IFC) -> Vec<DVec3> {
+        let basis_curve = ifc.data.get_untyped(self.basis_curve);
+
+        if let Some(line) = basis_curve.downcast_ref::<Line>() {
+            let start = self.trim_parameter(&self.trim1, |p| line.parameter_of(p, ifc), 1.0, ifc);
+            let end = self.trim_parameter(&self.trim2, |p| line.parameter_of(p, ifc), 1.0, ifc);
+
+            vec![line.point_at(start, ifc), line.point_at(end, ifc)]
+        } else if let Some(circle) = basis_curve.downcast_ref::<Circle>() {
+            let factor = plane_angle_factor(ifc);
+            let start = self.trim_parameter(&self.trim1, |p| circle.angle_of(p, ifc), factor, ifc);
+            let end = self.trim_parameter(&self.trim2, |p| circle.angle_of(p, ifc), factor, ifc);
+
+            circle.arc(start, self.conic_sweep(start, end), tolerance, ifc)
+        } else if let Some(ellipse) = basis_curve.downcast_ref::<Ellipse>() {
+            let factor = plane_angle_factor(ifc);
+            let start = self.trim_parameter(&self.trim1, |p| ellipse.angle_of(p, ifc), factor, ifc);
+            let end = self.trim_parameter(&self.trim2, |p| ellipse.angle_of(p, ifc), factor, ifc);
+
+            ellipse.arc(start, self.conic_sweep(start, end), tolerance, ifc)
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::PI;
+
+    use glam::{DVec2, DVec3};
+    use winnow::Parser;
+
+    use super::{TrimmedCurve, TrimmingSelect};
+    use crate::{
+        geometry::{
+            axis::Axis2D,
+            circle::Circle,
+            curve::{plane_angle_factor, Curve},
+            point::Point2D,
+        },
+        objects::project::Project,
+        parser::{optional::OptionalParameter, IFCParse},
+        IFC,
+    };
+
+    #[test]
+    fn trimmed_curve_round_trip() {
+        let examples = [
+            "IFCTRIMMEDCURVE(#36,(IFCPARAMETERVALUE(0.)),(IFCPARAMETERVALUE(90.)),.TRUE.,.PARAMETER.);",
+            "IFCTRIMMEDCURVE(#36,(#40,IFCPARAMETERVALUE(180.)),(#41),.FALSE.,.CARTESIAN.);",
+        ];
+
+        for example in examples {
+            let parsed: TrimmedCurve = TrimmedCurve::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn trimmed_circle_discretization() {
+        let mut ifc = IFC::default();
+
+        let position = Axis2D::new(DVec2::ZERO.into(), &mut ifc);
+        let circle = Circle::new(position, 1.0, &mut ifc);
+        let circle = ifc.data.insert_new(circle);
+
+        let start = ifc.data.insert_new(Point2D::from(DVec2::new(1.0, 0.0)));
+        let end = ifc.data.insert_new(Point2D::from(DVec2::new(0.0, 1.0)));
+
+        let quarter = TrimmedCurve::new(
+            circle,
+            TrimmingSelect::Point(start.id()),
+            TrimmingSelect::Point(end.id()),
+            &mut ifc,
+        );
+        let points = quarter.discretize(0.001, &ifc);
+        assert!(points[0].distance(DVec3::X) < 1e-9);
+        assert!(points.last().unwrap().distance(DVec3::Y) < 1e-9);
+        assert!(points
+            .iter()
+            .all(|point| point.x >= -1e-9 && point.y >= -1e-9));
+
+        // the opposite sense runs clockwise around the other three quarters
+        let rest = TrimmedCurve::new(
+            circle,
+            TrimmingSelect::Point(start.id()),
+            TrimmingSelect::Point(end.id()),
+            &mut ifc,
+        )
+        .sense_agreement(false);
+        let points = rest.discretize(0.001, &ifc);
+        assert!(points.last().unwrap().distance(DVec3::Y) < 1e-9);
+        assert!(points.iter().any(|point| point.x < -0.99));
+    }
+
+    #[test]
+    fn plane_angle_factor_of_project_units() {
+        let mut ifc = IFC::from_file("resources/AC20-FZK-Haus.ifc").unwrap();
+        assert!((plane_angle_factor(&ifc) - PI / 180.0).abs() < 1e-9);
+
+        // degrees which aren't assigned to the project don't apply
+        let (project, _) = ifc.data.find_all_of_type::<Project>().next().unwrap();
+        ifc.data.get_mut(project).units_in_context = OptionalParameter::omitted();
+        assert_eq!(plane_angle_factor(&ifc), 1.0);
+    }
+}
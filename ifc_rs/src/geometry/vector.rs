@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+use glam::DVec3;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::curve::direction_coordinates,
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// The IfcVector is a geometric representation item having both a magnitude
+/// and direction. The magnitude of the vector is solely defined by the
+/// Magnitude attribute and the direction is given by the Orientation
+/// attribute.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcvector.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Vector {
+    /// The direction of the vector.
+    #[ifc_types(Direction2D, Direction3D)]
+    pub orientation: Id,
+    /// The magnitude of the vector. All vectors of magnitude 0.0 are regarded
+    /// as equal in value regardless of the orientation attribute.
+    pub magnitude: IfcFloat,
+}
+
+impl Vector {
+    pub fn new<D: IfcType>(orientation: impl Into<IdOr<D>>, magnitude: f64, ifc: &mut IFC) -> Self {
+        Self {
+            orientation: orientation.into().or_insert(ifc).id(),
+            magnitude: magnitude.into(),
+        }
+    }
+
+    /// The vector scaled by its magnitude, two dimensional vectors lie on
+    /// the xy plane.
+    pub fn value(&self, ifc: &IFC) -> DVec3 {
+        direction_coordinates(self.orientation, ifc) * self.magnitude.0
+    }
+}
+
+impl IFCParse for Vector {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCVECTOR("),
+
+                orientation: Id::parse(),
+                _: Comma::parse(),
+                magnitude: IfcFloat::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for Vector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCVECTOR({},{});", self.orientation, self.magnitude)
+    }
+}
+
+#[typetag::serde(name = "IfcVector")]
+impl IfcType for Vector {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Vector;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn vector_round_trip() {
+        let example = "IFCVECTOR(#33,2.5);";
+
+        let parsed: Vector = Vector::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
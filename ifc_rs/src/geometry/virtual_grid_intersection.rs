@@ -0,0 +1,205 @@
+use std::fmt::Display;
+
+use glam::{DVec2, DVec3};
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, list::IfcList, p_space_or_comment_surrounded, IFCParse,
+        IFCParser,
+    },
+    prelude::*,
+};
+
+/// The virtual grid intersection defines the derived location of the
+/// intersection between two grid axes. Offset values may be given to set an
+/// offset distance to the grid axis for the calculation of the virtual grid
+/// intersection.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcvirtualgridintersection.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct VirtualGridIntersection {
+    /// Two grid axes which intersects at exactly one intersection.
+    pub intersecting_axes: IfcList<TypedId<GridAxis>>,
+    /// Offset distances to the grid axes. The first two distances offset the
+    /// intersecting axes to their left, the optional third one is the offset
+    /// along the z axis of the grid.
+    pub offset_distances: IfcList<IfcFloat>,
+}
+
+impl VirtualGridIntersection {
+    pub fn new(
+        first_axis: impl Into<IdOr<GridAxis>>,
+        second_axis: impl Into<IdOr<GridAxis>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            intersecting_axes: IfcList(vec![
+                first_axis.into().or_insert(ifc),
+                second_axis.into().or_insert(ifc),
+            ]),
+            offset_distances: IfcList(vec![IfcFloat(0.0), IfcFloat(0.0)]),
+        }
+    }
+
+    pub fn offset_distances(mut self, first: f64, second: f64) -> Self {
+        let elevation = self
+            .offset_distances
+            .0
+            .get(2)
+            .map(|elevation| IfcFloat(elevation.0));
+
+        self.offset_distances = IfcList(vec![IfcFloat(first), IfcFloat(second)]);
+        self.offset_distances.0.extend(elevation);
+        self
+    }
+
+    pub fn elevation(mut self, elevation: f64) -> Self {
+        self.offset_distances.0.truncate(2);
+        self.offset_distances.0.push(IfcFloat(elevation));
+        self
+    }
+
+    /// Location of the intersection in the coordinate system of the grid,
+    /// `None` if the (offset) axes don't intersect.
+    pub fn point(&self, ifc: &IFC) -> Option<DVec3> {
+        let offset = |index: usize| self.offset_distances.0.get(index).map_or(0.0, |d| d.0);
+
+        let [first, second] = [0, 1].map(|index| {
+            self.intersecting_axes
+                .0
+                .get(index)
+                .map(|axis| offset_segments(&ifc.data.get(*axis).points(ifc), offset(index)))
+                .unwrap_or_default()
+        });
+
+        let segment_intersection = first.iter().find_map(|a| {
+            second
+                .iter()
+                .find_map(|b| {
+                    line_intersection(*a, *b).filter(|(s, t)| on_segment(*s) && on_segment(*t))
+                })
+                .map(|(s, _)| a.0.lerp(a.1, s))
+        });
+
+        // straight axes are treated as unbounded lines
+        let point =
+            segment_intersection.or_else(|| match (first.as_slice(), second.as_slice()) {
+                ([a], [b]) => line_intersection(*a, *b).map(|(s, _)| a.0.lerp(a.1, s)),
+                _ => None,
+            })?;
+
+        Some(point.extend(offset(2)))
+    }
+}
+
+/// Segments of the polyline in the xy plane moved by `offset` to their left.
+fn offset_segments(points: &[DVec3], offset: f64) -> Vec<(DVec2, DVec2)> {
+    points
+        .windows(2)
+        .filter_map(|window| {
+            let (start, end) = (window[0].truncate(), window[1].truncate());
+            let normal = (end - start).normalize_or_zero().perp();
+
+            (normal != DVec2::ZERO).then(|| (start + offset * normal, end + offset * normal))
+        })
+        .collect()
+}
+
+/// Parameters of the intersection point on both (unbounded) lines, `None`
+/// for parallel lines.
+fn line_intersection(a: (DVec2, DVec2), b: (DVec2, DVec2)) -> Option<(f64, f64)> {
+    let direction_a = a.1 - a.0;
+    let direction_b = b.1 - b.0;
+    let denominator = direction_a.perp_dot(direction_b);
+
+    if denominator.abs() <= f64::EPSILON * direction_a.length() * direction_b.length() {
+        return None;
+    }
+
+    let start_offset = b.0 - a.0;
+    Some((
+        start_offset.perp_dot(direction_b) / denominator,
+        start_offset.perp_dot(direction_a) / denominator,
+    ))
+}
+
+fn on_segment(parameter: f64) -> bool {
+    const EPSILON: f64 = 1e-9;
+    (-EPSILON..=1.0 + EPSILON).contains(&parameter)
+}
+
+impl IFCParse for VirtualGridIntersection {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCVIRTUALGRIDINTERSECTION("),
+
+                intersecting_axes: IfcList::parse(),
+                _: Comma::parse(),
+                offset_distances: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for VirtualGridIntersection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCVIRTUALGRIDINTERSECTION({},{});",
+            self.intersecting_axes, self.offset_distances
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcVirtualGridIntersection")]
+impl IfcType for VirtualGridIntersection {}
+
+#[cfg(test)]
+mod test {
+    use glam::{DVec2, DVec3};
+    use winnow::Parser;
+
+    use super::VirtualGridIntersection;
+    use crate::{parser::IFCParse, prelude::*};
+
+    #[test]
+    fn virtual_grid_intersection_round_trip() {
+        let example = "IFCVIRTUALGRIDINTERSECTION((#44,#52),(0.,0.5));";
+
+        let intersection: VirtualGridIntersection =
+            VirtualGridIntersection::parse().parse(example).unwrap();
+        assert_eq!(example, intersection.to_string());
+    }
+
+    #[test]
+    fn virtual_grid_intersection_point() {
+        let mut ifc = IFC::default();
+
+        let axis = |tag: &str, start: DVec2, end: DVec2, ifc: &mut IFC| {
+            let curve = PolyLine::from([start, end].into_iter().map(Point2D::from), ifc);
+            GridAxis::new(tag, curve, ifc)
+        };
+
+        let first = axis("A", DVec2::new(2.0, -1.0), DVec2::new(2.0, 5.0), &mut ifc);
+        let second = axis("1", DVec2::new(-1.0, 3.0), DVec2::new(5.0, 3.0), &mut ifc);
+        let intersection = VirtualGridIntersection::new(first, second, &mut ifc);
+        assert_eq!(intersection.point(&ifc), Some(DVec3::new(2.0, 3.0, 0.0)));
+
+        // the first axis runs along +y, so its left side is -x
+        let intersection = intersection.offset_distances(0.5, 0.25).elevation(1.0);
+        assert_eq!(intersection.point(&ifc), Some(DVec3::new(1.5, 3.25, 1.0)));
+
+        // axes are unbounded straight lines
+        let third = axis("2", DVec2::new(-1.0, 8.0), DVec2::new(0.0, 8.0), &mut ifc);
+        let first = axis("A", DVec2::new(2.0, -1.0), DVec2::new(2.0, 5.0), &mut ifc);
+        let intersection = VirtualGridIntersection::new(first, third, &mut ifc);
+        assert_eq!(intersection.point(&ifc), Some(DVec3::new(2.0, 8.0, 0.0)));
+    }
+}
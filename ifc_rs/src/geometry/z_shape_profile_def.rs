@@ -0,0 +1,207 @@
+use std::fmt::Display;
+
+use glam::DVec2;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::{
+        profile_def::{position_transform, ProfileDef, ProfilePolygon},
+        profile_type::ProfileType,
+    },
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcZShapeProfileDef defines a section profile that provides the defining parameters of a Z-shape
+/// section for use by the swept area solid. The web is centred on the y-axis, the bottom flange
+/// points into the positive, the top flange into the negative x direction. The origin of the
+/// profile is the center of its bounding box.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcprofileresource/lexical/ifczshapeprofiledef.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct ZShapeProfileDef {
+    /// Defines the type of geometry into which this profile definition shall be resolved, either a
+    /// curve or a surface area. In case of curve the profile should be referenced by a swept
+    /// surface, in case of area the profile should be referenced by a swept area solid.
+    pub profile_type: ProfileType,
+    /// Human-readable name of the profile, for example according to a standard profile table. As
+    /// noted above, machine-readable standardized profile designations should be provided in
+    /// IfcExternalReference.ItemReference.
+    pub profile_name: OptionalParameter<Label>,
+    /// Position coordinate system of the parameterized profile definition. If unspecified, no
+    /// translation and no rotation is applied.
+    #[ifc_types(Axis2D, Axis3D)]
+    pub position: OptionalParameter<Id>,
+    /// Web length (= h).
+    pub depth: IfcFloat,
+    /// Flange length (= b).
+    pub flange_width: IfcFloat,
+    /// Thickness of web (= ts).
+    pub web_thickness: IfcFloat,
+    /// Thickness of flanges (= tg).
+    pub flange_thickness: IfcFloat,
+    /// Fillet radius (= r1).
+    pub fillet_radius: OptionalParameter<IfcFloat>,
+    /// Edge radius (= r2).
+    pub edge_radius: OptionalParameter<IfcFloat>,
+}
+
+impl ZShapeProfileDef {
+    pub fn new(
+        profile_type: ProfileType,
+        depth: f64,
+        flange_width: f64,
+        web_thickness: f64,
+        flange_thickness: f64,
+    ) -> Self {
+        Self {
+            profile_type,
+            profile_name: OptionalParameter::omitted(),
+            position: OptionalParameter::omitted(),
+            depth: depth.into(),
+            flange_width: flange_width.into(),
+            web_thickness: web_thickness.into(),
+            flange_thickness: flange_thickness.into(),
+            fillet_radius: OptionalParameter::omitted(),
+            edge_radius: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn profile_name(mut self, name: impl Into<Label>) -> Self {
+        self.profile_name = name.into().into();
+        self
+    }
+
+    pub fn position<A: AxisPlacement>(
+        mut self,
+        position: impl Into<IdOr<A>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.position = position.into().or_insert(ifc).id().into();
+        self
+    }
+
+    pub fn fillet_radius(mut self, fillet_radius: f64) -> Self {
+        self.fillet_radius = IfcFloat(fillet_radius).into();
+        self
+    }
+
+    pub fn edge_radius(mut self, edge_radius: f64) -> Self {
+        self.edge_radius = IfcFloat(edge_radius).into();
+        self
+    }
+}
+
+impl IFCParse for ZShapeProfileDef {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCZSHAPEPROFILEDEF("),
+
+                profile_type: ProfileType::parse(),
+                _: Comma::parse(),
+                profile_name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                position: OptionalParameter::parse(),
+                _: Comma::parse(),
+                depth: IfcFloat::parse(),
+                _: Comma::parse(),
+                flange_width: IfcFloat::parse(),
+                _: Comma::parse(),
+                web_thickness: IfcFloat::parse(),
+                _: Comma::parse(),
+                flange_thickness: IfcFloat::parse(),
+                _: Comma::parse(),
+                fillet_radius: OptionalParameter::parse(),
+                _: Comma::parse(),
+                edge_radius: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for ZShapeProfileDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCZSHAPEPROFILEDEF({},{},{},{},{},{},{},{},{});",
+            self.profile_type,
+            self.profile_name,
+            self.position,
+            self.depth,
+            self.flange_width,
+            self.web_thickness,
+            self.flange_thickness,
+            self.fillet_radius,
+            self.edge_radius,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcZShapeProfileDef")]
+impl IfcType for ZShapeProfileDef {
+    fn to_profile_def(&self) -> Option<&dyn ProfileDef> {
+        Some(self)
+    }
+}
+
+impl ProfileDef for ZShapeProfileDef {
+    fn polygons(&self, ifc: &IFC) -> Vec<ProfilePolygon> {
+        let d = self.depth.0 * 0.5;
+        let (web, flange) = (self.web_thickness.0 * 0.5, self.flange_thickness.0);
+        let b = self.flange_width.0 - web;
+
+        let polygon = ProfilePolygon::new(vec![
+            DVec2::new(-web, -d),
+            DVec2::new(b, -d),
+            DVec2::new(b, -d + flange),
+            DVec2::new(web, -d + flange),
+            DVec2::new(web, d),
+            DVec2::new(-b, d),
+            DVec2::new(-b, d - flange),
+            DVec2::new(-web, d - flange),
+        ]);
+
+        vec![polygon.transformed(position_transform(&self.position, ifc))]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::ZShapeProfileDef;
+    use crate::parser::IFCParse;
+    use crate::{
+        geometry::{profile_def::ProfileDef, profile_type::ProfileType},
+        IFC,
+    };
+
+    #[test]
+    fn z_shape_profile_def_round_trip() {
+        let examples = ["IFCZSHAPEPROFILEDEF(.AREA.,'Z 160',$,0.16,0.07,0.0075,0.0085,$,$);"];
+
+        for example in examples {
+            let parsed: ZShapeProfileDef = ZShapeProfileDef::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn z_shape_profile_def_polygon() {
+        let ifc = IFC::default();
+        let polygons =
+            ZShapeProfileDef::new(ProfileType::Area, 0.2, 0.1, 0.02, 0.05).polygons(&ifc);
+
+        let area = 2.0 * 0.1 * 0.05 + 0.02 * 0.1;
+        assert!((polygons[0].area() - area).abs() < 1e-9);
+    }
+}
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use winnow::{combinator::alt, Parser};
 
@@ -9,7 +10,7 @@ use crate::{
 
 use super::{Id, TypedId};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum IdOr<T: IfcType> {
     // e.g. #01
     Id(TypedId<T>),
@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use serde::{Deserialize, Serialize};
 use winnow::{ascii::dec_uint, combinator::preceded, Parser};
 
 use crate::{
@@ -9,7 +10,7 @@ use crate::{
 
 use super::TypedId;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Id(pub usize);
 
 impl IFCParse for Id {
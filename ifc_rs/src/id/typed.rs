@@ -3,6 +3,7 @@ use std::{
     marker::PhantomData,
 };
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use winnow::Parser;
 
 use crate::{
@@ -66,6 +67,19 @@ impl<T: IfcType> From<Id> for TypedId<T> {
     }
 }
 
+/// Serialized as the plain [`Id`], the type only exists at compile time.
+impl<T: IfcType> Serialize for TypedId<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.id.serialize(serializer)
+    }
+}
+
+impl<'de, T: IfcType> Deserialize<'de> for TypedId<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Id::deserialize(deserializer).map(Self::new)
+    }
+}
+
 impl<T: IfcType> IFCParse for TypedId<T> {
     fn parse<'a>() -> impl crate::parser::IFCParser<'a, Self>
     where
@@ -81,8 +95,10 @@ impl<T: IfcType> Display for TypedId<T> {
     }
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct FIXMETYPE;
 impl IfcVerify for FIXMETYPE {}
+#[typetag::serde]
 impl IfcType for FIXMETYPE {}
 impl Display for FIXMETYPE {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
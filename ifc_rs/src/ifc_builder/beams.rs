@@ -0,0 +1,180 @@
+use glam::DVec3;
+
+use crate::prelude::*;
+
+pub struct BeamParameter {
+    /// Local to the storey
+    pub start: DVec3,
+    /// Local to the storey
+    pub end: DVec3,
+}
+
+impl<'a> IfcStoreyBuilder<'a> {
+    pub fn beam_type(
+        &mut self,
+        material: TypedId<MaterialProfileSet>,
+        name: &str,
+        beam_type: BeamTypeEnum,
+    ) -> TypedId<BeamType> {
+        let beam_type = BeamType::new(name, beam_type)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .name(name);
+
+        let beam_type_id = self.project.ifc.data.insert_new(beam_type);
+
+        self.beam_type_to_beam.entry(beam_type_id).or_default();
+        self.project
+            .material_to_beam_type
+            .entry(material)
+            .or_insert_with(|| {
+                RelAssociatesMaterial::new(
+                    format!("Material{material:?}ToBeamType"),
+                    material,
+                    &mut self.project.ifc,
+                )
+                .owner_history(self.owner_history, &mut self.project.ifc)
+            })
+            .relate_push(beam_type_id, &mut self.project.ifc);
+
+        beam_type_id
+    }
+
+    /// Creates a beam by extruding the profile of `material` from `start` to
+    /// `end`.
+    ///
+    /// # Panics
+    /// If `start` and `end` are the same point.
+    pub fn beam(
+        &mut self,
+        material: TypedId<MaterialProfileSetUsage>,
+        beam_type: TypedId<BeamType>,
+        name: &str,
+        beam_parameter: BeamParameter,
+    ) -> TypedId<Beam> {
+        let (product_shape, local_placement) =
+            self.axis_extrusion(material, beam_parameter.start, beam_parameter.end);
+
+        let beam = Beam::new(name)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .object_placement(local_placement, &mut self.project.ifc)
+            .representation(product_shape, &mut self.project.ifc);
+
+        let beam_id = self.project.ifc.data.insert_new(beam);
+
+        self.beams.insert(beam_id);
+        self.beam_type_to_beam
+            .entry(beam_type)
+            .or_default()
+            .insert(beam_id);
+        self.project
+            .material_to_beam
+            .entry(material)
+            .or_insert_with(|| {
+                RelAssociatesMaterial::new(
+                    format!("Material{material:?}ToBeams"),
+                    material,
+                    &mut self.project.ifc,
+                )
+                .owner_history(self.owner_history, &mut self.project.ifc)
+            })
+            .relate_push(beam_id, &mut self.project.ifc);
+
+        beam_id
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::DVec3;
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    #[test]
+    fn builder_beams() {
+        let mut builder = create_builder();
+
+        {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+            let material_profile = storey_builder.material_profile(
+                "Steel",
+                RectangleProfileDef::new(ProfileType::Area, 0.1, 0.2),
+            );
+            let material_profile_set = storey_builder.material_profile_set([material_profile]);
+            let material_profile_set_usage =
+                storey_builder.material_profile_set_usage(material_profile_set);
+
+            let beam_type =
+                storey_builder.beam_type(material_profile_set, "IPE 200", BeamTypeEnum::Beam);
+
+            storey_builder.beam(
+                material_profile_set_usage,
+                beam_type,
+                "ExampleBeam",
+                BeamParameter {
+                    start: DVec3::new(0.0, 0.0, 2.5),
+                    end: DVec3::new(0.0, 4.0, 2.5),
+                },
+            );
+        }
+
+        let s = builder.build();
+        let ifc = IFC::from_str(&s).unwrap();
+
+        assert_eq!(s, ifc.to_string());
+
+        let (_, beam) = ifc.data.find_all_of_type::<Beam>().next().unwrap();
+
+        let body = beam.shapes(&ifc).into_iter().next().unwrap();
+        let Some(ShapeItemEnum::ExtrudedAreaSolid(solid)) = body.items(&ifc).next() else {
+            panic!("beam body is not an extruded area solid");
+        };
+        assert_eq!(solid.depth.0, 4.0);
+
+        let placement = ifc
+            .data
+            .get::<LocalPlacement>((*beam.object_placement.custom().unwrap()).into());
+        let position = ifc
+            .data
+            .get_untyped(placement.relative_placement)
+            .downcast_ref::<Axis3D>()
+            .unwrap();
+        let local_z = ifc.data.get(*position.local_z.custom().unwrap());
+        assert_eq!(local_z.to_string(), Direction3D::from(DVec3::Y).to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Axis of a linear element must not have zero length")]
+    fn builder_beam_without_length() {
+        let mut builder = create_builder();
+        let mut site_builder = builder.new_site("test", DVec3::ZERO);
+        let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+        let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+        let material_profile = storey_builder.material_profile(
+            "Steel",
+            RectangleProfileDef::new(ProfileType::Area, 0.1, 0.2),
+        );
+        let material_profile_set = storey_builder.material_profile_set([material_profile]);
+        let material_profile_set_usage =
+            storey_builder.material_profile_set_usage(material_profile_set);
+        let beam_type =
+            storey_builder.beam_type(material_profile_set, "IPE 200", BeamTypeEnum::Beam);
+
+        storey_builder.beam(
+            material_profile_set_usage,
+            beam_type,
+            "ExampleBeam",
+            BeamParameter {
+                start: DVec3::new(1.0, 1.0, 2.5),
+                end: DVec3::new(1.0, 1.0, 2.5),
+            },
+        );
+    }
+}
@@ -20,12 +20,7 @@ impl<'a> IfcBuildingBuilder<'a> {
         building: TypedId<Building>,
         owner_history: TypedId<OwnerHistory>,
     ) -> Self {
-        let sub_context = project
-            .ifc
-            .data
-            .id_of::<GeometricRepresentationSubContext>()
-            .last()
-            .unwrap();
+        let sub_context = project.body_sub_context;
 
         Self {
             project,
@@ -0,0 +1,185 @@
+use crate::{prelude::*, relations::rel_associates_classification::ClassificationRelatable};
+
+impl IfcProjectBuilder {
+    /// Creates a classification system (e.g. `DIN 276` or `Uniclass`) and
+    /// associates it with the project.
+    pub fn classification(
+        &mut self,
+        name: &str,
+        source: &str,
+        edition: &str,
+    ) -> TypedId<Classification> {
+        let classification = Classification::new(name).source(source).edition(edition);
+        let classification_id = self.ifc.data.insert_new(classification);
+
+        let mut classification_relation = RelAssociatesClassification::new(
+            format!("Classification{classification_id:?}ToProject"),
+            classification_id,
+            &mut self.ifc,
+        )
+        .owner_history(self.owner_history, &mut self.ifc);
+        classification_relation.relate_push(self.project, &mut self.ifc);
+
+        self.ifc.data.insert_new(classification_relation);
+
+        classification_id
+    }
+}
+
+impl<'a> IfcStoreyBuilder<'a> {
+    /// Assigns the code `code` of the given classification system to an
+    /// object or type, e.g. a wall, slab, space or wall type. References
+    /// with the same code are shared between all classified objects.
+    pub fn classify<T: ClassificationRelatable>(
+        &mut self,
+        object: TypedId<T>,
+        classification: TypedId<Classification>,
+        code: &str,
+        name: &str,
+    ) {
+        let reference = match self
+            .project
+            .classification_references
+            .get(&(classification, code.to_string()))
+        {
+            Some(reference) => *reference,
+            None => {
+                let reference = ClassificationReference::new(code)
+                    .name(name)
+                    .referenced_source(classification, &mut self.project.ifc);
+                let reference_id = self.project.ifc.data.insert_new(reference);
+
+                self.project
+                    .classification_references
+                    .insert((classification, code.to_string()), reference_id);
+
+                reference_id
+            }
+        };
+
+        self.project
+            .classification_to_objects
+            .entry(reference)
+            .or_insert_with(|| {
+                RelAssociatesClassification::new(
+                    format!("Classification{reference:?}ToObjects"),
+                    reference,
+                    &mut self.project.ifc,
+                )
+                .owner_history(self.owner_history, &mut self.project.ifc)
+            })
+            .relate_push(object, &mut self.project.ifc);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::{DVec2, DVec3};
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    #[test]
+    fn builder_classifications() {
+        let mut builder = create_builder();
+
+        let din_276 = builder.classification("DIN 276", "DIN", "2018-12");
+        let uniclass = builder.classification("Uniclass", "NBS", "2015");
+
+        let (wall, other_wall, slab) = {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+            let material = storey_builder.material("Concrete");
+            let material_layer =
+                MaterialLayer::new(0.2, false).material(material, &mut storey_builder.project.ifc);
+            let material_layer = storey_builder.project.ifc.data.insert_new(material_layer);
+            let material_layer_set = storey_builder.material_layer_set([material_layer]);
+            let material_layer_set_usage = storey_builder.material_layer_set_usage(
+                material_layer_set,
+                LayerSetDirectionEnum::Axis2,
+                DirectionSenseEnum::Positive,
+                0.0,
+            );
+
+            let wall_type = storey_builder.wall_type(
+                material_layer_set,
+                "ExampleWallType",
+                WallTypeEnum::NotDefined,
+            );
+            let slab_type = storey_builder.slab_type(
+                material_layer_set,
+                "ExampleSlabType",
+                SlabTypeEnum::Floor,
+            );
+
+            let wall = storey_builder.vertical_wall(
+                material_layer_set_usage,
+                wall_type,
+                "ExampleWall",
+                VerticalWallParameter {
+                    height: 2.0,
+                    length: 4.0,
+                    placement: DVec3::ZERO,
+                },
+            );
+            let other_wall = storey_builder.vertical_wall(
+                material_layer_set_usage,
+                wall_type,
+                "OtherWall",
+                VerticalWallParameter {
+                    height: 2.0,
+                    length: 4.0,
+                    placement: DVec3::new(0.0, 5.0, 0.0),
+                },
+            );
+            let slab = storey_builder.horizontal_arbitrary_slab(
+                material_layer_set_usage,
+                slab_type,
+                "ExampleSlab",
+                HorizontalArbitrarySlabParameter {
+                    coords: vec![
+                        DVec2::new(0.0, 0.0),
+                        DVec2::new(4.0, 0.0),
+                        DVec2::new(4.0, 4.0),
+                        DVec2::new(0.0, 4.0),
+                    ],
+                    placement: DVec3::ZERO,
+                },
+            );
+
+            storey_builder.classify(wall, din_276, "342", "Nichttragende Aussenwaende");
+            storey_builder.classify(other_wall, din_276, "342", "Nichttragende Aussenwaende");
+            storey_builder.classify(slab, din_276, "351", "Deckenkonstruktionen");
+            storey_builder.classify(wall_type, uniclass, "Ss_25_10", "Framed wall systems");
+
+            (wall, other_wall, slab)
+        };
+
+        let s = builder.build();
+        let ifc = IfcExtractor::from(IFC::from_str(&s).unwrap());
+
+        assert_eq!(s, ifc.to_string());
+
+        // both walls share one reference
+        assert_eq!(
+            ifc.data
+                .find_all_of_type::<ClassificationReference>()
+                .count(),
+            3
+        );
+
+        assert_eq!(ifc.classification_code(wall, "DIN 276"), Some("342"));
+        assert_eq!(ifc.classification_code(other_wall, "DIN 276"), Some("342"));
+        assert_eq!(ifc.classification_code(slab, "DIN 276"), Some("351"));
+        assert_eq!(ifc.classification_code(slab, "Uniclass"), None);
+
+        // codes of the type apply to its occurrences
+        assert_eq!(ifc.classification_code(wall, "Uniclass"), Some("Ss_25_10"));
+        assert_eq!(ifc.classification_references(wall).len(), 2);
+    }
+}
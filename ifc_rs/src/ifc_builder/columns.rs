@@ -0,0 +1,162 @@
+use glam::DVec3;
+
+use crate::prelude::*;
+
+pub struct ColumnParameter {
+    pub height: f64,
+    /// Local to the storey, base point of the column axis
+    pub placement: DVec3,
+}
+
+impl<'a> IfcStoreyBuilder<'a> {
+    pub fn column_type(
+        &mut self,
+        material: TypedId<MaterialProfileSet>,
+        name: &str,
+        column_type: ColumnTypeEnum,
+    ) -> TypedId<ColumnType> {
+        let column_type = ColumnType::new(name, column_type)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .name(name);
+
+        let column_type_id = self.project.ifc.data.insert_new(column_type);
+
+        self.column_type_to_column
+            .entry(column_type_id)
+            .or_default();
+        self.project
+            .material_to_column_type
+            .entry(material)
+            .or_insert_with(|| {
+                RelAssociatesMaterial::new(
+                    format!("Material{material:?}ToColumnType"),
+                    material,
+                    &mut self.project.ifc,
+                )
+                .owner_history(self.owner_history, &mut self.project.ifc)
+            })
+            .relate_push(column_type_id, &mut self.project.ifc);
+
+        column_type_id
+    }
+
+    /// Creates a vertical column by extruding the profile of `material`
+    /// upwards from its base point.
+    ///
+    /// # Panics
+    /// If the height is zero.
+    pub fn column(
+        &mut self,
+        material: TypedId<MaterialProfileSetUsage>,
+        column_type: TypedId<ColumnType>,
+        name: &str,
+        column_parameter: ColumnParameter,
+    ) -> TypedId<Column> {
+        let (product_shape, local_placement) = self.axis_extrusion(
+            material,
+            column_parameter.placement,
+            column_parameter.placement + DVec3::Z * column_parameter.height,
+        );
+
+        let column = Column::new(name)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .object_placement(local_placement, &mut self.project.ifc)
+            .representation(product_shape, &mut self.project.ifc);
+
+        self.insert_column(material, column_type, column)
+    }
+
+    pub(super) fn insert_column(
+        &mut self,
+        material: TypedId<MaterialProfileSetUsage>,
+        column_type: TypedId<ColumnType>,
+        column: Column,
+    ) -> TypedId<Column> {
+        let column_id = self.project.ifc.data.insert_new(column);
+
+        self.columns.insert(column_id);
+        self.column_type_to_column
+            .entry(column_type)
+            .or_default()
+            .insert(column_id);
+        self.project
+            .material_to_column
+            .entry(material)
+            .or_insert_with(|| {
+                RelAssociatesMaterial::new(
+                    format!("Material{material:?}ToColumns"),
+                    material,
+                    &mut self.project.ifc,
+                )
+                .owner_history(self.owner_history, &mut self.project.ifc)
+            })
+            .relate_push(column_id, &mut self.project.ifc);
+
+        column_id
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::DVec3;
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    #[test]
+    fn builder_columns() {
+        let mut builder = create_builder();
+
+        {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+            let material_profile = storey_builder.material_profile(
+                "Concrete",
+                RectangleProfileDef::new(ProfileType::Area, 0.3, 0.3),
+            );
+            let material_profile_set = storey_builder.material_profile_set([material_profile]);
+            let material_profile_set_usage =
+                storey_builder.material_profile_set_usage(material_profile_set);
+
+            let column_type = storey_builder.column_type(
+                material_profile_set,
+                "ExampleColumnType",
+                ColumnTypeEnum::Column,
+            );
+
+            storey_builder.column(
+                material_profile_set_usage,
+                column_type,
+                "ExampleColumn",
+                ColumnParameter {
+                    height: 2.5,
+                    placement: DVec3::new(1.0, 1.0, 0.0),
+                },
+            );
+        }
+
+        let s = builder.build();
+        let ifc = IFC::from_str(&s).unwrap();
+
+        assert_eq!(s, ifc.to_string());
+
+        let (column_id, _) = ifc.data.find_all_of_type::<Column>().next().unwrap();
+        let associates_profile =
+            ifc.data
+                .find_all_of_type::<RelAssociatesMaterial>()
+                .any(|(_, associates)| {
+                    associates.is_related_to(column_id)
+                        && ifc
+                            .data
+                            .get_untyped(associates.relating_material)
+                            .downcast_ref::<MaterialProfileSetUsage>()
+                            .is_some()
+                });
+        assert!(associates_profile);
+    }
+}
@@ -0,0 +1,287 @@
+use glam::DVec3;
+
+use crate::prelude::*;
+
+pub struct CurtainWallParameter {
+    pub height: f64,
+    /// Local to the storey
+    pub start: DVec3,
+    /// Local to the storey, only the horizontal direction towards the end
+    /// is taken into account
+    pub end: DVec3,
+    /// Number of panels along the curtain wall
+    pub columns: usize,
+    /// Number of panels on top of each other
+    pub rows: usize,
+    /// Side length of the square mullion cross section
+    pub mullion_size: f64,
+    pub panel_thickness: f64,
+}
+
+impl<'a> IfcStoreyBuilder<'a> {
+    /// Creates a curtain wall subdividing the rectangle above the axis from
+    /// `start` to `end` into a grid of `columns` x `rows` panels. Mullions
+    /// are centred on the grid lines, the vertical ones run along the whole
+    /// height and the horizontal ones between them. Panels and mullions are
+    /// aggregated under the curtain wall.
+    ///
+    /// # Panics
+    /// If the axis has no horizontal length, if there are no columns or rows
+    /// or if the mullions are as wide as the panels.
+    pub fn curtain_wall(
+        &mut self,
+        name: &str,
+        curtain_wall_parameter: CurtainWallParameter,
+    ) -> TypedId<CurtainWall> {
+        let direction = curtain_wall_parameter.end - curtain_wall_parameter.start;
+        let length = direction.truncate().length();
+        let height = curtain_wall_parameter.height;
+        let columns = curtain_wall_parameter.columns;
+        let rows = curtain_wall_parameter.rows;
+        let mullion_size = curtain_wall_parameter.mullion_size;
+
+        assert!(length > 0.0, "Curtain wall axis must not have zero length");
+        assert!(
+            columns > 0 && rows > 0,
+            "Curtain wall needs at least one column and row"
+        );
+
+        let position = Axis3D::new(
+            Point3D::from(curtain_wall_parameter.start),
+            &mut self.project.ifc,
+        )
+        .local_x(
+            Direction3D::from(DVec3::new(direction.x, direction.y, 0.0)),
+            &mut self.project.ifc,
+        );
+        let local_placement =
+            LocalPlacement::new_relative(position, self.storey, &mut self.project.ifc);
+        let local_placement = self.project.ifc.data.insert_new(local_placement);
+
+        let column_width = length / columns as f64;
+        let row_height = height / rows as f64;
+        assert!(
+            mullion_size < column_width.min(row_height),
+            "Mullions must be smaller than the panels"
+        );
+
+        let mullion_profile = self.project.ifc.data.insert_new(RectangleProfileDef::new(
+            ProfileType::Area,
+            mullion_size,
+            mullion_size,
+        ));
+        let panel_profile = self.project.ifc.data.insert_new(RectangleProfileDef::new(
+            ProfileType::Area,
+            column_width - mullion_size,
+            curtain_wall_parameter.panel_thickness,
+        ));
+
+        let mut parts = Vec::new();
+
+        // vertical mullions
+        for column in 0..=columns {
+            let mullion = self.curtain_wall_member(
+                name,
+                mullion_profile.id(),
+                height,
+                DVec3::new(column as f64 * column_width, 0.0, 0.0),
+                DVec3::Z,
+                local_placement,
+            );
+            parts.push(mullion.id());
+        }
+
+        // horizontal mullions between the vertical ones
+        for row in 0..=rows {
+            for column in 0..columns {
+                let mullion = self.curtain_wall_member(
+                    name,
+                    mullion_profile.id(),
+                    column_width - mullion_size,
+                    DVec3::new(
+                        column as f64 * column_width + mullion_size * 0.5,
+                        0.0,
+                        row as f64 * row_height,
+                    ),
+                    DVec3::X,
+                    local_placement,
+                );
+                parts.push(mullion.id());
+            }
+        }
+
+        // panels filling the space between the mullions
+        for row in 0..rows {
+            for column in 0..columns {
+                let panel = self.curtain_wall_panel(
+                    name,
+                    panel_profile.id(),
+                    row_height - mullion_size,
+                    DVec3::new(
+                        (column as f64 + 0.5) * column_width,
+                        0.0,
+                        row as f64 * row_height + mullion_size * 0.5,
+                    ),
+                    local_placement,
+                );
+                parts.push(panel.id());
+            }
+        }
+
+        let curtain_wall = CurtainWall::new(name)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .object_placement(local_placement, &mut self.project.ifc);
+
+        let curtain_wall_id = self.project.ifc.data.insert_new(curtain_wall);
+
+        let curtain_wall_parts =
+            RelAggregates::new("CurtainWallToParts", curtain_wall_id.id(), parts)
+                .owner_history(self.owner_history, &mut self.project.ifc);
+        self.project.ifc.data.insert_new(curtain_wall_parts);
+
+        self.curtain_walls.insert(curtain_wall_id);
+
+        curtain_wall_id
+    }
+
+    /// Mullion extruded from `start` along `direction` within the curtain
+    /// wall placement.
+    fn curtain_wall_member(
+        &mut self,
+        name: &str,
+        profile: Id,
+        length: f64,
+        start: DVec3,
+        direction: DVec3,
+        curtain_wall_placement: TypedId<LocalPlacement>,
+    ) -> TypedId<Member> {
+        let product_shape = self.profile_extrusion_shape(profile, length);
+
+        let mut position = Axis3D::new(Point3D::from(start), &mut self.project.ifc);
+        if direction != DVec3::Z {
+            position = position
+                .local_z(Direction3D::from(direction), &mut self.project.ifc)
+                .local_x(
+                    Direction3D::from(DVec3::Z.cross(direction)),
+                    &mut self.project.ifc,
+                );
+        }
+        let local_placement = LocalPlacement::new(position, &mut self.project.ifc)
+            .relative_to(curtain_wall_placement, &mut self.project.ifc);
+
+        let member = Member::new(name)
+            .predefined_type(MemberTypeEnum::Mullion)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .object_placement(local_placement, &mut self.project.ifc)
+            .representation(product_shape, &mut self.project.ifc);
+
+        self.project.ifc.data.insert_new(member)
+    }
+
+    /// Panel with the center of its bottom at `bottom_center` within the
+    /// curtain wall placement.
+    fn curtain_wall_panel(
+        &mut self,
+        name: &str,
+        profile: Id,
+        height: f64,
+        bottom_center: DVec3,
+        curtain_wall_placement: TypedId<LocalPlacement>,
+    ) -> TypedId<Plate> {
+        let product_shape = self.profile_extrusion_shape(profile, height);
+
+        let position = Axis3D::new(Point3D::from(bottom_center), &mut self.project.ifc);
+        let local_placement = LocalPlacement::new(position, &mut self.project.ifc)
+            .relative_to(curtain_wall_placement, &mut self.project.ifc);
+
+        let panel = Plate::new(name)
+            .predefined_type(PlateTypeEnum::CurtainPanel)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .object_placement(local_placement, &mut self.project.ifc)
+            .representation(product_shape, &mut self.project.ifc);
+
+        self.project.ifc.data.insert_new(panel)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::DVec3;
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    #[test]
+    fn builder_curtain_wall() {
+        let mut builder = create_builder();
+
+        let curtain_wall = {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+            storey_builder.curtain_wall(
+                "Facade",
+                CurtainWallParameter {
+                    height: 3.0,
+                    start: DVec3::new(0.0, 0.0, 0.0),
+                    end: DVec3::new(6.0, 0.0, 0.0),
+                    columns: 3,
+                    rows: 2,
+                    mullion_size: 0.125,
+                    panel_thickness: 0.03125,
+                },
+            )
+        };
+
+        let s = builder.build();
+        let ifc = IfcExtractor::from(IFC::from_str(&s).unwrap());
+
+        assert_eq!(s, ifc.to_string());
+
+        let panels = ifc.relations_of::<CurtainWall, Plate>(curtain_wall);
+        assert_eq!(panels.len(), 6);
+
+        // 4 vertical mullions and 3 horizontal ones in each of the 3 rows
+        let mullions = ifc.relations_of::<CurtainWall, Member>(curtain_wall);
+        assert_eq!(mullions.len(), 4 + 3 * 3);
+
+        // the last panel sits in the upper right cell between the mullions
+        let (last_panel, _) = panels[5];
+        let bottom_center = ifc
+            .placement_transform(last_panel)
+            .transform_point3(DVec3::ZERO);
+        assert!(bottom_center.abs_diff_eq(DVec3::new(5.0, 0.0, 1.5 + 0.0625), 1e-9));
+
+        // the curtain wall is contained in the storey, its parts are aggregated
+        let (storey, _) = ifc.data.find_all_of_type::<Storey>().next().unwrap();
+        let contained = ifc.contained_structures(storey);
+        assert!(contained.contains(&curtain_wall.id()));
+        assert!(!contained.contains(&last_panel.id()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Mullions must be smaller than the panels")]
+    fn builder_curtain_wall_with_oversized_mullions() {
+        let mut builder = create_builder();
+        let mut site_builder = builder.new_site("test", DVec3::ZERO);
+        let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+        let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+        storey_builder.curtain_wall(
+            "Facade",
+            CurtainWallParameter {
+                height: 3.0,
+                start: DVec3::new(0.0, 0.0, 0.0),
+                end: DVec3::new(1.0, 0.0, 0.0),
+                columns: 4,
+                rows: 2,
+                mullion_size: 0.25,
+                panel_thickness: 0.03125,
+            },
+        );
+    }
+}
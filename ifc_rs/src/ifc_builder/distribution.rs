@@ -0,0 +1,330 @@
+use glam::DVec3;
+
+use crate::{prelude::*, relations::rel_assigns_to_group::GroupRelatable};
+
+/// Cross section of the segments of a route.
+pub enum FlowSegmentProfile {
+    Circular {
+        diameter: f64,
+    },
+    /// The width is horizontal for non vertical segments.
+    Rectangular {
+        width: f64,
+        height: f64,
+    },
+}
+
+pub struct FlowRouteParameter {
+    /// Local to the storey, a segment is created between each pair of
+    /// consecutive points. Repeated points are skipped.
+    pub points: Vec<DVec3>,
+    pub profile: FlowSegmentProfile,
+}
+
+impl<'a> IfcStoreyBuilder<'a> {
+    pub fn pipe_segment_type(
+        &mut self,
+        name: &str,
+        pipe_segment_type: PipeSegmentTypeEnum,
+    ) -> TypedId<PipeSegmentType> {
+        let pipe_segment_type = PipeSegmentType::new(name, pipe_segment_type)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .name(name);
+
+        let pipe_segment_type_id = self.project.ifc.data.insert_new(pipe_segment_type);
+
+        self.pipe_segment_type_to_pipe_segment
+            .entry(pipe_segment_type_id)
+            .or_default();
+
+        pipe_segment_type_id
+    }
+
+    pub fn duct_segment_type(
+        &mut self,
+        name: &str,
+        duct_segment_type: DuctSegmentTypeEnum,
+    ) -> TypedId<DuctSegmentType> {
+        let duct_segment_type = DuctSegmentType::new(name, duct_segment_type)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .name(name);
+
+        let duct_segment_type_id = self.project.ifc.data.insert_new(duct_segment_type);
+
+        self.duct_segment_type_to_duct_segment
+            .entry(duct_segment_type_id)
+            .or_default();
+
+        duct_segment_type_id
+    }
+
+    /// Routes pipe segments of `system` along the points of the route. Each
+    /// segment gets an inlet port at its start and an outlet port at its
+    /// end, the outlet of a segment is connected to the inlet of the next
+    /// one.
+    ///
+    /// # Panics
+    /// If the route has less than two distinct points.
+    pub fn pipe_route(
+        &mut self,
+        system: TypedId<DistributionSystem>,
+        pipe_segment_type: TypedId<PipeSegmentType>,
+        name: &str,
+        route_parameter: FlowRouteParameter,
+    ) -> Vec<TypedId<PipeSegment>> {
+        let pipe_segments = self.flow_route(
+            system,
+            DistributionPortTypeEnum::Pipe,
+            route_parameter,
+            || PipeSegment::new(name),
+        );
+
+        self.pipe_segments.extend(pipe_segments.iter().copied());
+        self.pipe_segment_type_to_pipe_segment
+            .entry(pipe_segment_type)
+            .or_default()
+            .extend(pipe_segments.iter().copied());
+
+        pipe_segments
+    }
+
+    /// Routes duct segments of `system` along the points of the route. Each
+    /// segment gets an inlet port at its start and an outlet port at its
+    /// end, the outlet of a segment is connected to the inlet of the next
+    /// one.
+    ///
+    /// # Panics
+    /// If the route has less than two distinct points.
+    pub fn duct_route(
+        &mut self,
+        system: TypedId<DistributionSystem>,
+        duct_segment_type: TypedId<DuctSegmentType>,
+        name: &str,
+        route_parameter: FlowRouteParameter,
+    ) -> Vec<TypedId<DuctSegment>> {
+        let duct_segments = self.flow_route(
+            system,
+            DistributionPortTypeEnum::Duct,
+            route_parameter,
+            || DuctSegment::new(name),
+        );
+
+        self.duct_segments.extend(duct_segments.iter().copied());
+        self.duct_segment_type_to_duct_segment
+            .entry(duct_segment_type)
+            .or_default()
+            .extend(duct_segments.iter().copied());
+
+        duct_segments
+    }
+
+    fn flow_route<T: ProductBuilder + RootBuilder + GroupRelatable>(
+        &mut self,
+        system: TypedId<DistributionSystem>,
+        port_type: DistributionPortTypeEnum,
+        route_parameter: FlowRouteParameter,
+        segment: impl Fn() -> T,
+    ) -> Vec<TypedId<T>> {
+        let mut points = route_parameter.points;
+        points.dedup();
+        assert!(
+            points.len() >= 2,
+            "Route needs at least two distinct points"
+        );
+
+        let profile = match route_parameter.profile {
+            FlowSegmentProfile::Circular { diameter } => self
+                .project
+                .ifc
+                .data
+                .insert_new(CircleProfileDef::new(ProfileType::Area, diameter * 0.5))
+                .id(),
+            FlowSegmentProfile::Rectangular { width, height } => self
+                .project
+                .ifc
+                .data
+                .insert_new(RectangleProfileDef::new(ProfileType::Area, width, height))
+                .id(),
+        };
+
+        let mut segments = Vec::new();
+        let mut previous_outlet: Option<TypedId<DistributionPort>> = None;
+
+        for points in points.windows(2) {
+            let (start, end) = (points[0], points[1]);
+            let length = start.distance(end);
+
+            let product_shape = self.profile_extrusion_shape(profile, length);
+            let local_placement = self.axis_placement(start, end);
+            let local_placement = self.project.ifc.data.insert_new(local_placement);
+
+            let segment = segment()
+                .owner_history(self.owner_history, &mut self.project.ifc)
+                .object_placement(local_placement, &mut self.project.ifc)
+                .representation(product_shape, &mut self.project.ifc);
+
+            let segment_id = self.project.ifc.data.insert_new(segment);
+
+            let inlet = self.distribution_port(
+                "Inlet",
+                FlowDirectionEnum::Sink,
+                port_type,
+                system,
+                DVec3::ZERO,
+                local_placement,
+            );
+            let outlet = self.distribution_port(
+                "Outlet",
+                FlowDirectionEnum::Source,
+                port_type,
+                system,
+                DVec3::new(0.0, 0.0, length),
+                local_placement,
+            );
+
+            let nests = RelNests::new("SegmentToPorts", segment_id.id(), [inlet.id(), outlet.id()])
+                .owner_history(self.owner_history, &mut self.project.ifc);
+            self.project.ifc.data.insert_new(nests);
+
+            if let Some(previous_outlet) = previous_outlet {
+                let connection = RelConnectsPorts::new(
+                    "PortToPort",
+                    previous_outlet,
+                    inlet,
+                    &mut self.project.ifc,
+                )
+                .owner_history(self.owner_history, &mut self.project.ifc);
+                self.project.ifc.data.insert_new(connection);
+            }
+            previous_outlet = Some(outlet);
+
+            self.assign_to_group(system, segment_id);
+            segments.push(segment_id);
+        }
+
+        segments
+    }
+
+    /// Port placed at `position` within the placement of its element.
+    fn distribution_port(
+        &mut self,
+        name: &str,
+        flow_direction: FlowDirectionEnum,
+        port_type: DistributionPortTypeEnum,
+        system: TypedId<DistributionSystem>,
+        position: DVec3,
+        element_placement: TypedId<LocalPlacement>,
+    ) -> TypedId<DistributionPort> {
+        let position = Axis3D::new(Point3D::from(position), &mut self.project.ifc);
+        let local_placement = LocalPlacement::new(position, &mut self.project.ifc)
+            .relative_to(element_placement, &mut self.project.ifc);
+
+        let mut port = DistributionPort::new(name)
+            .flow_direction(flow_direction)
+            .predefined_type(port_type)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .object_placement(local_placement, &mut self.project.ifc);
+        if let Some(system_type) = self.project.ifc.data.get(system).predefined_type.custom() {
+            port = port.system_type(*system_type);
+        }
+
+        self.project.ifc.data.insert_new(port)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::DVec3;
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    #[test]
+    fn builder_pipe_and_duct_routes() {
+        let mut builder = create_builder();
+
+        let heating = builder.distribution_system("Heating", DistributionSystemEnum::Heating);
+        let ventilation =
+            builder.distribution_system("Ventilation", DistributionSystemEnum::Ventilation);
+
+        let (pipe_segments, duct_segments) = {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+            let pipe_type =
+                storey_builder.pipe_segment_type("Copper 22", PipeSegmentTypeEnum::RigidSegment);
+            let pipe_segments = storey_builder.pipe_route(
+                heating,
+                pipe_type,
+                "Supply",
+                FlowRouteParameter {
+                    points: vec![
+                        DVec3::new(0.0, 0.0, 0.2),
+                        DVec3::new(4.0, 0.0, 0.2),
+                        // repeated points don't create a segment
+                        DVec3::new(4.0, 0.0, 0.2),
+                        DVec3::new(4.0, 3.0, 0.2),
+                        DVec3::new(4.0, 3.0, 2.5),
+                    ],
+                    profile: FlowSegmentProfile::Circular { diameter: 0.022 },
+                },
+            );
+
+            let duct_type =
+                storey_builder.duct_segment_type("Duct 400x200", DuctSegmentTypeEnum::RigidSegment);
+            let duct_segments = storey_builder.duct_route(
+                ventilation,
+                duct_type,
+                "Supply air",
+                FlowRouteParameter {
+                    points: vec![DVec3::new(0.0, 1.0, 2.6), DVec3::new(5.0, 1.0, 2.6)],
+                    profile: FlowSegmentProfile::Rectangular {
+                        width: 0.4,
+                        height: 0.2,
+                    },
+                },
+            );
+
+            (pipe_segments, duct_segments)
+        };
+
+        let s = builder.build();
+        let ifc = IfcExtractor::from(IFC::from_str(&s).unwrap());
+
+        assert_eq!(s, ifc.to_string());
+
+        assert_eq!(pipe_segments.len(), 3);
+        assert_eq!(duct_segments.len(), 1);
+
+        let ports = ifc.ports_of(pipe_segments[1]);
+        assert_eq!(ports.len(), 2);
+        assert!(ports.iter().all(|(_, port)| matches!(
+            port.system_type.custom(),
+            Some(DistributionSystemEnum::Heating)
+        )));
+
+        // the middle segment is connected to both of its neighbours
+        let mut connected = ifc.connected_flow_elements(pipe_segments[1]);
+        connected.sort();
+        assert_eq!(
+            connected,
+            vec![pipe_segments[0].id(), pipe_segments[2].id()]
+        );
+        assert_eq!(
+            ifc.connected_flow_elements(pipe_segments[0]),
+            vec![pipe_segments[1].id()]
+        );
+        assert!(ifc.connected_flow_elements(duct_segments[0]).is_empty());
+
+        let mut heating_members = ifc.group_members(heating);
+        heating_members.sort();
+        assert_eq!(
+            heating_members,
+            pipe_segments.iter().map(|id| id.id()).collect::<Vec<_>>()
+        );
+    }
+}
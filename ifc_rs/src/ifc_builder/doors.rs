@@ -0,0 +1,378 @@
+use std::{collections::HashSet, f64::consts::FRAC_PI_2};
+
+use glam::{DVec2, DVec3};
+
+use crate::prelude::*;
+
+/// Number of line segments a quarter circle of a door swing is made of.
+const SWING_SEGMENTS: usize = 16;
+
+pub struct DoorParameter {
+    pub height: f64,
+    pub width: f64,
+    /// Local to the attached parent
+    pub placement: DVec3,
+}
+
+impl<'a> IfcStoreyBuilder<'a> {
+    pub fn door_type(
+        &mut self,
+        name: &str,
+        door_type: DoorTypeEnum,
+        operation_type: DoorTypeOperationEnum,
+    ) -> TypedId<DoorType> {
+        let door_type = DoorType::new(name, door_type, operation_type)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .name(name);
+
+        let door_type_id = self.project.ifc.data.insert_new(door_type);
+
+        self.door_type_to_door.insert(door_type_id, HashSet::new());
+
+        door_type_id
+    }
+
+    /// Assumes the given `opening_element` is attached to a wall. The door
+    /// opens into the direction of the positive y-axis of the wall.
+    pub fn wall_door(
+        &mut self,
+        material: TypedId<MaterialConstituentSet>,
+        door_type: TypedId<DoorType>,
+        opening_element: TypedId<OpeningElement>,
+        name: &str,
+        door_parameter: DoorParameter,
+    ) -> TypedId<Door> {
+        let wall = self.opening_elements_to_wall.get(&opening_element).unwrap();
+        let wall_material_set_usage = self
+            .project
+            .material_to_wall
+            .iter()
+            .find_map(|(mat, associates)| associates.is_related_to(*wall).then_some(mat))
+            .copied()
+            .unwrap();
+        // NOTE: same as for windows, the door leaf is centered in the opening element gap
+        let door_thickness =
+            self.calculate_material_layer_set_thickness(wall_material_set_usage) / 3.0;
+        let operation_type = self.project.ifc.data.get(door_type).operation_type;
+
+        let body_repr = ShapeRepresentation::new(self.sub_context, &mut self.project.ifc)
+            .identifier("Body")
+            .repr_type("SweptSolid")
+            .add_item(
+                ExtrudedAreaSolid::new(
+                    RectangleProfileDef::new(
+                        ProfileType::Area,
+                        door_parameter.width,
+                        door_thickness,
+                    )
+                    // center of the rectangle
+                    .position(
+                        Axis2D::new(
+                            Point2D::from(DVec2::new(
+                                door_parameter.width * 0.5,
+                                door_thickness * 0.5,
+                            )),
+                            &mut self.project.ifc,
+                        ),
+                        &mut self.project.ifc,
+                    ),
+                    Direction3D::from(DVec3::new(0.0, 0.0, 1.0)),
+                    door_parameter.height,
+                    &mut self.project.ifc,
+                ),
+                &mut self.project.ifc,
+            );
+
+        let mut plan_repr =
+            ShapeRepresentation::new(self.project.plan_sub_context, &mut self.project.ifc)
+                .identifier("Plan")
+                .repr_type("Curve2D");
+        for curve in door_swing(door_parameter.width, door_thickness, operation_type) {
+            plan_repr = plan_repr.add_item(
+                PolyLine::from(curve.into_iter().map(Point2D::from), &mut self.project.ifc),
+                &mut self.project.ifc,
+            );
+        }
+
+        let product_shape = ProductDefinitionShape::new()
+            .add_representation(body_repr, &mut self.project.ifc)
+            .add_representation(plan_repr, &mut self.project.ifc);
+
+        let position = Axis3D::new(
+            Point3D::from(door_parameter.placement + DVec3::new(0., door_thickness, 0.)),
+            &mut self.project.ifc,
+        );
+        let local_placement =
+            LocalPlacement::new_relative(position, opening_element, &mut self.project.ifc);
+
+        let door = Door::new(name)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .representation(product_shape, &mut self.project.ifc)
+            .object_placement(local_placement, &mut self.project.ifc)
+            .overall_height(door_parameter.height)
+            .overall_width(door_parameter.width)
+            .operation_type(operation_type);
+
+        let door_id = self.project.ifc.data.insert_new(door);
+
+        self.doors.insert(door_id);
+        self.opening_elements_to_door
+            .insert(opening_element, door_id);
+        self.door_type_to_door
+            .entry(door_type)
+            .or_default()
+            .insert(door_id);
+        self.project
+            .material_to_door
+            .entry(material)
+            .or_insert_with(|| {
+                RelAssociatesMaterial::new(
+                    format!("Material{material:?}ToDoors"),
+                    material,
+                    &mut self.project.ifc,
+                )
+                .owner_history(self.owner_history, &mut self.project.ifc)
+            })
+            .relate_push(door_id, &mut self.project.ifc);
+
+        if self.base_quantities {
+            self.filling_base_quantities(
+                door_id.id(),
+                "Qto_DoorBaseQuantities",
+                door_parameter.width,
+                door_parameter.height,
+            );
+        }
+
+        door_id
+    }
+
+    /// Creates a wall door filling its whole opening. Also handle creation of
+    /// the opening element.
+    pub fn wall_door_with_opening(
+        &mut self,
+        door_material: TypedId<MaterialConstituentSet>,
+        door_type: TypedId<DoorType>,
+        wall: TypedId<Wall>,
+        name: &str,
+        door_parameter: DoorParameter,
+    ) -> TypedId<Door> {
+        let opening_element = self.vertical_wall_opening(
+            wall,
+            &format!("OpeningElementOfDoor{name}"),
+            VerticalOpeningParameter {
+                height: door_parameter.height,
+                length: door_parameter.width,
+                placement: door_parameter.placement,
+            },
+        );
+
+        self.wall_door(
+            door_material,
+            door_type,
+            opening_element,
+            name,
+            DoorParameter {
+                height: door_parameter.height,
+                width: door_parameter.width,
+                placement: DVec3::new(0.0, 0.0, 0.0),
+            },
+        )
+    }
+}
+
+/// The 2D curves of a door on the floor plan, local to the door placement:
+/// the opened leaves and their swing arcs for swinging doors, the leaves in
+/// closed position otherwise. The leaves open into the positive y direction.
+fn door_swing(
+    width: f64,
+    thickness: f64,
+    operation_type: DoorTypeOperationEnum,
+) -> Vec<Vec<DVec2>> {
+    use DoorTypeOperationEnum::*;
+
+    // (hinge position, leaf width, opening direction along x, swing side)
+    let leaves: Vec<(f64, f64, f64, f64)> = match operation_type {
+        SingleSwingLeft | SwingFixedLeft => vec![(0.0, width, 1.0, 1.0)],
+        SingleSwingRight | SwingFixedRight => vec![(width, width, -1.0, 1.0)],
+        DoubleSwingLeft => vec![(0.0, width, 1.0, 1.0), (0.0, width, 1.0, -1.0)],
+        DoubleSwingRight => vec![(width, width, -1.0, 1.0), (width, width, -1.0, -1.0)],
+        DoubleDoorSingleSwing => vec![
+            (0.0, width * 0.5, 1.0, 1.0),
+            (width, width * 0.5, -1.0, 1.0),
+        ],
+        DoubleDoorSingleSwingOppositeLeft => vec![
+            (0.0, width * 0.5, 1.0, 1.0),
+            (width, width * 0.5, -1.0, -1.0),
+        ],
+        DoubleDoorSingleSwingOppositeRight => vec![
+            (0.0, width * 0.5, 1.0, -1.0),
+            (width, width * 0.5, -1.0, 1.0),
+        ],
+        DoubleDoorDoubleSwing => vec![
+            (0.0, width * 0.5, 1.0, 1.0),
+            (0.0, width * 0.5, 1.0, -1.0),
+            (width, width * 0.5, -1.0, 1.0),
+            (width, width * 0.5, -1.0, -1.0),
+        ],
+        _ => {
+            // non swinging doors are drawn closed
+            return vec![vec![
+                DVec2::new(0.0, 0.0),
+                DVec2::new(width, 0.0),
+                DVec2::new(width, thickness),
+                DVec2::new(0.0, thickness),
+                DVec2::new(0.0, 0.0),
+            ]];
+        }
+    };
+
+    // swings to the negative side start on the opposite face of the door
+    let face = |side: f64| if side > 0.0 { thickness } else { 0.0 };
+
+    leaves
+        .into_iter()
+        .flat_map(|(hinge, leaf_width, direction, side)| {
+            let hinge = DVec2::new(hinge, face(side));
+
+            let leaf = vec![hinge, hinge + DVec2::new(0.0, side * leaf_width)];
+            let arc = (0..=SWING_SEGMENTS)
+                .map(|segment| {
+                    let angle = FRAC_PI_2 * segment as f64 / SWING_SEGMENTS as f64;
+                    hinge
+                        + DVec2::new(
+                            direction * leaf_width * angle.cos(),
+                            side * leaf_width * angle.sin(),
+                        )
+                })
+                .collect();
+
+            [leaf, arc]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::{DVec2, DVec3};
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+    use super::door_swing;
+
+    #[test]
+    fn single_swing_left_door_swing() {
+        let curves = door_swing(1.0, 0.1, DoorTypeOperationEnum::SingleSwingLeft);
+        assert_eq!(curves.len(), 2);
+
+        let leaf = &curves[0];
+        assert_eq!(leaf[0], DVec2::new(0.0, 0.1));
+        assert_eq!(leaf[1], DVec2::new(0.0, 1.1));
+
+        let arc = &curves[1];
+        assert_eq!(arc.first().copied(), Some(DVec2::new(1.0, 0.1)));
+        assert!(arc.last().unwrap().distance(DVec2::new(0.0, 1.1)) < 1e-9);
+        assert!(arc
+            .iter()
+            .all(|point| (point.distance(DVec2::new(0.0, 0.1)) - 1.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn builder_doors() {
+        let mut builder = create_builder();
+
+        {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+            let material_layer = storey_builder.material_layer("ExampleMaterial", 0.02, false);
+            let material_layer_set = storey_builder.material_layer_set([material_layer]);
+            let material_layer_set_usage = storey_builder.material_layer_set_usage(
+                material_layer_set,
+                LayerSetDirectionEnum::Axis2,
+                DirectionSenseEnum::Positive,
+                0.0,
+            );
+
+            let wall_type = storey_builder.wall_type(
+                material_layer_set,
+                "ExampleWallType",
+                WallTypeEnum::NotDefined,
+            );
+
+            let wall = storey_builder.vertical_wall(
+                material_layer_set_usage,
+                wall_type,
+                "ExampleWallDefault",
+                VerticalWallParameter {
+                    height: 2.5,
+                    length: 4.0,
+                    placement: DVec3::new(0.0, 0.0, 0.0),
+                },
+            );
+
+            let door_type = storey_builder.door_type(
+                "ExampleDoorType",
+                DoorTypeEnum::Door,
+                DoorTypeOperationEnum::SingleSwingLeft,
+            );
+
+            let material_constituent = storey_builder.material_constituent("Wood", "Leaf");
+            let material_constituent_set =
+                storey_builder.material_constituent_set([material_constituent]);
+
+            storey_builder.wall_door_with_opening(
+                material_constituent_set,
+                door_type,
+                wall,
+                "ExampleDoor",
+                DoorParameter {
+                    height: 2.0,
+                    width: 1.0,
+                    placement: DVec3::new(1.0, 0.0, 0.0),
+                },
+            );
+        }
+
+        let s = builder.build();
+        let ifc = IFC::from_str(&s).unwrap();
+
+        assert_eq!(s, ifc.to_string());
+
+        let (door_id, door) = ifc.data.find_all_of_type::<Door>().next().unwrap();
+        assert_eq!(door.overall_width.custom().map(|width| width.0), Some(1.0));
+
+        let fills = ifc
+            .data
+            .find_all_of_type::<RelFillsElement>()
+            .find(|(_, fills)| fills.related_building_element == door_id.id());
+        assert!(fills.is_some());
+
+        let plan = door
+            .shapes(&ifc)
+            .into_iter()
+            .find(|shape| {
+                shape
+                    .representation_identifier
+                    .custom()
+                    .is_some_and(|identifier| identifier.0 == "Plan")
+            })
+            .unwrap();
+        assert_eq!(plan.items(&ifc).count(), 2);
+
+        // the door swing is given in the plan context
+        let plan_context = ifc.data.get(plan.context_of_items);
+        assert!(matches!(
+            plan_context.target_view,
+            GeometricProjection::PlanView
+        ));
+        assert!(plan_context
+            .context_type
+            .custom()
+            .is_some_and(|context_type| context_type.0 == "Plan"));
+    }
+}
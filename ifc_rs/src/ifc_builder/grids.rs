@@ -0,0 +1,351 @@
+use glam::{DVec2, DVec3};
+
+use crate::prelude::*;
+
+pub struct OrthogonalGridParameter<'b> {
+    /// Tags and x coordinates of the axes running along the y axis of the grid
+    pub u_axes: Vec<(&'b str, f64)>,
+    /// Tags and y coordinates of the axes running along the x axis of the grid
+    pub v_axes: Vec<(&'b str, f64)>,
+    /// Length the axes extend beyond the outermost crossing axes
+    pub overhang: f64,
+    /// Local to the storey, origin of the grid
+    pub placement: DVec3,
+}
+
+pub struct RadialGridParameter<'b> {
+    /// Tags and angles (in radians, counter clockwise from the x axis of the
+    /// grid) of the axes radiating from the grid origin
+    pub radial_axes: Vec<(&'b str, f64)>,
+    /// Tags and radii of the circular axes around the grid origin
+    pub circular_axes: Vec<(&'b str, f64)>,
+    /// Length the radial axes extend beyond the outermost circular axis
+    pub overhang: f64,
+    /// Local to the storey, origin of the grid
+    pub placement: DVec3,
+}
+
+pub struct GridColumnParameter<'b> {
+    pub grid: TypedId<Grid>,
+    /// Tag of the first intersecting axis
+    pub u_axis: &'b str,
+    /// Tag of the second intersecting axis
+    pub v_axis: &'b str,
+    pub height: f64,
+}
+
+impl<'a> IfcStoreyBuilder<'a> {
+    /// Creates a rectangular grid with straight axes parallel to the x and y
+    /// axis of the grid.
+    pub fn orthogonal_grid(
+        &mut self,
+        name: &str,
+        grid_parameter: OrthogonalGridParameter,
+    ) -> TypedId<Grid> {
+        let (x_min, x_max) = extent(&grid_parameter.u_axes, grid_parameter.overhang);
+        let (y_min, y_max) = extent(&grid_parameter.v_axes, grid_parameter.overhang);
+
+        let u_axes = grid_parameter
+            .u_axes
+            .iter()
+            .map(|(tag, x)| self.line_axis(tag, DVec2::new(*x, y_min), DVec2::new(*x, y_max)))
+            .collect();
+        let v_axes = grid_parameter
+            .v_axes
+            .iter()
+            .map(|(tag, y)| self.line_axis(tag, DVec2::new(x_min, *y), DVec2::new(x_max, *y)))
+            .collect();
+
+        self.grid(
+            name,
+            GridTypeEnum::Rectangular,
+            u_axes,
+            v_axes,
+            grid_parameter.placement,
+        )
+    }
+
+    /// Creates a radial grid with straight axes radiating from the grid
+    /// origin and circular axes around it.
+    pub fn radial_grid(
+        &mut self,
+        name: &str,
+        grid_parameter: RadialGridParameter,
+    ) -> TypedId<Grid> {
+        let (_, radius) = extent(&grid_parameter.circular_axes, grid_parameter.overhang);
+
+        let u_axes = grid_parameter
+            .radial_axes
+            .iter()
+            .map(|(tag, angle)| {
+                self.line_axis(tag, DVec2::ZERO, DVec2::from_angle(*angle) * radius)
+            })
+            .collect();
+        let v_axes = grid_parameter
+            .circular_axes
+            .iter()
+            .map(|(tag, radius)| {
+                let position = Axis2D::new(Point2D::from(DVec2::ZERO), &mut self.project.ifc);
+                let circle = Circle::new(position, *radius, &mut self.project.ifc);
+                let axis = GridAxis::new(*tag, circle, &mut self.project.ifc);
+
+                self.project.ifc.data.insert_new(axis)
+            })
+            .collect();
+
+        self.grid(
+            name,
+            GridTypeEnum::Radial,
+            u_axes,
+            v_axes,
+            grid_parameter.placement,
+        )
+    }
+
+    /// Placement at the intersection of the grid axes tagged `u_axis` and
+    /// `v_axis`.
+    ///
+    /// # Panics
+    /// If the grid has no axis with one of the tags or if the axes don't
+    /// intersect.
+    pub fn grid_placement(
+        &mut self,
+        grid: TypedId<Grid>,
+        u_axis: &str,
+        v_axis: &str,
+    ) -> TypedId<GridPlacement> {
+        let [u_axis, v_axis] = [u_axis, v_axis].map(|tag| {
+            self.project
+                .ifc
+                .data
+                .get(grid)
+                .axis(tag, &self.project.ifc)
+                .unwrap_or_else(|| panic!("Grid axis {tag} couldn't be found"))
+        });
+
+        let intersection = VirtualGridIntersection::new(u_axis, v_axis, &mut self.project.ifc);
+        assert!(
+            intersection.point(&self.project.ifc).is_some(),
+            "Grid axes don't intersect"
+        );
+
+        let grid_placement = GridPlacement::new(intersection, &mut self.project.ifc);
+
+        self.project.ifc.data.insert_new(grid_placement)
+    }
+
+    /// Creates a vertical column standing at a grid intersection.
+    ///
+    /// # Panics
+    /// See [`Self::grid_placement`].
+    pub fn grid_column(
+        &mut self,
+        material: TypedId<MaterialProfileSetUsage>,
+        column_type: TypedId<ColumnType>,
+        name: &str,
+        column_parameter: GridColumnParameter,
+    ) -> TypedId<Column> {
+        let grid_placement = self.grid_placement(
+            column_parameter.grid,
+            column_parameter.u_axis,
+            column_parameter.v_axis,
+        );
+        let product_shape = self.axis_extrusion_shape(material, column_parameter.height);
+
+        let column = Column::new(name)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .grid_placement(grid_placement, &mut self.project.ifc)
+            .representation(product_shape, &mut self.project.ifc);
+
+        self.insert_column(material, column_type, column)
+    }
+
+    fn grid(
+        &mut self,
+        name: &str,
+        grid_type: GridTypeEnum,
+        u_axes: Vec<TypedId<GridAxis>>,
+        v_axes: Vec<TypedId<GridAxis>>,
+        placement: DVec3,
+    ) -> TypedId<Grid> {
+        // the axis curves are shared with the footprint representation
+        let mut footprint = ShapeRepresentation::new(self.sub_context, &mut self.project.ifc)
+            .identifier("FootPrint")
+            .repr_type("Curve2D");
+        footprint.items.0.extend(
+            u_axes
+                .iter()
+                .chain(v_axes.iter())
+                .map(|axis| self.project.ifc.data.get(*axis).axis_curve),
+        );
+        let product_shape =
+            ProductDefinitionShape::new().add_representation(footprint, &mut self.project.ifc);
+
+        let position = Axis3D::new(Point3D::from(placement), &mut self.project.ifc);
+        let local_placement =
+            LocalPlacement::new_relative(position, self.storey, &mut self.project.ifc);
+
+        let mut grid = Grid::new(name)
+            .predefined_type(grid_type)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .object_placement(local_placement, &mut self.project.ifc)
+            .representation(product_shape, &mut self.project.ifc);
+
+        for axis in u_axes {
+            grid = grid.add_u_axis(axis, &mut self.project.ifc);
+        }
+        for axis in v_axes {
+            grid = grid.add_v_axis(axis, &mut self.project.ifc);
+        }
+
+        let grid_id = self.project.ifc.data.insert_new(grid);
+        self.grids.insert(grid_id);
+
+        grid_id
+    }
+
+    fn line_axis(&mut self, tag: &str, start: DVec2, end: DVec2) -> TypedId<GridAxis> {
+        let poly_line = PolyLine::from(
+            [start, end].into_iter().map(Point2D::from),
+            &mut self.project.ifc,
+        );
+        let axis = GridAxis::new(tag, poly_line, &mut self.project.ifc);
+
+        self.project.ifc.data.insert_new(axis)
+    }
+}
+
+/// Range covered by the axis positions, extended by `overhang` on both ends.
+fn extent(axes: &[(&str, f64)], overhang: f64) -> (f64, f64) {
+    let (min, max) = axes.iter().fold(
+        (f64::INFINITY, f64::NEG_INFINITY),
+        |(min, max), (_, position)| (min.min(*position), max.max(*position)),
+    );
+
+    if min > max {
+        (-overhang, overhang)
+    } else {
+        (min - overhang, max + overhang)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{f64::consts::FRAC_PI_2, str::FromStr};
+
+    use glam::DVec3;
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    #[test]
+    fn builder_grids() {
+        let mut builder = create_builder();
+
+        let (orthogonal_column, radial_column) = {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 3.0);
+
+            let material_profile = storey_builder.material_profile(
+                "Concrete",
+                RectangleProfileDef::new(ProfileType::Area, 0.3, 0.3),
+            );
+            let material_profile_set = storey_builder.material_profile_set([material_profile]);
+            let material_profile_set_usage =
+                storey_builder.material_profile_set_usage(material_profile_set);
+            let column_type = storey_builder.column_type(
+                material_profile_set,
+                "ExampleColumnType",
+                ColumnTypeEnum::Column,
+            );
+
+            let orthogonal_grid = storey_builder.orthogonal_grid(
+                "Grid",
+                OrthogonalGridParameter {
+                    u_axes: vec![("A", 0.0), ("B", 5.0), ("C", 10.0)],
+                    v_axes: vec![("1", 0.0), ("2", 6.0)],
+                    overhang: 1.0,
+                    placement: DVec3::new(10.0, 0.0, 0.0),
+                },
+            );
+            let radial_grid = storey_builder.radial_grid(
+                "RadialGrid",
+                RadialGridParameter {
+                    radial_axes: vec![("R1", 0.0), ("R2", FRAC_PI_2)],
+                    circular_axes: vec![("C1", 5.0), ("C2", 10.0)],
+                    overhang: 1.0,
+                    placement: DVec3::new(-20.0, 0.0, 0.0),
+                },
+            );
+
+            let orthogonal_column = storey_builder.grid_column(
+                material_profile_set_usage,
+                column_type,
+                "OrthogonalColumn",
+                GridColumnParameter {
+                    grid: orthogonal_grid,
+                    u_axis: "B",
+                    v_axis: "2",
+                    height: 2.5,
+                },
+            );
+            let radial_column = storey_builder.grid_column(
+                material_profile_set_usage,
+                column_type,
+                "RadialColumn",
+                GridColumnParameter {
+                    grid: radial_grid,
+                    u_axis: "R2",
+                    v_axis: "C2",
+                    height: 2.5,
+                },
+            );
+
+            (orthogonal_column, radial_column)
+        };
+
+        let s = builder.build();
+        let ifc = IFC::from_str(&s).unwrap();
+
+        assert_eq!(s, ifc.to_string());
+
+        assert_eq!(ifc.data.find_all_of_type::<Grid>().count(), 2);
+        let contained_in_storey = ifc
+            .data
+            .find_all_of_type::<RelContainedInSpatialStructure>()
+            .flat_map(|(_, relation)| relation.related_elements.0.clone())
+            .filter(|id| ifc.data.get_untyped(*id).downcast_ref::<Grid>().is_some())
+            .count();
+        assert_eq!(contained_in_storey, 2);
+
+        let orthogonal_location = ifc.placement_transform(orthogonal_column).translation;
+        assert!(orthogonal_location.abs_diff_eq(DVec3::new(15.0, 6.0, 3.0), 1e-9));
+
+        // the circular axes are approximated within the chord tolerance
+        let radial_location = ifc.placement_transform(radial_column).translation;
+        assert!(radial_location.abs_diff_eq(DVec3::new(-20.0, 10.0, 3.0), DEFAULT_CHORD_TOLERANCE));
+    }
+
+    #[test]
+    #[should_panic(expected = "Grid axes don't intersect")]
+    fn builder_grid_placement_of_parallel_axes() {
+        let mut builder = create_builder();
+        let mut site_builder = builder.new_site("test", DVec3::ZERO);
+        let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+        let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+        let grid = storey_builder.orthogonal_grid(
+            "Grid",
+            OrthogonalGridParameter {
+                u_axes: vec![("A", 0.0), ("B", 5.0)],
+                v_axes: vec![("1", 0.0)],
+                overhang: 1.0,
+                placement: DVec3::ZERO,
+            },
+        );
+
+        storey_builder.grid_placement(grid, "A", "B");
+    }
+}
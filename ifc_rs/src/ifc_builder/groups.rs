@@ -0,0 +1,152 @@
+use crate::{
+    prelude::*,
+    relations::rel_assigns_to_group::{GroupRelatable, RelatableGroup},
+};
+
+impl IfcProjectBuilder {
+    /// Creates a zone, e.g. a fire compartment, an apartment or a HVAC zone.
+    /// Zones are not part of the spatial structure, so spaces of several
+    /// storeys can be assigned to the same zone.
+    pub fn zone(&mut self, name: &str, long_name: &str) -> TypedId<Zone> {
+        let zone = Zone::new(name)
+            .long_name(long_name)
+            .owner_history(self.owner_history, &mut self.ifc);
+
+        self.ifc.data.insert_new(zone)
+    }
+
+    /// Creates a building system grouping building elements which serve a
+    /// common function, e.g. the load bearing structure.
+    pub fn building_system(
+        &mut self,
+        name: &str,
+        predefined_type: BuildingSystemTypeEnum,
+    ) -> TypedId<BuildingSystem> {
+        let building_system = BuildingSystem::new(name)
+            .predefined_type(predefined_type)
+            .owner_history(self.owner_history, &mut self.ifc);
+
+        self.ifc.data.insert_new(building_system)
+    }
+
+    /// Creates a distribution system, e.g. a heating circuit or a
+    /// ventilation system. The ports of segments routed within the system
+    /// get the system type of its predefined type.
+    pub fn distribution_system(
+        &mut self,
+        name: &str,
+        predefined_type: DistributionSystemEnum,
+    ) -> TypedId<DistributionSystem> {
+        let distribution_system = DistributionSystem::new(name)
+            .predefined_type(predefined_type)
+            .owner_history(self.owner_history, &mut self.ifc);
+
+        self.ifc.data.insert_new(distribution_system)
+    }
+}
+
+impl<'a> IfcStoreyBuilder<'a> {
+    /// Assigns an object to a group, e.g. a space to a zone or a wall to a
+    /// building system.
+    pub fn assign_to_group<G: RelatableGroup, T: GroupRelatable>(
+        &mut self,
+        group: TypedId<G>,
+        object: TypedId<T>,
+    ) {
+        self.project
+            .group_to_objects
+            .entry(group.id())
+            .or_insert_with(|| {
+                RelAssignsToGroup::new(
+                    format!("Group{group:?}ToObjects"),
+                    group,
+                    &mut self.project.ifc,
+                )
+                .owner_history(self.owner_history, &mut self.project.ifc)
+            })
+            .relate_push(object, &mut self.project.ifc);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::{DVec2, DVec3};
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    #[test]
+    fn builder_zones_across_storeys() {
+        let mut builder = create_builder();
+
+        let apartment = builder.zone("A-01", "Apartment 1");
+        let fire_compartment = builder.zone("FC-01", "Fire compartment 1");
+
+        let space_parameter = || SpaceParameter {
+            coords: vec![
+                DVec2::new(0.0, 0.0),
+                DVec2::new(4.0, 0.0),
+                DVec2::new(4.0, 4.0),
+                DVec2::new(0.0, 4.0),
+            ],
+            height: 2.5,
+            placement: DVec3::ZERO,
+        };
+
+        let (living_room, bedroom, other_bedroom) = {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+
+            let living_room = {
+                let mut storey_builder = building_builder.new_storey("ground floor", 0.0);
+
+                let space_type = storey_builder.space_type("Room", SpaceTypeEnum::Space);
+                let living_room =
+                    storey_builder.space(space_type, "Living room", space_parameter());
+
+                storey_builder.assign_to_group(apartment, living_room);
+                storey_builder.assign_to_group(fire_compartment, living_room);
+
+                living_room
+            };
+
+            let (bedroom, other_bedroom) = {
+                let mut storey_builder = building_builder.new_storey("first floor", 3.0);
+
+                let space_type = storey_builder.space_type("Room", SpaceTypeEnum::Space);
+                let bedroom = storey_builder.space(space_type, "Bedroom", space_parameter());
+                let other_bedroom =
+                    storey_builder.space(space_type, "Other bedroom", space_parameter());
+
+                storey_builder.assign_to_group(apartment, bedroom);
+
+                (bedroom, other_bedroom)
+            };
+
+            (living_room, bedroom, other_bedroom)
+        };
+
+        let s = builder.build();
+        let ifc = IfcExtractor::from(IFC::from_str(&s).unwrap());
+
+        assert_eq!(s, ifc.to_string());
+
+        let mut members = ifc.group_members(apartment);
+        members.sort();
+        assert_eq!(members, vec![living_room.id(), bedroom.id()]);
+        assert_eq!(ifc.group_members(fire_compartment), vec![living_room.id()]);
+
+        assert_eq!(ifc.groups_of::<Zone, _>(living_room).len(), 2);
+        assert_eq!(
+            ifc.groups_of::<Zone, _>(bedroom)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>(),
+            vec![apartment]
+        );
+        assert!(ifc.groups_of::<Zone, _>(other_bedroom).is_empty());
+    }
+}
@@ -0,0 +1,156 @@
+use crate::prelude::*;
+
+impl<'a> IfcStoreyBuilder<'a> {
+    /// Assigns the representations of an element, e.g. a wall, slab or roof,
+    /// to the presentation layer named `layer`. Layers are shared by the
+    /// whole project and created on build, elements without a shape are not
+    /// assigned.
+    pub fn assign_to_layer<T: TransformableType>(&mut self, layer: &str, element: TypedId<T>) {
+        if let Some(shape) = self.project.ifc.data.get(element).shape() {
+            self.project
+                .presentation_layers
+                .entry(layer.to_string())
+                .or_default()
+                .push(shape);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::{DVec2, DVec3};
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    #[test]
+    fn builder_presentation_layers() {
+        let mut builder = create_builder();
+
+        let (walls, slab, roof) = {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+            let material_layer = storey_builder.material_layer("ExampleMaterial", 0.25, false);
+            let material_layer_set = storey_builder.material_layer_set([material_layer]);
+            let material_layer_set_usage = storey_builder.material_layer_set_usage(
+                material_layer_set,
+                LayerSetDirectionEnum::Axis2,
+                DirectionSenseEnum::Positive,
+                0.0,
+            );
+
+            let wall_type = storey_builder.wall_type(
+                material_layer_set,
+                "ExampleWallType",
+                WallTypeEnum::NotDefined,
+            );
+            let walls: Vec<_> = (0..2)
+                .map(|index| {
+                    storey_builder.vertical_wall(
+                        material_layer_set_usage,
+                        wall_type,
+                        "ExampleWall",
+                        VerticalWallParameter {
+                            height: 3.0,
+                            length: 4.0,
+                            placement: DVec3::new(0.0, index as f64 * 4.0, 0.0),
+                        },
+                    )
+                })
+                .collect();
+
+            // clipping replaces the items of the wall representation
+            storey_builder.clip_wall_by_plane(
+                walls[1],
+                ClippingPlaneParameter {
+                    location: DVec3::new(2.0, 0.0, 2.0),
+                    normal: DVec3::new(1.0, 0.0, 1.0),
+                },
+            );
+
+            let coords = vec![
+                DVec2::new(0.0, 0.0),
+                DVec2::new(4.0, 0.0),
+                DVec2::new(4.0, 4.0),
+                DVec2::new(0.0, 4.0),
+            ];
+
+            let slab_type = storey_builder.slab_type(
+                material_layer_set,
+                "ExampleSlabType",
+                SlabTypeEnum::Floor,
+            );
+            let slab = storey_builder.horizontal_arbitrary_slab(
+                material_layer_set_usage,
+                slab_type,
+                "ExampleSlab",
+                HorizontalArbitrarySlabParameter {
+                    coords: coords.clone(),
+                    placement: DVec3::ZERO,
+                },
+            );
+
+            let roof_type = storey_builder.roof_type(
+                material_layer_set,
+                "ExampleRoofType",
+                RoofTypeEnum::FlatRoof,
+            );
+            let roof = storey_builder.horizontal_arbitrary_roof(
+                material_layer_set_usage,
+                roof_type,
+                "ExampleRoof",
+                HorizontalArbitraryRoofParameter {
+                    coords,
+                    placement: DVec3::new(0.0, 0.0, 3.0),
+                },
+            );
+
+            for wall in &walls {
+                storey_builder.assign_to_layer("A-WALL", *wall);
+            }
+            storey_builder.assign_to_layer("A-SLAB", slab);
+            storey_builder.assign_to_layer("A-ROOF", roof);
+
+            (walls, slab, roof)
+        };
+
+        let s = builder.build();
+        let ifc = IfcExtractor::from(IFC::from_str(&s).unwrap());
+
+        assert_eq!(s, ifc.to_string());
+
+        assert_eq!(
+            ifc.data
+                .find_all_of_type::<PresentationLayerAssignment>()
+                .count(),
+            3
+        );
+
+        let layer_names = |item_layers: Vec<(Id, Option<&PresentationLayer>)>| {
+            item_layers
+                .into_iter()
+                .map(|(_, layer)| layer.map(|layer| layer.name.0.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        for wall in walls {
+            assert_eq!(
+                layer_names(ifc.representation_item_layers(wall)),
+                vec![Some("A-WALL".to_string())]
+            );
+        }
+        assert_eq!(
+            layer_names(ifc.representation_item_layers(slab)),
+            vec![Some("A-SLAB".to_string())]
+        );
+        assert_eq!(
+            layer_names(ifc.representation_item_layers(roof)),
+            vec![Some("A-ROOF".to_string())]
+        );
+    }
+}
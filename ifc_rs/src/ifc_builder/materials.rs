@@ -1,4 +1,4 @@
-use crate::prelude::*;
+use crate::{geometry::profile_def::ProfileDef, prelude::*};
 
 impl<'a> IfcStoreyBuilder<'a> {
     pub fn material_layer(
@@ -66,6 +66,58 @@ impl<'a> IfcStoreyBuilder<'a> {
             .sum()
     }
 
+    pub fn material_profile<P: ProfileDef>(
+        &mut self,
+        material_name: &str,
+        profile: impl Into<IdOr<P>>,
+    ) -> TypedId<MaterialProfile> {
+        let material = self.material(material_name);
+        let material_profile = MaterialProfile::new(profile, &mut self.project.ifc)
+            .material(material, &mut self.project.ifc);
+        self.project.ifc.data.insert_new(material_profile)
+    }
+
+    pub fn material_profile_set(
+        &mut self,
+        profiles: impl IntoIterator<Item = TypedId<MaterialProfile>>,
+    ) -> TypedId<MaterialProfileSet> {
+        let mut material_profile_set = MaterialProfileSet::new();
+
+        for profile in profiles {
+            material_profile_set = material_profile_set.add_profile(profile, &mut self.project.ifc);
+        }
+
+        self.project.ifc.data.insert_new(material_profile_set)
+    }
+
+    /// The profiles are centered on the member axis (cardinal point 5).
+    pub fn material_profile_set_usage(
+        &mut self,
+        material_profile_set: TypedId<MaterialProfileSet>,
+    ) -> TypedId<MaterialProfileSetUsage> {
+        let material_profile_set_usage =
+            MaterialProfileSetUsage::new(material_profile_set, &mut self.project.ifc)
+                .cardinal_point(5);
+
+        self.project.ifc.data.insert_new(material_profile_set_usage)
+    }
+
+    /// The profile of the first material profile in the used set.
+    pub(super) fn material_profile_set_usage_profile(
+        &self,
+        material: TypedId<MaterialProfileSetUsage>,
+    ) -> Id {
+        let profile_set_usage = self.project.ifc.data.get(material);
+        let profile_set = self.project.ifc.data.get(profile_set_usage.for_profile_set);
+        let material_profile = self
+            .project
+            .ifc
+            .data
+            .get(*profile_set.material_profiles.0.first().unwrap());
+
+        material_profile.profile
+    }
+
     pub fn material_constituent(
         &mut self,
         material_name: &str,
@@ -0,0 +1,168 @@
+use glam::DVec3;
+
+use crate::{parser::optional::OptionalParameter, prelude::*};
+
+pub struct MemberParameter {
+    /// Local to the storey
+    pub start: DVec3,
+    /// Local to the storey
+    pub end: DVec3,
+}
+
+impl<'a> IfcStoreyBuilder<'a> {
+    pub fn member_type(
+        &mut self,
+        material: TypedId<MaterialProfileSet>,
+        name: &str,
+        member_type: MemberTypeEnum,
+    ) -> TypedId<MemberType> {
+        let member_type = MemberType::new(name, member_type)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .name(name);
+
+        let member_type_id = self.project.ifc.data.insert_new(member_type);
+
+        self.member_type_to_member
+            .entry(member_type_id)
+            .or_default();
+        self.project
+            .material_to_member_type
+            .entry(material)
+            .or_insert_with(|| {
+                RelAssociatesMaterial::new(
+                    format!("Material{material:?}ToMemberType"),
+                    material,
+                    &mut self.project.ifc,
+                )
+                .owner_history(self.owner_history, &mut self.project.ifc)
+            })
+            .relate_push(member_type_id, &mut self.project.ifc);
+
+        member_type_id
+    }
+
+    /// Creates a member by extruding the profile of `material` from `start`
+    /// to `end`.
+    ///
+    /// # Panics
+    /// If `start` and `end` are the same point.
+    pub fn member(
+        &mut self,
+        material: TypedId<MaterialProfileSetUsage>,
+        member_type: TypedId<MemberType>,
+        name: &str,
+        member_parameter: MemberParameter,
+    ) -> TypedId<Member> {
+        let (product_shape, local_placement) =
+            self.axis_extrusion(material, member_parameter.start, member_parameter.end);
+
+        let member = Member::new(name)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .object_placement(local_placement, &mut self.project.ifc)
+            .representation(product_shape, &mut self.project.ifc);
+
+        let member_id = self.project.ifc.data.insert_new(member);
+
+        self.members.insert(member_id);
+        self.member_type_to_member
+            .entry(member_type)
+            .or_default()
+            .insert(member_id);
+        self.project
+            .material_to_member
+            .entry(material)
+            .or_insert_with(|| {
+                RelAssociatesMaterial::new(
+                    format!("Material{material:?}ToMembers"),
+                    material,
+                    &mut self.project.ifc,
+                )
+                .owner_history(self.owner_history, &mut self.project.ifc)
+            })
+            .relate_push(member_id, &mut self.project.ifc);
+
+        member_id
+    }
+
+    /// Body shape and placement of a linear element, extruding the profile of
+    /// `material` along the axis from `start` to `end`. The placement is
+    /// located at `start` with its z-axis pointing towards `end`, the profile
+    /// y-axis points upwards for non vertical axes.
+    pub(super) fn axis_extrusion(
+        &mut self,
+        material: TypedId<MaterialProfileSetUsage>,
+        start: DVec3,
+        end: DVec3,
+    ) -> (ProductDefinitionShape, LocalPlacement) {
+        let product_shape = self.axis_extrusion_shape(material, (end - start).length());
+        let local_placement = self.axis_placement(start, end);
+
+        (product_shape, local_placement)
+    }
+
+    /// Placement of a linear element located at `start` with its z-axis
+    /// pointing towards `end`, the x-axis is horizontal for non vertical
+    /// axes.
+    ///
+    /// # Panics
+    /// If `start` and `end` are the same point.
+    pub(super) fn axis_placement(&mut self, start: DVec3, end: DVec3) -> LocalPlacement {
+        let direction = (end - start)
+            .try_normalize()
+            .expect("Axis of a linear element must not have zero length");
+
+        let mut position = Axis3D::new(Point3D::from(start), &mut self.project.ifc);
+        if direction.cross(DVec3::Z).length() > f64::EPSILON {
+            position = position
+                .local_z(Direction3D::from(direction), &mut self.project.ifc)
+                .local_x(
+                    Direction3D::from(DVec3::Z.cross(direction).normalize()),
+                    &mut self.project.ifc,
+                );
+        } else if direction.z < 0.0 {
+            position = position.local_z(Direction3D::from(direction), &mut self.project.ifc);
+        }
+
+        LocalPlacement::new_relative(position, self.storey, &mut self.project.ifc)
+    }
+
+    /// Body shape extruding the profile of `material` along the z-axis of
+    /// the element placement.
+    pub(super) fn axis_extrusion_shape(
+        &mut self,
+        material: TypedId<MaterialProfileSetUsage>,
+        length: f64,
+    ) -> ProductDefinitionShape {
+        let profile = self.material_profile_set_usage_profile(material);
+
+        self.profile_extrusion_shape(profile, length)
+    }
+
+    /// Body shape extruding the given profile along the z-axis of the
+    /// element placement.
+    pub(super) fn profile_extrusion_shape(
+        &mut self,
+        profile: Id,
+        length: f64,
+    ) -> ProductDefinitionShape {
+        let extruded_direction = self
+            .project
+            .ifc
+            .data
+            .insert_new(Direction3D::from(DVec3::Z));
+        // the profile may be taken from a material, so only its id is known here
+        let body = ExtrudedAreaSolid {
+            swept_area: profile,
+            position: OptionalParameter::omitted(),
+            extruded_direction,
+            depth: length.into(),
+        };
+
+        let shape_repr = ShapeRepresentation::new(self.sub_context, &mut self.project.ifc)
+            .identifier("Body")
+            .repr_type("SweptSolid")
+            .add_item(body, &mut self.project.ifc);
+
+        ProductDefinitionShape::new().add_representation(shape_repr, &mut self.project.ifc)
+    }
+}
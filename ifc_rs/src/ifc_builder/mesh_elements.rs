@@ -0,0 +1,367 @@
+use std::ops::Deref;
+
+use glam::DVec3;
+
+use crate::{ifc_type::IfcType, prelude::*};
+
+/// Triangle mesh as exported by render engines and configurators.
+pub struct TriangleMesh {
+    pub vertices: Vec<DVec3>,
+    /// Zero based indices into the vertices, three per triangle
+    pub indices: Vec<usize>,
+}
+
+pub struct MeshPlacementParameter {
+    /// Local to the storey
+    pub placement: DVec3,
+    /// Direction of the local x-axis of the mesh, only the horizontal
+    /// direction is taken into account
+    pub direction: DVec3,
+}
+
+impl<'a> IfcStoreyBuilder<'a> {
+    /// Creates a building element proxy type whose body is given by the
+    /// mesh. The body is shared by all proxies of the type.
+    ///
+    /// # Panics
+    /// If the number of mesh indices isn't a multiple of three or an index
+    /// doesn't refer to a vertex.
+    pub fn building_element_proxy_type(
+        &mut self,
+        name: &str,
+        proxy_type: BuildingElementProxyTypeEnum,
+        mesh: &TriangleMesh,
+    ) -> TypedId<BuildingElementProxyType> {
+        let representation_map = self.mesh_representation_map(mesh);
+
+        let proxy_type = BuildingElementProxyType::new(name, proxy_type)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .name(name)
+            .add_representation_map(representation_map, &mut self.project.ifc);
+
+        let proxy_type_id = self.project.ifc.data.insert_new(proxy_type);
+
+        self.building_element_proxy_type_to_building_element_proxy
+            .entry(proxy_type_id)
+            .or_default();
+
+        proxy_type_id
+    }
+
+    /// Creates a building element proxy instancing the body of its type.
+    pub fn building_element_proxy(
+        &mut self,
+        proxy_type: TypedId<BuildingElementProxyType>,
+        name: &str,
+        placement_parameter: MeshPlacementParameter,
+    ) -> TypedId<BuildingElementProxy> {
+        let representation_map = self.type_representation_map(proxy_type);
+        let (product_shape, local_placement) =
+            self.mapped_mesh(representation_map, placement_parameter);
+
+        let proxy = BuildingElementProxy::new(name)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .object_placement(local_placement, &mut self.project.ifc)
+            .representation(product_shape, &mut self.project.ifc);
+
+        let proxy_id = self.project.ifc.data.insert_new(proxy);
+
+        self.building_element_proxies.insert(proxy_id);
+        self.building_element_proxy_type_to_building_element_proxy
+            .entry(proxy_type)
+            .or_default()
+            .insert(proxy_id);
+
+        proxy_id
+    }
+
+    /// Creates a furniture type whose body is given by the mesh. The body is
+    /// shared by all furniture of the type.
+    pub fn furniture_type(
+        &mut self,
+        name: &str,
+        furniture_type: FurnitureTypeEnum,
+        mesh: &TriangleMesh,
+    ) -> TypedId<FurnitureType> {
+        let representation_map = self.mesh_representation_map(mesh);
+
+        let furniture_type = FurnitureType::new(name, furniture_type)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .name(name)
+            .add_representation_map(representation_map, &mut self.project.ifc);
+
+        let furniture_type_id = self.project.ifc.data.insert_new(furniture_type);
+
+        self.furniture_type_to_furniture
+            .entry(furniture_type_id)
+            .or_default();
+
+        furniture_type_id
+    }
+
+    /// Creates a piece of furniture instancing the body of its type.
+    pub fn furniture(
+        &mut self,
+        furniture_type: TypedId<FurnitureType>,
+        name: &str,
+        placement_parameter: MeshPlacementParameter,
+    ) -> TypedId<Furniture> {
+        let representation_map = self.type_representation_map(furniture_type);
+        let (product_shape, local_placement) =
+            self.mapped_mesh(representation_map, placement_parameter);
+
+        let furniture = Furniture::new(name)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .object_placement(local_placement, &mut self.project.ifc)
+            .representation(product_shape, &mut self.project.ifc);
+
+        let furniture_id = self.project.ifc.data.insert_new(furniture);
+
+        self.furniture.insert(furniture_id);
+        self.furniture_type_to_furniture
+            .entry(furniture_type)
+            .or_default()
+            .insert(furniture_id);
+
+        furniture_id
+    }
+
+    /// Representation map holding the mesh as triangulated face set body.
+    fn mesh_representation_map(&mut self, mesh: &TriangleMesh) -> TypedId<RepresentationMap> {
+        assert!(
+            mesh.indices.len().is_multiple_of(3),
+            "Mesh indices must be given as three per triangle"
+        );
+        assert!(
+            mesh.indices
+                .iter()
+                .all(|index| *index < mesh.vertices.len()),
+            "Mesh indices must refer to the vertices"
+        );
+
+        let face_set = TriangulatedFaceSet::from_mesh(
+            mesh.vertices.iter().copied(),
+            mesh.indices
+                .chunks_exact(3)
+                .map(|triangle| [triangle[0], triangle[1], triangle[2]]),
+            &mut self.project.ifc,
+        );
+
+        let shape_repr = ShapeRepresentation::new(self.sub_context, &mut self.project.ifc)
+            .identifier("Body")
+            .repr_type("Tessellation")
+            .add_item(face_set, &mut self.project.ifc);
+
+        let representation_map = RepresentationMap::new(
+            Axis3D::new(Point3D::from(DVec3::ZERO), &mut self.project.ifc),
+            shape_repr,
+            &mut self.project.ifc,
+        );
+
+        self.project.ifc.data.insert_new(representation_map)
+    }
+
+    fn type_representation_map<T: IfcType + Deref<Target = ElementType>>(
+        &self,
+        element_type: TypedId<T>,
+    ) -> TypedId<RepresentationMap> {
+        self.project
+            .ifc
+            .data
+            .get(element_type)
+            .representation_maps
+            .custom()
+            .and_then(|representation_maps| representation_maps.0.first().copied())
+            .expect("mesh element types are created with a representation map")
+    }
+
+    /// Body shape instancing the representation map and the placement of the
+    /// element.
+    fn mapped_mesh(
+        &mut self,
+        representation_map: TypedId<RepresentationMap>,
+        placement_parameter: MeshPlacementParameter,
+    ) -> (ProductDefinitionShape, LocalPlacement) {
+        // the mesh is positioned by the element placement
+        let transform = CartesianTransformationOperator3DnonUniform::new(
+            Point3D::from(DVec3::ZERO),
+            (
+                Direction3D::from(DVec3::X),
+                Direction3D::from(DVec3::Y),
+                Direction3D::from(DVec3::Z),
+            ),
+            (1.0, 1.0, 1.0),
+            &mut self.project.ifc,
+        );
+
+        let shape_repr = ShapeRepresentation::new(self.sub_context, &mut self.project.ifc)
+            .identifier("Body")
+            .repr_type("MappedRepresentation")
+            .add_item(
+                MappedItem::new(representation_map, transform, &mut self.project.ifc),
+                &mut self.project.ifc,
+            );
+        let product_shape =
+            ProductDefinitionShape::new().add_representation(shape_repr, &mut self.project.ifc);
+
+        let direction = placement_parameter.direction;
+        let position = Axis3D::new(
+            Point3D::from(placement_parameter.placement),
+            &mut self.project.ifc,
+        )
+        .local_x(
+            Direction3D::from(DVec3::new(direction.x, direction.y, 0.0)),
+            &mut self.project.ifc,
+        );
+        let local_placement =
+            LocalPlacement::new_relative(position, self.storey, &mut self.project.ifc);
+
+        (product_shape, local_placement)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::DVec3;
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    fn box_mesh(size: DVec3) -> TriangleMesh {
+        let vertices = (0..8)
+            .map(|corner| {
+                DVec3::new(
+                    (corner & 1) as f64 * size.x,
+                    ((corner >> 1) & 1) as f64 * size.y,
+                    (corner >> 2) as f64 * size.z,
+                )
+            })
+            .collect();
+
+        #[rustfmt::skip]
+        let indices = vec![
+            0, 2, 1, 1, 2, 3, // bottom
+            4, 5, 6, 5, 7, 6, // top
+            0, 1, 4, 1, 5, 4, // front
+            2, 6, 3, 3, 6, 7, // back
+            0, 4, 2, 2, 4, 6, // left
+            1, 3, 5, 3, 7, 5, // right
+        ];
+
+        TriangleMesh { vertices, indices }
+    }
+
+    #[test]
+    fn builder_mesh_elements() {
+        let mut builder = create_builder();
+
+        let (chairs, kitchen) = {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+            let chair_type = storey_builder.furniture_type(
+                "Chair",
+                FurnitureTypeEnum::Chair,
+                &box_mesh(DVec3::new(0.5, 0.5, 1.0)),
+            );
+            let chairs: Vec<_> = (0..3)
+                .map(|index| {
+                    storey_builder.furniture(
+                        chair_type,
+                        "Chair",
+                        MeshPlacementParameter {
+                            placement: DVec3::new(index as f64, 0.0, 0.0),
+                            direction: DVec3::Y,
+                        },
+                    )
+                })
+                .collect();
+
+            let kitchen_type = storey_builder.building_element_proxy_type(
+                "Kitchen",
+                BuildingElementProxyTypeEnum::Element,
+                &box_mesh(DVec3::new(3.0, 0.75, 2.0)),
+            );
+            let kitchen = storey_builder.building_element_proxy(
+                kitchen_type,
+                "Kitchen",
+                MeshPlacementParameter {
+                    placement: DVec3::new(0.0, 4.0, 0.0),
+                    direction: DVec3::X,
+                },
+            );
+
+            (chairs, kitchen)
+        };
+
+        let s = builder.build();
+        let ifc = IfcExtractor::from(IFC::from_str(&s).unwrap());
+
+        assert_eq!(s, ifc.to_string());
+
+        // the mapping targets are written with their schema attributes
+        let xml = ifc.to_xml().unwrap();
+        assert!(xml.contains("<IfcCartesianTransformationOperator3DnonUniform"));
+        assert!(xml.contains(r#"Scale2="1.""#));
+        assert_eq!(
+            IFC::from_xml(&xml).unwrap().data.to_string(),
+            ifc.data.to_string()
+        );
+
+        // one mesh per type, instanced by all elements of the type
+        assert_eq!(
+            ifc.data.find_all_of_type::<TriangulatedFaceSet>().count(),
+            2
+        );
+        assert_eq!(ifc.data.find_all_of_type::<RepresentationMap>().count(), 2);
+        assert_eq!(ifc.data.find_all_of_type::<MappedItem>().count(), 4);
+
+        let mapping_source = |shape: TypedId<ProductDefinitionShape>| {
+            let shape_repr = ifc.data.get(shape).representations.0[0];
+            let item = ifc.data.get(shape_repr).items.0[0];
+            ifc.data
+                .get_untyped(item)
+                .downcast_ref::<MappedItem>()
+                .unwrap()
+                .source
+                .id()
+        };
+        let chair_sources: Vec<_> = chairs
+            .iter()
+            .map(|chair| mapping_source(*ifc.data.get(*chair).representation.custom().unwrap()))
+            .collect();
+        assert!(chair_sources
+            .iter()
+            .all(|source| *source == chair_sources[0]));
+        assert_ne!(
+            mapping_source(*ifc.data.get(kitchen).representation.custom().unwrap()),
+            chair_sources[0]
+        );
+
+        let chair_type = ifc.related_type(chairs[0]).downcast_ref::<FurnitureType>();
+        assert!(chair_type.is_some());
+
+        let (storey, _) = ifc.data.find_all_of_type::<Storey>().next().unwrap();
+        let contained = ifc.contained_structures(storey);
+        assert!(contained.contains(&kitchen.id()));
+        assert!(chairs.iter().all(|chair| contained.contains(&chair.id())));
+    }
+
+    #[test]
+    #[should_panic(expected = "Mesh indices must refer to the vertices")]
+    fn builder_mesh_with_index_out_of_the_vertices() {
+        let mut builder = create_builder();
+        let mut site_builder = builder.new_site("test", DVec3::ZERO);
+        let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+        let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+        let mut mesh = box_mesh(DVec3::ONE);
+        mesh.indices[5] = 8;
+
+        storey_builder.furniture_type("Chair", FurnitureTypeEnum::Chair, &mesh);
+    }
+}
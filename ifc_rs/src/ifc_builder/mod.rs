@@ -1,14 +1,31 @@
+pub mod beams;
 pub mod building;
+pub mod classifications;
+pub mod columns;
+pub mod curtain_walls;
+pub mod distribution;
+pub mod doors;
+pub mod grids;
+pub mod groups;
+pub mod layers;
 pub mod materials;
+pub mod members;
+pub mod mesh_elements;
 pub mod openings;
 pub mod prelude;
 pub mod project;
+pub mod properties;
+pub mod quantities;
 pub mod roofs;
 pub mod site;
 pub mod slabs;
+pub mod space_boundaries;
 pub mod spaces;
+pub mod stairs;
 pub mod storey;
+pub mod styles;
 pub mod transforms;
+pub mod wall_connections;
 pub mod walls;
 pub mod windows;
 
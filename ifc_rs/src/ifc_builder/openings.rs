@@ -71,6 +71,10 @@ impl<'a> IfcStoreyBuilder<'a> {
         self.opening_elements.insert(opening_element_id);
         self.opening_elements_to_wall
             .insert(opening_element_id, wall);
+        self.opening_element_areas.insert(
+            opening_element_id,
+            opening_information.length * opening_information.height,
+        );
 
         opening_element_id
     }
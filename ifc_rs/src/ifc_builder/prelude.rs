@@ -1,12 +1,21 @@
+pub use super::beams::BeamParameter;
 pub use super::building::IfcBuildingBuilder;
+pub use super::columns::ColumnParameter;
+pub use super::curtain_walls::CurtainWallParameter;
+pub use super::distribution::{FlowRouteParameter, FlowSegmentProfile};
+pub use super::doors::DoorParameter;
+pub use super::grids::{GridColumnParameter, OrthogonalGridParameter, RadialGridParameter};
+pub use super::members::MemberParameter;
+pub use super::mesh_elements::{MeshPlacementParameter, TriangleMesh};
 pub use super::openings::VerticalOpeningParameter;
 pub use super::project::IfcProjectBuilder;
 pub use super::roofs::HorizontalArbitraryRoofParameter;
 pub use super::site::IfcSiteBuilder;
 pub use super::slabs::HorizontalArbitrarySlabParameter;
 pub use super::spaces::SpaceParameter;
+pub use super::stairs::{StairParameter, StairShape, StairTurn};
 pub use super::storey::IfcStoreyBuilder;
 pub use super::transforms::TransformParameter;
-pub use super::walls::VerticalWallParameter;
+pub use super::walls::{ClippingPlaneParameter, VerticalWallParameter, WallAxisParameter};
 pub use super::windows::WindowParameter;
 pub use super::{ApplicationInfo, OwnerInfo};
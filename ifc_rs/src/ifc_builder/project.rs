@@ -9,6 +9,8 @@ pub struct IfcProjectBuilder {
 
     pub(crate) owner_history: TypedId<OwnerHistory>,
     pub(crate) sub_context: TypedId<GeometricRepresentationContext>,
+    pub(crate) body_sub_context: TypedId<GeometricRepresentationSubContext>,
+    pub(crate) plan_sub_context: TypedId<GeometricRepresentationSubContext>,
 
     pub(crate) project: TypedId<Project>,
     pub(crate) sites: HashSet<TypedId<Site>>,
@@ -18,10 +20,29 @@ pub struct IfcProjectBuilder {
     pub(crate) material_to_slab: HashMap<TypedId<MaterialLayerSetUsage>, RelAssociatesMaterial>,
     pub(crate) material_to_roof: HashMap<TypedId<MaterialLayerSetUsage>, RelAssociatesMaterial>,
     pub(crate) material_to_window: HashMap<TypedId<MaterialConstituentSet>, RelAssociatesMaterial>,
+    pub(crate) material_to_door: HashMap<TypedId<MaterialConstituentSet>, RelAssociatesMaterial>,
+    pub(crate) material_to_beam: HashMap<TypedId<MaterialProfileSetUsage>, RelAssociatesMaterial>,
+    pub(crate) material_to_column: HashMap<TypedId<MaterialProfileSetUsage>, RelAssociatesMaterial>,
+    pub(crate) material_to_member: HashMap<TypedId<MaterialProfileSetUsage>, RelAssociatesMaterial>,
     // TODO: Required??
     pub(crate) material_to_wall_type: HashMap<TypedId<MaterialLayerSet>, RelAssociatesMaterial>,
     pub(crate) material_to_slab_type: HashMap<TypedId<MaterialLayerSet>, RelAssociatesMaterial>,
     pub(crate) material_to_roof_type: HashMap<TypedId<MaterialLayerSet>, RelAssociatesMaterial>,
+    pub(crate) material_to_beam_type: HashMap<TypedId<MaterialProfileSet>, RelAssociatesMaterial>,
+    pub(crate) material_to_column_type: HashMap<TypedId<MaterialProfileSet>, RelAssociatesMaterial>,
+    pub(crate) material_to_member_type: HashMap<TypedId<MaterialProfileSet>, RelAssociatesMaterial>,
+
+    // Classifications
+    pub(crate) classification_references:
+        HashMap<(TypedId<Classification>, String), TypedId<ClassificationReference>>,
+    pub(crate) classification_to_objects:
+        HashMap<TypedId<ClassificationReference>, RelAssociatesClassification>,
+
+    // Groups, zones & systems
+    pub(crate) group_to_objects: HashMap<Id, RelAssignsToGroup>,
+
+    // Presentation layers
+    pub(crate) presentation_layers: HashMap<String, Vec<TypedId<ProductDefinitionShape>>>,
 }
 
 impl IfcProjectBuilder {
@@ -70,31 +91,53 @@ impl IfcProjectBuilder {
 
         // create world root coordinate
         let world_root = Axis3D::new(Point3D::from(DVec3::new(0.0, 0.0, 0.0)), &mut ifc);
+        let world_root = ifc.data.insert_new(world_root);
 
-        // create geometry context
+        // create geometry contexts, the body of the elements is given in
+        // the model context and their plan symbols in the plan context
         let context =
-            GeometricRepresentationContext::new(DimensionCount::Three, world_root, &mut ifc);
+            GeometricRepresentationContext::new(DimensionCount::Three, world_root, &mut ifc)
+                .context_type("Model");
         let context_id = ifc.data.insert_new(context);
 
-        let sub_context = GeometricRepresentationSubContext::derive(
+        let plan_context =
+            GeometricRepresentationContext::new(DimensionCount::Three, world_root, &mut ifc)
+                .context_type("Plan");
+        let plan_context_id = ifc.data.insert_new(plan_context);
+
+        let body_sub_context = GeometricRepresentationSubContext::derive(
             context_id,
             GeometricProjection::ModelView,
             &mut ifc,
-        );
+        )
+        .context_identifier("Body")
+        .context_type("Model");
+
+        let plan_sub_context = GeometricRepresentationSubContext::derive(
+            plan_context_id,
+            GeometricProjection::PlanView,
+            &mut ifc,
+        )
+        .context_identifier("Annotation")
+        .context_type("Plan");
 
         let project = Project::new(project_name)
             .name(project_name)
             .owner_history(owner_history_id, &mut ifc)
             .unit_assignment(unit_assignment, &mut ifc)
-            .add_context(context_id, &mut ifc);
+            .add_context(context_id, &mut ifc)
+            .add_context(plan_context_id, &mut ifc);
 
         let project_id = ifc.data.insert_new(project);
-        ifc.data.insert_new(sub_context);
+        let body_sub_context = ifc.data.insert_new(body_sub_context);
+        let plan_sub_context = ifc.data.insert_new(plan_sub_context);
 
         Self {
             ifc,
             owner_history: owner_history_id,
             sub_context: context_id,
+            body_sub_context,
+            plan_sub_context,
             project: project_id,
             sites: HashSet::new(),
 
@@ -105,6 +148,20 @@ impl IfcProjectBuilder {
             material_to_roof: HashMap::new(),
             material_to_roof_type: HashMap::new(),
             material_to_window: HashMap::new(),
+            material_to_door: HashMap::new(),
+            material_to_beam: HashMap::new(),
+            material_to_beam_type: HashMap::new(),
+            material_to_column: HashMap::new(),
+            material_to_column_type: HashMap::new(),
+            material_to_member: HashMap::new(),
+            material_to_member_type: HashMap::new(),
+
+            classification_references: HashMap::new(),
+            classification_to_objects: HashMap::new(),
+
+            group_to_objects: HashMap::new(),
+
+            presentation_layers: HashMap::new(),
         }
     }
 
@@ -127,13 +184,47 @@ impl IfcProjectBuilder {
             .chain(self.material_to_slab.into_values())
             .chain(self.material_to_roof.into_values())
             .chain(self.material_to_window.into_values())
+            .chain(self.material_to_door.into_values())
+            .chain(self.material_to_beam.into_values())
+            .chain(self.material_to_column.into_values())
+            .chain(self.material_to_member.into_values())
             .chain(self.material_to_wall_type.into_values())
             .chain(self.material_to_slab_type.into_values())
             .chain(self.material_to_roof_type.into_values())
+            .chain(self.material_to_beam_type.into_values())
+            .chain(self.material_to_column_type.into_values())
+            .chain(self.material_to_member_type.into_values())
             .for_each(|associate_relations| {
                 self.ifc.data.insert_new(associate_relations);
             });
 
+        self.classification_to_objects
+            .into_values()
+            .for_each(|classification_relations| {
+                self.ifc.data.insert_new(classification_relations);
+            });
+
+        self.group_to_objects
+            .into_values()
+            .for_each(|group_relations| {
+                self.ifc.data.insert_new(group_relations);
+            });
+
+        // the representations are collected last, transformations replace
+        // the representations of a shape
+        for (name, shapes) in self.presentation_layers {
+            let representations: Vec<_> = shapes
+                .into_iter()
+                .flat_map(|shape| self.ifc.data.get(shape).representations.0.clone())
+                .collect();
+
+            let layer = representations.into_iter().fold(
+                PresentationLayerAssignment::new(name),
+                |layer, representation| layer.add_item(representation, &mut self.ifc),
+            );
+            self.ifc.data.insert_new(layer);
+        }
+
         // rel aggregates
         let rel_agg = RelAggregates::new(
             "ProjectSitesLink",
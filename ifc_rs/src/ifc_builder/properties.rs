@@ -0,0 +1,167 @@
+use crate::{ifc_type::IfcType, prelude::*};
+
+impl<'a> IfcStoreyBuilder<'a> {
+    /// Creates a property set containing a single value property for each
+    /// of the given name/value pairs.
+    pub fn property_set<'b>(
+        &mut self,
+        name: &str,
+        properties: impl IntoIterator<Item = (&'b str, IfcValue)>,
+    ) -> TypedId<PropertySet> {
+        let mut property_set =
+            PropertySet::new(name).owner_history(self.owner_history, &mut self.project.ifc);
+
+        for (property_name, value) in properties {
+            property_set = property_set.add_property(
+                PropertySingleValue::new(property_name, value),
+                &mut self.project.ifc,
+            );
+        }
+
+        self.project.ifc.data.insert_new(property_set)
+    }
+
+    pub fn wall_property_set(&mut self, wall: TypedId<Wall>, property_set: TypedId<PropertySet>) {
+        self.relate_property_set(wall, property_set);
+    }
+
+    pub fn slab_property_set(&mut self, slab: TypedId<Slab>, property_set: TypedId<PropertySet>) {
+        self.relate_property_set(slab, property_set);
+    }
+
+    pub fn space_property_set(
+        &mut self,
+        space: TypedId<Space>,
+        property_set: TypedId<PropertySet>,
+    ) {
+        self.relate_property_set(space, property_set);
+    }
+
+    /// Attaches the property set to a type object, it then applies to all
+    /// occurrences of this type.
+    pub fn type_property_set<T: IfcType + TypeObjectBuilder>(
+        &mut self,
+        type_object: TypedId<T>,
+        property_set: TypedId<PropertySet>,
+    ) {
+        self.project
+            .ifc
+            .data
+            .get_mut(type_object)
+            .type_object_mut()
+            .push_property_set(property_set.id());
+    }
+
+    fn relate_property_set<T: IfcType>(
+        &mut self,
+        object: TypedId<T>,
+        property_set: TypedId<PropertySet>,
+    ) {
+        let property_set_relation = self
+            .property_set_to_objects
+            .entry(property_set)
+            .or_insert_with(|| {
+                RelDefinesByProperties::new(
+                    format!("PropertySet{property_set:?}ToObjects"),
+                    property_set,
+                    &mut self.project.ifc,
+                )
+                .owner_history(self.owner_history, &mut self.project.ifc)
+            });
+
+        if !property_set_relation
+            .related_objects
+            .0
+            .contains(&object.id())
+        {
+            property_set_relation.relate_push(object, &mut self.project.ifc);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::DVec3;
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    #[test]
+    fn builder_property_sets() {
+        let mut builder = create_builder();
+        let wall;
+
+        {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+            let material_layer = storey_builder.material_layer("ExampleMaterial", 0.02, false);
+            let material_layer_set = storey_builder.material_layer_set([material_layer]);
+            let material_layer_set_usage = storey_builder.material_layer_set_usage(
+                material_layer_set,
+                LayerSetDirectionEnum::Axis2,
+                DirectionSenseEnum::Positive,
+                0.0,
+            );
+
+            let wall_type = storey_builder.wall_type(
+                material_layer_set,
+                "ExampleWallType",
+                WallTypeEnum::NotDefined,
+            );
+
+            wall = storey_builder.vertical_wall(
+                material_layer_set_usage,
+                wall_type,
+                "ExampleWallDefault",
+                VerticalWallParameter {
+                    height: 2.0,
+                    length: 4.0,
+                    placement: DVec3::new(0.0, 0.0, 0.0),
+                },
+            );
+
+            let wall_common = storey_builder.property_set(
+                "Pset_WallCommon",
+                [("IsExternal", true.into()), ("FireRating", "F90".into())],
+            );
+            storey_builder.wall_property_set(wall, wall_common);
+
+            let wall_type_common = storey_builder.property_set(
+                "Pset_WallCommon",
+                [(
+                    "ThermalTransmittance",
+                    IfcValue::ThermalTransmittanceMeasure(0.24.into()),
+                )],
+            );
+            storey_builder.type_property_set(wall_type, wall_type_common);
+        }
+
+        let s = builder.build();
+        let ifc = IFC::from_str(&s).unwrap();
+
+        assert_eq!(s, ifc.to_string());
+
+        let ifc = IfcExtractor::from(ifc);
+        assert_eq!(ifc.property_sets(wall).len(), 2);
+        assert_eq!(
+            ifc.property_value(wall, "Pset_WallCommon", "IsExternal")
+                .and_then(IfcValue::as_bool),
+            Some(true)
+        );
+        assert_eq!(
+            ifc.property_value(wall, "Pset_WallCommon", "FireRating")
+                .and_then(IfcValue::as_str),
+            Some("F90")
+        );
+        assert_eq!(
+            ifc.property_value(wall, "Pset_WallCommon", "ThermalTransmittance")
+                .and_then(IfcValue::as_f64),
+            Some(0.24)
+        );
+    }
+}
@@ -0,0 +1,331 @@
+use glam::DVec2;
+
+use crate::prelude::*;
+
+/// Method of measurement of all element quantities created by the builder.
+const BASE_QUANTITIES: &str = "BaseQuantities";
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WallDimensions {
+    pub(crate) length: f64,
+    pub(crate) height: f64,
+    pub(crate) thickness: f64,
+}
+
+impl<'a> IfcStoreyBuilder<'a> {
+    /// Enables the computation of base quantities (`Qto_WallBaseQuantities`,
+    /// `Qto_SlabBaseQuantities`, `Qto_SpaceBaseQuantities`,
+    /// `Qto_WindowBaseQuantities` and `Qto_DoorBaseQuantities`) for all
+    /// elements created afterwards.
+    pub fn compute_base_quantities(&mut self, compute: bool) {
+        self.base_quantities = compute;
+    }
+
+    pub(super) fn slab_base_quantities(
+        &mut self,
+        slab: TypedId<Slab>,
+        footprint: &[DVec2],
+        thickness: f64,
+    ) {
+        let area = polygon_area(footprint);
+
+        let element_quantity = ElementQuantity::new("Qto_SlabBaseQuantities")
+            .add_quantity(
+                QuantityLength::new("Width", thickness),
+                &mut self.project.ifc,
+            )
+            .add_quantity(
+                QuantityLength::new("Perimeter", polygon_perimeter(footprint)),
+                &mut self.project.ifc,
+            )
+            .add_quantity(QuantityArea::new("GrossArea", area), &mut self.project.ifc)
+            .add_quantity(QuantityArea::new("NetArea", area), &mut self.project.ifc)
+            .add_quantity(
+                QuantityVolume::new("GrossVolume", area * thickness),
+                &mut self.project.ifc,
+            )
+            .add_quantity(
+                QuantityVolume::new("NetVolume", area * thickness),
+                &mut self.project.ifc,
+            );
+
+        self.element_quantity(slab.id(), element_quantity);
+    }
+
+    pub(super) fn space_base_quantities(
+        &mut self,
+        space: TypedId<Space>,
+        footprint: &[DVec2],
+        height: f64,
+    ) {
+        let area = polygon_area(footprint);
+
+        let element_quantity = ElementQuantity::new("Qto_SpaceBaseQuantities")
+            .add_quantity(QuantityLength::new("Height", height), &mut self.project.ifc)
+            .add_quantity(
+                QuantityLength::new("GrossPerimeter", polygon_perimeter(footprint)),
+                &mut self.project.ifc,
+            )
+            .add_quantity(
+                QuantityArea::new("GrossFloorArea", area),
+                &mut self.project.ifc,
+            )
+            .add_quantity(
+                QuantityArea::new("NetFloorArea", area),
+                &mut self.project.ifc,
+            )
+            .add_quantity(
+                QuantityVolume::new("GrossVolume", area * height),
+                &mut self.project.ifc,
+            )
+            .add_quantity(
+                QuantityVolume::new("NetVolume", area * height),
+                &mut self.project.ifc,
+            );
+
+        self.element_quantity(space.id(), element_quantity);
+    }
+
+    /// Base quantities of elements filling an opening, like windows and
+    /// doors.
+    pub(super) fn filling_base_quantities(
+        &mut self,
+        filling: Id,
+        qto_name: &str,
+        width: f64,
+        height: f64,
+    ) {
+        let element_quantity = ElementQuantity::new(qto_name)
+            .add_quantity(QuantityLength::new("Width", width), &mut self.project.ifc)
+            .add_quantity(QuantityLength::new("Height", height), &mut self.project.ifc)
+            .add_quantity(
+                QuantityArea::new("Area", width * height),
+                &mut self.project.ifc,
+            );
+
+        self.element_quantity(filling, element_quantity);
+    }
+
+    /// Creates the base quantities of all walls, the openings in the walls
+    /// are subtracted from their net areas and volumes.
+    pub(super) fn wall_base_quantities(&mut self) {
+        let walls: Vec<_> = self.wall_dimensions.drain().collect();
+
+        for (wall, dimensions) in walls {
+            let opening_area: f64 = self
+                .opening_elements_to_wall
+                .iter()
+                .filter(|(_, opening_wall)| **opening_wall == wall)
+                .filter_map(|(opening_element, _)| self.opening_element_areas.get(opening_element))
+                .sum();
+
+            let footprint_area = dimensions.length * dimensions.thickness;
+            let gross_side_area = dimensions.length * dimensions.height;
+            let net_side_area = gross_side_area - opening_area;
+
+            let element_quantity = ElementQuantity::new("Qto_WallBaseQuantities")
+                .add_quantity(
+                    QuantityLength::new("Length", dimensions.length),
+                    &mut self.project.ifc,
+                )
+                .add_quantity(
+                    QuantityLength::new("Height", dimensions.height),
+                    &mut self.project.ifc,
+                )
+                .add_quantity(
+                    QuantityLength::new("Width", dimensions.thickness),
+                    &mut self.project.ifc,
+                )
+                .add_quantity(
+                    QuantityArea::new("GrossFootprintArea", footprint_area),
+                    &mut self.project.ifc,
+                )
+                .add_quantity(
+                    QuantityArea::new("NetFootprintArea", footprint_area),
+                    &mut self.project.ifc,
+                )
+                .add_quantity(
+                    QuantityArea::new("GrossSideArea", gross_side_area),
+                    &mut self.project.ifc,
+                )
+                .add_quantity(
+                    QuantityArea::new("NetSideArea", net_side_area),
+                    &mut self.project.ifc,
+                )
+                .add_quantity(
+                    QuantityVolume::new("GrossVolume", gross_side_area * dimensions.thickness),
+                    &mut self.project.ifc,
+                )
+                .add_quantity(
+                    QuantityVolume::new("NetVolume", net_side_area * dimensions.thickness),
+                    &mut self.project.ifc,
+                );
+
+            self.element_quantity(wall.id(), element_quantity);
+        }
+    }
+
+    fn element_quantity(&mut self, object: Id, element_quantity: ElementQuantity) {
+        let element_quantity = element_quantity
+            .method_of_measurement(BASE_QUANTITIES)
+            .owner_history(self.owner_history, &mut self.project.ifc);
+        let element_quantity_id = self.project.ifc.data.insert_new(element_quantity);
+
+        self.element_quantity_to_object
+            .insert(element_quantity_id, object);
+    }
+}
+
+/// Area of a simple polygon, it may or may not repeat its first point at
+/// the end.
+fn polygon_area(polygon: &[DVec2]) -> f64 {
+    let doubled_area: f64 = polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(a, b)| a.perp_dot(*b))
+        .sum();
+
+    doubled_area.abs() * 0.5
+}
+
+/// Length of the closed outline of a polygon, it may or may not repeat its
+/// first point at the end.
+fn polygon_perimeter(polygon: &[DVec2]) -> f64 {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(a, b)| a.distance(*b))
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::{DVec2, DVec3};
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    #[test]
+    fn builder_base_quantities() {
+        let mut builder = create_builder();
+        let wall;
+        let slab;
+        let space;
+
+        {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+            storey_builder.compute_base_quantities(true);
+
+            let material_layer = storey_builder.material_layer("ExampleMaterial", 0.25, false);
+            let material_layer_set = storey_builder.material_layer_set([material_layer]);
+            let material_layer_set_usage = storey_builder.material_layer_set_usage(
+                material_layer_set,
+                LayerSetDirectionEnum::Axis2,
+                DirectionSenseEnum::Positive,
+                0.0,
+            );
+
+            let wall_type = storey_builder.wall_type(
+                material_layer_set,
+                "ExampleWallType",
+                WallTypeEnum::NotDefined,
+            );
+
+            wall = storey_builder.vertical_wall(
+                material_layer_set_usage,
+                wall_type,
+                "ExampleWallDefault",
+                VerticalWallParameter {
+                    height: 2.0,
+                    length: 4.0,
+                    placement: DVec3::new(0.0, 0.0, 0.0),
+                },
+            );
+
+            storey_builder.vertical_wall_opening(
+                wall,
+                "ExampleOpeningElement",
+                VerticalOpeningParameter {
+                    height: 0.5,
+                    length: 1.0,
+                    placement: DVec3::new(1.0, 0.0, 0.5),
+                },
+            );
+
+            let slab_type = storey_builder.slab_type(
+                material_layer_set,
+                "ExampleSlabType",
+                SlabTypeEnum::NotDefined,
+            );
+
+            slab = storey_builder.horizontal_arbitrary_slab(
+                material_layer_set_usage,
+                slab_type,
+                "ExampleSlab",
+                HorizontalArbitrarySlabParameter {
+                    coords: vec![
+                        DVec2::new(0.0, 0.0),
+                        DVec2::new(0.0, 2.0),
+                        DVec2::new(4.0, 2.0),
+                        DVec2::new(4.0, 0.0),
+                    ],
+                    placement: DVec3::new(0.0, 0.0, 0.0),
+                },
+            );
+
+            let space_type = storey_builder.space_type("ExampleSpaceType", SpaceTypeEnum::Space);
+            space = storey_builder.space(
+                space_type,
+                "ExampleSpace",
+                SpaceParameter {
+                    coords: vec![
+                        DVec2::ZERO,
+                        DVec2::new(0.0, 3.0),
+                        DVec2::new(3.0, 3.0),
+                        DVec2::new(3.0, 0.0),
+                        DVec2::ZERO,
+                    ],
+                    height: 2.5,
+                    placement: DVec3::new(0.0, 0.0, 0.0),
+                },
+            );
+        }
+
+        let s = builder.build();
+        let ifc = IFC::from_str(&s).unwrap();
+
+        assert_eq!(s, ifc.to_string());
+
+        let ifc = IfcExtractor::from(ifc);
+        let wall_quantity = |name| {
+            ifc.quantity_value(wall, "Qto_WallBaseQuantities", name)
+                .unwrap()
+        };
+        assert_eq!(wall_quantity("Width"), 0.25);
+        assert_eq!(wall_quantity("GrossSideArea"), 8.0);
+        assert_eq!(wall_quantity("NetSideArea"), 7.5);
+        assert_eq!(wall_quantity("NetVolume"), 7.5 * 0.25);
+
+        let slab_quantity = |name| {
+            ifc.quantity_value(slab, "Qto_SlabBaseQuantities", name)
+                .unwrap()
+        };
+        assert_eq!(slab_quantity("Perimeter"), 12.0);
+        assert_eq!(slab_quantity("GrossArea"), 8.0);
+        assert_eq!(slab_quantity("GrossVolume"), 2.0);
+
+        let space_quantity = |name| {
+            ifc.quantity_value(space, "Qto_SpaceBaseQuantities", name)
+                .unwrap()
+        };
+        assert_eq!(space_quantity("GrossPerimeter"), 12.0);
+        assert_eq!(space_quantity("NetFloorArea"), 9.0);
+        assert_eq!(space_quantity("GrossVolume"), 22.5);
+    }
+}
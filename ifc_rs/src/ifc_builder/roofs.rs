@@ -16,7 +16,7 @@ impl<'a> IfcStoreyBuilder<'a> {
         roof_type: TypedId<RoofType>,
         name: &str,
         roof_information: HorizontalArbitraryRoofParameter,
-    ) {
+    ) -> TypedId<Roof> {
         let position = Axis3D::new(
             Point3D::from(roof_information.placement),
             &mut self.project.ifc,
@@ -52,7 +52,7 @@ impl<'a> IfcStoreyBuilder<'a> {
             .object_placement(local_placement, &mut self.project.ifc)
             .representation(product_shape, &mut self.project.ifc);
 
-        self.roof(material, roof_type, roof);
+        self.roof(material, roof_type, roof)
     }
 
     pub fn roof_type(
@@ -89,7 +89,7 @@ impl<'a> IfcStoreyBuilder<'a> {
         material: TypedId<MaterialLayerSetUsage>,
         roof_type: TypedId<RoofType>,
         roof: Roof,
-    ) {
+    ) -> TypedId<Roof> {
         let roof_id = self.project.ifc.data.insert_new(roof);
 
         self.roofs.insert(roof_id);
@@ -109,6 +109,8 @@ impl<'a> IfcStoreyBuilder<'a> {
                 .owner_history(self.owner_history, &mut self.project.ifc)
             })
             .relate_push(roof_id, &mut self.project.ifc);
+
+        roof_id
     }
 }
 
@@ -20,12 +20,7 @@ impl<'a> IfcSiteBuilder<'a> {
         site: TypedId<Site>,
         owner_history: TypedId<OwnerHistory>,
     ) -> Self {
-        let sub_context = project
-            .ifc
-            .data
-            .id_of::<GeometricRepresentationSubContext>()
-            .last()
-            .unwrap();
+        let sub_context = project.body_sub_context;
 
         Self {
             project,
@@ -16,12 +16,13 @@ impl<'a> IfcStoreyBuilder<'a> {
         slab_type: TypedId<SlabType>,
         name: &str,
         slab_information: HorizontalArbitrarySlabParameter,
-    ) {
+    ) -> TypedId<Slab> {
         let position = Axis3D::new(
             Point3D::from(slab_information.placement),
             &mut self.project.ifc,
         );
         let slab_thickness = self.calculate_material_layer_set_thickness(material);
+        let footprint = slab_information.coords.clone();
 
         let shape_repr = ShapeRepresentation::new(self.sub_context, &mut self.project.ifc)
             .add_item(
@@ -52,7 +53,13 @@ impl<'a> IfcStoreyBuilder<'a> {
             .object_placement(local_placement, &mut self.project.ifc)
             .representation(product_shape, &mut self.project.ifc);
 
-        self.slab(material, slab_type, slab);
+        let slab_id = self.slab(material, slab_type, slab);
+
+        if self.base_quantities {
+            self.slab_base_quantities(slab_id, &footprint, slab_thickness);
+        }
+
+        slab_id
     }
 
     pub fn slab_type(
@@ -89,7 +96,7 @@ impl<'a> IfcStoreyBuilder<'a> {
         material: TypedId<MaterialLayerSetUsage>,
         slab_type: TypedId<SlabType>,
         slab: Slab,
-    ) {
+    ) -> TypedId<Slab> {
         let slab_id = self.project.ifc.data.insert_new(slab);
 
         self.slabs.insert(slab_id);
@@ -109,6 +116,8 @@ impl<'a> IfcStoreyBuilder<'a> {
                 .owner_history(self.owner_history, &mut self.project.ifc)
             })
             .relate_push(slab_id, &mut self.project.ifc);
+
+        slab_id
     }
 }
 
@@ -0,0 +1,821 @@
+use std::collections::{HashMap, HashSet};
+
+use glam::{DAffine3, DVec2, DVec3};
+
+use crate::{
+    geometry::profile_def::signed_area, prelude::*,
+    relations::rel_space_boundary::SpaceBoundaryRelatable,
+};
+
+/// Maximum distance at which faces of spaces and elements are considered to
+/// be touching.
+pub(super) const CONTACT_TOLERANCE: f64 = 1e-3;
+
+/// Body of a space or element extruded vertically from its footprint, given
+/// in the coordinate system of the storey.
+pub(super) struct Prism {
+    /// Counter-clockwise footprint, the first point is not repeated at the
+    /// end.
+    pub(super) footprint: Vec<DVec2>,
+    pub(super) bottom: f64,
+    pub(super) top: f64,
+}
+
+/// Planar face of a space boundary, given in the coordinate system of the
+/// storey.
+struct BoundaryFace {
+    origin: DVec3,
+    x_axis: DVec3,
+    /// Normal of the face pointing away from the space.
+    normal: DVec3,
+    /// Outline of the face within its xy plane.
+    outline: Vec<DVec2>,
+    voids: Vec<Vec<DVec2>>,
+}
+
+/// Contact of a space to the side of a wall.
+struct WallContact {
+    space: TypedId<Space>,
+    wall: TypedId<Wall>,
+    start: DVec2,
+    end: DVec2,
+    bottom: f64,
+    top: f64,
+}
+
+impl<'a> IfcStoreyBuilder<'a> {
+    /// Enables the computation of first level space boundaries between the
+    /// spaces of the storey and the walls, slabs, windows and doors bounding
+    /// them. The boundaries are derived from the geometry of the elements
+    /// when the storey is finished, only vertically extruded bodies are
+    /// taken into account.
+    ///
+    /// Walls bounding more than one space are internal, the other walls are
+    /// only internal or external if `Pset_WallCommon.IsExternal` of the wall
+    /// or its type says so.
+    pub fn compute_space_boundaries(&mut self, compute: bool) {
+        self.space_boundaries = compute;
+    }
+
+    pub(super) fn first_level_space_boundaries(&mut self) {
+        let to_storey = self.project.ifc.placement_transform(self.storey).inverse();
+
+        let mut spaces: Vec<_> = self.spaces.iter().copied().collect();
+        spaces.sort_by_key(|space| space.id());
+        let mut walls: Vec<_> = self.walls.iter().copied().collect();
+        walls.sort_by_key(|wall| wall.id());
+        let mut slabs: Vec<_> = self.slabs.iter().copied().collect();
+        slabs.sort_by_key(|slab| slab.id());
+
+        let space_prisms: Vec<_> = spaces
+            .into_iter()
+            .filter_map(|space| {
+                let space_to_storey = to_storey * self.project.ifc.placement_transform(space);
+                let representation = self.project.ifc.data.get(space).representation.custom();
+
+                self.prism(space_to_storey, representation.copied())
+                    .map(|prism| (space, space_to_storey.inverse(), prism))
+            })
+            .collect();
+        let wall_prisms: Vec<_> = walls
+            .into_iter()
+            .filter_map(|wall| {
+                let representation = self.project.ifc.data.get(wall).representation.custom();
+
+                self.prism(
+                    to_storey * self.project.ifc.placement_transform(wall),
+                    representation.copied(),
+                )
+                .map(|prism| (wall, prism))
+            })
+            .collect();
+        let slab_prisms: Vec<_> = slabs
+            .into_iter()
+            .filter_map(|slab| {
+                let representation = self.project.ifc.data.get(slab).representation.custom();
+
+                self.prism(
+                    to_storey * self.project.ifc.placement_transform(slab),
+                    representation.copied(),
+                )
+                .map(|prism| (slab, prism))
+            })
+            .collect();
+        let opening_prisms: HashMap<_, _> = self
+            .opening_elements_to_wall
+            .keys()
+            .filter_map(|opening_element| {
+                let representation = self
+                    .project
+                    .ifc
+                    .data
+                    .get(*opening_element)
+                    .representation
+                    .custom();
+
+                self.prism(
+                    to_storey * self.project.ifc.placement_transform(*opening_element),
+                    representation.copied(),
+                )
+                .map(|prism| (*opening_element, prism))
+            })
+            .collect();
+
+        let contacts: Vec<_> = space_prisms
+            .iter()
+            .flat_map(|(space, _, space_prism)| {
+                wall_prisms.iter().flat_map(move |(wall, wall_prism)| {
+                    wall_contacts(space_prism, wall_prism).map(move |(start, end, bottom, top)| {
+                        WallContact {
+                            space: *space,
+                            wall: *wall,
+                            start,
+                            end,
+                            bottom,
+                            top,
+                        }
+                    })
+                })
+            })
+            .collect();
+
+        // walls bounding more than one space of the storey separate spaces
+        let mut spaces_of_wall: HashMap<TypedId<Wall>, HashSet<TypedId<Space>>> = HashMap::new();
+        for contact in contacts.iter() {
+            spaces_of_wall
+                .entry(contact.wall)
+                .or_default()
+                .insert(contact.space);
+        }
+
+        let mut windows: Vec<_> = self
+            .opening_elements_to_window
+            .iter()
+            .map(|(opening_element, window)| (*opening_element, *window))
+            .collect();
+        windows.sort_by_key(|(opening_element, _)| opening_element.id());
+        let mut doors: Vec<_> = self
+            .opening_elements_to_door
+            .iter()
+            .map(|(opening_element, door)| (*opening_element, *door))
+            .collect();
+        doors.sort_by_key(|(opening_element, _)| opening_element.id());
+
+        let space_transforms: HashMap<_, _> = space_prisms
+            .iter()
+            .map(|(space, storey_to_space, _)| (*space, *storey_to_space))
+            .collect();
+
+        for contact in contacts {
+            let storey_to_space = space_transforms[&contact.space];
+            let internal_or_external = match spaces_of_wall[&contact.wall].len() > 1 {
+                true => InternalOrExternalEnum::Internal,
+                false => match self.wall_is_external(contact.wall) {
+                    Some(true) => InternalOrExternalEnum::External,
+                    Some(false) => InternalOrExternalEnum::Internal,
+                    None => InternalOrExternalEnum::NotDefined,
+                },
+            };
+
+            let openings_of_wall = |opening_element: &TypedId<OpeningElement>| {
+                self.opening_elements_to_wall.get(opening_element) == Some(&contact.wall)
+            };
+            let window_faces: Vec<_> = windows
+                .iter()
+                .filter(|(opening_element, _)| openings_of_wall(opening_element))
+                .filter_map(|(opening_element, window)| {
+                    opening_face(&contact, opening_prisms.get(opening_element)?)
+                        .map(|face| (*window, face))
+                })
+                .collect();
+            let door_faces: Vec<_> = doors
+                .iter()
+                .filter(|(opening_element, _)| openings_of_wall(opening_element))
+                .filter_map(|(opening_element, door)| {
+                    opening_face(&contact, opening_prisms.get(opening_element)?)
+                        .map(|face| (*door, face))
+                })
+                .collect();
+
+            let mut face = wall_face(&contact);
+            face.voids.extend(
+                window_faces
+                    .iter()
+                    .map(|(_, face)| face.outline.clone())
+                    .chain(door_faces.iter().map(|(_, face)| face.outline.clone())),
+            );
+
+            let wall_boundary = self.space_boundary(
+                contact.space,
+                contact.wall,
+                storey_to_space,
+                face,
+                internal_or_external,
+                None,
+            );
+
+            for (window, face) in window_faces {
+                self.space_boundary(
+                    contact.space,
+                    window,
+                    storey_to_space,
+                    face,
+                    internal_or_external,
+                    Some(wall_boundary),
+                );
+            }
+
+            for (door, face) in door_faces {
+                self.space_boundary(
+                    contact.space,
+                    door,
+                    storey_to_space,
+                    face,
+                    internal_or_external,
+                    Some(wall_boundary),
+                );
+            }
+        }
+
+        for (space, storey_to_space, space_prism) in space_prisms.iter() {
+            for (slab, slab_prism) in slab_prisms.iter() {
+                for face in slab_faces(space_prism, slab_prism) {
+                    self.space_boundary(
+                        *space,
+                        *slab,
+                        *storey_to_space,
+                        face,
+                        InternalOrExternalEnum::NotDefined,
+                        None,
+                    );
+                }
+            }
+        }
+    }
+
+    /// `Pset_WallCommon.IsExternal` of the wall, the property sets of the
+    /// occurrence take precedence over the ones of its type.
+    fn wall_is_external(&self, wall: TypedId<Wall>) -> Option<bool> {
+        let ifc = &self.project.ifc;
+
+        let occurrence_property_sets = self
+            .property_set_to_objects
+            .iter()
+            .filter(|(_, relation)| relation.related_objects.0.contains(&wall.id()))
+            .map(|(property_set, _)| property_set.id());
+        let type_property_sets = self
+            .wall_type_to_wall
+            .iter()
+            .filter(|(_, walls)| walls.contains(&wall))
+            .filter_map(|(wall_type, _)| ifc.data.get(*wall_type).has_property_sets.custom())
+            .flat_map(|property_sets| property_sets.0.iter().copied());
+
+        occurrence_property_sets
+            .chain(type_property_sets)
+            .filter_map(|id| ifc.data.get_untyped(id).downcast_ref::<PropertySet>())
+            .filter(|pset| {
+                pset.name
+                    .custom()
+                    .is_some_and(|name| name.0 == "Pset_WallCommon")
+            })
+            .find_map(|pset| pset.single_value("IsExternal", ifc)?.as_bool())
+    }
+
+    /// Vertical prism of the body of a product, `None` if the body is not a
+    /// vertically extruded area solid. Clippings of the solid are ignored.
+    pub(super) fn prism(
+        &self,
+        to_storey: DAffine3,
+        representation: Option<TypedId<ProductDefinitionShape>>,
+    ) -> Option<Prism> {
+        let ifc = &self.project.ifc;
+
+        let solid = representation
+            .into_iter()
+            .flat_map(|shape| ifc.data.get(shape).representations.iter())
+            .flat_map(|representation| ifc.data.get(*representation).items.iter())
+            .find_map(|item| extruded_area_solid(*item, ifc))?;
+
+        let solid_position = solid
+            .position
+            .custom()
+            .map(|position| ifc.data.get(*position).transform(ifc))
+            .unwrap_or(DAffine3::IDENTITY);
+        let transform = to_storey * solid_position;
+
+        let extrusion = transform
+            .transform_vector3(ifc.data.get(solid.extruded_direction).normalize() * solid.depth.0);
+        let horizontal_profile = transform
+            .transform_vector3(DVec3::Z)
+            .abs_diff_eq(DVec3::Z, CONTACT_TOLERANCE);
+        if !horizontal_profile || extrusion.truncate().length() > CONTACT_TOLERANCE {
+            return None;
+        }
+
+        let outline = profile_polygons(solid.swept_area, ifc)?
+            .into_iter()
+            .next()?
+            .outer;
+        let mut footprint: Vec<DVec2> = Vec::with_capacity(outline.len());
+        for point in outline {
+            let point = transform.transform_point3(point.extend(0.0)).truncate();
+
+            if footprint
+                .last()
+                .is_none_or(|last| last.distance(point) > CONTACT_TOLERANCE)
+            {
+                footprint.push(point);
+            }
+        }
+        if footprint.len() > 1
+            && footprint[0].distance(footprint[footprint.len() - 1]) <= CONTACT_TOLERANCE
+        {
+            footprint.pop();
+        }
+        if footprint.len() < 3 {
+            return None;
+        }
+        if signed_area(&footprint) < 0.0 {
+            footprint.reverse();
+        }
+
+        let base = transform.translation.z;
+
+        Some(Prism {
+            footprint,
+            bottom: base + extrusion.z.min(0.0),
+            top: base + extrusion.z.max(0.0),
+        })
+    }
+
+    fn space_boundary<E: SpaceBoundaryRelatable>(
+        &mut self,
+        space: TypedId<Space>,
+        element: TypedId<E>,
+        storey_to_space: DAffine3,
+        face: BoundaryFace,
+        internal_or_external: InternalOrExternalEnum,
+        parent_boundary: Option<TypedId<RelSpaceBoundary1stLevel>>,
+    ) -> TypedId<RelSpaceBoundary1stLevel> {
+        let ifc = &mut self.project.ifc;
+
+        let position = Axis3D::new(
+            Point3D::from(storey_to_space.transform_point3(face.origin)),
+            ifc,
+        )
+        .local_z(
+            Direction3D::from(storey_to_space.transform_vector3(face.normal)),
+            ifc,
+        )
+        .local_x(
+            Direction3D::from(storey_to_space.transform_vector3(face.x_axis)),
+            ifc,
+        );
+
+        let mut surface = CurveBoundedPlane::new(
+            Plane::new(position, ifc),
+            closed_polyline(&face.outline, ifc),
+            ifc,
+        );
+        for void in face.voids.iter() {
+            surface = surface.add_inner_boundary(closed_polyline(void, ifc), ifc);
+        }
+
+        let mut boundary = RelSpaceBoundary1stLevel::new("1stLevel", space, element, ifc)
+            .owner_history(self.owner_history, ifc)
+            .connection_geometry(ConnectionSurfaceGeometry::new(surface, ifc), ifc)
+            .internal_or_external(internal_or_external);
+
+        if let Some(parent_boundary) = parent_boundary {
+            boundary = boundary.parent_boundary(parent_boundary, ifc);
+        }
+
+        ifc.data.insert_new(boundary)
+    }
+}
+
+/// The extruded area solid of a representation item, following the first
+/// operands of clipping results.
+fn extruded_area_solid(item: Id, ifc: &IFC) -> Option<&ExtrudedAreaSolid> {
+    let item = ifc.data.get_untyped(item);
+
+    if let Some(clipping) = item.downcast_ref::<BooleanClippingResult>() {
+        extruded_area_solid(clipping.first_operand, ifc)
+    } else {
+        item.downcast_ref::<ExtrudedAreaSolid>()
+    }
+}
+
+fn closed_polyline(points: &[DVec2], ifc: &mut IFC) -> PolyLine {
+    PolyLine::from(
+        points
+            .iter()
+            .chain(points.first())
+            .map(|point| Point2D::from(*point)),
+        ifc,
+    )
+}
+
+/// Parts of the footprint edges of a space lying on a wall, together with
+/// the height range both share.
+fn wall_contacts<'p>(
+    space: &'p Prism,
+    wall: &'p Prism,
+) -> impl Iterator<Item = (DVec2, DVec2, f64, f64)> + 'p {
+    let bottom = space.bottom.max(wall.bottom);
+    let top = space.top.min(wall.top);
+
+    edges(&space.footprint)
+        .filter(move |_| top - bottom > CONTACT_TOLERANCE && is_convex(&wall.footprint))
+        .filter_map(move |(start, end)| {
+            clip_segment(start, end, &wall.footprint)
+                .map(|(t0, t1)| (start.lerp(end, t0), start.lerp(end, t1), bottom, top))
+        })
+}
+
+/// Face of a wall bounding a space along one of its footprint edges. The
+/// face is spanned by the edge and the vertical, its normal points out of
+/// the counter-clockwise footprint.
+fn wall_face(contact: &WallContact) -> BoundaryFace {
+    let length = contact.start.distance(contact.end);
+    let height = contact.top - contact.bottom;
+    let direction = (contact.end - contact.start) / length;
+
+    BoundaryFace {
+        origin: contact.start.extend(contact.bottom),
+        x_axis: direction.extend(0.0),
+        normal: DVec3::new(direction.y, -direction.x, 0.0),
+        outline: vec![
+            DVec2::ZERO,
+            DVec2::new(length, 0.0),
+            DVec2::new(length, height),
+            DVec2::new(0.0, height),
+        ],
+        voids: Vec::new(),
+    }
+}
+
+/// Face of the filling of an opening within the face of the wall contact,
+/// given in the same coordinate system as the wall face.
+fn opening_face(contact: &WallContact, opening: &Prism) -> Option<BoundaryFace> {
+    let bottom = contact.bottom.max(opening.bottom) - contact.bottom;
+    let top = contact.top.min(opening.top) - contact.bottom;
+    if top - bottom <= CONTACT_TOLERANCE || !is_convex(&opening.footprint) {
+        return None;
+    }
+
+    let (t0, t1) = clip_segment(contact.start, contact.end, &opening.footprint)?;
+    let length = contact.start.distance(contact.end);
+
+    let mut face = wall_face(contact);
+    face.outline = vec![
+        DVec2::new(t0 * length, bottom),
+        DVec2::new(t1 * length, bottom),
+        DVec2::new(t1 * length, top),
+        DVec2::new(t0 * length, top),
+    ];
+
+    Some(face)
+}
+
+/// Floor and ceiling faces of a space provided by a slab. The slab forms the
+/// floor if the bottom of the space lies on or within the slab and the
+/// ceiling if the top does.
+fn slab_faces(space: &Prism, slab: &Prism) -> Vec<BoundaryFace> {
+    let touches = |elevation: f64| {
+        elevation >= slab.bottom - CONTACT_TOLERANCE && elevation <= slab.top + CONTACT_TOLERANCE
+    };
+
+    let Some(outline) = polygon_intersection(&space.footprint, &slab.footprint) else {
+        return Vec::new();
+    };
+
+    let mut faces = Vec::new();
+
+    if touches(space.bottom) {
+        faces.push(BoundaryFace {
+            origin: DVec3::new(0.0, 0.0, space.bottom),
+            x_axis: DVec3::X,
+            normal: DVec3::NEG_Z,
+            // the y axis of the face points along the negative y axis
+            outline: outline
+                .iter()
+                .map(|point| DVec2::new(point.x, -point.y))
+                .collect(),
+            voids: Vec::new(),
+        });
+    }
+
+    if touches(space.top) {
+        faces.push(BoundaryFace {
+            origin: DVec3::new(0.0, 0.0, space.top),
+            x_axis: DVec3::X,
+            normal: DVec3::Z,
+            outline,
+            voids: Vec::new(),
+        });
+    }
+
+    faces
+}
+
+fn edges(polygon: &[DVec2]) -> impl Iterator<Item = (DVec2, DVec2)> + Clone + '_ {
+    polygon
+        .iter()
+        .copied()
+        .zip(polygon.iter().copied().cycle().skip(1))
+}
+
+fn is_convex(polygon: &[DVec2]) -> bool {
+    edges(polygon)
+        .zip(edges(polygon).cycle().skip(1))
+        .all(|((a, b), (_, c))| (b - a).perp_dot(c - b) >= -CONTACT_TOLERANCE * CONTACT_TOLERANCE)
+}
+
+/// Parameter range of the segment from `start` to `end` lying within the
+/// convex counter-clockwise polygon, `None` if the segment merely touches a
+/// corner of the polygon. Segments running along an edge of the polygon are
+/// considered within it if they deviate less than the contact tolerance.
+fn clip_segment(start: DVec2, end: DVec2, polygon: &[DVec2]) -> Option<(f64, f64)> {
+    let direction = end - start;
+    let (mut t0, mut t1) = (0.0_f64, 1.0_f64);
+
+    for (a, b) in edges(polygon) {
+        let outward = DVec2::new(b.y - a.y, a.x - b.x).normalize_or_zero();
+        let distance = outward.dot(start - a);
+        let rate = outward.dot(direction);
+
+        if rate.abs() <= CONTACT_TOLERANCE {
+            if distance.max(distance + rate) > CONTACT_TOLERANCE {
+                return None;
+            }
+        } else if rate > 0.0 {
+            t1 = t1.min(-distance / rate);
+        } else {
+            t0 = t0.max(-distance / rate);
+        }
+    }
+
+    ((t1 - t0) * direction.length() > CONTACT_TOLERANCE).then_some((t0, t1))
+}
+
+/// Whether the point lies within the polygon or on its boundary.
+fn contains(polygon: &[DVec2], point: DVec2) -> bool {
+    let on_boundary = edges(polygon).any(|(a, b)| {
+        let edge = b - a;
+        let t = ((point - a).dot(edge) / edge.length_squared()).clamp(0.0, 1.0);
+
+        point.distance(a + edge * t) <= CONTACT_TOLERANCE
+    });
+
+    let crossings = edges(polygon)
+        .filter(|(a, b)| {
+            (a.y > point.y) != (b.y > point.y)
+                && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+        })
+        .count();
+
+    on_boundary || crossings % 2 == 1
+}
+
+/// Intersection of two counter-clockwise polygons, one of them has to be
+/// convex unless one contains the other. `None` if they don't overlap.
+fn polygon_intersection(subject: &[DVec2], clip: &[DVec2]) -> Option<Vec<DVec2>> {
+    let intersection = if subject.iter().all(|point| contains(clip, *point)) {
+        subject.to_vec()
+    } else if clip.iter().all(|point| contains(subject, *point)) {
+        clip.to_vec()
+    } else if is_convex(clip) {
+        clip_polygon(subject, clip)
+    } else if is_convex(subject) {
+        clip_polygon(clip, subject)
+    } else {
+        return None;
+    };
+
+    (signed_area(&intersection) > CONTACT_TOLERANCE * CONTACT_TOLERANCE).then_some(intersection)
+}
+
+/// Sutherland–Hodgman clipping of a polygon by a convex counter-clockwise
+/// polygon.
+fn clip_polygon(subject: &[DVec2], clip: &[DVec2]) -> Vec<DVec2> {
+    edges(clip).fold(subject.to_vec(), |polygon, (a, b)| {
+        let edge = b - a;
+        let inside = |point: DVec2| edge.perp_dot(point - a) >= 0.0;
+
+        edges(&polygon)
+            .flat_map(|(start, end)| {
+                let crossing = || {
+                    let t = edge.perp_dot(start - a)
+                        / (edge.perp_dot(start - a) - edge.perp_dot(end - a));
+                    start.lerp(end, t)
+                };
+
+                match (inside(start), inside(end)) {
+                    (true, true) => vec![end],
+                    (true, false) => vec![crossing()],
+                    (false, true) => vec![crossing(), end],
+                    (false, false) => Vec::new(),
+                }
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::{DVec2, DVec3};
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    #[test]
+    fn builder_space_boundaries() {
+        let mut builder = create_builder();
+
+        {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+            storey_builder.compute_space_boundaries(true);
+
+            let material_layer = storey_builder.material_layer("ExampleMaterial", 0.2, false);
+            let material_layer_set = storey_builder.material_layer_set([material_layer]);
+            let material_layer_set_usage = storey_builder.material_layer_set_usage(
+                material_layer_set,
+                LayerSetDirectionEnum::Axis2,
+                DirectionSenseEnum::Positive,
+                0.0,
+            );
+
+            let wall_type = storey_builder.wall_type(
+                material_layer_set,
+                "ExampleWallType",
+                WallTypeEnum::NotDefined,
+            );
+
+            // two rooms next to each other, separated by the middle wall
+            let walls = [-0.2, 3.0, 6.2].map(|y| {
+                storey_builder.vertical_wall(
+                    material_layer_set_usage,
+                    wall_type,
+                    "ExampleWall",
+                    VerticalWallParameter {
+                        height: 2.5,
+                        length: 4.0,
+                        placement: DVec3::new(0.0, y, 0.0),
+                    },
+                )
+            });
+
+            // only the first outer wall is known to be external
+            let wall_common =
+                storey_builder.property_set("Pset_WallCommon", [("IsExternal", true.into())]);
+            storey_builder.wall_property_set(walls[0], wall_common);
+
+            let window_type = storey_builder.window_type(
+                "ExampleWindowType",
+                WindowTypeEnum::Window,
+                WindowPartitioningTypeEnum::SinglePanel,
+            );
+            let material_constituent = storey_builder.material_constituent("Wood", "Framing");
+            let material_constituent_set =
+                storey_builder.material_constituent_set([material_constituent]);
+            storey_builder.wall_window_with_opening(
+                material_constituent_set,
+                window_type,
+                walls[0],
+                "ExampleWindow",
+                WindowParameter {
+                    height: 1.0,
+                    width: 1.5,
+                    placement: DVec3::new(1.0, 0.0, 1.0),
+                },
+            );
+
+            let slab_type = storey_builder.slab_type(
+                material_layer_set,
+                "ExampleSlabType",
+                SlabTypeEnum::Floor,
+            );
+            storey_builder.horizontal_arbitrary_slab(
+                material_layer_set_usage,
+                slab_type,
+                "ExampleSlab",
+                HorizontalArbitrarySlabParameter {
+                    coords: vec![
+                        DVec2::new(0.0, -0.2),
+                        DVec2::new(4.0, -0.2),
+                        DVec2::new(4.0, 6.4),
+                        DVec2::new(0.0, 6.4),
+                    ],
+                    placement: DVec3::new(0.0, 0.0, -0.2),
+                },
+            );
+
+            let space_type = storey_builder.space_type("ExampleSpaceType", SpaceTypeEnum::Space);
+            let room_footprint = vec![
+                DVec2::ZERO,
+                DVec2::new(4.0, 0.0),
+                DVec2::new(4.0, 3.0),
+                DVec2::new(0.0, 3.0),
+            ];
+            for y in [0.0, 3.2] {
+                storey_builder.space(
+                    space_type,
+                    "ExampleSpace",
+                    SpaceParameter {
+                        coords: room_footprint.clone(),
+                        height: 2.5,
+                        placement: DVec3::new(0.0, y, 0.0),
+                    },
+                );
+            }
+        }
+
+        let s = builder.build();
+        let ifc = IFC::from_str(&s).unwrap();
+
+        assert_eq!(s, ifc.to_string());
+
+        let ifc = IfcExtractor::from(ifc);
+        let boundaries: Vec<_> = ifc
+            .data
+            .find_all_of_type::<RelSpaceBoundary1stLevel>()
+            .collect();
+
+        // each room has two walls and the floor, the first room the window
+        assert_eq!(boundaries.len(), 7);
+
+        let wall_boundaries: Vec<_> = boundaries
+            .iter()
+            .filter(|(_, boundary)| {
+                ifc.data
+                    .get_untyped(boundary.related_building_element)
+                    .downcast_ref::<Wall>()
+                    .is_some()
+            })
+            .collect();
+        assert_eq!(wall_boundaries.len(), 4);
+        let wall_boundary_count = |internal_or_external: InternalOrExternalEnum| {
+            wall_boundaries
+                .iter()
+                .filter(|(_, boundary)| {
+                    boundary.internal_or_external_boundary == internal_or_external
+                })
+                .count()
+        };
+        assert_eq!(wall_boundary_count(InternalOrExternalEnum::Internal), 2);
+        assert_eq!(wall_boundary_count(InternalOrExternalEnum::External), 1);
+        assert_eq!(wall_boundary_count(InternalOrExternalEnum::NotDefined), 1);
+
+        let (_, window_boundary) = boundaries
+            .iter()
+            .find(|(_, boundary)| boundary.parent_boundary.custom().is_some())
+            .expect("window boundary exists");
+        assert!(matches!(
+            window_boundary.internal_or_external_boundary,
+            InternalOrExternalEnum::External
+        ));
+        let polygon = ifc
+            .space_boundary_polygon(window_boundary)
+            .expect("window boundary has geometry");
+        let mut expected = [
+            DVec3::new(1.0, 0.0, 1.0),
+            DVec3::new(2.5, 0.0, 1.0),
+            DVec3::new(2.5, 0.0, 2.0),
+            DVec3::new(1.0, 0.0, 2.0),
+        ]
+        .into_iter();
+        assert!(polygon
+            .iter()
+            .all(|point| point.abs_diff_eq(expected.next().unwrap(), 1e-9)));
+
+        let floor_areas: Vec<_> = boundaries
+            .iter()
+            .filter(|(_, boundary)| {
+                ifc.data
+                    .get_untyped(boundary.related_building_element)
+                    .downcast_ref::<Slab>()
+                    .is_some()
+            })
+            .map(|(_, boundary)| {
+                let polygon = ifc.space_boundary_polygon(boundary).unwrap();
+                assert!(polygon.iter().all(|point| point.z.abs() < 1e-9));
+
+                polygon
+                    .iter()
+                    .zip(polygon.iter().cycle().skip(1))
+                    .map(|(a, b)| a.cross(*b))
+                    .sum::<DVec3>()
+                    .length()
+                    * 0.5
+            })
+            .collect();
+        assert_eq!(floor_areas.len(), 2);
+        assert!(floor_areas.iter().all(|area| (area - 12.0).abs() < 1e-9));
+    }
+}
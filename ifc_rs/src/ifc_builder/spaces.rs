@@ -16,7 +16,9 @@ impl<'a> IfcStoreyBuilder<'a> {
         space_type: TypedId<SpaceType>,
         name: &str,
         space_information: SpaceParameter,
-    ) {
+    ) -> TypedId<Space> {
+        let footprint = space_information.coords.clone();
+
         let shape_repr_3d = ShapeRepresentation::new(self.sub_context, &mut self.project.ifc)
             .add_item(
                 ExtrudedAreaSolid::new(
@@ -59,6 +61,12 @@ impl<'a> IfcStoreyBuilder<'a> {
             .entry(space_type)
             .or_default()
             .insert(space_id);
+
+        if self.base_quantities {
+            self.space_base_quantities(space_id, &footprint, space_information.height);
+        }
+
+        space_id
     }
 
     pub fn space_type(&mut self, name: &str, space_type: SpaceTypeEnum) -> TypedId<SpaceType> {
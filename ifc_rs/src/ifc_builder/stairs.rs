@@ -0,0 +1,407 @@
+use glam::{DVec2, DVec3};
+
+use crate::prelude::*;
+
+/// Direction in which the second flight of an L-shaped stair turns, seen
+/// in walking direction upwards.
+#[derive(Clone, Copy)]
+pub enum StairTurn {
+    Left,
+    Right,
+}
+
+pub enum StairShape {
+    /// A single flight.
+    Straight,
+    /// Two flights connected by a square landing, the second flight turns
+    /// by 90° after the risers of the first one.
+    LShaped {
+        first_flight_risers: usize,
+        turn: StairTurn,
+    },
+}
+
+pub struct StairParameter {
+    /// Local to the storey, bottom of the first riser at the right side of
+    /// the stair.
+    pub position: DVec3,
+    /// Walking direction of the first flight, only the horizontal direction
+    /// is taken into account.
+    pub direction: DVec3,
+    pub riser_count: usize,
+    pub riser_height: f64,
+    pub tread_length: f64,
+    pub width: f64,
+    pub shape: StairShape,
+}
+
+impl<'a> IfcStoreyBuilder<'a> {
+    /// Creates a stair aggregating its flights and landings. The last riser
+    /// of a flight ends at the floor or landing it leads to, so a flight has
+    /// one tread less than risers.
+    ///
+    /// # Panics
+    /// If a flight of the stair has less than two risers or if the direction
+    /// has no horizontal part.
+    pub fn stair(&mut self, name: &str, stair_parameter: StairParameter) -> TypedId<Stair> {
+        assert!(
+            stair_parameter.riser_count >= 2,
+            "Stair flight needs at least two risers"
+        );
+        if let StairShape::LShaped {
+            first_flight_risers,
+            ..
+        } = stair_parameter.shape
+        {
+            assert!(
+                (2..=stair_parameter.riser_count - 2).contains(&first_flight_risers),
+                "Stair flight needs at least two risers"
+            );
+        }
+        let direction = stair_parameter
+            .direction
+            .truncate()
+            .try_normalize()
+            .expect("Stair direction has to be horizontal")
+            .extend(0.0);
+        let position = Axis3D::new(
+            Point3D::from(stair_parameter.position),
+            &mut self.project.ifc,
+        )
+        .local_x(Direction3D::from(direction), &mut self.project.ifc);
+        let local_placement =
+            LocalPlacement::new_relative(position, self.storey, &mut self.project.ifc);
+        let local_placement = self.project.ifc.data.insert_new(local_placement);
+
+        let riser_count = stair_parameter.riser_count;
+        let riser_height = stair_parameter.riser_height;
+        let tread_length = stair_parameter.tread_length;
+        let width = stair_parameter.width;
+
+        let (predefined_type, parts) = match stair_parameter.shape {
+            StairShape::Straight => {
+                let flight = self.stair_flight(
+                    name,
+                    riser_count,
+                    &stair_parameter,
+                    DVec3::ZERO,
+                    DVec3::X,
+                    local_placement,
+                );
+
+                (StairTypeEnum::StraightRunStair, vec![flight.id()])
+            }
+            StairShape::LShaped {
+                first_flight_risers,
+                turn,
+            } => {
+                let second_flight_risers = riser_count - first_flight_risers;
+                let landing_start = (first_flight_risers - 1) as f64 * tread_length;
+                let landing_height = first_flight_risers as f64 * riser_height;
+
+                let first_flight = self.stair_flight(
+                    name,
+                    first_flight_risers,
+                    &stair_parameter,
+                    DVec3::ZERO,
+                    DVec3::X,
+                    local_placement,
+                );
+
+                // the landing is as thick as the flights below their treads
+                let landing_thickness = 2.0 * riser_height;
+                let landing = self.stair_landing(
+                    name,
+                    DVec3::new(
+                        landing_start + width * 0.5,
+                        width * 0.5,
+                        landing_height - landing_thickness,
+                    ),
+                    width,
+                    landing_thickness,
+                    local_placement,
+                );
+
+                // the flight extends to the left of its walking direction
+                let (second_flight_start, second_flight_direction) = match turn {
+                    StairTurn::Left => (
+                        DVec3::new(landing_start + width, width, landing_height),
+                        DVec3::Y,
+                    ),
+                    StairTurn::Right => (DVec3::new(landing_start, 0.0, landing_height), -DVec3::Y),
+                };
+                let second_flight = self.stair_flight(
+                    name,
+                    second_flight_risers,
+                    &stair_parameter,
+                    second_flight_start,
+                    second_flight_direction,
+                    local_placement,
+                );
+
+                (
+                    StairTypeEnum::QuarterTurnStair,
+                    vec![first_flight.id(), landing.id(), second_flight.id()],
+                )
+            }
+        };
+
+        let stair = Stair::new(name)
+            .predefined_type(predefined_type)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .object_placement(local_placement, &mut self.project.ifc);
+
+        let stair_id = self.project.ifc.data.insert_new(stair);
+
+        let stair_parts = RelAggregates::new("StairToParts", stair_id.id(), parts)
+            .owner_history(self.owner_history, &mut self.project.ifc);
+        self.project.ifc.data.insert_new(stair_parts);
+
+        self.stairs.insert(stair_id);
+
+        stair_id
+    }
+
+    /// Stair flight starting at `start` within the stair placement. The
+    /// flight extends to the left of `direction` by the stair width.
+    fn stair_flight(
+        &mut self,
+        name: &str,
+        riser_count: usize,
+        stair_parameter: &StairParameter,
+        start: DVec3,
+        direction: DVec3,
+        stair_placement: TypedId<LocalPlacement>,
+    ) -> TypedId<StairFlight> {
+        let riser_height = stair_parameter.riser_height;
+        let tread_length = stair_parameter.tread_length;
+
+        // side view of the flight with the steps on top and the underside
+        // parallel to the pitch line, one riser below the inner corners of
+        // the steps. The flight ends with a vertical back face below its
+        // last tread, the last riser is formed by the floor it leads to.
+        let mut side_view: Vec<DVec2> = (0..riser_count - 1)
+            .flat_map(|step| {
+                let x = step as f64 * tread_length;
+                [
+                    DVec2::new(x, step as f64 * riser_height),
+                    DVec2::new(x, (step + 1) as f64 * riser_height),
+                ]
+            })
+            .collect();
+        let flight_length = (riser_count - 1) as f64 * tread_length;
+        side_view.push(DVec2::new(
+            flight_length,
+            (riser_count - 1) as f64 * riser_height,
+        ));
+        if riser_count > 2 {
+            side_view.push(DVec2::new(
+                flight_length,
+                (riser_count - 2) as f64 * riser_height,
+            ));
+        }
+        side_view.push(DVec2::new(tread_length, 0.0));
+
+        // the side view lies in the xz plane of the flight, extruded from
+        // its left side to its walking line
+        let solid_position = Axis3D::new(
+            Point3D::from(DVec3::new(0.0, stair_parameter.width, 0.0)),
+            &mut self.project.ifc,
+        )
+        .local_z(Direction3D::from(-DVec3::Y), &mut self.project.ifc)
+        .local_x(Direction3D::from(DVec3::X), &mut self.project.ifc);
+
+        let body = ExtrudedAreaSolid::new(
+            ArbitraryClosedProfileDef::new(
+                ProfileType::Area,
+                IndexedPolyCurve::new(
+                    PointList2D::new(side_view.into_iter()),
+                    &mut self.project.ifc,
+                ),
+                &mut self.project.ifc,
+            ),
+            Direction3D::from(DVec3::Z),
+            stair_parameter.width,
+            &mut self.project.ifc,
+        )
+        .position(solid_position, &mut self.project.ifc);
+
+        let shape_repr = ShapeRepresentation::new(self.sub_context, &mut self.project.ifc)
+            .identifier("Body")
+            .repr_type("SweptSolid")
+            .add_item(body, &mut self.project.ifc);
+        let product_shape =
+            ProductDefinitionShape::new().add_representation(shape_repr, &mut self.project.ifc);
+
+        let position = Axis3D::new(Point3D::from(start), &mut self.project.ifc)
+            .local_x(Direction3D::from(direction), &mut self.project.ifc);
+        let local_placement = LocalPlacement::new(position, &mut self.project.ifc)
+            .relative_to(stair_placement, &mut self.project.ifc);
+
+        let flight = StairFlight::new(name)
+            .number_of_risers(riser_count as i64)
+            .number_of_treads((riser_count - 1) as i64)
+            .riser_height(riser_height)
+            .tread_length(tread_length)
+            .predefined_type(StairFlightTypeEnum::Straight)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .object_placement(local_placement, &mut self.project.ifc)
+            .representation(product_shape, &mut self.project.ifc);
+
+        self.project.ifc.data.insert_new(flight)
+    }
+
+    /// Square landing slab with the center of its bottom at `center`.
+    fn stair_landing(
+        &mut self,
+        name: &str,
+        center: DVec3,
+        width: f64,
+        thickness: f64,
+        stair_placement: TypedId<LocalPlacement>,
+    ) -> TypedId<Slab> {
+        let profile = self.project.ifc.data.insert_new(RectangleProfileDef::new(
+            ProfileType::Area,
+            width,
+            width,
+        ));
+        let product_shape = self.profile_extrusion_shape(profile.id(), thickness);
+
+        let position = Axis3D::new(Point3D::from(center), &mut self.project.ifc);
+        let local_placement = LocalPlacement::new(position, &mut self.project.ifc)
+            .relative_to(stair_placement, &mut self.project.ifc);
+
+        let landing = Slab::new(name)
+            .predefined_type(SlabTypeEnum::Landing)
+            .owner_history(self.owner_history, &mut self.project.ifc)
+            .object_placement(local_placement, &mut self.project.ifc)
+            .representation(product_shape, &mut self.project.ifc);
+
+        self.project.ifc.data.insert_new(landing)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::DVec3;
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    #[test]
+    fn builder_stairs() {
+        let mut builder = create_builder();
+
+        let (straight_stair, l_shaped_stair) = {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+            let straight_stair = storey_builder.stair(
+                "Straight stair",
+                StairParameter {
+                    position: DVec3::new(0.0, 0.0, 0.0),
+                    direction: DVec3::X,
+                    riser_count: 16,
+                    riser_height: 0.1875,
+                    tread_length: 0.25,
+                    width: 1.0,
+                    shape: StairShape::Straight,
+                },
+            );
+
+            let l_shaped_stair = storey_builder.stair(
+                "L-shaped stair",
+                StairParameter {
+                    position: DVec3::new(5.0, 0.0, 0.0),
+                    direction: DVec3::Y,
+                    riser_count: 16,
+                    riser_height: 0.1875,
+                    tread_length: 0.25,
+                    width: 1.0,
+                    shape: StairShape::LShaped {
+                        first_flight_risers: 10,
+                        turn: StairTurn::Left,
+                    },
+                },
+            );
+
+            (straight_stair, l_shaped_stair)
+        };
+
+        let s = builder.build();
+        let ifc = IfcExtractor::from(IFC::from_str(&s).unwrap());
+
+        assert_eq!(s, ifc.to_string());
+
+        let flights = ifc.relations_of::<Stair, StairFlight>(straight_stair);
+        assert_eq!(flights.len(), 1);
+        assert_eq!(flights[0].1.number_of_risers.custom().unwrap().0, 16);
+
+        // the side view is a simple polygon made of the steps, the back face
+        // and the underside
+        let body = flights[0].1.shapes(&ifc).into_iter().next().unwrap();
+        let Some(ShapeItemEnum::ExtrudedAreaSolid(solid)) = body.items(&ifc).next() else {
+            panic!("flight body is not an extruded area solid");
+        };
+        let side_view = profile_polygons(solid.swept_area, &ifc).unwrap();
+        let outline = &side_view[0].outer;
+        assert_eq!(outline.len(), 2 * 15 + 3);
+        assert!((side_view[0].area() - 0.25 * 0.1875 * (3.0 * 16.0 - 4.0) * 0.5).abs() < 1e-9);
+        assert!(outline
+            .iter()
+            .zip(outline.iter().cycle().skip(1))
+            .zip(outline.iter().cycle().skip(2))
+            .all(|((a, b), c)| (*b - *a).perp_dot(*c - *b).abs() > 1e-9
+                || (*b - *a).dot(*c - *b) > 0.0));
+
+        let flights = ifc.relations_of::<Stair, StairFlight>(l_shaped_stair);
+        let risers: Vec<i64> = flights
+            .iter()
+            .map(|(_, flight)| flight.number_of_risers.custom().unwrap().0)
+            .collect();
+        assert_eq!(risers, vec![10, 6]);
+        assert_eq!(ifc.relations_of::<Stair, Slab>(l_shaped_stair).len(), 1);
+
+        // the second flight starts on top of the landing
+        let (second_flight, _) = flights[1];
+        let start = ifc
+            .placement_transform(second_flight)
+            .transform_point3(DVec3::ZERO);
+        assert!(start.abs_diff_eq(DVec3::new(5.0 - 1.0, 9.0 * 0.25 + 1.0, 10.0 * 0.1875), 1e-9));
+
+        // stairs are contained in the storey, their parts are aggregated
+        let (storey, _) = ifc.data.find_all_of_type::<Storey>().next().unwrap();
+        let contained = ifc.contained_structures(storey);
+        assert!(contained.contains(&straight_stair.id()));
+        assert!(!contained.contains(&flights[0].0.id()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Stair flight needs at least two risers")]
+    fn builder_stair_without_second_flight() {
+        let mut builder = create_builder();
+        let mut site_builder = builder.new_site("test", DVec3::ZERO);
+        let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+        let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+        storey_builder.stair(
+            "L-shaped stair",
+            StairParameter {
+                position: DVec3::ZERO,
+                direction: DVec3::X,
+                riser_count: 16,
+                riser_height: 0.1875,
+                tread_length: 0.25,
+                width: 1.0,
+                shape: StairShape::LShaped {
+                    first_flight_risers: 16,
+                    turn: StairTurn::Right,
+                },
+            },
+        );
+    }
+}
@@ -2,6 +2,8 @@ use std::collections::{HashMap, HashSet};
 
 use crate::prelude::*;
 
+use super::quantities::WallDimensions;
+
 pub struct IfcStoreyBuilder<'a> {
     pub(crate) project: &'a mut IfcProjectBuilder,
 
@@ -10,12 +12,30 @@ pub struct IfcStoreyBuilder<'a> {
 
     pub(crate) storey: TypedId<Storey>,
 
+    /// Whether base quantities are computed for the created elements.
+    pub(crate) base_quantities: bool,
+    /// Whether first level space boundaries are computed for the spaces.
+    pub(crate) space_boundaries: bool,
+    /// Whether connections between the walls are detected.
+    pub(crate) wall_connections: bool,
+
     pub(crate) spaces: HashSet<TypedId<Space>>,
     pub(crate) walls: HashSet<TypedId<Wall>>,
     pub(crate) slabs: HashSet<TypedId<Slab>>,
     pub(crate) roofs: HashSet<TypedId<Roof>>,
     pub(crate) opening_elements: HashSet<TypedId<OpeningElement>>,
     pub(crate) windows: HashSet<TypedId<Window>>,
+    pub(crate) doors: HashSet<TypedId<Door>>,
+    pub(crate) beams: HashSet<TypedId<Beam>>,
+    pub(crate) columns: HashSet<TypedId<Column>>,
+    pub(crate) members: HashSet<TypedId<Member>>,
+    pub(crate) grids: HashSet<TypedId<Grid>>,
+    pub(crate) pipe_segments: HashSet<TypedId<PipeSegment>>,
+    pub(crate) duct_segments: HashSet<TypedId<DuctSegment>>,
+    pub(crate) stairs: HashSet<TypedId<Stair>>,
+    pub(crate) curtain_walls: HashSet<TypedId<CurtainWall>>,
+    pub(crate) building_element_proxies: HashSet<TypedId<BuildingElementProxy>>,
+    pub(crate) furniture: HashSet<TypedId<Furniture>>,
 
     // Wall relations
     pub(crate) wall_type_to_wall: HashMap<TypedId<WallType>, HashSet<TypedId<Wall>>>,
@@ -29,12 +49,49 @@ pub struct IfcStoreyBuilder<'a> {
     // Opening element relations
     pub(crate) opening_elements_to_wall: HashMap<TypedId<OpeningElement>, TypedId<Wall>>,
     pub(crate) opening_elements_to_window: HashMap<TypedId<OpeningElement>, TypedId<Window>>,
+    pub(crate) opening_elements_to_door: HashMap<TypedId<OpeningElement>, TypedId<Door>>,
 
     // Window relations
     pub(crate) window_type_to_window: HashMap<TypedId<WindowType>, HashSet<TypedId<Window>>>,
 
+    // Door relations
+    pub(crate) door_type_to_door: HashMap<TypedId<DoorType>, HashSet<TypedId<Door>>>,
+
+    // Beam relations
+    pub(crate) beam_type_to_beam: HashMap<TypedId<BeamType>, HashSet<TypedId<Beam>>>,
+
+    // Column relations
+    pub(crate) column_type_to_column: HashMap<TypedId<ColumnType>, HashSet<TypedId<Column>>>,
+
+    // Member relations
+    pub(crate) member_type_to_member: HashMap<TypedId<MemberType>, HashSet<TypedId<Member>>>,
+
+    // Pipe segment relations
+    pub(crate) pipe_segment_type_to_pipe_segment:
+        HashMap<TypedId<PipeSegmentType>, HashSet<TypedId<PipeSegment>>>,
+
+    // Duct segment relations
+    pub(crate) duct_segment_type_to_duct_segment:
+        HashMap<TypedId<DuctSegmentType>, HashSet<TypedId<DuctSegment>>>,
+
+    // Building element proxy relations
+    pub(crate) building_element_proxy_type_to_building_element_proxy:
+        HashMap<TypedId<BuildingElementProxyType>, HashSet<TypedId<BuildingElementProxy>>>,
+
+    // Furniture relations
+    pub(crate) furniture_type_to_furniture:
+        HashMap<TypedId<FurnitureType>, HashSet<TypedId<Furniture>>>,
+
     // Space relations
     pub(crate) space_type_to_space: HashMap<TypedId<SpaceType>, HashSet<TypedId<Space>>>,
+
+    // Property set relations
+    pub(crate) property_set_to_objects: HashMap<TypedId<PropertySet>, RelDefinesByProperties>,
+
+    // Base quantities
+    pub(crate) wall_dimensions: HashMap<TypedId<Wall>, WallDimensions>,
+    pub(crate) opening_element_areas: HashMap<TypedId<OpeningElement>, f64>,
+    pub(crate) element_quantity_to_object: HashMap<TypedId<ElementQuantity>, Id>,
 }
 
 impl<'a> IfcStoreyBuilder<'a> {
@@ -43,12 +100,7 @@ impl<'a> IfcStoreyBuilder<'a> {
         storey: TypedId<Storey>,
         owner_history: TypedId<OwnerHistory>,
     ) -> Self {
-        let sub_context = project
-            .ifc
-            .data
-            .id_of::<GeometricRepresentationSubContext>()
-            .last()
-            .unwrap();
+        let sub_context = project.body_sub_context;
 
         Self {
             project,
@@ -56,12 +108,27 @@ impl<'a> IfcStoreyBuilder<'a> {
             owner_history,
             sub_context,
 
+            base_quantities: false,
+            space_boundaries: false,
+            wall_connections: false,
+
             spaces: HashSet::new(),
             walls: HashSet::new(),
             slabs: HashSet::new(),
             roofs: HashSet::new(),
             opening_elements: HashSet::new(),
             windows: HashSet::new(),
+            doors: HashSet::new(),
+            beams: HashSet::new(),
+            columns: HashSet::new(),
+            members: HashSet::new(),
+            grids: HashSet::new(),
+            pipe_segments: HashSet::new(),
+            duct_segments: HashSet::new(),
+            stairs: HashSet::new(),
+            curtain_walls: HashSet::new(),
+            building_element_proxies: HashSet::new(),
+            furniture: HashSet::new(),
 
             wall_type_to_wall: HashMap::new(),
 
@@ -71,10 +138,33 @@ impl<'a> IfcStoreyBuilder<'a> {
 
             opening_elements_to_wall: HashMap::new(),
             opening_elements_to_window: HashMap::new(),
+            opening_elements_to_door: HashMap::new(),
 
             window_type_to_window: HashMap::new(),
 
+            door_type_to_door: HashMap::new(),
+
+            beam_type_to_beam: HashMap::new(),
+
+            column_type_to_column: HashMap::new(),
+
+            member_type_to_member: HashMap::new(),
+
+            pipe_segment_type_to_pipe_segment: HashMap::new(),
+
+            duct_segment_type_to_duct_segment: HashMap::new(),
+
+            building_element_proxy_type_to_building_element_proxy: HashMap::new(),
+
+            furniture_type_to_furniture: HashMap::new(),
+
             space_type_to_space: HashMap::new(),
+
+            property_set_to_objects: HashMap::new(),
+
+            wall_dimensions: HashMap::new(),
+            opening_element_areas: HashMap::new(),
+            element_quantity_to_object: HashMap::new(),
         }
     }
 }
@@ -225,8 +315,305 @@ impl<'a> Drop for IfcStoreyBuilder<'a> {
             spatial_relation = spatial_relation.relate_structure(*window, &mut self.project.ifc);
         }
 
+        // doors ----------------------
+
+        // relate door type to door
+        for (index, (door_type, doors)) in self.door_type_to_door.iter().enumerate() {
+            let mut door_door_type_relation = RelDefinesByType::new(
+                format!("DoorTypeToDoor{index}"),
+                *door_type,
+                &mut self.project.ifc,
+            )
+            .owner_history(self.owner_history, &mut self.project.ifc);
+
+            for door in doors {
+                door_door_type_relation =
+                    door_door_type_relation.relate_obj(*door, &mut self.project.ifc)
+            }
+
+            self.project.ifc.data.insert_new(door_door_type_relation);
+        }
+
+        // relate opening elements to doors
+        for (index, (opening_element, door)) in self.opening_elements_to_door.iter().enumerate() {
+            let opening_element_door_relation = RelFillsElement::new(
+                format!("OpeningElementToDoor{index}"),
+                *opening_element,
+                *door,
+                &mut self.project.ifc,
+            )
+            .owner_history(self.owner_history, &mut self.project.ifc);
+
+            self.project
+                .ifc
+                .data
+                .insert_new(opening_element_door_relation);
+        }
+
+        // relate storey to doors
+        for door in self.doors.iter() {
+            spatial_relation = spatial_relation.relate_structure(*door, &mut self.project.ifc);
+        }
+
+        // beams ----------------------
+
+        // relate beam type to beam
+        for (index, (beam_type, beams)) in self.beam_type_to_beam.iter().enumerate() {
+            let mut beam_beam_type_relation = RelDefinesByType::new(
+                format!("BeamTypeToBeam{index}"),
+                *beam_type,
+                &mut self.project.ifc,
+            )
+            .owner_history(self.owner_history, &mut self.project.ifc);
+
+            for beam in beams {
+                beam_beam_type_relation =
+                    beam_beam_type_relation.relate_obj(*beam, &mut self.project.ifc)
+            }
+
+            self.project.ifc.data.insert_new(beam_beam_type_relation);
+        }
+
+        // relate storey to beams
+        for beam in self.beams.iter() {
+            spatial_relation = spatial_relation.relate_structure(*beam, &mut self.project.ifc);
+        }
+
+        // columns ----------------------
+
+        // relate column type to column
+        for (index, (column_type, columns)) in self.column_type_to_column.iter().enumerate() {
+            let mut column_column_type_relation = RelDefinesByType::new(
+                format!("ColumnTypeToColumn{index}"),
+                *column_type,
+                &mut self.project.ifc,
+            )
+            .owner_history(self.owner_history, &mut self.project.ifc);
+
+            for column in columns {
+                column_column_type_relation =
+                    column_column_type_relation.relate_obj(*column, &mut self.project.ifc)
+            }
+
+            self.project
+                .ifc
+                .data
+                .insert_new(column_column_type_relation);
+        }
+
+        // relate storey to columns
+        for column in self.columns.iter() {
+            spatial_relation = spatial_relation.relate_structure(*column, &mut self.project.ifc);
+        }
+
+        // members ----------------------
+
+        // relate member type to member
+        for (index, (member_type, members)) in self.member_type_to_member.iter().enumerate() {
+            let mut member_member_type_relation = RelDefinesByType::new(
+                format!("MemberTypeToMember{index}"),
+                *member_type,
+                &mut self.project.ifc,
+            )
+            .owner_history(self.owner_history, &mut self.project.ifc);
+
+            for member in members {
+                member_member_type_relation =
+                    member_member_type_relation.relate_obj(*member, &mut self.project.ifc)
+            }
+
+            self.project
+                .ifc
+                .data
+                .insert_new(member_member_type_relation);
+        }
+
+        // relate storey to members
+        for member in self.members.iter() {
+            spatial_relation = spatial_relation.relate_structure(*member, &mut self.project.ifc);
+        }
+
+        // pipe segments ----------------------
+
+        // relate pipe segment type to pipe segment
+        for (index, (pipe_segment_type, pipe_segments)) in
+            self.pipe_segment_type_to_pipe_segment.iter().enumerate()
+        {
+            let mut pipe_segment_pipe_segment_type_relation = RelDefinesByType::new(
+                format!("PipeSegmentTypeToPipeSegment{index}"),
+                *pipe_segment_type,
+                &mut self.project.ifc,
+            )
+            .owner_history(self.owner_history, &mut self.project.ifc);
+
+            for pipe_segment in pipe_segments {
+                pipe_segment_pipe_segment_type_relation = pipe_segment_pipe_segment_type_relation
+                    .relate_obj(*pipe_segment, &mut self.project.ifc)
+            }
+
+            self.project
+                .ifc
+                .data
+                .insert_new(pipe_segment_pipe_segment_type_relation);
+        }
+
+        // relate storey to pipe segments
+        for pipe_segment in self.pipe_segments.iter() {
+            spatial_relation =
+                spatial_relation.relate_structure(*pipe_segment, &mut self.project.ifc);
+        }
+
+        // duct segments ----------------------
+
+        // relate duct segment type to duct segment
+        for (index, (duct_segment_type, duct_segments)) in
+            self.duct_segment_type_to_duct_segment.iter().enumerate()
+        {
+            let mut duct_segment_duct_segment_type_relation = RelDefinesByType::new(
+                format!("DuctSegmentTypeToDuctSegment{index}"),
+                *duct_segment_type,
+                &mut self.project.ifc,
+            )
+            .owner_history(self.owner_history, &mut self.project.ifc);
+
+            for duct_segment in duct_segments {
+                duct_segment_duct_segment_type_relation = duct_segment_duct_segment_type_relation
+                    .relate_obj(*duct_segment, &mut self.project.ifc)
+            }
+
+            self.project
+                .ifc
+                .data
+                .insert_new(duct_segment_duct_segment_type_relation);
+        }
+
+        // relate storey to duct segments
+        for duct_segment in self.duct_segments.iter() {
+            spatial_relation =
+                spatial_relation.relate_structure(*duct_segment, &mut self.project.ifc);
+        }
+
+        // building element proxies ----------------------
+
+        // relate building element proxy type to building element proxy
+        for (index, (proxy_type, proxies)) in self
+            .building_element_proxy_type_to_building_element_proxy
+            .iter()
+            .enumerate()
+        {
+            let mut proxy_proxy_type_relation = RelDefinesByType::new(
+                format!("BuildingElementProxyTypeToBuildingElementProxy{index}"),
+                *proxy_type,
+                &mut self.project.ifc,
+            )
+            .owner_history(self.owner_history, &mut self.project.ifc);
+
+            for proxy in proxies {
+                proxy_proxy_type_relation =
+                    proxy_proxy_type_relation.relate_obj(*proxy, &mut self.project.ifc)
+            }
+
+            self.project.ifc.data.insert_new(proxy_proxy_type_relation);
+        }
+
+        // relate storey to building element proxies
+        for proxy in self.building_element_proxies.iter() {
+            spatial_relation = spatial_relation.relate_structure(*proxy, &mut self.project.ifc);
+        }
+
+        // furniture ----------------------
+
+        // relate furniture type to furniture
+        for (index, (furniture_type, furniture)) in
+            self.furniture_type_to_furniture.iter().enumerate()
+        {
+            let mut furniture_furniture_type_relation = RelDefinesByType::new(
+                format!("FurnitureTypeToFurniture{index}"),
+                *furniture_type,
+                &mut self.project.ifc,
+            )
+            .owner_history(self.owner_history, &mut self.project.ifc);
+
+            for piece in furniture {
+                furniture_furniture_type_relation =
+                    furniture_furniture_type_relation.relate_obj(*piece, &mut self.project.ifc)
+            }
+
+            self.project
+                .ifc
+                .data
+                .insert_new(furniture_furniture_type_relation);
+        }
+
+        // relate storey to furniture
+        for piece in self.furniture.iter() {
+            spatial_relation = spatial_relation.relate_structure(*piece, &mut self.project.ifc);
+        }
+
+        // stairs ----------------------
+
+        // relate storey to stairs, their flights and landings are aggregated
+        for stair in self.stairs.iter() {
+            spatial_relation = spatial_relation.relate_structure(*stair, &mut self.project.ifc);
+        }
+
+        // curtain walls ----------------------
+
+        // relate storey to curtain walls, their panels and mullions are aggregated
+        for curtain_wall in self.curtain_walls.iter() {
+            spatial_relation =
+                spatial_relation.relate_structure(*curtain_wall, &mut self.project.ifc);
+        }
+
+        // grids ----------------------
+
+        // relate storey to grids
+        for grid in self.grids.iter() {
+            spatial_relation = spatial_relation.relate_structure(*grid, &mut self.project.ifc);
+        }
+
         self.project.ifc.data.insert_new(spatial_relation);
 
+        // space boundaries ----------------------
+
+        if self.space_boundaries {
+            self.first_level_space_boundaries();
+        }
+
+        // wall connections ----------------------
+
+        if self.wall_connections {
+            self.detect_wall_connections();
+        }
+
+        // base quantities ----------------------
+
+        // walls get their quantities last, as their net areas depend on the openings
+        self.wall_base_quantities();
+
+        // relate element quantities to objects
+        for (index, (element_quantity, object)) in
+            self.element_quantity_to_object.iter().enumerate()
+        {
+            let mut element_quantity_relation = RelDefinesByProperties::new(
+                format!("ElementQuantityToObject{index}"),
+                *element_quantity,
+                &mut self.project.ifc,
+            )
+            .owner_history(self.owner_history, &mut self.project.ifc);
+
+            element_quantity_relation.related_objects.0.push(*object);
+
+            self.project.ifc.data.insert_new(element_quantity_relation);
+        }
+
+        // property sets ----------------------
+
+        // relate property sets to objects
+        for (_, property_set_relation) in self.property_set_to_objects.drain() {
+            self.project.ifc.data.insert_new(property_set_relation);
+        }
+
         // rel aggregates
         let rel_agg = RelAggregates::new(
             "StoreySpacesLink",
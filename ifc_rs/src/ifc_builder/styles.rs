@@ -0,0 +1,162 @@
+use crate::prelude::*;
+
+impl<'a> IfcStoreyBuilder<'a> {
+    /// Assigns a colour to a material. Viewers use it for all elements associated with the
+    /// material which don't have a colour of their own.
+    pub fn material_colour(&mut self, material: TypedId<Material>, colour: SurfaceColour) {
+        let surface_style = self.surface_style(colour);
+
+        let styled_representation =
+            StyledRepresentation::new(self.project.sub_context, &mut self.project.ifc).add_item(
+                StyledItem::new().add_style(surface_style, &mut self.project.ifc),
+                &mut self.project.ifc,
+            );
+
+        let material_definition =
+            MaterialDefinitionRepresentation::new(material, &mut self.project.ifc)
+                .add_representation(styled_representation, &mut self.project.ifc);
+
+        self.project.ifc.data.insert_new(material_definition);
+    }
+
+    /// Assigns a colour to all representation items of the shape of an element, overriding
+    /// the colour of its material.
+    pub fn shape_colour<T: TransformableType>(
+        &mut self,
+        element: TypedId<T>,
+        colour: SurfaceColour,
+    ) {
+        let Some(shape) = self.project.ifc.data.get(element).shape() else {
+            return;
+        };
+
+        let surface_style = self.surface_style(colour);
+
+        let items: Vec<Id> = self
+            .project
+            .ifc
+            .data
+            .get(shape)
+            .representations
+            .iter()
+            .flat_map(|representation| self.project.ifc.data.get(*representation).items.0.clone())
+            .collect();
+
+        for item in items {
+            let styled_item = StyledItem::new()
+                .item(item)
+                .add_style(surface_style, &mut self.project.ifc);
+
+            self.project.ifc.data.insert_new(styled_item);
+        }
+    }
+
+    fn surface_style(&mut self, colour: SurfaceColour) -> TypedId<SurfaceStyle> {
+        let rendering = SurfaceStyleRendering::new(
+            ColourRgb::new(colour.red, colour.green, colour.blue),
+            &mut self.project.ifc,
+        )
+        .transparency(colour.transparency);
+
+        let surface_style =
+            SurfaceStyle::new(SurfaceSide::Both).add_style(rendering, &mut self.project.ifc);
+
+        self.project.ifc.data.insert_new(surface_style)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::DVec3;
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    #[test]
+    fn builder_colours() {
+        let mut builder = create_builder();
+
+        let (wall, glass_wall) = {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+            let material = storey_builder.material("Concrete");
+            storey_builder.material_colour(material, SurfaceColour::new(0.5, 0.5, 0.5));
+
+            let material_layer =
+                MaterialLayer::new(0.2, false).material(material, &mut storey_builder.project.ifc);
+            let material_layer = storey_builder.project.ifc.data.insert_new(material_layer);
+            let material_layer_set = storey_builder.material_layer_set([material_layer]);
+            let material_layer_set_usage = storey_builder.material_layer_set_usage(
+                material_layer_set,
+                LayerSetDirectionEnum::Axis2,
+                DirectionSenseEnum::Positive,
+                0.0,
+            );
+
+            let wall_type = storey_builder.wall_type(
+                material_layer_set,
+                "ExampleWallType",
+                WallTypeEnum::NotDefined,
+            );
+
+            let wall = storey_builder.vertical_wall(
+                material_layer_set_usage,
+                wall_type,
+                "ExampleWall",
+                VerticalWallParameter {
+                    height: 2.0,
+                    length: 4.0,
+                    placement: DVec3::ZERO,
+                },
+            );
+            let glass_wall = storey_builder.vertical_wall(
+                material_layer_set_usage,
+                wall_type,
+                "ExampleGlassWall",
+                VerticalWallParameter {
+                    height: 2.0,
+                    length: 1.0,
+                    placement: DVec3::new(5.0, 0.0, 0.0),
+                },
+            );
+            storey_builder.shape_colour(
+                glass_wall,
+                SurfaceColour::new(0.0, 0.0, 1.0).transparency(0.5),
+            );
+
+            (wall, glass_wall)
+        };
+
+        let s = builder.build();
+        let ifc = IfcExtractor::from(IFC::from_str(&s).unwrap());
+
+        assert_eq!(s, ifc.to_string());
+
+        let items_of = |id: TypedId<Wall>| -> Vec<Id> {
+            ifc.data
+                .get(id)
+                .shapes(&ifc)
+                .iter()
+                .flat_map(|shape| shape.items.0.clone())
+                .collect()
+        };
+
+        for item in items_of(wall) {
+            assert_eq!(
+                ifc.item_colour(item),
+                Some(SurfaceColour::new(0.5, 0.5, 0.5))
+            );
+        }
+        for item in items_of(glass_wall) {
+            assert_eq!(
+                ifc.item_colour(item),
+                Some(SurfaceColour::new(0.0, 0.0, 1.0).transparency(0.5))
+            );
+        }
+    }
+}
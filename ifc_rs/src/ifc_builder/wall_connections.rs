@@ -0,0 +1,285 @@
+use glam::{DAffine3, DVec2, DVec3};
+
+use crate::prelude::*;
+
+use super::space_boundaries::CONTACT_TOLERANCE;
+
+/// Center line of the body of a wall, given in the coordinate system of the
+/// storey. The axis runs along the local x axis of the wall.
+struct WallAxis {
+    wall: TypedId<Wall>,
+    start: DVec2,
+    end: DVec2,
+    thickness: f64,
+    bottom: f64,
+    top: f64,
+}
+
+impl<'a> IfcStoreyBuilder<'a> {
+    /// Enables the detection of connections between the walls of the
+    /// storey. Walls whose axes meet at their ends or at the path of another
+    /// wall are connected by `IfcRelConnectsPathElements` when the storey is
+    /// finished, walls already connected with `connect_walls` are skipped.
+    pub fn compute_wall_connections(&mut self, compute: bool) {
+        self.wall_connections = compute;
+    }
+
+    /// Connects two walls explicitly, e.g. to connect walls the detection
+    /// can't handle.
+    pub fn connect_walls(
+        &mut self,
+        relating_wall: TypedId<Wall>,
+        relating_connection_type: ConnectionTypeEnum,
+        related_wall: TypedId<Wall>,
+        related_connection_type: ConnectionTypeEnum,
+    ) -> TypedId<RelConnectsPathElements> {
+        let connection = RelConnectsPathElements::new(
+            "WallToWall",
+            relating_wall,
+            relating_connection_type,
+            related_wall,
+            related_connection_type,
+            &mut self.project.ifc,
+        )
+        .owner_history(self.owner_history, &mut self.project.ifc);
+
+        self.project.ifc.data.insert_new(connection)
+    }
+
+    pub(super) fn detect_wall_connections(&mut self) {
+        let mut walls: Vec<_> = self.walls.iter().copied().collect();
+        walls.sort_by_key(|wall| wall.id());
+
+        let axes: Vec<_> = walls
+            .into_iter()
+            .filter_map(|wall| self.wall_axis(wall))
+            .collect();
+
+        let connections: Vec<_> = axes
+            .iter()
+            .enumerate()
+            .flat_map(|(index, relating)| {
+                axes[index + 1..].iter().filter_map(move |related| {
+                    wall_connection(relating, related)
+                        .map(|types| (relating.wall, related.wall, types))
+                })
+            })
+            .collect();
+
+        for (relating, related, (relating_type, related_type)) in connections {
+            let connected = self
+                .project
+                .ifc
+                .data
+                .find_all_of_type::<RelConnectsPathElements>()
+                .any(|(_, connection)| connection.other(relating) == Some(related.id()));
+
+            if !connected {
+                self.connect_walls(relating, relating_type, related, related_type);
+            }
+        }
+    }
+
+    /// Axis of a wall whose body is a vertically extruded area solid.
+    fn wall_axis(&self, wall: TypedId<Wall>) -> Option<WallAxis> {
+        let representation = self.project.ifc.data.get(wall).representation.custom();
+        let local = self.prism(DAffine3::IDENTITY, representation.copied())?;
+
+        let wall_to_storey = self.project.ifc.placement_transform(self.storey).inverse()
+            * self.project.ifc.placement_transform(wall);
+        let body = self.prism(wall_to_storey, representation.copied())?;
+
+        let min = local
+            .footprint
+            .iter()
+            .fold(DVec2::INFINITY, |min, point| min.min(*point));
+        let max = local
+            .footprint
+            .iter()
+            .fold(DVec2::NEG_INFINITY, |max, point| max.max(*point));
+        let center = (min.y + max.y) * 0.5;
+
+        let to_storey = |x: f64| {
+            wall_to_storey
+                .transform_point3(DVec3::new(x, center, 0.0))
+                .truncate()
+        };
+
+        Some(WallAxis {
+            wall,
+            start: to_storey(min.x),
+            end: to_storey(max.x),
+            thickness: max.y - min.y,
+            bottom: body.bottom,
+            top: body.top,
+        })
+    }
+}
+
+/// Connection types of two walls at the location their axes meet, `None` if
+/// they don't meet. Axes meet at the start or end of a wall if they reach
+/// its end within half the thickness of the other wall.
+fn wall_connection(
+    relating: &WallAxis,
+    related: &WallAxis,
+) -> Option<(ConnectionTypeEnum, ConnectionTypeEnum)> {
+    if relating.top.min(related.top) - relating.bottom.max(related.bottom) <= CONTACT_TOLERANCE {
+        return None;
+    }
+
+    let relating_direction = (relating.end - relating.start).normalize_or_zero();
+    let related_direction = (related.end - related.start).normalize_or_zero();
+    if relating_direction == DVec2::ZERO || related_direction == DVec2::ZERO {
+        return None;
+    }
+
+    let cross = relating_direction.perp_dot(related_direction);
+    let offset = related.start - relating.start;
+
+    if cross.abs() <= CONTACT_TOLERANCE {
+        // parallel walls only connect if they continue each other
+        if relating_direction.perp_dot(offset).abs() > CONTACT_TOLERANCE {
+            return None;
+        }
+
+        let ends = |axis: &WallAxis| {
+            [
+                (axis.start, ConnectionTypeEnum::AtStart),
+                (axis.end, ConnectionTypeEnum::AtEnd),
+            ]
+        };
+
+        return ends(relating)
+            .into_iter()
+            .find_map(|(relating_end, relating_type)| {
+                ends(related)
+                    .into_iter()
+                    .find(|(related_end, _)| {
+                        relating_end.distance(*related_end) <= CONTACT_TOLERANCE
+                    })
+                    .map(|(_, related_type)| (relating_type, related_type))
+            });
+    }
+
+    let relating_position = offset.perp_dot(related_direction) / cross;
+    let related_position = offset.perp_dot(relating_direction) / cross;
+
+    Some((
+        path_connection_type(
+            relating_position,
+            relating.start.distance(relating.end),
+            related.thickness * 0.5,
+        )?,
+        path_connection_type(
+            related_position,
+            related.start.distance(related.end),
+            relating.thickness * 0.5,
+        )?,
+    ))
+}
+
+/// Connection type of a position along the axis of a wall, `None` if it lies
+/// beyond the wall.
+fn path_connection_type(position: f64, length: f64, reach: f64) -> Option<ConnectionTypeEnum> {
+    let reach = reach + CONTACT_TOLERANCE;
+
+    if position.abs() <= reach {
+        Some(ConnectionTypeEnum::AtStart)
+    } else if (position - length).abs() <= reach {
+        Some(ConnectionTypeEnum::AtEnd)
+    } else if position > 0.0 && position < length {
+        Some(ConnectionTypeEnum::AtPath)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use glam::DVec3;
+
+    use crate::prelude::*;
+
+    use super::super::test::create_builder;
+
+    #[test]
+    fn builder_wall_connections() {
+        let mut builder = create_builder();
+
+        let walls = {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+            storey_builder.compute_wall_connections(true);
+
+            let material_layer = storey_builder.material_layer("ExampleMaterial", 0.2, false);
+            let material_layer_set = storey_builder.material_layer_set([material_layer]);
+            let material_layer_set_usage = storey_builder.material_layer_set_usage(
+                material_layer_set,
+                LayerSetDirectionEnum::Axis2,
+                DirectionSenseEnum::Positive,
+                0.0,
+            );
+
+            let wall_type = storey_builder.wall_type(
+                material_layer_set,
+                "ExampleWallType",
+                WallTypeEnum::NotDefined,
+            );
+
+            // an L shaped corner, a wall ending at the side of the first one
+            // and a separate wall
+            [
+                (DVec3::new(0.0, 0.0, 0.0), DVec3::new(8.0, 0.0, 0.0)),
+                (DVec3::new(8.0, 0.0, 0.0), DVec3::new(8.0, 5.0, 0.0)),
+                (DVec3::new(4.0, 0.2, 0.0), DVec3::new(4.0, 5.0, 0.0)),
+                (DVec3::new(0.0, 10.0, 0.0), DVec3::new(8.0, 10.0, 0.0)),
+            ]
+            .map(|(start, end)| {
+                storey_builder.vertical_wall_along_axis(
+                    material_layer_set_usage,
+                    wall_type,
+                    "ExampleWall",
+                    WallAxisParameter {
+                        height: 2.5,
+                        start,
+                        end,
+                    },
+                )
+            })
+        };
+
+        let s = builder.build();
+        let ifc = IFC::from_str(&s).unwrap();
+
+        assert_eq!(s, ifc.to_string());
+
+        let ifc = IfcExtractor::from(ifc);
+
+        let mut connected = ifc.connected_walls(walls[0]);
+        connected.sort_by_key(|(wall, _, _)| wall.id());
+        assert_eq!(
+            connected,
+            vec![
+                (
+                    walls[1],
+                    ConnectionTypeEnum::AtEnd,
+                    ConnectionTypeEnum::AtStart
+                ),
+                (
+                    walls[2],
+                    ConnectionTypeEnum::AtPath,
+                    ConnectionTypeEnum::AtStart
+                ),
+            ]
+        );
+
+        assert!(ifc.connected_walls(walls[3]).is_empty());
+        assert_eq!(
+            ifc.wall_networks(),
+            vec![vec![walls[0], walls[1], walls[2]], vec![walls[3]]]
+        );
+    }
+}
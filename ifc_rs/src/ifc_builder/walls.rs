@@ -1,6 +1,8 @@
-use glam::{DVec2, DVec3};
+use glam::{DAffine3, DVec2, DVec3};
 
-use crate::prelude::*;
+use crate::{parser::label::Label, prelude::*};
+
+use super::quantities::WallDimensions;
 
 pub struct VerticalWallParameter {
     pub height: f64,
@@ -8,6 +10,22 @@ pub struct VerticalWallParameter {
     pub placement: DVec3,
 }
 
+pub struct WallAxisParameter {
+    pub height: f64,
+    /// Local to the storey
+    pub start: DVec3,
+    /// Local to the storey, only the horizontal direction towards the end
+    /// is taken into account
+    pub end: DVec3,
+}
+
+pub struct ClippingPlaneParameter {
+    /// a point on the clipping plane, relative to the storey
+    pub location: DVec3,
+    /// normal of the clipping plane, pointing to the part of the wall which is removed
+    pub normal: DVec3,
+}
+
 impl<'a> IfcStoreyBuilder<'a> {
     pub fn vertical_wall(
         &mut self,
@@ -20,6 +38,48 @@ impl<'a> IfcStoreyBuilder<'a> {
             Point3D::from(wall_information.placement),
             &mut self.project.ifc,
         );
+
+        self.vertical_wall_at(material, wall_type, name, position, wall_information)
+    }
+
+    /// Creates a vertical wall whose axis runs from `start` to `end`, the
+    /// wall thickness extends to the left of the axis.
+    pub fn vertical_wall_along_axis(
+        &mut self,
+        material: TypedId<MaterialLayerSetUsage>,
+        wall_type: TypedId<WallType>,
+        name: &str,
+        wall_information: WallAxisParameter,
+    ) -> TypedId<Wall> {
+        let direction = wall_information.end - wall_information.start;
+
+        let position = Axis3D::new(Point3D::from(wall_information.start), &mut self.project.ifc)
+            .local_x(
+                Direction3D::from(DVec3::new(direction.x, direction.y, 0.0)),
+                &mut self.project.ifc,
+            );
+
+        self.vertical_wall_at(
+            material,
+            wall_type,
+            name,
+            position,
+            VerticalWallParameter {
+                height: wall_information.height,
+                length: direction.truncate().length(),
+                placement: wall_information.start,
+            },
+        )
+    }
+
+    fn vertical_wall_at(
+        &mut self,
+        material: TypedId<MaterialLayerSetUsage>,
+        wall_type: TypedId<WallType>,
+        name: &str,
+        position: Axis3D,
+        wall_information: VerticalWallParameter,
+    ) -> TypedId<Wall> {
         let wall_thickness = self.calculate_material_layer_set_thickness(material);
 
         let shape_repr = ShapeRepresentation::new(self.sub_context, &mut self.project.ifc)
@@ -59,7 +119,159 @@ impl<'a> IfcStoreyBuilder<'a> {
             .object_placement(local_placement, &mut self.project.ifc)
             .representation(product_shape, &mut self.project.ifc);
 
-        self.wall(material, wall_type, wall)
+        let wall_id = self.wall(material, wall_type, wall);
+
+        if self.base_quantities {
+            self.wall_dimensions.insert(
+                wall_id,
+                WallDimensions {
+                    length: wall_information.length,
+                    height: wall_information.height,
+                    thickness: wall_thickness,
+                },
+            );
+        }
+
+        wall_id
+    }
+
+    /// Clips the body of the wall by a plane, removing the part the plane normal points
+    /// to. Applying two sloped planes one after another results in a gable wall.
+    pub fn clip_wall_by_plane(&mut self, wall: TypedId<Wall>, plane: ClippingPlaneParameter) {
+        let storey_to_wall = self.project.ifc.placement_transform(wall).inverse()
+            * self.project.ifc.placement_transform(self.storey);
+
+        let position = Axis3D::new(
+            Point3D::from(storey_to_wall.transform_point3(plane.location)),
+            &mut self.project.ifc,
+        )
+        .local_z(
+            Direction3D::from(storey_to_wall.transform_vector3(plane.normal)),
+            &mut self.project.ifc,
+        );
+        let half_space = HalfSpaceSolid::new(
+            Plane::new(position, &mut self.project.ifc),
+            false,
+            &mut self.project.ifc,
+        );
+
+        let half_space_id = self.project.ifc.data.insert_new(half_space);
+        self.clip_wall(wall, half_space_id.id());
+    }
+
+    /// Clips the body of the wall by the underside of the roof, removing the part of the wall
+    /// above the roof within the outline of the roof.
+    pub fn clip_wall_by_roof(&mut self, wall: TypedId<Wall>, roof: TypedId<Roof>) {
+        let ifc = &self.project.ifc;
+
+        let Some(roof_solid) = ifc
+            .data
+            .get(roof)
+            .shape()
+            .into_iter()
+            .flat_map(|shape| ifc.data.get(shape).representations.iter())
+            .flat_map(|representation| ifc.data.get(*representation).items.iter())
+            .find_map(|item| {
+                ifc.data
+                    .get_untyped(*item)
+                    .downcast_ref::<ExtrudedAreaSolid>()
+            })
+        else {
+            return;
+        };
+
+        let Some(outline) = profile_polygons(roof_solid.swept_area, ifc)
+            .and_then(|polygons| polygons.into_iter().next())
+            .map(|polygon| polygon.outer)
+        else {
+            return;
+        };
+
+        let solid_position = roof_solid
+            .position
+            .custom()
+            .map(|position| ifc.data.get(*position).transform(ifc))
+            .unwrap_or(DAffine3::IDENTITY);
+
+        // the underside of the roof is the profile plane, unless the roof is extruded downwards
+        let extruded_direction = ifc.data.get(roof_solid.extruded_direction).normalize();
+        let underside = match extruded_direction.z < 0.0 {
+            true => extruded_direction * roof_solid.depth.0,
+            false => DVec3::ZERO,
+        };
+
+        let roof_to_wall = ifc.placement_transform(wall).inverse()
+            * ifc.placement_transform(roof)
+            * solid_position
+            * DAffine3::from_translation(underside);
+
+        let position = Axis3D::new(
+            Point3D::from(roof_to_wall.translation),
+            &mut self.project.ifc,
+        )
+        .local_z(
+            Direction3D::from(roof_to_wall.matrix3.z_axis),
+            &mut self.project.ifc,
+        )
+        .local_x(
+            Direction3D::from(roof_to_wall.matrix3.x_axis),
+            &mut self.project.ifc,
+        );
+        let position_id = self.project.ifc.data.insert_new(position);
+
+        let base_surface = Plane::new(position_id, &mut self.project.ifc);
+        let boundary =
+            IndexedPolyCurve::new(PointList2D::new(outline.into_iter()), &mut self.project.ifc);
+
+        let half_space = PolygonalBoundedHalfSpace::new(
+            base_surface,
+            false,
+            position_id,
+            boundary,
+            &mut self.project.ifc,
+        );
+
+        let half_space_id = self.project.ifc.data.insert_new(half_space);
+        self.clip_wall(wall, half_space_id.id());
+    }
+
+    fn clip_wall(&mut self, wall: TypedId<Wall>, half_space: Id) {
+        let Some(shape) = self.project.ifc.data.get(wall).shape() else {
+            return;
+        };
+
+        for representation in self.project.ifc.data.get(shape).representations.0.clone() {
+            let items = self.project.ifc.data.get(representation).items.0.clone();
+
+            // mapped items are placed by their own transformation, clip before transforming
+            if items.iter().any(|item| {
+                self.project
+                    .ifc
+                    .data
+                    .get_untyped(*item)
+                    .downcast_ref::<MappedItem>()
+                    .is_some()
+            }) {
+                continue;
+            }
+
+            let clipped_items = items
+                .into_iter()
+                .map(|item| {
+                    let clipping = BooleanClippingResult {
+                        operator: BooleanOperator::Difference,
+                        first_operand: item,
+                        second_operand: half_space,
+                    };
+
+                    self.project.ifc.data.insert_new(clipping).id()
+                })
+                .collect();
+
+            let representation = self.project.ifc.data.get_mut(representation);
+            representation.items.0 = clipped_items;
+            representation.representation_type = Label::from("Clipping").into();
+        }
     }
 
     pub fn wall_type(
@@ -125,9 +337,9 @@ impl<'a> IfcStoreyBuilder<'a> {
 mod test {
     use std::str::FromStr;
 
-    use glam::DVec3;
+    use glam::{DVec2, DVec3};
 
-    use crate::prelude::*;
+    use crate::{parser::bool::IfcBool, prelude::*};
 
     use super::super::test::create_builder;
 
@@ -172,4 +384,172 @@ mod test {
 
         assert_eq!(s, ifc.to_string());
     }
+
+    #[test]
+    fn builder_gable_wall() {
+        let mut builder = create_builder();
+
+        {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+            let material_layer = storey_builder.material_layer("ExampleMaterial", 0.02, false);
+            let material_layer_set = storey_builder.material_layer_set([material_layer]);
+            let material_layer_set_usage = storey_builder.material_layer_set_usage(
+                material_layer_set,
+                LayerSetDirectionEnum::Axis2,
+                DirectionSenseEnum::Positive,
+                0.0,
+            );
+
+            let wall_type = storey_builder.wall_type(
+                material_layer_set,
+                "ExampleWallType",
+                WallTypeEnum::NotDefined,
+            );
+
+            let wall = storey_builder.vertical_wall(
+                material_layer_set_usage,
+                wall_type,
+                "ExampleGableWall",
+                VerticalWallParameter {
+                    height: 3.0,
+                    length: 4.0,
+                    placement: DVec3::new(1.0, 0.0, 0.0),
+                },
+            );
+
+            // gable with the ridge at the middle of the wall
+            storey_builder.clip_wall_by_plane(
+                wall,
+                ClippingPlaneParameter {
+                    location: DVec3::new(1.0, 0.0, 2.0),
+                    normal: DVec3::new(-1.0, 0.0, 2.0),
+                },
+            );
+            storey_builder.clip_wall_by_plane(
+                wall,
+                ClippingPlaneParameter {
+                    location: DVec3::new(5.0, 0.0, 2.0),
+                    normal: DVec3::new(1.0, 0.0, 2.0),
+                },
+            );
+        }
+
+        let s = builder.build();
+        let ifc = IFC::from_str(&s).unwrap();
+
+        assert_eq!(s, ifc.to_string());
+
+        let (_, shape_repr) = ifc
+            .data
+            .find_all_of_type::<ShapeRepresentation>()
+            .find(|(_, shape_repr)| {
+                shape_repr.representation_type.custom() == Some(&"Clipping".into())
+            })
+            .unwrap();
+        assert_eq!(shape_repr.items.0.len(), 1);
+
+        let outer = ifc
+            .data
+            .get_untyped(shape_repr.items.0[0])
+            .downcast_ref::<BooleanClippingResult>()
+            .unwrap();
+        assert!(ifc
+            .data
+            .get_untyped(outer.first_operand)
+            .downcast_ref::<BooleanClippingResult>()
+            .is_some());
+
+        // clipping planes are given in the coordinate system of the wall
+        let half_space = ifc
+            .data
+            .get_untyped(outer.second_operand)
+            .downcast_ref::<HalfSpaceSolid>()
+            .unwrap();
+        let plane = ifc
+            .data
+            .get_untyped(half_space.base_surface)
+            .downcast_ref::<Plane>()
+            .unwrap();
+        let position = ifc.data.get(plane.position).transform(&ifc);
+        assert_eq!(position.translation, DVec3::new(4.0, 0.0, 2.0));
+    }
+
+    #[test]
+    fn builder_wall_clipped_by_roof() {
+        let mut builder = create_builder();
+
+        {
+            let mut site_builder = builder.new_site("test", DVec3::ZERO);
+            let mut building_builder = site_builder.new_building("test", DVec3::ZERO);
+            let mut storey_builder = building_builder.new_storey("test", 0.0);
+
+            let material_layer = storey_builder.material_layer("ExampleMaterial", 0.02, false);
+            let material_layer_set = storey_builder.material_layer_set([material_layer]);
+            let material_layer_set_usage = storey_builder.material_layer_set_usage(
+                material_layer_set,
+                LayerSetDirectionEnum::Axis2,
+                DirectionSenseEnum::Positive,
+                0.0,
+            );
+
+            let wall_type = storey_builder.wall_type(
+                material_layer_set,
+                "ExampleWallType",
+                WallTypeEnum::NotDefined,
+            );
+            let roof_type = storey_builder.roof_type(
+                material_layer_set,
+                "ExampleRoofType",
+                RoofTypeEnum::FlatRoof,
+            );
+
+            let wall = storey_builder.vertical_wall(
+                material_layer_set_usage,
+                wall_type,
+                "ExampleWall",
+                VerticalWallParameter {
+                    height: 3.0,
+                    length: 4.0,
+                    placement: DVec3::new(0.0, 0.0, 0.0),
+                },
+            );
+            let roof = storey_builder.horizontal_arbitrary_roof(
+                material_layer_set_usage,
+                roof_type,
+                "ExampleRoof",
+                HorizontalArbitraryRoofParameter {
+                    coords: vec![
+                        DVec2::new(0.0, 0.0),
+                        DVec2::new(0.0, 4.0),
+                        DVec2::new(4.0, 4.0),
+                        DVec2::new(4.0, 0.0),
+                    ],
+                    placement: DVec3::new(0.0, 0.0, 2.5),
+                },
+            );
+
+            storey_builder.clip_wall_by_roof(wall, roof);
+        }
+
+        let s = builder.build();
+        let ifc = IFC::from_str(&s).unwrap();
+
+        assert_eq!(s, ifc.to_string());
+
+        let (_, half_space) = ifc
+            .data
+            .find_all_of_type::<PolygonalBoundedHalfSpace>()
+            .next()
+            .unwrap();
+        let position = ifc.data.get(half_space.position).transform(&ifc);
+        assert_eq!(position.translation, DVec3::new(0.0, 0.0, 2.5));
+        assert_eq!(half_space.agreement_flag, IfcBool::False);
+        assert_eq!(
+            ifc.data.find_all_of_type::<BooleanClippingResult>().count(),
+            1
+        );
+    }
 }
@@ -115,6 +115,15 @@ impl<'a> IfcStoreyBuilder<'a> {
             })
             .relate_push(window_id, &mut self.project.ifc);
 
+        if self.base_quantities {
+            self.filling_base_quantities(
+                window_id.id(),
+                "Qto_WindowBaseQuantities",
+                window_parameter.width,
+                window_parameter.height,
+            );
+        }
+
         window_id
     }
 
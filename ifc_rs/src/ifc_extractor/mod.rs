@@ -1,6 +1,8 @@
 pub mod prelude;
 
-use std::ops::Deref;
+use std::{collections::HashSet, ops::Deref};
+
+use glam::{DAffine3, DVec3};
 
 use crate::{ifc_type::IfcType, prelude::*};
 
@@ -24,7 +26,7 @@ impl IfcExtractor {
         self.ifc
             .data
             .find_all_of_type::<RelAggregates>()
-            .filter(|&(_, rel_aggregate)| (rel_aggregate.relating_object == id.id()))
+            .filter(|&(_, rel_aggregate)| rel_aggregate.relating_object == id.id())
             .flat_map(|(_, rel_aggregate)| {
                 rel_aggregate.related_objects.0.iter().filter_map(|id| {
                     self.ifc
@@ -44,7 +46,7 @@ impl IfcExtractor {
         self.ifc
             .data
             .find_all_of_type::<RelContainedInSpatialStructure>()
-            .filter(|&(_, rel_structure)| (rel_structure.relating_structure == id.id()))
+            .filter(|&(_, rel_structure)| rel_structure.relating_structure == id.id())
             .flat_map(|(_, rel_structure)| rel_structure.related_elements.0.clone())
             .collect()
     }
@@ -123,6 +125,527 @@ impl IfcExtractor {
             .map(|constituent_id| self.ifc.data.get(*constituent_id))
             .collect()
     }
+
+    /// Property sets of the given object, the ones assigned to the
+    /// occurrence first, followed by the ones of its type.
+    pub fn property_sets<T: IfcType>(
+        &self,
+        id: TypedId<T>,
+    ) -> Vec<(TypedId<PropertySet>, &PropertySet)> {
+        self.property_definitions(id)
+    }
+
+    /// Element quantities of the given object, the ones assigned to the
+    /// occurrence first, followed by the ones of its type.
+    pub fn element_quantities<T: IfcType>(
+        &self,
+        id: TypedId<T>,
+    ) -> Vec<(TypedId<ElementQuantity>, &ElementQuantity)> {
+        self.property_definitions(id)
+    }
+
+    /// Nominal value of the single value property `property_name` in the
+    /// property set `pset_name` of the given object. Values of the
+    /// occurrence take precedence over the ones of its type.
+    pub fn property_value<T: IfcType>(
+        &self,
+        id: TypedId<T>,
+        pset_name: &str,
+        property_name: &str,
+    ) -> Option<&IfcValue> {
+        self.property_sets(id)
+            .into_iter()
+            .filter(|(_, pset)| pset.name.custom().is_some_and(|name| name.0 == pset_name))
+            .find_map(|(_, pset)| pset.single_value(property_name, &self.ifc))
+    }
+
+    /// Value of the quantity `quantity_name` in the element quantity
+    /// `qto_name` (e.g. `Qto_WallBaseQuantities`) of the given object.
+    pub fn quantity_value<T: IfcType>(
+        &self,
+        id: TypedId<T>,
+        qto_name: &str,
+        quantity_name: &str,
+    ) -> Option<f64> {
+        self.element_quantities(id)
+            .into_iter()
+            .filter(|(_, qto)| qto.name.custom().is_some_and(|name| name.0 == qto_name))
+            .find_map(|(_, qto)| qto.quantity_value(quantity_name, &self.ifc))
+    }
+
+    /// Effective colour and transparency of a representation item. A style
+    /// assigned to the item itself takes precedence over the style of the
+    /// materials of the product the item belongs to.
+    pub fn item_colour(&self, item: Id) -> Option<SurfaceColour> {
+        self.ifc
+            .data
+            .find_all_of_type::<StyledItem>()
+            .filter(|(_, styled_item)| styled_item.item.custom() == Some(&item))
+            .find_map(|(_, styled_item)| styled_item.colour(&self.ifc))
+            .or_else(|| {
+                self.products_of_item(item)
+                    .find_map(|product| self.object_material_colour(product))
+            })
+    }
+
+    /// Colour and transparency of the first material associated with the
+    /// given object or its type which has a styled representation.
+    pub fn material_colour<T: IfcType>(&self, id: TypedId<T>) -> Option<SurfaceColour> {
+        self.object_material_colour(id.id())
+    }
+
+    /// Classification references assigned to the given object, the ones
+    /// of the occurrence first, followed by the ones of its type.
+    pub fn classification_references<T: IfcType>(
+        &self,
+        id: TypedId<T>,
+    ) -> Vec<(TypedId<ClassificationReference>, &ClassificationReference)> {
+        self.object_and_type_ids(id.id())
+            .flat_map(|object_id| {
+                self.ifc
+                    .data
+                    .find_all_of_type::<RelAssociatesClassification>()
+                    .filter(move |(_, rel_classification)| {
+                        rel_classification.is_related_to(object_id)
+                    })
+                    .map(|(_, rel_classification)| rel_classification.relating_classification)
+            })
+            .filter_map(|reference_id| {
+                self.ifc
+                    .data
+                    .get_untyped(reference_id)
+                    .downcast_ref::<ClassificationReference>()
+                    .map(|reference| (TypedId::new(reference_id), reference))
+            })
+            .collect()
+    }
+
+    /// Code of the given object in the classification system named
+    /// `classification_name` (e.g. `DIN 276`). Codes of the occurrence take
+    /// precedence over the ones of its type.
+    pub fn classification_code<T: IfcType>(
+        &self,
+        id: TypedId<T>,
+        classification_name: &str,
+    ) -> Option<&str> {
+        self.classification_references(id)
+            .into_iter()
+            .filter(|(_, reference)| {
+                reference
+                    .classification(&self.ifc)
+                    .is_some_and(|classification| classification.name.0 == classification_name)
+            })
+            .find_map(|(_, reference)| reference.identification.custom())
+            .map(|identification| identification.0.as_str())
+    }
+
+    /// Objects assigned to the given group, zone or system.
+    pub fn group_members<G: IfcType>(&self, group: TypedId<G>) -> Vec<Id> {
+        self.ifc
+            .data
+            .find_all_of_type::<RelAssignsToGroup>()
+            .filter(|(_, rel_group)| rel_group.relating_group == group.id())
+            .flat_map(|(_, rel_group)| rel_group.related_objects.0.iter().copied())
+            .collect()
+    }
+
+    /// Groups of type `G` (e.g. zones or building systems) the given object
+    /// is assigned to.
+    pub fn groups_of<G: IfcType, T: IfcType>(&self, id: TypedId<T>) -> Vec<(TypedId<G>, &G)> {
+        self.ifc
+            .data
+            .find_all_of_type::<RelAssignsToGroup>()
+            .filter(|(_, rel_group)| rel_group.is_related_to(id))
+            .filter_map(|(_, rel_group)| {
+                self.ifc
+                    .data
+                    .get_untyped(rel_group.relating_group)
+                    .downcast_ref::<G>()
+                    .map(|group| (TypedId::new(rel_group.relating_group), group))
+            })
+            .collect()
+    }
+
+    /// Space boundaries of any level delimiting the given space.
+    pub fn space_boundaries(&self, space: TypedId<Space>) -> Vec<(Id, &SpaceBoundary)> {
+        self.all_space_boundaries()
+            .filter(|(_, boundary)| boundary.relating_space == space)
+            .collect()
+    }
+
+    /// Space boundaries of any level the given element provides to spaces.
+    pub fn element_space_boundaries<T: IfcType>(
+        &self,
+        element: TypedId<T>,
+    ) -> Vec<(Id, &SpaceBoundary)> {
+        self.all_space_boundaries()
+            .filter(|(_, boundary)| boundary.related_building_element == element.id())
+            .collect()
+    }
+
+    /// Outer boundary of the connection geometry of a space boundary in
+    /// world coordinates, `None` if the boundary has no surface geometry.
+    pub fn space_boundary_polygon(&self, boundary: &SpaceBoundary) -> Option<Vec<DVec3>> {
+        let geometry = self
+            .ifc
+            .data
+            .get_untyped(*boundary.connection_geometry.custom()?)
+            .downcast_ref::<ConnectionSurfaceGeometry>()?;
+        let surface = self
+            .ifc
+            .data
+            .get_untyped(geometry.surface_on_relating_element)
+            .downcast_ref::<CurveBoundedPlane>()?;
+
+        let space_transform = self
+            .ifc
+            .data
+            .get(boundary.relating_space)
+            .object_placement
+            .custom()
+            .map(|placement| object_placement_transform(*placement, &self.ifc))
+            .unwrap_or(DAffine3::IDENTITY);
+
+        Some(
+            surface
+                .outer_points(&self.ifc)
+                .into_iter()
+                .map(|point| space_transform.transform_point3(point))
+                .collect(),
+        )
+    }
+
+    /// Elements connected to the given element together with the relations
+    /// connecting them.
+    pub fn connected_elements<T: IfcType>(
+        &self,
+        element: TypedId<T>,
+    ) -> Vec<(Id, &ConnectsElements)> {
+        self.ifc
+            .data
+            .0
+            .values()
+            .filter_map(|ifc_type| as_connects_elements(ifc_type.as_ref()))
+            .filter_map(|connection| connection.other(element).map(|other| (other, connection)))
+            .collect()
+    }
+
+    /// Walls connected to the given wall by path connections, with the type
+    /// of the connection at the given wall followed by the type at the
+    /// connected wall.
+    pub fn connected_walls(
+        &self,
+        wall: TypedId<Wall>,
+    ) -> Vec<(TypedId<Wall>, ConnectionTypeEnum, ConnectionTypeEnum)> {
+        self.ifc
+            .data
+            .find_all_of_type::<RelConnectsPathElements>()
+            .filter_map(|(_, connection)| {
+                let other = connection.other(wall)?;
+                self.ifc.data.get_untyped(other).downcast_ref::<Wall>()?;
+
+                Some((
+                    TypedId::new(other),
+                    connection.connection_type(wall)?,
+                    connection.connection_type(other)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Groups of walls connected to each other by path connections, each
+    /// sorted by id. Walls without connections form a group of their own.
+    pub fn wall_networks(&self) -> Vec<Vec<TypedId<Wall>>> {
+        let mut walls: Vec<TypedId<Wall>> = self
+            .ifc
+            .data
+            .find_all_of_type::<Wall>()
+            .map(|(id, _)| id)
+            .collect();
+        walls.sort_by_key(|wall| wall.id());
+
+        let mut visited = HashSet::new();
+        let mut networks = Vec::new();
+
+        for wall in walls {
+            if !visited.insert(wall) {
+                continue;
+            }
+
+            let mut network = vec![wall];
+            let mut pending = vec![wall];
+            while let Some(current) = pending.pop() {
+                for (other, _, _) in self.connected_walls(current) {
+                    if visited.insert(other) {
+                        network.push(other);
+                        pending.push(other);
+                    }
+                }
+            }
+
+            network.sort_by_key(|wall| wall.id());
+            networks.push(network);
+        }
+
+        networks
+    }
+
+    /// Distribution ports nested to the given element.
+    pub fn ports_of<T: IfcType>(
+        &self,
+        element: TypedId<T>,
+    ) -> Vec<(TypedId<DistributionPort>, &DistributionPort)> {
+        self.ifc
+            .data
+            .find_all_of_type::<RelNests>()
+            .filter(|(_, rel_nests)| rel_nests.relating_object == element.id())
+            .flat_map(|(_, rel_nests)| rel_nests.related_objects.iter())
+            .filter_map(|id| {
+                self.ifc
+                    .data
+                    .get_untyped(*id)
+                    .downcast_ref::<DistributionPort>()
+                    .map(|port| (TypedId::new(*id), port))
+            })
+            .collect()
+    }
+
+    /// Elements whose ports are connected to the ports of the given element.
+    pub fn connected_flow_elements<T: IfcType>(&self, element: TypedId<T>) -> Vec<Id> {
+        let ports = self.ports_of(element);
+
+        self.ifc
+            .data
+            .find_all_of_type::<RelConnectsPorts>()
+            .filter_map(|(_, connection)| {
+                ports.iter().find_map(|(port, _)| connection.other(*port))
+            })
+            .filter_map(|other_port| {
+                self.ifc
+                    .data
+                    .find_all_of_type::<RelNests>()
+                    .find(|(_, rel_nests)| rel_nests.related_objects.0.contains(&other_port.id()))
+                    .map(|(_, rel_nests)| rel_nests.relating_object)
+            })
+            .collect()
+    }
+
+    /// Presentation layer of a representation item. A layer assigned to the
+    /// item itself takes precedence over the layer of the representation the
+    /// item belongs to.
+    pub fn presentation_layer(&self, item: Id) -> Option<&PresentationLayer> {
+        self.assigned_presentation_layer(item).or_else(|| {
+            self.ifc
+                .data
+                .find_all_of_type::<ShapeRepresentation>()
+                .filter(|(_, representation)| representation.items.0.contains(&item))
+                .find_map(|(representation_id, _)| {
+                    self.assigned_presentation_layer(representation_id.id())
+                })
+        })
+    }
+
+    /// Representation items of all representations of the given element
+    /// together with their presentation layer, `None` for items on no
+    /// layer.
+    pub fn representation_item_layers<T: TransformableType>(
+        &self,
+        element: TypedId<T>,
+    ) -> Vec<(Id, Option<&PresentationLayer>)> {
+        let Some(shape) = self.ifc.data.get(element).shape() else {
+            return Vec::new();
+        };
+
+        self.ifc
+            .data
+            .get(shape)
+            .representations
+            .iter()
+            .flat_map(|representation| {
+                let representation_layer = self.assigned_presentation_layer(representation.id());
+
+                self.ifc
+                    .data
+                    .get(*representation)
+                    .items
+                    .iter()
+                    .map(move |item| {
+                        (
+                            *item,
+                            self.assigned_presentation_layer(*item)
+                                .or(representation_layer),
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    fn object_material_colour(&self, id: Id) -> Option<SurfaceColour> {
+        self.object_and_type_ids(id)
+            .flat_map(|object_id| {
+                self.ifc
+                    .data
+                    .find_all_of_type::<RelAssociatesMaterial>()
+                    .filter(move |(_, rel_material)| {
+                        rel_material.related_objects.0.contains(&object_id)
+                    })
+                    .flat_map(|(_, rel_material)| self.materials_of(rel_material.relating_material))
+            })
+            .find_map(|material| {
+                self.ifc
+                    .data
+                    .find_all_of_type::<MaterialDefinitionRepresentation>()
+                    .filter(|(_, definition)| definition.represented_material == material)
+                    .find_map(|(_, definition)| definition.colour(&self.ifc))
+            })
+    }
+
+    /// Materials used by a material definition, e.g. the materials of all
+    /// layers of a layer set usage.
+    fn materials_of(&self, id: Id) -> Vec<TypedId<Material>> {
+        let material_select = self.ifc.data.get_untyped(id);
+
+        let ids: Vec<Id> = if material_select.downcast_ref::<Material>().is_some() {
+            return vec![TypedId::new(id)];
+        } else if let Some(usage) = material_select.downcast_ref::<MaterialLayerSetUsage>() {
+            vec![usage.spatial_element_structure.id()]
+        } else if let Some(layer_set) = material_select.downcast_ref::<MaterialLayerSet>() {
+            layer_set.material_layers.iter().map(|id| id.id()).collect()
+        } else if let Some(layer) = material_select.downcast_ref::<MaterialLayer>() {
+            layer
+                .material
+                .custom()
+                .map(|id| id.id())
+                .into_iter()
+                .collect()
+        } else if let Some(set) = material_select.downcast_ref::<MaterialConstituentSet>() {
+            set.material_constituents.iter().map(|id| id.id()).collect()
+        } else if let Some(constituent) = material_select.downcast_ref::<MaterialConstituent>() {
+            vec![constituent.material.id()]
+        } else if let Some(usage) = material_select.downcast_ref::<MaterialProfileSetUsage>() {
+            vec![usage.for_profile_set.id()]
+        } else if let Some(profile_set) = material_select.downcast_ref::<MaterialProfileSet>() {
+            profile_set
+                .material_profiles
+                .iter()
+                .map(|id| id.id())
+                .collect()
+        } else if let Some(profile) = material_select.downcast_ref::<MaterialProfile>() {
+            profile
+                .material
+                .custom()
+                .map(|id| id.id())
+                .into_iter()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        ids.into_iter()
+            .flat_map(|id| self.materials_of(id))
+            .collect()
+    }
+
+    /// Products with a shape representation containing the given item.
+    fn products_of_item(&self, item: Id) -> impl Iterator<Item = Id> + '_ {
+        let shapes: Vec<TypedId<ProductDefinitionShape>> = self
+            .ifc
+            .data
+            .find_all_of_type::<ShapeRepresentation>()
+            .filter(|(_, representation)| representation.items.0.contains(&item))
+            .flat_map(|(representation_id, _)| {
+                self.ifc
+                    .data
+                    .find_all_of_type::<ProductDefinitionShape>()
+                    .filter(move |(_, shape)| shape.representations.0.contains(&representation_id))
+                    .map(|(shape_id, _)| shape_id)
+            })
+            .collect();
+
+        self.ifc
+            .data
+            .0
+            .iter()
+            .filter(move |(_, ifc_type)| {
+                ifc_type
+                    .as_product()
+                    .and_then(|product| product.representation.custom())
+                    .is_some_and(|shape| shapes.contains(shape))
+            })
+            .map(|(id, _)| *id)
+    }
+
+    /// The given object followed by the types it is defined by.
+    fn object_and_type_ids(&self, id: Id) -> impl Iterator<Item = Id> + '_ {
+        let type_ids = self
+            .ifc
+            .data
+            .find_all_of_type::<RelDefinesByType>()
+            .filter(move |(_, rel_type)| rel_type.related_objects.0.contains(&id))
+            .map(|(_, rel_type)| rel_type.relating_type);
+
+        std::iter::once(id).chain(type_ids)
+    }
+
+    fn all_space_boundaries(&self) -> impl Iterator<Item = (Id, &SpaceBoundary)> {
+        self.ifc.data.0.iter().filter_map(|(id, ifc_type)| {
+            as_space_boundary(ifc_type.as_ref()).map(|boundary| (*id, boundary))
+        })
+    }
+
+    fn assigned_presentation_layer(&self, id: Id) -> Option<&PresentationLayer> {
+        self.ifc
+            .data
+            .0
+            .values()
+            .filter_map(|ifc_type| as_presentation_layer(ifc_type.as_ref()))
+            .find(|layer| layer.assigned_items.0.contains(&id))
+    }
+
+    fn property_definitions<T: IfcType, D: IfcType>(
+        &self,
+        id: TypedId<T>,
+    ) -> Vec<(TypedId<D>, &D)> {
+        let occurrence_definitions = self.property_definition_ids_of(id.id());
+
+        let type_definitions = self
+            .ifc
+            .data
+            .find_all_of_type::<RelDefinesByType>()
+            .filter(|(_, rel_type)| rel_type.related_objects.0.contains(&id.id()))
+            .flat_map(|(_, rel_type)| {
+                let type_object = self
+                    .ifc
+                    .data
+                    .get_untyped(rel_type.relating_type)
+                    .as_type_object();
+
+                type_object
+                    .and_then(|type_object| type_object.has_property_sets.custom())
+                    .into_iter()
+                    .flat_map(|property_sets| property_sets.0.clone())
+                    .chain(self.property_definition_ids_of(rel_type.relating_type))
+            });
+
+        occurrence_definitions
+            .chain(type_definitions)
+            .filter_map(|definition_id| {
+                self.ifc
+                    .data
+                    .get_untyped(definition_id)
+                    .downcast_ref::<D>()
+                    .map(|definition| (TypedId::new(definition_id), definition))
+            })
+            .collect()
+    }
+
+    fn property_definition_ids_of(&self, id: Id) -> impl Iterator<Item = Id> + '_ {
+        self.ifc
+            .data
+            .find_all_of_type::<RelDefinesByProperties>()
+            .filter(move |(_, rel_properties)| rel_properties.related_objects.0.contains(&id))
+            .map(|(_, rel_properties)| rel_properties.relating_property_definition)
+    }
 }
 
 impl From<IFC> for IfcExtractor {
@@ -220,6 +743,10 @@ mod test {
                             StructureType::Slab(_slab) => (),
                             StructureType::Roof(_roof) => (),
                             StructureType::Window(_window) => (),
+                            StructureType::Door(_door) => (),
+                            StructureType::Beam(_beam) => (),
+                            StructureType::Column(_column) => (),
+                            StructureType::Member(_member) => (),
                         }
                     }
                 }
@@ -243,4 +770,193 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn archicad_file_property_sets() -> Result<()> {
+        let ifc = IfcExtractor::from(IFC::from_file("resources/AC20-FZK-Haus.ifc")?);
+        let wall_id = TypedId::<Wall>::new(Id(15042));
+
+        let pset_names = ifc
+            .property_sets(wall_id)
+            .into_iter()
+            .filter_map(|(_, pset)| pset.name.custom().map(|name| name.0.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pset_names,
+            ["Pset_WallCommon", "AC_Pset_Name", "ArchiCADProperties"]
+        );
+
+        let thermal_transmittance = ifc
+            .property_value(wall_id, "Pset_WallCommon", "ThermalTransmittance")
+            .and_then(IfcValue::as_f64);
+        assert_eq!(thermal_transmittance, Some(1.5));
+
+        let name = ifc
+            .property_value(wall_id, "AC_Pset_Name", "Name")
+            .and_then(IfcValue::as_str);
+        assert_eq!(name, Some("Wand-Int-ERDG-4"));
+
+        assert!(ifc
+            .property_value(wall_id, "Pset_WallCommon", "IsExternal")
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn archicad_file_element_quantities() -> Result<()> {
+        let ifc = IfcExtractor::from(IFC::from_file("resources/AC20-FZK-Haus.ifc")?);
+        let wall_id = TypedId::<Wall>::new(Id(15042));
+
+        assert_eq!(ifc.element_quantities(wall_id).len(), 2);
+        assert_eq!(
+            ifc.quantity_value(wall_id, "BaseQuantities", "Length"),
+            Some(4.17)
+        );
+        assert_eq!(
+            ifc.quantity_value(wall_id, "BaseQuantities", "GrossSideArea"),
+            Some(10.425)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn archicad_file_colours() -> Result<()> {
+        let ifc = IfcExtractor::from(IFC::from_file("resources/AC20-FZK-Haus.ifc")?);
+
+        // styled item assigned to the terrain geometry
+        assert_eq!(
+            ifc.item_colour(Id(174)),
+            Some(SurfaceColour::new(
+                0.206363012131,
+                0.555901426719,
+                0.162447547112
+            ))
+        );
+
+        // colour of the material of the single wall layer
+        let wall_id = TypedId::<Wall>::new(Id(15042));
+        assert_eq!(
+            ifc.material_colour(wall_id),
+            Some(SurfaceColour::new(1.0, 1.0, 1.0))
+        );
+
+        Ok(())
+    }
+    #[test]
+    fn archicad_file_classifications() -> Result<()> {
+        let ifc = IfcExtractor::from(IFC::from_file("resources/AC20-FZK-Haus.ifc")?);
+
+        let space_id = TypedId::<Space>::new(Id(20909));
+        let references = ifc.classification_references(space_id);
+        assert_eq!(references.len(), 1);
+        assert_eq!(
+            references[0]
+                .1
+                .identification
+                .custom()
+                .map(|id| id.0.as_str()),
+            Some("000")
+        );
+
+        // the reference is not part of a classification system
+        assert_eq!(ifc.classification_code(space_id, "AC Zone Category"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn archicad_file_space_boundaries() -> Result<()> {
+        let ifc = IfcExtractor::from(IFC::from_file("resources/AC20-FZK-Haus.ifc")?);
+
+        let space_id = TypedId::<Space>::new(Id(20909));
+        let boundaries = ifc.space_boundaries(space_id);
+        assert!(!boundaries.is_empty());
+
+        let (_, wall_boundary) = boundaries
+            .iter()
+            .find(|(id, _)| *id == Id(76511))
+            .expect("boundary to wall exists");
+        assert_eq!(wall_boundary.related_building_element, Id(15042));
+        assert!(matches!(
+            wall_boundary.internal_or_external_boundary,
+            InternalOrExternalEnum::Internal
+        ));
+
+        let wall_boundaries = ifc.element_space_boundaries(TypedId::<Wall>::new(Id(15042)));
+        assert!(wall_boundaries.iter().any(|(id, _)| *id == Id(76511)));
+
+        // 4.05 x 2.5 face of the wall
+        let polygon = ifc
+            .space_boundary_polygon(wall_boundary)
+            .expect("boundary has surface geometry");
+        assert_eq!(polygon.len(), 4);
+
+        let area = polygon
+            .iter()
+            .zip(polygon.iter().cycle().skip(1))
+            .map(|(a, b)| a.cross(*b))
+            .sum::<glam::DVec3>()
+            .length()
+            * 0.5;
+        assert!((area - 4.05 * 2.5).abs() < 1e-6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn archicad_file_wall_connections() -> Result<()> {
+        let ifc = IfcExtractor::from(IFC::from_file("resources/AC20-FZK-Haus.ifc")?);
+
+        let wall_id = TypedId::<Wall>::new(Id(17040));
+        let connected = ifc.connected_walls(wall_id);
+
+        assert!(connected.contains(&(
+            TypedId::new(Id(15042)),
+            ConnectionTypeEnum::AtEnd,
+            ConnectionTypeEnum::AtStart
+        )));
+        assert!(connected.contains(&(
+            TypedId::new(Id(18698)),
+            ConnectionTypeEnum::AtPath,
+            ConnectionTypeEnum::AtEnd
+        )));
+        assert_eq!(ifc.connected_elements(wall_id).len(), connected.len());
+
+        let networks = ifc.wall_networks();
+        assert_eq!(
+            networks.iter().map(Vec::len).sum::<usize>(),
+            ifc.data.find_all_of_type::<Wall>().count()
+        );
+        assert!(networks.iter().any(
+            |network| network.contains(&wall_id) && network.contains(&TypedId::new(Id(18698)))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn archicad_file_presentation_layers() -> Result<()> {
+        let ifc = IfcExtractor::from(IFC::from_file("resources/AC20-FZK-Haus.ifc")?);
+
+        // the layer is assigned to the body representation of the slab
+        let layer = ifc
+            .presentation_layer(Id(34485))
+            .expect("slab body is layered");
+        assert_eq!(layer.name.0, "Decken");
+
+        // body and axis of the wall are on a layer, its bounding box is not
+        let wall_id = TypedId::<Wall>::new(Id(15042));
+        let item_layers = ifc.representation_item_layers(wall_id);
+        assert_eq!(item_layers.len(), 3);
+        for (item, layer) in item_layers {
+            match item {
+                Id(15023) => assert!(layer.is_none()),
+                _ => assert_eq!(layer.unwrap().name.0, "Innenw\\X2\\00E4\\X0\\nde"),
+            }
+        }
+
+        Ok(())
+    }
 }
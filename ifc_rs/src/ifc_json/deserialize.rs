@@ -0,0 +1,328 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde_json::{Map, Value};
+use winnow::Parser;
+
+use super::{is_rooted, json_name, CONTAINS_ELEMENTS, IS_DECOMPOSED_BY};
+use crate::{
+    id::Id,
+    meta::{
+        datamap::DataMap,
+        footer::Footer,
+        header::{
+            description::{FileDescription, ImplementationLevel, ViewDefinition},
+            details::{
+                Author, Authorization, FileDetails, FileName, Organization, OriginatingSystem,
+                PreprocessorVersion, TimeStamp,
+            },
+            schema::{FileSchema, FileSchemas},
+            Header,
+        },
+        version::Version,
+    },
+    parser::{
+        ifc_float::format_double,
+        step_value::{StepEntity, StepValue},
+        IFCParse,
+    },
+    schema::{AttributeKind, DefinedTypeDef, EntityDef},
+    IFC,
+};
+
+pub(super) fn read_json(json: &Value) -> Result<IFC> {
+    let header = read_header(json)?;
+
+    let top_level = match json.get("data") {
+        Some(Value::Array(objects)) => objects.iter().collect::<Vec<_>>(),
+        Some(Value::Object(objects)) => objects.values().collect(),
+        _ => bail!("ifcJSON document without data"),
+    };
+
+    let mut reader = JsonReader::default();
+
+    // Rooted entities can be referenced before they appear (and can be
+    // embedded anywhere), so they get their ids upfront. Top level entities
+    // without GlobalId get their ids upfront as well to keep the order of
+    // the document.
+    let reserved = top_level
+        .iter()
+        .map(|object| {
+            let reserved = object_global_id(object).is_none().then(|| reader.next_id());
+            reader.register_rooted(object);
+            reserved
+        })
+        .collect::<Vec<_>>();
+
+    for (object, reserved) in top_level.iter().zip(reserved) {
+        reader.entity(object, reserved)?;
+    }
+
+    let mut data = String::from("DATA;\n");
+    for (id, entity) in reader.entities.iter() {
+        writeln!(data, "{id}= {entity}")?;
+    }
+    data.push_str("ENDSEC;\n");
+
+    let data = DataMap::parse()
+        .parse(data.as_str())
+        .map_err(|err| anyhow!("parsing failed: {err:#?}"))?;
+
+    let ifc = IFC {
+        header,
+        data,
+        footer: Footer {
+            version: Version::ISO_10303_21,
+        },
+    };
+    ifc.verify_id_types()?;
+
+    Ok(ifc)
+}
+
+fn read_header(json: &Value) -> Result<Header> {
+    let text = |name: &str| {
+        json.get(name)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_owned()
+    };
+    let texts = |name: &str| {
+        json.get(name)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(str::to_owned)
+            .collect::<Vec<_>>()
+    };
+
+    let schema = match text("schemaIdentifier").to_uppercase().as_str() {
+        "IFC2X3" => FileSchema::IFC2X3,
+        "IFC4X2" => FileSchema::IFC4x2,
+        _ => FileSchema::IFC4,
+    };
+
+    let timestamp = text("timeStamp");
+    let timestamp = if timestamp.is_empty() {
+        TimeStamp::default()
+    } else {
+        TimeStamp::from_iso8601(&timestamp)?
+    };
+
+    Ok(Header {
+        version: Version::ISO_10303_21,
+        description: FileDescription {
+            descriptions: texts("description")
+                .iter()
+                .map(|description| ViewDefinition::from_description(description))
+                .collect(),
+            implementation_level: ImplementationLevel::_2_1,
+        },
+        name: FileDetails {
+            name: FileName(text("name")),
+            timestamp,
+            author: texts("author").into_iter().map(Author).collect(),
+            organization: texts("organization")
+                .into_iter()
+                .map(Organization)
+                .collect(),
+            preprocessor_version: PreprocessorVersion(text("preprocessorVersion")),
+            originating_system: OriginatingSystem(text("originatingSystem")),
+            authorization: Authorization(text("authorization")),
+        },
+        schema: FileSchemas(vec![schema]),
+    })
+}
+
+/// GlobalId of an object describing a rooted entity.
+fn object_global_id(object: &Value) -> Option<&str> {
+    let definition = EntityDef::find(object.get("type")?.as_str()?)?;
+    if !is_rooted(definition) {
+        return None;
+    }
+
+    object.get("globalId")?.as_str()
+}
+
+#[derive(Default)]
+struct JsonReader {
+    entities: BTreeMap<Id, StepEntity>,
+    last_id: usize,
+
+    /// GlobalId → id of all rooted entities.
+    rooted: HashMap<String, Id>,
+    /// STEP representation → id of entities without GlobalId, used to merge
+    /// embedded copies of the same entity.
+    embedded: HashMap<String, Id>,
+}
+
+impl JsonReader {
+    fn next_id(&mut self) -> Id {
+        self.last_id += 1;
+        Id(self.last_id)
+    }
+
+    /// Assigns ids to the rooted entities in document order (depth first).
+    fn register_rooted(&mut self, value: &Value) {
+        match value {
+            Value::Object(object) => {
+                if let Some(global_id) = object_global_id(value) {
+                    if !self.rooted.contains_key(global_id) && object.get("ref").is_none() {
+                        let id = self.next_id();
+                        self.rooted.insert(global_id.to_owned(), id);
+                    }
+                }
+                for value in object.values() {
+                    self.register_rooted(value);
+                }
+            }
+            Value::Array(values) => {
+                for value in values {
+                    self.register_rooted(value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Creates the entity described by the object (and all embedded entities)
+    /// and returns its id. Entities without GlobalId use the reserved id if
+    /// given, otherwise they are merged with equal entities.
+    fn entity(&mut self, value: &Value, reserved: Option<Id>) -> Result<Id> {
+        let object = value
+            .as_object()
+            .with_context(|| format!("expected entity object, found {value}"))?;
+        let type_name = object
+            .get("type")
+            .and_then(Value::as_str)
+            .context("entity object without type")?;
+        let definition = EntityDef::find(type_name);
+
+        if let Some(global_id) = object_global_id(value) {
+            let id = self.rooted[global_id];
+            if self.entities.contains_key(&id) {
+                return Ok(id);
+            }
+            let entity = self.step_entity(type_name, definition, object)?;
+            self.entities.insert(id, entity);
+            self.inverse_relations(object)?;
+            return Ok(id);
+        }
+
+        let entity = self.step_entity(type_name, definition, object)?;
+        let id = match reserved {
+            Some(id) => id,
+            None => match self.embedded.get(&entity.to_string()) {
+                Some(id) => return Ok(*id),
+                None => self.next_id(),
+            },
+        };
+
+        self.embedded.insert(entity.to_string(), id);
+        self.entities.insert(id, entity);
+
+        Ok(id)
+    }
+
+    fn inverse_relations(&mut self, object: &Map<String, Value>) -> Result<()> {
+        for inverse in [IS_DECOMPOSED_BY, CONTAINS_ELEMENTS] {
+            for relation in object
+                .get(inverse)
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                self.entity(relation, None)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn step_entity(
+        &mut self,
+        type_name: &str,
+        definition: Option<&EntityDef>,
+        object: &Map<String, Value>,
+    ) -> Result<StepEntity> {
+        let attributes = match (definition, object.get("attributes")) {
+            (_, Some(Value::Array(values))) => values
+                .iter()
+                .map(|value| self.value(None, value))
+                .collect::<Result<Vec<_>>>()?,
+            (Some(definition), _) => definition
+                .all_attributes()
+                .into_iter()
+                .map(|attribute| match object.get(&json_name(attribute.name)) {
+                    Some(value) => self.value(Some(attribute.kind), value),
+                    None => Ok(StepValue::Omitted),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            (None, _) => bail!("unknown entity {type_name} without attributes"),
+        };
+
+        Ok(StepEntity {
+            name: definition
+                .map(|definition| definition.step_name())
+                .unwrap_or_else(|| type_name.to_uppercase()),
+            attributes,
+        })
+    }
+
+    fn value(&mut self, kind: Option<AttributeKind>, value: &Value) -> Result<StepValue> {
+        Ok(match value {
+            Value::Null => StepValue::Omitted,
+            Value::Bool(true) => StepValue::Enum("T".to_owned()),
+            Value::Bool(false) => StepValue::Enum("F".to_owned()),
+            Value::Number(number) => StepValue::Number(match number.as_i64() {
+                Some(integer) if !number.is_f64() => integer.to_string(),
+                _ => format_double(number.as_f64().context("invalid number")?),
+            }),
+            Value::String(s) if kind == Some(AttributeKind::Enum) => StepValue::Enum(s.clone()),
+            Value::String(s) => StepValue::String(StepValue::encode_string(s)),
+            Value::Array(values) => {
+                let inner = match kind {
+                    Some(AttributeKind::List(inner)) => Some(*inner),
+                    _ => None,
+                };
+                StepValue::List(
+                    values
+                        .iter()
+                        .map(|value| self.value(inner, value))
+                        .collect::<Result<_>>()?,
+                )
+            }
+            Value::Object(object) => {
+                if let Some(global_id) = object.get("ref") {
+                    let global_id = global_id.as_str().context("invalid reference")?;
+                    let id = self
+                        .rooted
+                        .get(global_id)
+                        .with_context(|| format!("unresolved reference {global_id}"))?;
+                    return Ok(StepValue::Ref(*id));
+                }
+
+                if let Some(Value::String(e)) = object.get("enum") {
+                    return Ok(StepValue::Enum(e.clone()));
+                }
+
+                let type_name = object
+                    .get("type")
+                    .and_then(Value::as_str)
+                    .with_context(|| format!("value without type {value}"))?;
+
+                match object.get("value") {
+                    Some(inner) if EntityDef::find(type_name).is_none() => StepValue::Typed(
+                        type_name.to_uppercase(),
+                        Box::new(self.value(Some(DefinedTypeDef::kind_of(type_name)), inner)?),
+                    ),
+                    _ => StepValue::Ref(self.entity(value, None)?),
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,168 @@
+//! ifcJSON encoding of IFC files following the buildingSMART ifcJSON draft.
+//!
+//! Every entity is written as JSON object with a `type` member holding the
+//! IFC entity name and its attributes in lower camel case (`globalId`,
+//! `ownerHistory`, ...). Entities which derive from `IfcRoot` are identified
+//! by their GlobalId and referenced as `{ "type": "IfcWall", "ref": "<GlobalId>" }`.
+//! All other entities are embedded into the objects that reference them.
+//! Typed select values are written as `{ "type": "IfcLabel", "value": "..." }`.
+//!
+//! Two layouts are supported, see [`IfcJsonForm`]. The reader accepts both.
+//!
+//! Derived attributes (`*`) are not part of ifcJSON and therefore read back
+//! as omitted. Shared entities without GlobalId are embedded once per
+//! reference and merged again while reading, so the instance names of a
+//! re-imported model can differ from the original STEP file.
+
+mod deserialize;
+mod serialize;
+
+use anyhow::Result;
+
+use crate::{schema::EntityDef, IFC};
+
+/// Layout of the `data` member of an ifcJSON document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IfcJsonForm {
+    /// `data` is an object which maps the GlobalId of every rooted entity to
+    /// the entity. Entities without GlobalId which are not referenced by any
+    /// other entity (e.g. presentation layer assignments) are keyed by their
+    /// STEP instance name (`#42`).
+    Flat,
+    /// `data` is an array of the top level objects. Spatial elements are
+    /// embedded into their parents following the spatial hierarchy
+    /// (`isDecomposedBy`) and elements into their spatial structure
+    /// (`containsElements`).
+    Nested,
+}
+
+const IS_DECOMPOSED_BY: &str = "isDecomposedBy";
+const CONTAINS_ELEMENTS: &str = "containsElements";
+
+impl IFC {
+    /// Serializes the IFC into an ifcJSON document.
+    pub fn to_json(&self, form: IfcJsonForm) -> Result<String> {
+        let json = serialize::write_json(self, form)?;
+        Ok(serde_json::to_string_pretty(&json)?)
+    }
+
+    /// Reads an ifcJSON document in either [`IfcJsonForm`].
+    pub fn from_json(json: &str) -> Result<Self> {
+        deserialize::read_json(&serde_json::from_str(json)?)
+    }
+}
+
+/// `GlobalId` → `globalId`
+fn json_name(name: &str) -> String {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|first| first.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn is_rooted(entity: &EntityDef) -> bool {
+    entity.is_subtype_of("IfcRoot")
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+    use serde_json::Value;
+
+    use super::IfcJsonForm;
+    use crate::{objects::wall::Wall, IFC};
+
+    fn round_trip(path: &str, form: IfcJsonForm) -> Result<()> {
+        let ifc = IFC::from_file(path)?;
+        let json = ifc.to_json(form)?;
+        let from_json = IFC::from_json(&json)?;
+
+        assert_eq!(ifc.header, from_json.header);
+        assert_eq!(
+            ifc.data.find_all_of_type::<Wall>().count(),
+            from_json.data.find_all_of_type::<Wall>().count()
+        );
+        assert_eq!(normalized(&json)?, normalized(&from_json.to_json(form)?)?);
+
+        Ok(())
+    }
+
+    /// Entities without GlobalId are keyed by their instance name in the flat
+    /// form which isn't stable, so these are compared by value only.
+    fn normalized(json: &str) -> Result<Value> {
+        let mut json: Value = serde_json::from_str(json)?;
+
+        if let Value::Object(data) = &mut json["data"] {
+            let mut without_global_id = data
+                .iter()
+                .filter(|(key, _)| key.starts_with('#'))
+                .map(|(_, value)| value.to_string())
+                .collect::<Vec<_>>();
+            without_global_id.sort();
+
+            data.retain(|key, _| !key.starts_with('#'));
+            data.insert("#".to_owned(), without_global_id.into());
+        }
+
+        Ok(json)
+    }
+
+    #[test]
+    fn json_round_trip_wall_example_file() -> Result<()> {
+        round_trip("resources/wall-standard-case.ifc", IfcJsonForm::Flat)?;
+        round_trip("resources/wall-standard-case.ifc", IfcJsonForm::Nested)
+    }
+
+    #[test]
+    fn json_round_trip_archicad_file() -> Result<()> {
+        round_trip("resources/AC20-FZK-Haus.ifc", IfcJsonForm::Flat)?;
+        round_trip("resources/AC20-FZK-Haus.ifc", IfcJsonForm::Nested)
+    }
+
+    #[test]
+    fn json_flat_form_is_keyed_by_global_id() -> Result<()> {
+        let ifc = IFC::from_file("resources/wall-standard-case.ifc")?;
+        let json: Value = serde_json::from_str(&ifc.to_json(IfcJsonForm::Flat)?)?;
+
+        let wall = &json["data"]["0DWgwt6o1FOx7466fPk$jl"];
+        assert_eq!(wall["type"], "IfcWall");
+        assert_eq!(wall["ownerHistory"]["type"], "IfcOwnerHistory");
+        assert_eq!(wall["objectPlacement"]["type"], "IfcLocalPlacement");
+
+        Ok(())
+    }
+
+    #[test]
+    fn json_nested_form_follows_spatial_hierarchy() -> Result<()> {
+        let ifc = IFC::from_file("resources/AC20-FZK-Haus.ifc")?;
+        let json: Value = serde_json::from_str(&ifc.to_json(IfcJsonForm::Nested)?)?;
+
+        let project = json["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|object| object["type"] == "IfcProject")
+            .unwrap();
+        let site = &project["isDecomposedBy"][0]["relatedObjects"][0];
+        let building = &site["isDecomposedBy"][0]["relatedObjects"][0];
+        let storeys = building["isDecomposedBy"][0]["relatedObjects"]
+            .as_array()
+            .unwrap();
+
+        assert_eq!(site["type"], "IfcSite");
+        assert_eq!(building["type"], "IfcBuilding");
+        assert!(storeys
+            .iter()
+            .all(|storey| storey["type"] == "IfcBuildingStorey"));
+        assert!(storeys
+            .iter()
+            .any(|storey| storey["containsElements"][0]["relatedElements"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|element| element["type"] == "IfcWall")));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,414 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use anyhow::{bail, Context, Result};
+use serde_json::{Map, Number, Value};
+
+use super::{is_rooted, json_name, IfcJsonForm, CONTAINS_ELEMENTS, IS_DECOMPOSED_BY};
+use crate::{
+    id::Id,
+    parser::step_value::{StepEntity, StepValue},
+    schema::{AttributeKind, DefinedTypeDef, EntityDef},
+    IFC,
+};
+
+pub(super) fn write_json(ifc: &IFC, form: IfcJsonForm) -> Result<Value> {
+    let entities = ifc
+        .data
+        .0
+        .iter()
+        .map(|(id, ifc_type)| Ok((*id, StepEntity::from_ifc_type(ifc_type.as_ref())?)))
+        .collect::<Result<BTreeMap<_, _>>>()?;
+
+    let writer = JsonWriter::new(entities, form);
+    let data = match form {
+        IfcJsonForm::Flat => Value::Object(writer.flat()?),
+        IfcJsonForm::Nested => Value::Array(writer.nested()?),
+    };
+
+    let header = &ifc.header;
+    let mut json = Map::new();
+    json.insert("type".into(), "ifcJSON".into());
+    json.insert("version".into(), "0.0.1".into());
+    json.insert(
+        "schemaIdentifier".into(),
+        header
+            .schema
+            .0
+            .iter()
+            .map(|schema| schema.to_string())
+            .next()
+            .unwrap_or_default()
+            .into(),
+    );
+    json.insert("name".into(), header.name.name.0.clone().into());
+    json.insert(
+        "description".into(),
+        header
+            .description
+            .descriptions
+            .iter()
+            .map(|view_definition| view_definition.to_string())
+            .collect(),
+    );
+    json.insert(
+        "timeStamp".into(),
+        header
+            .name
+            .timestamp
+            .0
+            .format("%Y-%m-%dT%H:%M:%S")
+            .to_string()
+            .into(),
+    );
+    json.insert(
+        "author".into(),
+        header.name.author.iter().map(|a| a.0.clone()).collect(),
+    );
+    json.insert(
+        "organization".into(),
+        header
+            .name
+            .organization
+            .iter()
+            .map(|o| o.0.clone())
+            .collect(),
+    );
+    json.insert(
+        "preprocessorVersion".into(),
+        header.name.preprocessor_version.0.clone().into(),
+    );
+    json.insert(
+        "originatingSystem".into(),
+        header.name.originating_system.0.clone().into(),
+    );
+    json.insert(
+        "authorization".into(),
+        header.name.authorization.0.clone().into(),
+    );
+    json.insert("data".into(), data);
+
+    Ok(Value::Object(json))
+}
+
+struct JsonWriter {
+    entities: BTreeMap<Id, StepEntity>,
+
+    /// GlobalIds of all rooted entities.
+    global_ids: HashMap<Id, String>,
+    /// Entities which are referenced by any other entity.
+    referenced: HashSet<Id>,
+
+    /// Nested form: relationships embedded into their relating spatial element.
+    inverse_relations: HashMap<Id, Vec<(&'static str, Id)>>,
+    /// Nested form: rooted entity → relationship it is embedded into.
+    embedded_into: HashMap<Id, Id>,
+}
+
+impl JsonWriter {
+    fn new(entities: BTreeMap<Id, StepEntity>, form: IfcJsonForm) -> Self {
+        let global_ids = entities
+            .iter()
+            .filter(|(_, entity)| EntityDef::find(&entity.name).is_some_and(is_rooted))
+            .filter_map(|(id, entity)| match entity.attributes.first() {
+                Some(StepValue::String(global_id)) => {
+                    Some((*id, StepValue::decode_string(global_id)))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut referenced = HashSet::new();
+        for entity in entities.values() {
+            collect_references(&entity.attributes, &mut referenced);
+        }
+
+        let mut writer = Self {
+            entities,
+            global_ids,
+            referenced,
+            inverse_relations: HashMap::new(),
+            embedded_into: HashMap::new(),
+        };
+
+        if form == IfcJsonForm::Nested {
+            writer.collect_spatial_hierarchy();
+        }
+
+        writer
+    }
+
+    fn collect_spatial_hierarchy(&mut self) {
+        let relations = [
+            (
+                "IFCRELAGGREGATES",
+                IS_DECOMPOSED_BY,
+                "RelatingObject",
+                "RelatedObjects",
+            ),
+            (
+                "IFCRELCONTAINEDINSPATIALSTRUCTURE",
+                CONTAINS_ELEMENTS,
+                "RelatingStructure",
+                "RelatedElements",
+            ),
+        ];
+
+        for (id, entity) in self.entities.iter() {
+            for (name, inverse, relating, related) in relations {
+                if entity.name != name {
+                    continue;
+                }
+
+                let (Some(StepValue::Ref(relating)), Some(StepValue::List(related))) =
+                    (attribute(entity, relating), attribute(entity, related))
+                else {
+                    continue;
+                };
+
+                if !self.entities.contains_key(relating) || self.embedded_into.contains_key(id) {
+                    continue;
+                }
+
+                self.inverse_relations
+                    .entry(*relating)
+                    .or_default()
+                    .push((inverse, *id));
+                self.embedded_into.insert(*id, *relating);
+
+                for related in related.iter() {
+                    if let StepValue::Ref(related) = related {
+                        if self.global_ids.contains_key(related) && *related != *relating {
+                            self.embedded_into.entry(*related).or_insert(*id);
+                        }
+                    }
+                }
+            }
+        }
+
+        // entities which are (indirectly) embedded into themself would never
+        // be written, so they stay on the top level
+        let cyclic = self
+            .embedded_into
+            .keys()
+            .filter(|id| self.is_cyclic(**id))
+            .copied()
+            .collect::<HashSet<_>>();
+        for id in cyclic.iter() {
+            self.embedded_into.remove(id);
+        }
+        for relations in self.inverse_relations.values_mut() {
+            relations.retain(|(_, relation)| !cyclic.contains(relation));
+        }
+    }
+
+    fn is_cyclic(&self, id: Id) -> bool {
+        let mut visited = HashSet::new();
+        let mut current = id;
+        while let Some(parent) = self.embedded_into.get(&current) {
+            if !visited.insert(current) {
+                return true;
+            }
+            current = *parent;
+        }
+        false
+    }
+
+    fn is_top_level(&self, id: &Id) -> bool {
+        if self.global_ids.contains_key(id) {
+            !self.embedded_into.contains_key(id)
+        } else {
+            !self.referenced.contains(id)
+        }
+    }
+
+    fn flat(&self) -> Result<Map<String, Value>> {
+        self.entities
+            .keys()
+            .filter(|id| self.is_top_level(id))
+            .map(|id| {
+                let key = self
+                    .global_ids
+                    .get(id)
+                    .cloned()
+                    .unwrap_or_else(|| id.to_string());
+                Ok((key, self.object(*id, &mut Vec::new())?))
+            })
+            .collect()
+    }
+
+    fn nested(&self) -> Result<Vec<Value>> {
+        self.entities
+            .keys()
+            .filter(|id| self.is_top_level(id))
+            .map(|id| self.object(*id, &mut Vec::new()))
+            .collect()
+    }
+
+    fn object(&self, id: Id, visiting: &mut Vec<Id>) -> Result<Value> {
+        if visiting.contains(&id) {
+            bail!("cyclic reference of {id} can't be embedded");
+        }
+        visiting.push(id);
+
+        let entity = self
+            .entities
+            .get(&id)
+            .with_context(|| format!("unresolved reference {id}"))?;
+        let definition = EntityDef::find(&entity.name);
+
+        let mut object = Map::new();
+        object.insert(
+            "type".into(),
+            definition
+                .map(|definition| definition.name)
+                .unwrap_or(entity.name.as_str())
+                .into(),
+        );
+
+        match definition.and_then(|definition| definition.matching_attributes(entity)) {
+            Some(attributes) => {
+                for (attribute, value) in attributes.into_iter().zip(entity.attributes.iter()) {
+                    if matches!(value, StepValue::Omitted | StepValue::Inherited) {
+                        continue;
+                    }
+                    object.insert(
+                        json_name(attribute.name),
+                        self.value(Some(attribute.kind), value, id, visiting)?,
+                    );
+                }
+            }
+            None => {
+                let attributes = entity
+                    .attributes
+                    .iter()
+                    .map(|value| self.value(None, value, id, visiting))
+                    .collect::<Result<_>>()?;
+                object.insert("attributes".into(), Value::Array(attributes));
+            }
+        }
+
+        for (inverse, relation) in self.inverse_relations.get(&id).into_iter().flatten() {
+            let relation = self.object(*relation, visiting)?;
+            object
+                .entry(*inverse)
+                .or_insert_with(|| Value::Array(Vec::new()))
+                .as_array_mut()
+                .expect("inverse attributes are arrays")
+                .push(relation);
+        }
+
+        visiting.pop();
+
+        Ok(Value::Object(object))
+    }
+
+    fn value(
+        &self,
+        kind: Option<AttributeKind>,
+        value: &StepValue,
+        owner: Id,
+        visiting: &mut Vec<Id>,
+    ) -> Result<Value> {
+        Ok(match value {
+            StepValue::Omitted | StepValue::Inherited => Value::Null,
+            StepValue::String(s) => StepValue::decode_string(s).into(),
+            StepValue::Enum(e) => match e.as_str() {
+                "T" | "TRUE" => true.into(),
+                "F" | "FALSE" => false.into(),
+                e if kind == Some(AttributeKind::Enum) => e.into(),
+                e => {
+                    let mut object = Map::new();
+                    object.insert("enum".into(), e.into());
+                    Value::Object(object)
+                }
+            },
+            StepValue::Number(n) => Value::Number(json_number(n)?),
+            StepValue::Ref(target) => match self.global_ids.get(target) {
+                Some(_) if self.embedded_into.get(target) == Some(&owner) => {
+                    self.object(*target, visiting)?
+                }
+                Some(global_id) => {
+                    let mut object = Map::new();
+                    object.insert(
+                        "type".into(),
+                        self.entities
+                            .get(target)
+                            .and_then(|entity| EntityDef::find(&entity.name))
+                            .map(|definition| definition.name)
+                            .unwrap_or("IfcRoot")
+                            .into(),
+                    );
+                    object.insert("ref".into(), global_id.clone().into());
+                    Value::Object(object)
+                }
+                None => self.object(*target, visiting)?,
+            },
+            StepValue::List(values) => {
+                let inner = match kind {
+                    Some(AttributeKind::List(inner)) => Some(*inner),
+                    _ => None,
+                };
+                Value::Array(
+                    values
+                        .iter()
+                        .map(|value| self.value(inner, value, owner, visiting))
+                        .collect::<Result<_>>()?,
+                )
+            }
+            StepValue::Typed(type_name, inner) => {
+                let mut object = Map::new();
+                object.insert(
+                    "type".into(),
+                    DefinedTypeDef::find(type_name)
+                        .map(|defined_type| defined_type.name)
+                        .unwrap_or(type_name)
+                        .into(),
+                );
+                object.insert(
+                    "value".into(),
+                    self.value(
+                        Some(DefinedTypeDef::kind_of(type_name)),
+                        inner,
+                        owner,
+                        visiting,
+                    )?,
+                );
+                Value::Object(object)
+            }
+        })
+    }
+}
+
+fn attribute<'a>(entity: &'a StepEntity, name: &str) -> Option<&'a StepValue> {
+    let index = EntityDef::find(&entity.name)?
+        .all_attributes()
+        .iter()
+        .position(|attribute| attribute.name == name)?;
+    entity.attributes.get(index)
+}
+
+fn collect_references(values: &[StepValue], referenced: &mut HashSet<Id>) {
+    for value in values {
+        match value {
+            StepValue::Ref(id) => {
+                referenced.insert(*id);
+            }
+            StepValue::List(values) => collect_references(values, referenced),
+            StepValue::Typed(_, value) => {
+                collect_references(std::slice::from_ref(value), referenced)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn json_number(number: &str) -> Result<Number> {
+    let is_real = number.contains(['.', 'e', 'E']);
+    let parsed = if is_real {
+        number.parse::<f64>().ok().and_then(Number::from_f64)
+    } else {
+        number.parse::<i64>().ok().map(Number::from)
+    };
+
+    parsed.with_context(|| format!("invalid number {number}"))
+}
@@ -3,8 +3,26 @@ use std::{any::Any, fmt::Display};
 use anyhow::Result;
 use downcast_rs::{self, impl_downcast, Downcast};
 
-use crate::{objects::Structure, IFC};
+use crate::{
+    geometry::{curve::Curve, profile_def::ProfileDef},
+    objects::{
+        shared::{
+            context::Context, element::Element, element_type::ElementType, object::Object,
+            product::Product, root::Root, spatial_element::SpatialElement,
+            spatial_structure_element::SpatialStructureElement, type_object::TypeObject,
+            type_product::TypeProduct,
+        },
+        Structure,
+    },
+    properties::property::Property,
+    quantities::physical_simple_quantity::PhysicalSimpleQuantity,
+    schema::EntityDef,
+    IFC,
+};
 
+/// Implemented with `#[derive(IfcVerify)]`. Besides the id type checks, the
+/// derive implements the upcasting accessors following the `#[inherited]`
+/// fields, e.g. a wall returns its `Element`, `Product`, `Object` and `Root`.
 pub trait IfcVerify: Any {
     fn verify_id_types(&self, _ifc: &IFC) -> Result<()> {
         Ok(())
@@ -13,11 +31,170 @@ pub trait IfcVerify: Any {
     fn type_name(&self) -> &str {
         std::any::type_name::<Self>()
     }
+
+    fn as_root(&self) -> Option<&Root> {
+        None
+    }
+
+    fn as_object(&self) -> Option<&Object> {
+        None
+    }
+
+    fn as_context(&self) -> Option<&Context> {
+        None
+    }
+
+    fn as_product(&self) -> Option<&Product> {
+        None
+    }
+
+    fn as_element(&self) -> Option<&Element> {
+        None
+    }
+
+    fn as_spatial_element(&self) -> Option<&SpatialElement> {
+        None
+    }
+
+    fn as_spatial_structure_element(&self) -> Option<&SpatialStructureElement> {
+        None
+    }
+
+    fn as_type_object(&self) -> Option<&TypeObject> {
+        None
+    }
+
+    fn as_type_product(&self) -> Option<&TypeProduct> {
+        None
+    }
+
+    fn as_element_type(&self) -> Option<&ElementType> {
+        None
+    }
+
+    fn as_property(&self) -> Option<&Property> {
+        None
+    }
+
+    fn as_physical_simple_quantity(&self) -> Option<&PhysicalSimpleQuantity> {
+        None
+    }
 }
 
+/// Entities are serialized with serde tagged by their IFC entity name, e.g.
+/// `{ "IfcWall": { ... } }`.
+#[typetag::serde]
 pub trait IfcType: Downcast + Any + Display + IfcVerify {
     fn to_structure(&self) -> Option<&dyn Structure> {
         None
     }
+
+    /// Profile definitions can be evaluated to polygons, see [`ProfileDef`].
+    fn to_profile_def(&self) -> Option<&dyn ProfileDef> {
+        None
+    }
+
+    /// Curves can be discretized to polylines, see [`Curve`].
+    fn to_curve(&self) -> Option<&dyn Curve> {
+        None
+    }
+
+    /// Schema definition of the entity, looked up by the `typetag` name.
+    /// `None` for types which aren't IFC entities or aren't part of the
+    /// schema table.
+    fn entity_definition(&self) -> Option<&'static EntityDef> {
+        EntityDef::find(self.typetag_name())
+    }
+
+    /// IFC entity name as written in the schema, e.g. `IfcWall`.
+    fn entity_name(&self) -> Option<&'static str> {
+        self.entity_definition().map(|entity| entity.name)
+    }
+
+    /// Whether the entity is of the given IFC entity type (e.g.
+    /// `IfcBuildingElement`) or one of its subtypes. The comparison is case
+    /// insensitive.
+    fn is_subtype_of(&self, entity_name: &str) -> bool {
+        self.entity_definition()
+            .is_some_and(|entity| entity.is_subtype_of(entity_name))
+    }
 }
 impl_downcast!(IfcType);
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::IfcType;
+    use crate::{
+        objects::{storey::Storey, wall::Wall, walltype::WallType},
+        parser::dummy::Dummy,
+        relations::rel_aggregates::RelAggregates,
+        IFC,
+    };
+
+    #[test]
+    fn upcast_wall() -> Result<()> {
+        let ifc = IFC::from_file("resources/wall-standard-case.ifc")?;
+
+        let (id, _) = ifc.data.find_all_of_type::<Wall>().next().unwrap();
+        let wall = ifc.data.get_untyped(id);
+
+        assert_eq!(wall.entity_name(), Some("IfcWall"));
+        assert!(wall.is_subtype_of("IfcBuildingElement"));
+        assert!(wall.is_subtype_of("IFCPRODUCT"));
+        assert!(!wall.is_subtype_of("IfcSpatialElement"));
+
+        assert!(wall.as_element().is_some());
+        assert!(wall.as_product().is_some());
+        assert!(wall.as_object().is_some());
+        assert!(wall.as_spatial_element().is_none());
+        assert!(wall.as_type_object().is_none());
+        assert_eq!(
+            wall.as_root().unwrap().global_id.0,
+            "0DWgwt6o1FOx7466fPk$jl"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn upcast_spatial_and_type_objects() -> Result<()> {
+        let ifc = IFC::from_file("resources/AC20-FZK-Haus.ifc")?;
+
+        let (id, _) = ifc.data.find_all_of_type::<Storey>().next().unwrap();
+        let storey = ifc.data.get_untyped(id);
+        assert!(storey.is_subtype_of("IfcSpatialStructureElement"));
+        assert!(storey.as_spatial_structure_element().is_some());
+        assert!(storey.as_spatial_element().is_some());
+        assert!(storey.as_element().is_none());
+
+        let (id, _) = ifc.data.find_all_of_type::<WallType>().next().unwrap();
+        let wall_type = ifc.data.get_untyped(id);
+        assert!(wall_type.is_subtype_of("IfcTypeProduct"));
+        assert!(wall_type.as_element_type().is_some());
+        assert!(wall_type.as_type_object().is_some());
+        assert!(wall_type.as_product().is_none());
+
+        let (id, _) = ifc.data.find_all_of_type::<RelAggregates>().next().unwrap();
+        let relation = ifc.data.get_untyped(id);
+        assert!(relation.is_subtype_of("IfcRelationship"));
+        assert!(relation.as_root().is_some());
+        assert!(relation.as_object().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn entity_name_of_unparsed_entities() {
+        let dummy = Dummy {
+            s: "IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,$,$,$,$,$,$,$)".to_owned(),
+        };
+        assert_eq!(dummy.entity_name(), Some("IfcWall"));
+
+        let dummy = Dummy {
+            s: "IFCUNKNOWNENTITY(1.)".to_owned(),
+        };
+        assert_eq!(dummy.entity_name(), None);
+    }
+}
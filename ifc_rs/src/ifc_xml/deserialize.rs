@@ -0,0 +1,399 @@
+use std::fmt::Write;
+
+use anyhow::{anyhow, bail, Context, Result};
+use quick_xml::{events::Event, Reader};
+use winnow::Parser;
+
+use super::{enum_from_xml, schema_from_namespace};
+use crate::{
+    id::Id,
+    meta::{
+        datamap::DataMap,
+        footer::Footer,
+        header::{
+            description::{FileDescription, ImplementationLevel, ViewDefinition},
+            details::{
+                Author, Authorization, FileDetails, FileName, Organization, OriginatingSystem,
+                PreprocessorVersion, TimeStamp,
+            },
+            schema::{FileSchema, FileSchemas},
+            Header,
+        },
+        version::Version,
+    },
+    parser::{
+        step_value::{StepEntity, StepValue},
+        IFCParse,
+    },
+    schema::{AttributeKind, DefinedTypeDef, EntityDef},
+    IFC,
+};
+
+pub(super) fn read_xml(xml: &str) -> Result<IFC> {
+    let root = XmlElement::parse_document(xml)?;
+    if root.name != "ifcXML" {
+        bail!("expected ifcXML root element, found {}", root.name);
+    }
+
+    let schema = root
+        .attribute("xmlns")
+        .and_then(schema_from_namespace)
+        .unwrap_or(FileSchema::IFC4);
+
+    let header = root
+        .children
+        .iter()
+        .find(|child| child.name == "header")
+        .map(|header| read_header(header, schema))
+        .transpose()?
+        .unwrap_or_else(|| {
+            let mut header = IFC::default().header;
+            header.schema = FileSchemas(vec![schema]);
+            header
+        });
+
+    let mut reader = EntityReader::new(&root);
+    for element in root.children.iter().filter(|child| child.name != "header") {
+        reader.read_entity(element)?;
+    }
+
+    let mut data = String::from("DATA;\n");
+    for (id, entity) in reader.entities {
+        writeln!(data, "{id}= {entity}")?;
+    }
+    data.push_str("ENDSEC;\n");
+
+    let data = DataMap::parse()
+        .parse(data.as_str())
+        .map_err(|err| anyhow!("parsing failed: {err:#?}"))?;
+
+    let ifc = IFC {
+        header,
+        data,
+        footer: Footer {
+            version: Version::ISO_10303_21,
+        },
+    };
+    ifc.verify_id_types()?;
+
+    Ok(ifc)
+}
+
+fn read_header(header: &XmlElement, schema: FileSchema) -> Result<Header> {
+    let texts = |name: &'static str| header.children_named(name).map(|child| child.text.clone());
+    let text = |name: &'static str| texts(name).next().unwrap_or_default();
+
+    let timestamp = TimeStamp::from_iso8601(&text("time_stamp"))?;
+
+    Ok(Header {
+        version: Version::ISO_10303_21,
+        description: FileDescription {
+            descriptions: texts("documentation")
+                .map(|documentation| ViewDefinition::from_description(&documentation))
+                .collect(),
+            implementation_level: ImplementationLevel::_2_1,
+        },
+        name: FileDetails {
+            name: FileName(text("name")),
+            timestamp,
+            author: texts("author").map(Author).collect(),
+            organization: texts("organization").map(Organization).collect(),
+            preprocessor_version: PreprocessorVersion(text("preprocessor_version")),
+            originating_system: OriginatingSystem(text("originating_system")),
+            authorization: Authorization(text("authorization")),
+        },
+        schema: FileSchemas(vec![schema]),
+    })
+}
+
+fn read_id(element: &XmlElement, attribute: &str) -> Result<Id> {
+    let id = element
+        .attribute(attribute)
+        .with_context(|| format!("missing {attribute} on element {}", element.name))?;
+
+    id.strip_prefix('i')
+        .and_then(|id| id.parse().ok())
+        .map(Id)
+        .with_context(|| format!("invalid id {id} on element {}", element.name))
+}
+
+/// Collects the entities of the document, including the ones which are
+/// nested inline within attributes of other entities.
+struct EntityReader {
+    entities: Vec<(Id, StepEntity)>,
+    /// Id for the next nested entity without `id` attribute.
+    next_id: usize,
+}
+
+impl EntityReader {
+    fn new(root: &XmlElement) -> Self {
+        Self {
+            entities: Vec::new(),
+            next_id: root.max_id() + 1,
+        }
+    }
+
+    /// Reads the entity and all entities nested within it, returns the id of
+    /// the entity.
+    fn read_entity(&mut self, element: &XmlElement) -> Result<Id> {
+        let id = match element.attribute("id") {
+            Some(_) => read_id(element, "id")?,
+            None => {
+                self.next_id += 1;
+                Id(self.next_id - 1)
+            }
+        };
+
+        // nested entities may be typed by the attribute element holding them
+        let name = element.xsi_type().unwrap_or(&element.name);
+        let definition =
+            EntityDef::find(name).with_context(|| format!("{name} is not part of the schema"))?;
+
+        let attributes = definition
+            .all_attributes()
+            .into_iter()
+            .map(|attribute| {
+                // derived attributes aren't part of ifcXML
+                if definition.is_derived(attribute.name) {
+                    return Ok(StepValue::Inherited);
+                }
+
+                if let Some(simple) = element.attribute(attribute.name) {
+                    return read_simple(attribute.kind, simple);
+                }
+
+                match element.child(attribute.name) {
+                    Some(child) => self.read_attribute(attribute.kind, child),
+                    None => Ok(StepValue::Omitted),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let entity = StepEntity {
+            name: definition.step_name(),
+            attributes,
+        };
+        self.entities.push((id, entity));
+
+        Ok(id)
+    }
+
+    fn read_attribute(&mut self, kind: AttributeKind, element: &XmlElement) -> Result<StepValue> {
+        if element.attribute("ref").is_some() {
+            return Ok(StepValue::Ref(read_id(element, "ref")?));
+        }
+
+        // the attribute element itself is the entity, e.g.
+        // `<ObjectPlacement xsi:type="IfcLocalPlacement" id="i2">`
+        if element.xsi_type().is_some() {
+            return Ok(StepValue::Ref(self.read_entity(element)?));
+        }
+
+        match kind {
+            // nested aggregations give the position of each value by `pos`
+            AttributeKind::List(AttributeKind::List(inner)) => {
+                let mut rows: Vec<Vec<StepValue>> = Vec::new();
+                for child in element.children.iter() {
+                    let pos = child
+                        .attribute("pos")
+                        .with_context(|| format!("missing pos on element {}", child.name))?;
+                    let (row, column): (usize, usize) = pos
+                        .split_once(' ')
+                        .and_then(|(row, column)| Some((row.parse().ok()?, column.parse().ok()?)))
+                        .with_context(|| format!("invalid pos {pos} on element {}", child.name))?;
+
+                    if rows.len() <= row {
+                        rows.resize_with(row + 1, Vec::new);
+                    }
+                    if rows[row].len() != column {
+                        bail!("unexpected pos {pos} on element {}", child.name);
+                    }
+                    rows[row].push(read_simple(**inner, &child.text)?);
+                }
+
+                Ok(StepValue::List(
+                    rows.into_iter().map(StepValue::List).collect(),
+                ))
+            }
+            AttributeKind::List(
+                inner @ (AttributeKind::String | AttributeKind::Enum | AttributeKind::Number),
+            ) => Ok(StepValue::List(
+                element
+                    .children
+                    .iter()
+                    .map(|child| read_simple(*inner, &child.text))
+                    .collect::<Result<_>>()?,
+            )),
+            AttributeKind::List(_) => Ok(StepValue::List(
+                element
+                    .children
+                    .iter()
+                    .map(|child| self.read_value(child))
+                    .collect::<Result<_>>()?,
+            )),
+            _ => self.read_value(element.single_child()?),
+        }
+    }
+
+    /// Entity references and typed values within selects.
+    fn read_value(&mut self, element: &XmlElement) -> Result<StepValue> {
+        if element.attribute("ref").is_some() {
+            return Ok(StepValue::Ref(read_id(element, "ref")?));
+        }
+
+        // entity nested inline, e.g. `<IfcLocalPlacement id="i2">`
+        if element.is_nested_entity() {
+            return Ok(StepValue::Ref(self.read_entity(element)?));
+        }
+
+        let type_name = element
+            .name
+            .strip_suffix("-wrapper")
+            .with_context(|| format!("unexpected value element {}", element.name))?;
+        let inner = read_simple(DefinedTypeDef::kind_of(type_name), &element.text)?;
+
+        Ok(StepValue::Typed(type_name.to_uppercase(), Box::new(inner)))
+    }
+}
+
+fn read_simple(kind: AttributeKind, text: &str) -> Result<StepValue> {
+    Ok(match kind {
+        AttributeKind::String => StepValue::String(StepValue::encode_string(text)),
+        AttributeKind::Enum => StepValue::Enum(enum_from_xml(text)),
+        AttributeKind::Number => StepValue::Number(text.to_owned()),
+        AttributeKind::List(AttributeKind::Number) => StepValue::List(
+            text.split_whitespace()
+                .map(|number| StepValue::Number(number.to_owned()))
+                .collect(),
+        ),
+        kind => bail!("{kind:?} can't be read from text {text}"),
+    })
+}
+
+/// Minimal in memory representation of an XML element.
+#[derive(Debug, Default)]
+struct XmlElement {
+    /// Local name without namespace prefix.
+    name: String,
+    /// Attributes with their qualified names.
+    attributes: Vec<(String, String)>,
+    children: Vec<XmlElement>,
+    text: String,
+}
+
+impl XmlElement {
+    fn parse_document(xml: &str) -> Result<Self> {
+        // text is not trimmed since leading and trailing spaces of strings are significant
+        let mut reader = Reader::from_str(xml);
+
+        let mut stack: Vec<XmlElement> = Vec::new();
+        loop {
+            match reader.read_event()? {
+                Event::Start(start) => stack.push(Self::from_start(&start)?),
+                Event::Empty(start) => {
+                    let element = Self::from_start(&start)?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Ok(element),
+                    }
+                }
+                Event::Text(text) => {
+                    if let Some(current) = stack.last_mut() {
+                        current.text.push_str(&text.unescape()?);
+                    }
+                }
+                Event::CData(data) => {
+                    if let Some(current) = stack.last_mut() {
+                        current
+                            .text
+                            .push_str(std::str::from_utf8(data.into_inner().as_ref())?);
+                    }
+                }
+                Event::End(_) => {
+                    let element = stack.pop().context("unbalanced xml document")?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Ok(element),
+                    }
+                }
+                Event::Eof => bail!("unexpected end of xml document"),
+                Event::Decl(_) | Event::PI(_) | Event::Comment(_) | Event::DocType(_) => {}
+            }
+        }
+    }
+
+    fn from_start(start: &quick_xml::events::BytesStart) -> Result<Self> {
+        let name = std::str::from_utf8(start.local_name().as_ref())?.to_owned();
+        let attributes = start
+            .attributes()
+            .map(|attribute| {
+                let attribute = attribute?;
+                Ok((
+                    std::str::from_utf8(attribute.key.as_ref())?.to_owned(),
+                    attribute.unescape_value()?.into_owned(),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            name,
+            attributes,
+            ..Default::default()
+        })
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Entity type given by `xsi:type`, wrapped values aren't entities.
+    fn xsi_type(&self) -> Option<&str> {
+        self.attribute("xsi:type")
+            .filter(|type_name| !type_name.ends_with("-wrapper"))
+    }
+
+    /// Whether the element is an entity instance instead of a value. Nested
+    /// entities don't need an `id` if they aren't referenced elsewhere.
+    fn is_nested_entity(&self) -> bool {
+        !self.name.ends_with("-wrapper")
+            && (self.attribute("id").is_some()
+                || self.xsi_type().is_some()
+                || EntityDef::find(&self.name).is_some())
+    }
+
+    /// Highest `i<number>` id within the element and its descendants.
+    fn max_id(&self) -> usize {
+        let own = self
+            .attribute("id")
+            .and_then(|id| id.strip_prefix('i'))
+            .and_then(|id| id.parse().ok())
+            .unwrap_or_default();
+
+        self.children
+            .iter()
+            .map(XmlElement::max_id)
+            .fold(own, usize::max)
+    }
+
+    fn child(&self, name: &str) -> Option<&XmlElement> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlElement> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    fn single_child(&self) -> Result<&XmlElement> {
+        match self.children.as_slice() {
+            [child] => Ok(child),
+            children => bail!(
+                "expected exactly one value in {}, found {}",
+                self.name,
+                children.len()
+            ),
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! ifcXML encoding of IFC files.
+//!
+//! The entities are written as XML elements named after the IFC entity (e.g.
+//! `<IfcWall id="i42" GlobalId="..." PredefinedType="notdefined">`) following
+//! the conventions of the IFC4 ifcXML schema binding:
+//!
+//! * simple attribute values (strings, enumerations, numbers, lists of numbers)
+//!   are written as XML attributes
+//! * entity references are written as child elements with a `ref` attribute
+//! * typed select values are written as `<IfcLabel-wrapper>` elements
+//! * lists of defined types are written as wrapper elements, nested lists
+//!   carry their `pos` (e.g. `<IfcPositiveInteger-wrapper pos="0 2">`)
+//! * omitted (`$`) and derived (`*`) values are left out
+//! * the STEP header is mapped onto the ifcXML `<header>` element
+//!
+//! Only entities described by [`crate::schema`] can be written, everything
+//! else, including references to unknown entities, fails instead of producing
+//! markup no ifcXML consumer understands.
+
+mod deserialize;
+mod serialize;
+
+use anyhow::Result;
+
+use crate::{meta::header::schema::FileSchema, IFC};
+
+const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+impl IFC {
+    /// Serializes the IFC into an ifcXML document.
+    pub fn to_xml(&self) -> Result<String> {
+        serialize::write_xml(self)
+    }
+
+    /// Reads an ifcXML document. The entities are verified the same way as
+    /// when reading a STEP file.
+    pub fn from_xml(xml: &str) -> Result<Self> {
+        deserialize::read_xml(xml)
+    }
+}
+
+fn schema_namespace(schema: FileSchema) -> &'static str {
+    match schema {
+        FileSchema::IFC2X3 => "http://www.iai-tech.org/ifcXML/IFC2x3/FINAL",
+        FileSchema::IFC4x2 => "https://standards.buildingsmart.org/IFC/RELEASE/IFC4_2",
+        FileSchema::IFC4 => "http://www.buildingsmart-tech.org/ifcXML/IFC4/Add2",
+    }
+}
+
+fn schema_from_namespace(namespace: &str) -> Option<FileSchema> {
+    [FileSchema::IFC2X3, FileSchema::IFC4x2, FileSchema::IFC4]
+        .into_iter()
+        .find(|schema| schema_namespace(*schema) == namespace)
+}
+
+/// ifcXML writes enumerations in lower case and logicals as `true`, `false`
+/// and `unknown`.
+fn enum_to_xml(value: &str) -> String {
+    match value {
+        "T" => "true".to_owned(),
+        "F" => "false".to_owned(),
+        "U" => "unknown".to_owned(),
+        other => other.to_lowercase(),
+    }
+}
+
+fn enum_from_xml(value: &str) -> String {
+    match value {
+        "true" => "T".to_owned(),
+        "false" => "F".to_owned(),
+        "unknown" => "U".to_owned(),
+        other => other.to_uppercase(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use crate::{id::Id, parser::dummy::Dummy, IFC};
+
+    fn round_trip(path: &str) -> Result<()> {
+        let ifc = IFC::from_file(path)?;
+        assert_round_trip(&ifc)
+    }
+
+    fn assert_round_trip(ifc: &IFC) -> Result<()> {
+        let xml = ifc.to_xml()?;
+        let from_xml = IFC::from_xml(&xml)?;
+
+        assert_eq!(ifc.header, from_xml.header);
+        assert_eq!(ifc.data.to_string(), from_xml.data.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn xml_round_trip_wall_example_file() -> Result<()> {
+        let ifc = IFC::from_file("resources/wall-standard-case.ifc")?;
+        let from_xml = IFC::from_xml(&ifc.to_xml()?)?;
+
+        // the file omits derived attributes, they are read back as derived
+        let expected = ifc
+            .data
+            .to_string()
+            .replace("IFCSIUNIT($,", "IFCSIUNIT(*,")
+            .replace("'Model',*,$,$,$,", "'Model',*,*,*,*,");
+
+        assert_eq!(ifc.header, from_xml.header);
+        assert_eq!(expected, from_xml.data.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn xml_round_trip_archicad_file() -> Result<()> {
+        round_trip("resources/AC20-FZK-Haus.ifc")
+    }
+
+    #[test]
+    fn xml_rejects_entities_unknown_to_the_schema() -> Result<()> {
+        let mut ifc = IFC::from_file("resources/wall-standard-case.ifc")?;
+        ifc.data.insert(
+            Id(1000),
+            Dummy {
+                s: "IFCUNKNOWNENTITY('it''s',(1.,2.5),.T.,$,*,#1000)".to_owned(),
+            },
+        );
+
+        let error = ifc.to_xml().unwrap_err();
+        assert!(error.to_string().contains("IFCUNKNOWNENTITY"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn xml_rejects_attributes_not_matching_the_schema() -> Result<()> {
+        let mut ifc = IFC::from_file("resources/wall-standard-case.ifc")?;
+        ifc.data.insert(
+            Id(1000),
+            Dummy {
+                s: "IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,(#1000),$,$,$,$,$,$)".to_owned(),
+            },
+        );
+
+        assert!(ifc.to_xml().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn xml_rejects_unknown_references() -> Result<()> {
+        let mut ifc = IFC::from_file("resources/wall-standard-case.ifc")?;
+        ifc.data.insert(
+            Id(1000),
+            Dummy {
+                s: "IFCWALL('0DWgwt6o1FOx7466fPk$jl',#999,$,$,$,$,$,$,$)".to_owned(),
+            },
+        );
+
+        let error = ifc.to_xml().unwrap_err();
+        assert!(format!("{error:#}").contains("#999"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn xml_writes_lists_as_wrappers() -> Result<()> {
+        let mut ifc = IFC::from_file("resources/wall-standard-case.ifc")?;
+        ifc.data.insert(
+            Id(1000),
+            Dummy {
+                s: "IFCCARTESIANPOINTLIST3D(((0.,0.,0.),(1.,0.,0.),(0.,1.,0.)),$)".to_owned(),
+            },
+        );
+        ifc.data.insert(
+            Id(1001),
+            Dummy {
+                s: "IFCTRIANGULATEDFACESET(#1000,$,.TRUE.,((1,2,3)),$)".to_owned(),
+            },
+        );
+
+        let xml = ifc.to_xml()?;
+        assert!(
+            xml.contains(r#"<IfcLengthMeasure-wrapper pos="2 1">1.</IfcLengthMeasure-wrapper>"#)
+        );
+        assert!(
+            xml.contains(r#"<IfcPositiveInteger-wrapper pos="0 2">3</IfcPositiveInteger-wrapper>"#)
+        );
+        assert!(!xml.contains("<list>"));
+
+        let data = IFC::from_xml(&xml)?.data.to_string();
+        assert!(data.contains("#1001= IFCTRIANGULATEDFACESET(#1000,$,.TRUE.,((1,2,3)),$);"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn xml_reads_nested_entities() -> Result<()> {
+        let xml = std::fs::read_to_string("resources/wall-standard-case-nested.ifcxml")?;
+        let ifc = IFC::from_xml(&xml)?;
+        let data = ifc.data.to_string();
+
+        // nested entities keep their id, the ones without get a new one
+        assert!(data.contains("#11= IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,$,$,$,#33,#25,$,$);"));
+        assert!(data.contains("#33= IFCLOCALPLACEMENT($,#46);"));
+        assert!(data.contains("#46= IFCAXIS2PLACEMENT3D(#47,$,$);"));
+        assert!(data.contains("#47= IFCCARTESIANPOINT((0.,0.,0.));"));
+        assert!(data.contains("#25= IFCPRODUCTDEFINITIONSHAPE($,$,(#22,#23));"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn xml_uses_schema_names() -> Result<()> {
+        let ifc = IFC::from_file("resources/wall-standard-case.ifc")?;
+        let xml = ifc.to_xml()?;
+
+        assert!(xml.contains(r#"<IfcWall id="i11" GlobalId="0DWgwt6o1FOx7466fPk$jl""#));
+        assert!(xml.contains(r#"<OwnerHistory ref="i2" xsi:nil="true"/>"#));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,291 @@
+use std::{collections::HashMap, fmt::Write};
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use quick_xml::escape::escape;
+
+use super::{enum_to_xml, schema_namespace, XSI_NAMESPACE};
+use crate::{
+    id::Id,
+    meta::header::{schema::FileSchema, Header},
+    parser::step_value::{StepEntity, StepValue},
+    schema::{AttributeDef, AttributeKind, DefinedTypeDef, EntityDef},
+    IFC,
+};
+
+pub(super) fn write_xml(ifc: &IFC) -> Result<String> {
+    let entities = ifc
+        .data
+        .0
+        .iter()
+        .map(|(id, ifc_type)| Ok((*id, StepEntity::from_ifc_type(ifc_type.as_ref())?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut writer = XmlWriter {
+        xml: String::new(),
+        element_names: entities
+            .iter()
+            .map(|(id, entity)| (*id, element_name(&entity.name)))
+            .collect(),
+    };
+
+    let schema = ifc
+        .header
+        .schema
+        .0
+        .first()
+        .copied()
+        .unwrap_or(FileSchema::IFC4);
+
+    writeln!(writer.xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer.xml,
+        r#"<ifcXML xmlns="{ns}" xmlns:xsi="{XSI_NAMESPACE}">"#,
+        ns = schema_namespace(schema)
+    )?;
+    writer.header(&ifc.header)?;
+    for (id, entity) in entities.iter() {
+        writer.entity(*id, entity)?;
+    }
+    writeln!(writer.xml, "</ifcXML>")?;
+
+    Ok(writer.xml)
+}
+
+fn element_name(step_name: &str) -> String {
+    EntityDef::find(step_name)
+        .map(|entity| entity.name.to_owned())
+        .unwrap_or_else(|| step_name.to_owned())
+}
+
+fn xml_id(id: Id) -> String {
+    format!("i{}", id.0)
+}
+
+struct XmlWriter {
+    xml: String,
+    element_names: HashMap<Id, String>,
+}
+
+impl XmlWriter {
+    fn indent(&mut self, depth: usize) {
+        self.xml.push_str(&"  ".repeat(depth));
+    }
+
+    fn text_element(&mut self, depth: usize, name: &str, text: &str) -> Result<()> {
+        self.indent(depth);
+        writeln!(self.xml, "<{name}>{text}</{name}>", text = escape(text))?;
+        Ok(())
+    }
+
+    fn header(&mut self, header: &Header) -> Result<()> {
+        let details = &header.name;
+
+        self.indent(1);
+        writeln!(self.xml, "<header>")?;
+        self.text_element(2, "name", &details.name.0)?;
+        self.text_element(
+            2,
+            "time_stamp",
+            &details.timestamp.0.format("%Y-%m-%dT%H:%M:%S").to_string(),
+        )?;
+        for author in details.author.iter() {
+            self.text_element(2, "author", &author.0)?;
+        }
+        for organization in details.organization.iter() {
+            self.text_element(2, "organization", &organization.0)?;
+        }
+        self.text_element(2, "preprocessor_version", &details.preprocessor_version.0)?;
+        self.text_element(2, "originating_system", &details.originating_system.0)?;
+        self.text_element(2, "authorization", &details.authorization.0)?;
+        for view_definition in header.description.descriptions.iter() {
+            self.text_element(2, "documentation", &view_definition.to_string())?;
+        }
+        self.indent(1);
+        writeln!(self.xml, "</header>")?;
+
+        Ok(())
+    }
+
+    fn entity(&mut self, id: Id, entity: &StepEntity) -> Result<()> {
+        let definition = EntityDef::find(&entity.name)
+            .with_context(|| format!("#{}: {} is not part of the schema", id.0, entity.name))?;
+        let attributes = definition.matching_attributes(entity).with_context(|| {
+            format!(
+                "#{}: attributes don't match the schema definition of {}",
+                id.0, definition.name
+            )
+        })?;
+
+        let name = definition.name;
+        self.indent(1);
+        write!(self.xml, "<{name} id=\"{id}\"", id = xml_id(id))?;
+
+        let mut children = Vec::new();
+        for (attribute, value) in attributes.into_iter().zip(entity.attributes.iter()) {
+            match value {
+                // omitted and derived attributes are left out
+                StepValue::Omitted => {}
+                StepValue::Inherited if definition.is_derived(attribute.name) => {}
+                StepValue::Inherited => bail!(
+                    "#{}: {} of {name} is written as derived but isn't derived in the schema",
+                    id.0,
+                    attribute.name
+                ),
+                value => match simple_value(attribute.kind, value) {
+                    Some(simple) => {
+                        write!(self.xml, " {}=\"{}\"", attribute.name, escape(&simple))?
+                    }
+                    None => children.push((attribute, value)),
+                },
+            }
+        }
+
+        if children.is_empty() {
+            writeln!(self.xml, "/>")?;
+            return Ok(());
+        }
+
+        writeln!(self.xml, ">")?;
+        for (attribute, value) in children {
+            self.attribute(2, attribute, value)
+                .with_context(|| format!("#{}: invalid {} of {name}", id.0, attribute.name))?;
+        }
+
+        self.indent(1);
+        writeln!(self.xml, "</{name}>")?;
+
+        Ok(())
+    }
+
+    fn attribute(
+        &mut self,
+        depth: usize,
+        attribute: &AttributeDef,
+        value: &StepValue,
+    ) -> Result<()> {
+        let name = attribute.name;
+
+        if let (AttributeKind::Ref, StepValue::Ref(id)) = (attribute.kind, value) {
+            if !self.element_names.contains_key(id) {
+                bail!("unresolved reference #{} in {name}", id.0);
+            }
+            self.indent(depth);
+            writeln!(
+                self.xml,
+                r#"<{name} ref="{id}" xsi:nil="true"/>"#,
+                id = xml_id(*id)
+            )?;
+            return Ok(());
+        }
+
+        self.indent(depth);
+        writeln!(self.xml, "<{name}>")?;
+        match (attribute.kind, value) {
+            // aggregations of simple values are written as wrapper elements,
+            // nested aggregations give the position of each value by `pos`
+            (AttributeKind::List(AttributeKind::List(inner)), StepValue::List(rows)) => {
+                let item_type = item_type(attribute)?;
+                for (row, values) in rows.iter().enumerate() {
+                    let StepValue::List(values) = values else {
+                        bail!("expected nested list in {name}");
+                    };
+                    for (column, value) in values.iter().enumerate() {
+                        let simple = simple_value(**inner, value)
+                            .with_context(|| format!("unexpected value {value} in {name}"))?;
+                        self.indent(depth + 1);
+                        writeln!(
+                            self.xml,
+                            r#"<{item_type}-wrapper pos="{row} {column}">{}</{item_type}-wrapper>"#,
+                            escape(&simple)
+                        )?;
+                    }
+                }
+            }
+            (
+                AttributeKind::List(
+                    inner @ (AttributeKind::String | AttributeKind::Enum | AttributeKind::Number),
+                ),
+                StepValue::List(values),
+            ) => {
+                let item_type = item_type(attribute)?;
+                for value in values.iter() {
+                    let simple = simple_value(*inner, value)
+                        .with_context(|| format!("unexpected value {value} in {name}"))?;
+                    self.text_element(depth + 1, &format!("{item_type}-wrapper"), &simple)?;
+                }
+            }
+            (AttributeKind::List(_), StepValue::List(values)) => {
+                for value in values.iter() {
+                    self.value(depth + 1, value)?;
+                }
+            }
+            (AttributeKind::Select, value) => self.value(depth + 1, value)?,
+            (_, value) => bail!("unexpected value {value} in {name}"),
+        }
+        self.indent(depth);
+        writeln!(self.xml, "</{name}>")?;
+
+        Ok(())
+    }
+
+    /// Entity references and typed values within selects.
+    fn value(&mut self, depth: usize, value: &StepValue) -> Result<()> {
+        match value {
+            StepValue::Ref(id) => {
+                let name = self
+                    .element_names
+                    .get(id)
+                    .cloned()
+                    .with_context(|| format!("unresolved reference #{}", id.0))?;
+                self.indent(depth);
+                writeln!(
+                    self.xml,
+                    r#"<{name} ref="{id}" xsi:nil="true"/>"#,
+                    id = xml_id(*id)
+                )?;
+            }
+            StepValue::Typed(type_name, inner) => {
+                let name = format!(
+                    "{}-wrapper",
+                    DefinedTypeDef::find(type_name)
+                        .map(|defined_type| defined_type.name)
+                        .unwrap_or(type_name)
+                );
+                let simple = simple_value(DefinedTypeDef::kind_of(type_name), inner)
+                    .with_context(|| format!("unexpected value {inner} of {type_name}"))?;
+
+                self.text_element(depth, &name, &simple)?;
+            }
+            value => bail!("unexpected value {value}"),
+        }
+
+        Ok(())
+    }
+}
+
+/// Defined type of the values of an aggregation written as wrapper elements.
+fn item_type(attribute: &AttributeDef) -> Result<&'static str> {
+    attribute
+        .item_type
+        .with_context(|| format!("{} has no item type in the schema", attribute.name))
+}
+
+/// Values which are written as plain text, either as XML attribute or as
+/// content of a wrapper element.
+fn simple_value(kind: AttributeKind, value: &StepValue) -> Option<String> {
+    match (kind, value) {
+        (AttributeKind::String, StepValue::String(s)) => Some(StepValue::decode_string(s)),
+        (AttributeKind::Enum, StepValue::Enum(e)) => Some(enum_to_xml(e)),
+        (AttributeKind::Number, StepValue::Number(n)) => Some(n.clone()),
+        (AttributeKind::List(AttributeKind::Number), StepValue::List(values)) => values
+            .iter()
+            .map(|value| match value {
+                StepValue::Number(n) => Some(n.as_str()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(|numbers| numbers.into_iter().join(" ")),
+        _ => None,
+    }
+}
@@ -2,6 +2,7 @@
 
 use anyhow::{anyhow, Context, Result};
 use parser::IFCParse;
+use serde::{Deserialize, Serialize};
 use std::{fmt::Display, fs, path::Path, str::FromStr};
 use winnow::{seq, Parser};
 
@@ -21,16 +22,42 @@ pub mod geometry;
 pub mod id;
 pub mod ifc_builder;
 pub mod ifc_extractor;
+pub mod ifc_json;
 pub mod ifc_type;
+pub mod ifc_xml;
+mod ifc_zip;
 pub mod materials;
 pub mod meta;
 pub mod objects;
 pub mod parser;
 pub mod prelude;
+pub mod presentation;
+pub mod properties;
+pub mod quantities;
 pub mod relations;
+pub mod schema;
 pub mod traits;
 pub mod units;
 
+/// Encoding of a file written by [`IFC::save`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FileFormat {
+    /// STEP physical file (`.ifc`).
+    #[default]
+    Step,
+    /// ifcXML (`.ifcxml`).
+    Xml,
+}
+
+/// Options for [`IFC::save`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SaveOptions {
+    pub format: FileFormat,
+    /// Writes a `.ifczip` archive containing the encoded file.
+    pub compressed: bool,
+}
+
+#[derive(Serialize, Deserialize)]
 pub struct IFC {
     pub header: Header,
 
@@ -40,11 +67,58 @@ pub struct IFC {
 }
 
 impl IFC {
+    /// Loads a STEP or ifcXML file. `.ifczip` archives are detected by
+    /// their contents, independent of the file extension.
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
-        let contents = fs::read_to_string(path)?;
+        let bytes = fs::read(path)?;
+
+        let contents = if ifc_zip::is_zip(&bytes) {
+            ifc_zip::read_archive(&bytes)?
+        } else {
+            String::from_utf8(bytes)?
+        };
         let s = contents.as_str();
 
-        Self::from_str(s)
+        if s.trim_start().starts_with('<') {
+            Self::from_xml(s)
+        } else {
+            Self::from_str(s)
+        }
+    }
+
+    /// Writes the IFC to `path`. Compressed files contain a single entry
+    /// named after the file stem of `path`.
+    pub fn save(&self, path: impl AsRef<Path>, options: SaveOptions) -> Result<()> {
+        let path = path.as_ref();
+
+        let (contents, extension) = match options.format {
+            FileFormat::Step => (self.to_string(), "ifc"),
+            FileFormat::Xml => (self.to_xml()?, "ifcxml"),
+        };
+
+        if options.compressed {
+            let stem = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or("model");
+            ifc_zip::write_archive(
+                fs::File::create(path)?,
+                &format!("{stem}.{extension}"),
+                &contents,
+            )
+        } else {
+            Ok(fs::write(path, contents)?)
+        }
+    }
+
+    pub(crate) fn verify_id_types(&self) -> Result<()> {
+        for (id, ifc_type) in self.data.0.iter() {
+            ifc_type
+                .verify_id_types(self)
+                .context(format!("ID: {id}"))?;
+        }
+
+        Ok(())
     }
 }
 
@@ -60,9 +134,7 @@ impl FromStr for IFC {
         .parse_next(&mut s)
         .map_err(|err| anyhow!("parsing failed: {err:#?}"))?;
 
-        for (id, ifc_type) in me.data.0.iter() {
-            ifc_type.verify_id_types(&me).context(format!("ID: {id}"))?;
-        }
+        me.verify_id_types()?;
 
         Ok(me)
     }
@@ -118,4 +190,37 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn serde_round_trip() -> Result<()> {
+        for path in [
+            "resources/wall-standard-case.ifc",
+            "resources/AC20-FZK-Haus.ifc",
+        ] {
+            let ifc = IFC::from_file(path)?;
+
+            let serialized = serde_json::to_string(&ifc)?;
+            let deserialized: IFC = serde_json::from_str(&serialized)?;
+
+            assert_eq!(ifc.to_string(), deserialized.to_string());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn serde_tags_entities_by_ifc_name() -> Result<()> {
+        let ifc = IFC::from_file("resources/wall-standard-case.ifc")?;
+        let json = serde_json::to_value(&ifc.data)?;
+
+        let wall = json
+            .as_object()
+            .unwrap()
+            .values()
+            .find_map(|entity| entity.get("IfcWall"));
+
+        assert!(wall.is_some());
+
+        Ok(())
+    }
 }
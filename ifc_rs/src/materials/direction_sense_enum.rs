@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, VariantNames};
 use winnow::combinator::{alt, delimited};
 use winnow::Parser;
@@ -10,7 +11,7 @@ use crate::parser::*;
 /// direction is positive or negative along the given axis.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcmaterialresource/lexical/ifcdirectionsenseenum.htm
-#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
 pub enum DirectionSenseEnum {
     /// Direction defined to be positive.
     #[strum(to_string = ".POSITIVE.")]
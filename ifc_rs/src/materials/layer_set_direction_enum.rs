@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, VariantNames};
 use winnow::combinator::{alt, delimited};
 use winnow::Parser;
@@ -11,7 +12,7 @@ use crate::parser::*;
 /// or direction of layer offsets.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcmaterialresource/lexical/ifclayersetdirectionenum.htm
-#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
 pub enum LayerSetDirectionEnum {
     /// Usually x-axis.
     #[strum(to_string = ".AXIS1.")]
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     ifc_type::{IfcType, IfcVerify},
@@ -16,7 +17,7 @@ use crate::{
 /// used to form elements (physical products or their components).
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmaterial.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Material {
     /// Name of the material.
     pub material: OptionalParameter<Label>,
@@ -78,6 +79,7 @@ impl Display for Material {
     }
 }
 
+#[typetag::serde(name = "IfcMaterial")]
 impl IfcType for Material {}
 impl RelatableMaterial for Material {}
 
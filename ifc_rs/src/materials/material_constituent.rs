@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{IdOr, TypedId},
@@ -22,7 +23,7 @@ use crate::{
 /// their Name attribute.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcmaterialresource/lexical/ifcmaterialconstituent.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct MaterialConstituent {
     /// The name by which the material constituent is known.
     pub name: OptionalParameter<Label>,
@@ -112,6 +113,7 @@ impl Display for MaterialConstituent {
     }
 }
 
+#[typetag::serde(name = "IfcMaterialConstituent")]
 impl IfcType for MaterialConstituent {}
 
 #[cfg(test)]
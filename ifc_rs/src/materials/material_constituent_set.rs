@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{IdOr, TypedId},
@@ -20,7 +21,7 @@ use crate::{
 /// (layer thickness or layer profile).
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcmaterialresource/lexical/ifcmaterialconstituentset.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct MaterialConstituentSet {
     /// The name by which the constituent set is known.
     pub name: OptionalParameter<Label>,
@@ -97,6 +98,7 @@ impl Display for MaterialConstituentSet {
     }
 }
 
+#[typetag::serde(name = "IfcMaterialConstituentSet")]
 impl IfcType for MaterialConstituentSet {}
 impl RelatableMaterial for MaterialConstituentSet {}
 
@@ -0,0 +1,112 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcMaterialDefinitionRepresentation defines presentation information
+/// relating to IfcMaterial. It allows for multiple presentations of the
+/// same material for different geometric representation contexts.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmaterialdefinitionrepresentation.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct MaterialDefinitionRepresentation {
+    /// The word or group of words by which the representation is known.
+    pub name: OptionalParameter<Label>,
+    /// The word or group of words that characterize the representation.
+    pub description: OptionalParameter<Label>,
+    /// Contained list of styled representations of the material.
+    pub representations: IfcList<TypedId<StyledRepresentation>>,
+    /// Reference to the material to which the representation applies.
+    pub represented_material: TypedId<Material>,
+}
+
+impl MaterialDefinitionRepresentation {
+    pub fn new(represented_material: impl Into<IdOr<Material>>, ifc: &mut IFC) -> Self {
+        Self {
+            name: OptionalParameter::omitted(),
+            description: OptionalParameter::omitted(),
+            representations: IfcList::empty(),
+            represented_material: represented_material.into().or_insert(ifc),
+        }
+    }
+
+    pub fn add_representation(
+        mut self,
+        representation: impl Into<IdOr<StyledRepresentation>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.representations
+            .0
+            .push(representation.into().or_insert(ifc));
+        self
+    }
+
+    /// Colour and transparency of the first styled item defining a colour.
+    pub fn colour(&self, ifc: &IFC) -> Option<SurfaceColour> {
+        self.representations
+            .iter()
+            .flat_map(|representation| ifc.data.get(*representation).items.iter())
+            .find_map(|item| ifc.data.get(*item).colour(ifc))
+    }
+}
+
+impl IFCParse for MaterialDefinitionRepresentation {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCMATERIALDEFINITIONREPRESENTATION("),
+
+                name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                description: OptionalParameter::parse(),
+                _: Comma::parse(),
+                representations: IfcList::parse(),
+                _: Comma::parse(),
+                represented_material: Id::parse().map(TypedId::new),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for MaterialDefinitionRepresentation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCMATERIALDEFINITIONREPRESENTATION({},{},{},{});",
+            self.name, self.description, self.representations, self.represented_material
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcMaterialDefinitionRepresentation")]
+impl IfcType for MaterialDefinitionRepresentation {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::MaterialDefinitionRepresentation;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn material_definition_representation_round_trip() {
+        let example = "IFCMATERIALDEFINITIONREPRESENTATION($,$,(#15049),#15046);";
+
+        let parsed: MaterialDefinitionRepresentation = MaterialDefinitionRepresentation::parse()
+            .parse(example)
+            .unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{IdOr, TypedId},
@@ -18,7 +19,7 @@ use crate::{
 /// IfcMaterialLayerSet along the material layer set base (MlsBase).
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmateriallayer.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct MaterialLayer {
     /// Optional reference to the material from which the layer is constructed.
     /// Note that if this value is not given, it does not denote a layer
@@ -150,6 +151,7 @@ impl Display for MaterialLayer {
     }
 }
 
+#[typetag::serde(name = "IfcMaterialLayer")]
 impl IfcType for MaterialLayer {}
 
 #[cfg(test)]
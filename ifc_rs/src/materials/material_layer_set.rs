@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{IdOr, TypedId},
@@ -18,7 +19,7 @@ use crate::{
 /// relative positioning of individual layers can be expressed.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmateriallayerset.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct MaterialLayerSet {
     /// Identification of the IfcMaterialLayer’s from which the
     /// IfcMaterialLayerSet is composed.
@@ -90,6 +91,7 @@ impl Display for MaterialLayerSet {
     }
 }
 
+#[typetag::serde(name = "IfcMaterialLayerSet")]
 impl IfcType for MaterialLayerSet {}
 impl RelatableMaterial for MaterialLayerSet {}
 
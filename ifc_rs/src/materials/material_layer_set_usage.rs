@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{Id, IdOr, TypedId},
@@ -21,7 +22,7 @@ use crate::{
 /// type of the building element.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmateriallayersetusage.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct MaterialLayerSetUsage {
     /// The IfcMaterialLayerSet set to which the usage is applied.
     pub spatial_element_structure: TypedId<MaterialLayerSet>,
@@ -123,6 +124,7 @@ impl Display for MaterialLayerSetUsage {
     }
 }
 
+#[typetag::serde(name = "IfcMaterialLayerSetUsage")]
 impl IfcType for MaterialLayerSetUsage {}
 impl RelatableMaterial for MaterialLayerSetUsage {}
 
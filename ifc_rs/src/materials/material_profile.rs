@@ -0,0 +1,154 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geometry::profile_def::ProfileDef,
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_integer::IfcInteger, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcMaterialProfile is a single and identifiable cross section of an
+/// element which is constructed of a number of profiles (one or more). It
+/// assigns a material to a profile definition.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmaterialprofile.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct MaterialProfile {
+    /// The name by which the material profile is known.
+    pub name: OptionalParameter<Label>,
+
+    /// Definition of the material profile in descriptive terms.
+    pub description: OptionalParameter<Label>,
+
+    /// Optional reference to the material from which the profile is
+    /// constructed.
+    pub material: OptionalParameter<TypedId<Material>>,
+
+    /// Identification of the profile for which this material profile is
+    /// associating material.
+    #[ifc_types(
+        ArbitraryClosedProfileDef,
+        ArbitraryProfileDefWithVoids,
+        CircleProfileDef,
+        CircleHollowProfileDef,
+        CompositeProfileDef,
+        RectangleProfileDef,
+        RectangleHollowProfileDef,
+        IShapeProfileDef,
+        LShapeProfileDef,
+        TShapeProfileDef,
+        UShapeProfileDef,
+        CShapeProfileDef,
+        ZShapeProfileDef
+    )]
+    pub profile: Id,
+
+    /// The relative priority of the profile, expressed as normalised integer
+    /// range [0..100]. Controls how profiles intersect in connections and
+    /// corners of building elements.
+    pub priority: OptionalParameter<IfcInteger>,
+
+    /// Category of the material profile, e.g. the role it has in the profile
+    /// set it belongs to.
+    pub category: OptionalParameter<Label>,
+}
+
+impl MaterialProfile {
+    pub fn new<P: ProfileDef>(profile: impl Into<IdOr<P>>, ifc: &mut IFC) -> Self {
+        Self {
+            name: OptionalParameter::omitted(),
+            description: OptionalParameter::omitted(),
+            material: OptionalParameter::omitted(),
+            profile: profile.into().or_insert(ifc).id(),
+            priority: OptionalParameter::omitted(),
+            category: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn material(mut self, material: impl Into<IdOr<Material>>, ifc: &mut IFC) -> Self {
+        self.material = material.into().or_insert(ifc).into();
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<Label>) -> Self {
+        self.name = name.into().into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<Label>) -> Self {
+        self.description = description.into().into();
+        self
+    }
+
+    pub fn category(mut self, category: impl Into<Label>) -> Self {
+        self.category = category.into().into();
+        self
+    }
+
+    pub fn priority(mut self, priority: i64) -> Self {
+        self.priority = IfcInteger(priority).into();
+        self
+    }
+}
+
+impl IFCParse for MaterialProfile {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCMATERIALPROFILE("),
+
+                name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                description: OptionalParameter::parse(),
+                _: Comma::parse(),
+                material: OptionalParameter::parse(),
+                _: Comma::parse(),
+                profile: Id::parse(),
+                _: Comma::parse(),
+                priority: OptionalParameter::parse(),
+                _: Comma::parse(),
+                category: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for MaterialProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCMATERIALPROFILE({},{},{},{},{},{});",
+            self.name, self.description, self.material, self.profile, self.priority, self.category,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcMaterialProfile")]
+impl IfcType for MaterialProfile {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::MaterialProfile;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn material_profile_round_trip() {
+        let example = "IFCMATERIALPROFILE('IPE200',$,#52,#54,$,'LoadBearing');";
+
+        let parsed: MaterialProfile = MaterialProfile::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
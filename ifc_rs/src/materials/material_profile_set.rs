@@ -0,0 +1,122 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::rel_associates_material::RelatableMaterial,
+};
+
+/// The IfcMaterialProfileSet is a designation by which materials of an
+/// element constructed of a number of matched cross sections (profiles) are
+/// known, e.g. a steel beam with a single IPE profile or a composite column.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmaterialprofileset.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct MaterialProfileSet {
+    /// The name by which the IfcMaterialProfileSet is known.
+    pub name: OptionalParameter<Label>,
+
+    /// Definition of the IfcMaterialProfileSet in descriptive terms.
+    pub description: OptionalParameter<Label>,
+
+    /// Identification of the IfcMaterialProfile's from which the
+    /// IfcMaterialProfileSet is composed.
+    pub material_profiles: IfcList<TypedId<MaterialProfile>>,
+
+    /// Optional composite profile, combining the profiles of the individual
+    /// material profiles into one cross section.
+    #[ifc_types(CompositeProfileDef)]
+    pub composite_profile: OptionalParameter<Id>,
+}
+
+impl Default for MaterialProfileSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MaterialProfileSet {
+    pub fn new() -> Self {
+        Self {
+            name: OptionalParameter::omitted(),
+            description: OptionalParameter::omitted(),
+            material_profiles: IfcList::empty(),
+            composite_profile: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<Label>) -> Self {
+        self.name = name.into().into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<Label>) -> Self {
+        self.description = description.into().into();
+        self
+    }
+
+    pub fn add_profile(mut self, profile: impl Into<IdOr<MaterialProfile>>, ifc: &mut IFC) -> Self {
+        self.material_profiles.0.push(profile.into().or_insert(ifc));
+        self
+    }
+}
+
+impl IFCParse for MaterialProfileSet {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCMATERIALPROFILESET("),
+
+                name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                description: OptionalParameter::parse(),
+                _: Comma::parse(),
+                material_profiles: IfcList::parse(),
+                _: Comma::parse(),
+                composite_profile: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for MaterialProfileSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCMATERIALPROFILESET({},{},{},{});",
+            self.name, self.description, self.material_profiles, self.composite_profile,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcMaterialProfileSet")]
+impl IfcType for MaterialProfileSet {}
+impl RelatableMaterial for MaterialProfileSet {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::MaterialProfileSet;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn material_profile_set_round_trip() {
+        let example = "IFCMATERIALPROFILESET('IPE200',$,(#56),$);";
+
+        let parsed: MaterialProfileSet = MaterialProfileSet::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
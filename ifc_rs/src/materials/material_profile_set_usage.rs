@@ -0,0 +1,110 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, ifc_integer::IfcInteger, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::rel_associates_material::RelatableMaterial,
+};
+
+/// The IfcMaterialProfileSetUsage determines the usage of
+/// IfcMaterialProfileSet in terms of its location relative to the associated
+/// element geometry. The location of the profile set shall be compatible
+/// with the element geometry, which is usually an extrusion of the profile
+/// along the member axis.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmaterialprofilesetusage.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct MaterialProfileSetUsage {
+    /// The IfcMaterialProfileSet set to which the usage is applied.
+    pub for_profile_set: TypedId<MaterialProfileSet>,
+
+    /// Index reference to a significant point in the section profile.
+    /// Describes how the section is aligned relative to the axis of the
+    /// member it is associated with, e.g. 5 for the mid-depth centre.
+    pub cardinal_point: OptionalParameter<IfcInteger>,
+
+    /// Extent of the extrusion of the elements body shape representation to
+    /// which the IfcMaterialProfileSetUsage applies. It is used as the
+    /// reference value for the offsets of the
+    /// IfcMaterialProfileSetUsageTapering subtype.
+    pub reference_extent: OptionalParameter<IfcFloat>,
+}
+
+impl MaterialProfileSetUsage {
+    pub fn new(for_profile_set: impl Into<IdOr<MaterialProfileSet>>, ifc: &mut IFC) -> Self {
+        Self {
+            for_profile_set: for_profile_set.into().or_insert(ifc),
+            cardinal_point: OptionalParameter::omitted(),
+            reference_extent: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn cardinal_point(mut self, cardinal_point: i64) -> Self {
+        self.cardinal_point = IfcInteger(cardinal_point).into();
+        self
+    }
+
+    pub fn reference_extent(mut self, extent: f64) -> Self {
+        self.reference_extent = IfcFloat(extent).into();
+        self
+    }
+}
+
+impl IFCParse for MaterialProfileSetUsage {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCMATERIALPROFILESETUSAGE("),
+
+                for_profile_set: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                cardinal_point: OptionalParameter::parse(),
+                _: Comma::parse(),
+                reference_extent: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for MaterialProfileSetUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCMATERIALPROFILESETUSAGE({},{},{});",
+            self.for_profile_set, self.cardinal_point, self.reference_extent,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcMaterialProfileSetUsage")]
+impl IfcType for MaterialProfileSetUsage {}
+impl RelatableMaterial for MaterialProfileSetUsage {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::MaterialProfileSetUsage;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn material_profile_set_usage_round_trip() {
+        let example = "IFCMATERIALPROFILESETUSAGE(#58,5,$);";
+
+        let parsed: MaterialProfileSetUsage =
+            MaterialProfileSetUsage::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
@@ -10,9 +10,13 @@ pub mod layer_set_direction_enum;
 pub mod material;
 pub mod material_constituent;
 pub mod material_constituent_set;
+pub mod material_definition_representation;
 pub mod material_layer;
 pub mod material_layer_set;
 pub mod material_layer_set_usage;
+pub mod material_profile;
+pub mod material_profile_set;
+pub mod material_profile_set_usage;
 pub mod prelude;
 
 pub struct Materials;
@@ -26,6 +30,10 @@ impl Materials {
             material::Material::parse_any(),
             material_constituent::MaterialConstituent::parse_any(),
             material_constituent_set::MaterialConstituentSet::parse_any(),
+            material_profile_set_usage::MaterialProfileSetUsage::parse_any(),
+            material_profile_set::MaterialProfileSet::parse_any(),
+            material_profile::MaterialProfile::parse_any(),
+            material_definition_representation::MaterialDefinitionRepresentation::parse_any(),
         ))
     }
 }
@@ -3,6 +3,10 @@ pub use super::layer_set_direction_enum::LayerSetDirectionEnum;
 pub use super::material::Material;
 pub use super::material_constituent::MaterialConstituent;
 pub use super::material_constituent_set::MaterialConstituentSet;
+pub use super::material_definition_representation::MaterialDefinitionRepresentation;
 pub use super::material_layer::MaterialLayer;
 pub use super::material_layer_set::MaterialLayerSet;
 pub use super::material_layer_set_usage::MaterialLayerSetUsage;
+pub use super::material_profile::MaterialProfile;
+pub use super::material_profile_set::MaterialProfileSet;
+pub use super::material_profile_set_usage::MaterialProfileSetUsage;
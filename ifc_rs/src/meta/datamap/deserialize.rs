@@ -13,6 +13,9 @@ use crate::{
     materials::Materials,
     objects::Objects,
     parser::{dummy::Dummy, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    presentation::Presentation,
+    properties::Properties,
+    quantities::Quantities,
     relations::Relation,
     units::Units,
 };
@@ -25,6 +28,9 @@ impl IFCParse for DataMap {
             Relation::parse(),
             Units::parse(),
             Materials::parse(),
+            Properties::parse(),
+            Quantities::parse(),
+            Presentation::parse(),
             Dummy::parse_any(),
         )));
         let p_line = separated_pair(Id::parse(), p_space_or_comment_surrounded("="), p_obj);
@@ -3,6 +3,8 @@ mod serialize;
 
 use std::{any::TypeId, collections::BTreeMap};
 
+use serde::{Deserialize, Serialize};
+
 use crate::{
     id::{Id, TypedId},
     ifc_type::IfcType,
@@ -10,7 +12,7 @@ use crate::{
 
 /// CRITICAL: split up the index map into a proper struct with fields which hold Hashmaps mapping
 /// indices to one specific type instead of an enum
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
 pub struct DataMap(pub(crate) BTreeMap<Id, Box<dyn IfcType>>);
 
 impl DataMap {
@@ -75,7 +77,7 @@ impl DataMap {
     pub fn id_of<T: IfcType>(&self) -> impl Iterator<Item = TypedId<T>> + '_ {
         self.0
             .iter()
-            .filter(|&(_, ifc_type)| (ifc_type.type_id() == TypeId::of::<T>()))
+            .filter(|&(_, ifc_type)| ifc_type.type_id() == TypeId::of::<T>())
             .map(|(id, _)| TypedId::new(*id))
     }
 }
@@ -13,4 +13,5 @@ pub struct Footer {
 }
 
 impl IfcVerify for Footer {}
+#[typetag::serde(name = "Footer")]
 impl IfcType for Footer {}
@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
-use chrono::{DateTime, Utc};
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDateTime, Utc};
 use strum::VariantNames;
 use winnow::combinator::{alt, delimited, repeat, separated};
 use winnow::prelude::*;
@@ -17,6 +18,37 @@ use super::Header;
 use crate::parser::comma::Comma;
 use crate::parser::*;
 
+impl TimeStamp {
+    /// Parses the ISO 8601 time stamp of the ifcXML and ifcJSON headers,
+    /// either without time zone, which is taken as UTC, or as RFC 3339.
+    pub(crate) fn from_iso8601(timestamp: &str) -> Result<Self> {
+        NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M:%S")
+            .map(|time| time.and_utc())
+            .or_else(|_| DateTime::parse_from_rfc3339(timestamp).map(DateTime::<Utc>::from))
+            .map(Self)
+            .with_context(|| format!("invalid time stamp {timestamp}"))
+    }
+}
+
+impl ViewDefinition {
+    /// Inverse of the `Display` implementation, e.g.
+    /// `ViewDefinition [CoordinationView, QuantityTakeOffAddOnView]`.
+    pub(crate) fn from_description(description: &str) -> Self {
+        let (name, items) = description.split_once('[').unwrap_or((description, "]"));
+
+        Self {
+            name: name.trim().to_owned(),
+            items: items
+                .trim_end()
+                .trim_end_matches(']')
+                .split(',')
+                .map(|item| item.trim().to_owned())
+                .filter(|item| !item.is_empty())
+                .collect(),
+        }
+    }
+}
+
 impl IFCParse for Header {
     fn parse<'a>() -> impl IFCParser<'a, Self> {
         winnow::seq! {
@@ -16,6 +16,7 @@ pub struct Header {
 }
 
 impl IfcVerify for Header {}
+#[typetag::serde(name = "Header")]
 impl IfcType for Header {}
 
 pub mod description {
@@ -1,5 +1,6 @@
 use crate::parser::{p_space_or_comment, IFCParse, IFCParser};
 
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use winnow::Parser;
 
@@ -15,7 +16,7 @@ use winnow::combinator::{alt, delimited};
 /// model server so that an application can identify the state of the object.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcutilityresource/lexical/ifcstateenum.htm
-#[derive(Debug, EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(Debug, EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
 pub enum AccessState {
     /// Object is in a Read-Write state. It may be modified by an application.
     #[strum(to_string = ".READWRITE.")]
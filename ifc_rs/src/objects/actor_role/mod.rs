@@ -9,6 +9,7 @@ use crate::IFC;
 use std::str::FromStr;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, VariantNames};
 use winnow::combinator::{alt, delimited};
 use winnow::Parser;
@@ -17,7 +18,7 @@ use winnow::Parser;
 /// a person related to an organization.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcactorresource/lexical/ifcactorrole.htm
-#[derive(Debug, Clone, IfcVerify)]
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
 pub struct ActorRole {
     /// The name of the role played by an actor. If the Role has value USERDEFINED, then
     /// the user defined role shall be provided as a value of the attribute UserDefinedRole.
@@ -34,7 +35,7 @@ pub struct ActorRole {
 /// Roles which may be played by an actor.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcactorresource/lexical/ifcroleenum.htm
-#[derive(Debug, EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(Debug, EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
 pub enum Role {
     #[strum(to_string = ".SUPPLIER.")]
     Supplier,
@@ -99,4 +100,5 @@ impl IFCParse for Role {
     }
 }
 
+#[typetag::serde(name = "IfcActorRole")]
 impl IfcType for ActorRole {}
@@ -2,6 +2,7 @@ mod deserialize;
 mod serialize;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::ifc_type::{IfcType, IfcVerify};
 use crate::parser::label::Label;
@@ -15,7 +16,7 @@ pub trait Address: IfcType {}
 ///  telecommunications should be addressed.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcactorresource/lexical/ifctelecomaddress.htm
-#[derive(Debug, Clone, IfcVerify)]
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
 pub struct TelecomAddress {
     // First three attributes from IfcAddress:
     //
@@ -42,13 +43,13 @@ pub struct TelecomAddress {
     pub homepage_url: OptionalParameter<Label>,
 }
 
+#[typetag::serde(name = "IfcTelecomAddress")]
 impl IfcType for TelecomAddress {}
 impl Address for TelecomAddress {}
 
 /// The address for delivery of paper based mail.
-
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcactorresource/lexical/ifcpostaladdress.htm
-#[derive(Debug, Clone, IfcVerify)]
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
 pub struct PostalAddress {
     // First three attributes from IfcAddress:
     //
@@ -78,5 +79,6 @@ pub struct PostalAddress {
     pub country: OptionalParameter<Label>,
 }
 
+#[typetag::serde(name = "IfcPostalAddress")]
 impl IfcType for PostalAddress {}
 impl Address for PostalAddress {}
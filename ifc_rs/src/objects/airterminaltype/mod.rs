@@ -0,0 +1,126 @@
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use type_enum::AirTerminalTypeEnum;
+
+/// The element type IfcAirTerminalType defines commonly shared information for
+/// occurrences of air terminals. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcFlowTerminal or its
+/// specific subtype IfcAirTerminal.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcairterminaltype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct AirTerminalType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined type of the air terminal.
+    pub predefined_type: AirTerminalTypeEnum,
+}
+
+impl AirTerminalType {
+    pub fn new(name: impl Into<Label>, predefined_type: AirTerminalTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for AirTerminalType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for AirTerminalType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for AirTerminalType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for AirTerminalType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for AirTerminalType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for AirTerminalType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCAIRTERMINALTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: AirTerminalTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for AirTerminalType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCAIRTERMINALTYPE({},{});",
+            self.element_type, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcAirTerminalType")]
+impl IfcType for AirTerminalType {}
+impl MaterialRelatable for AirTerminalType {}
+impl ClassificationRelatable for AirTerminalType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::AirTerminalType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn air_terminal_type_round_trip() {
+        let example =
+            "IFCAIRTERMINALTYPE('1Hs6Vp2kB8eN4fR0tYq7zD',#2,'Ceiling diffuser',$,$,$,$,$,$,.DIFFUSER.);";
+
+        let parsed: AirTerminalType = AirTerminalType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the different types of air terminals.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcairterminaltypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum AirTerminalTypeEnum {
+    /// An outlet discharging supply air in various directions.
+    #[strum(to_string = ".DIFFUSER.")]
+    Diffuser,
+
+    /// A covering of an opening through which air passes.
+    #[strum(to_string = ".GRILLE.")]
+    Grille,
+
+    /// A rectilinear opening with blades, usually to the outside.
+    #[strum(to_string = ".LOUVRE.")]
+    Louvre,
+
+    /// A grille with a damper controlling the air flow.
+    #[strum(to_string = ".REGISTER.")]
+    Register,
+
+    /// User-defined type.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined type.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for AirTerminalTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid AirTerminalTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
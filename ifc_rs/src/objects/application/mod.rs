@@ -2,6 +2,7 @@ mod deserialize;
 mod serialize;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::id::{Id, IdOr};
 use crate::ifc_type::{IfcType, IfcVerify};
@@ -14,7 +15,7 @@ use crate::IFC;
 ///  developed by an application developer who is a member of buildingSMART.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcutilityresource/lexical/ifcapplication.htm
-#[derive(Debug, Clone, IfcVerify)]
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
 pub struct Application {
     /// Name of the application developer, being requested to be member
     /// of buildingSMART. (Person/org id)
@@ -45,4 +46,5 @@ impl Application {
     }
 }
 
+#[typetag::serde(name = "IfcApplication")]
 impl IfcType for Application {}
@@ -0,0 +1,23 @@
+use comma::Comma;
+use optional::OptionalParameter;
+use winnow::combinator::alt;
+
+use crate::{objects::shared::element::Element, parser::*};
+
+use super::Beam;
+
+impl IFCParse for Beam {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: alt((p_space_or_comment_surrounded("IFCBEAM("), p_space_or_comment_surrounded("IFCBEAMSTANDARDCASE("))),
+
+                element: Element::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
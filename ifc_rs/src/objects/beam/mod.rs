@@ -0,0 +1,151 @@
+mod deserialize;
+mod serialize;
+
+use std::ops::{Deref, DerefMut};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{label::Label, optional::OptionalParameter},
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable, rel_connects_elements::ConnectionRelatable,
+        rel_space_boundary::SpaceBoundaryRelatable,
+    },
+};
+
+use super::StructureType;
+
+/// An IfcBeam is a horizontal, or nearly horizontal, structural member that
+/// is capable of withstanding load primarily by resisting bending. It
+/// represents such a member from an architectural point of view. It is not
+/// required to be load bearing.
+///
+/// The representation of a beam is usually given by an extrusion of a profile
+/// along the beam axis. A common profile may be assigned through an
+/// IfcMaterialProfileSetUsage, using the IfcRelAssociatesMaterial
+/// relationship.
+///
+/// A particular usage type for the IfcBeam can be given (if type information
+/// is available) by referring to the type object IfcBeamType, using the
+/// `IfcRelDefinesByType` relationship, or (if only occurrence information is
+/// given) by using the PredefinedType attribute.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifcbeam.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Beam {
+    #[inherited]
+    element: Element,
+
+    /// Predefined generic type for a beam that is specified in an
+    /// enumeration. There may be a property set given specificly for the
+    /// predefined types.
+    ///
+    /// Note: The use of the predefined type directly at the occurrence object
+    /// level of IfcBeam is only permitted, if no type object `IfcBeamType`
+    /// is assigned.
+    pub predefined_type: OptionalParameter<BeamTypeEnum>,
+}
+
+impl Beam {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+            predefined_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn predefined_type(mut self, beam_type: BeamTypeEnum) -> Self {
+        self.predefined_type = beam_type.into();
+        self
+    }
+}
+
+impl RootBuilder for Beam {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for Beam {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for Beam {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for Beam {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for Beam {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for Beam {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+#[typetag::serde(name = "IfcBeam")]
+impl IfcType for Beam {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for Beam {
+    fn structure_type(&self) -> Option<StructureType<'_>> {
+        Some(StructureType::Beam(self))
+    }
+}
+impl MaterialRelatable for Beam {}
+impl ClassificationRelatable for Beam {}
+impl GroupRelatable for Beam {}
+impl SpaceBoundaryRelatable for Beam {}
+impl ConnectionRelatable for Beam {}
+
+impl TransformableType for Beam {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Beam;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn beam_round_trip() {
+        let examples = [
+            "IFCBEAM('0KR8nTQXz4vQdCMhhcaj2p',#2,'Beam',$,$,#33,#48,$,$);",
+            "IFCBEAM('1BnPgFRkH3$u7ETsJm1RQF',#12,'Sturz',$,$,#40121,#40160,'1F6E3A5B-2B0A-4E63-91-A1-6AB1F3E7F4E1',.LINTEL.);",
+        ];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let beam: Beam = Beam::parse().parse(example).unwrap();
+            let str_beam = beam.to_string();
+
+            assert_eq!(example, str_beam, "example {} failed", index);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use std::fmt::Display;
+
+use super::Beam;
+
+impl Display for Beam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCBEAM({},{});", self.element, self.predefined_type,)
+    }
+}
@@ -0,0 +1,22 @@
+use comma::Comma;
+
+use crate::objects::beamtype::type_enum::BeamTypeEnum;
+use crate::{objects::shared::element_type::ElementType, parser::*};
+
+use super::BeamType;
+
+impl IFCParse for BeamType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCBEAMTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: BeamTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::ops::Deref;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+use type_enum::BeamTypeEnum;
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::label::Label,
+    prelude::{ElementTypeBuilder, Root, RootBuilder, TypeObject, TypeProduct},
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+    IFC,
+};
+
+use super::shared::{
+    element_type::ElementType, type_object::TypeObjectBuilder, type_product::TypeProductBuilder,
+};
+
+mod deserialize;
+mod serialize;
+pub mod type_enum;
+
+/// The element type IfcBeamType defines commonly shared information for
+/// occurrences of beams. The set of shared information may include:
+///
+/// * common properties within shared property sets
+/// * common material information
+/// * common profile definitions
+/// * common shape representations
+///
+/// It is used to define a beam specification (i.e. the specific product
+/// information, that is common to all occurrences of that product type). Beam
+/// types may be exchanged without being already assigned to occurrences.
+///
+/// The occurrences of the IfcBeamType within building models are represented
+/// by instances of IfcBeam or IfcBeamStandardCase.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifcbeamtype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct BeamType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined types of a beam element from which the type
+    /// required may be set.
+    pub predefined_type: BeamTypeEnum,
+}
+
+impl BeamType {
+    pub fn new(name: impl Into<Label>, predefined_type: BeamTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for BeamType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for BeamType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for BeamType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for BeamType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for BeamType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+#[typetag::serde(name = "IfcBeamType")]
+impl IfcType for BeamType {}
+impl MaterialRelatable for BeamType {}
+impl ClassificationRelatable for BeamType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use crate::parser::IFCParse;
+
+    use super::BeamType;
+
+    #[test]
+    fn beam_type_round_trip() {
+        let example = "IFCBEAMTYPE('3K7q$4XWL3ZOg6UuVuP9r0',#2,'IPE 200',$,$,$,$,$,$,.BEAM.);";
+
+        let beam_type: BeamType = BeamType::parse().parse(example).unwrap();
+        let str_beam_type = beam_type.to_string();
+
+        assert_eq!(example, str_beam_type);
+    }
+}
@@ -0,0 +1,13 @@
+use std::fmt::Display;
+
+use super::BeamType;
+
+impl Display for BeamType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCBEAMTYPE({},{});",
+            self.element_type, self.predefined_type,
+        )
+    }
+}
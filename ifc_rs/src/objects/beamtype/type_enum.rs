@@ -0,0 +1,66 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the different predefined types of an IfcBeam or
+/// IfcBeamType object can fulfill.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifcbeamtypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
+pub enum BeamTypeEnum {
+    /// A standard beam usually used horizontally.
+    #[strum(to_string = ".BEAM.")]
+    Beam,
+
+    /// A beam used to support a floor or ceiling.
+    #[strum(to_string = ".JOIST.")]
+    Joist,
+
+    /// A wide often prestressed beam with a hollow-core profile that usually
+    /// serves as a slab component.
+    #[strum(to_string = ".HOLLOWCORE.")]
+    HollowCore,
+
+    /// A beam or horizontal piece of material over an opening (e.g. door,
+    /// window).
+    #[strum(to_string = ".LINTEL.")]
+    Lintel,
+
+    /// A special type of beam that forms part of the edge of a floor slab, often
+    /// with a profile that connects with the facade.
+    #[strum(to_string = ".SPANDREL.")]
+    Spandrel,
+
+    /// A beam that forms part of a slab construction acting together with the
+    /// slab which its carries. Such beams are often of T-shape.
+    #[strum(to_string = ".T_BEAM.")]
+    TBeam,
+
+    /// User-defined linear beam element.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined linear beam element.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for BeamTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid BeamTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
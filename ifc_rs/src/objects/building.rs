@@ -4,6 +4,7 @@ use std::{
 };
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{IdOr, TypedId},
@@ -21,7 +22,7 @@ use crate::{
 /// of a building project (together with site, storey, and space).
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcbuilding.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Building {
     #[inherited]
     spatial_element_structure: SpatialStructureElement,
@@ -148,6 +149,7 @@ impl Display for Building {
     }
 }
 
+#[typetag::serde(name = "IfcBuilding")]
 impl IfcType for Building {
     fn to_structure(&self) -> Option<&dyn Structure> {
         Some(self)
@@ -0,0 +1,158 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+/// The IfcBuildingElementProxy is a proxy definition that provides the same
+/// functionality as subtypes of IfcBuildingElement, but without having a
+/// predefined meaning of the special type of building element, it
+/// represents. It is used for elements which have no proper IFC class.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcbuildingelementproxy.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct BuildingElementProxy {
+    #[inherited]
+    element: Element,
+
+    /// Predefined generic type of the building element proxy.
+    ///
+    /// Note: The use of the predefined type directly at the occurrence object
+    /// level is only permitted, if no type object `IfcBuildingElementProxyType` is
+    /// assigned.
+    pub predefined_type: OptionalParameter<BuildingElementProxyTypeEnum>,
+}
+
+impl BuildingElementProxy {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+            predefined_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn predefined_type(mut self, predefined_type: BuildingElementProxyTypeEnum) -> Self {
+        self.predefined_type = predefined_type.into();
+        self
+    }
+}
+
+impl RootBuilder for BuildingElementProxy {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for BuildingElementProxy {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for BuildingElementProxy {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for BuildingElementProxy {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for BuildingElementProxy {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for BuildingElementProxy {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl IFCParse for BuildingElementProxy {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCBUILDINGELEMENTPROXY("),
+
+                element: Element::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for BuildingElementProxy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCBUILDINGELEMENTPROXY({},{});",
+            self.element, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcBuildingElementProxy")]
+impl IfcType for BuildingElementProxy {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for BuildingElementProxy {}
+impl MaterialRelatable for BuildingElementProxy {}
+impl ClassificationRelatable for BuildingElementProxy {}
+impl GroupRelatable for BuildingElementProxy {}
+
+impl TransformableType for BuildingElementProxy {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::BuildingElementProxy;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn building_element_proxy_round_trip() {
+        let examples = [
+            "IFCBUILDINGELEMENTPROXY('0Ks3mF6yQ1bW9hVn2cP0Jd',#2,'Kitchen',$,$,#37,#54,$,.ELEMENT.);",
+            "IFCBUILDINGELEMENTPROXY('3aW7Hd2pX9sL0mNb3Fh0cR',#12,'Objekt',$,$,#70311,#70342,'P-01',$);",
+        ];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: BuildingElementProxy =
+                BuildingElementProxy::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+pub mod type_enum;
+
+use std::ops::DerefMut;
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::rel_assigns_to_group::RelatableGroup,
+};
+
+use type_enum::BuildingSystemTypeEnum;
+
+/// The building system is used to define a collection of building elements
+/// that serve a common function, e.g. the load bearing structure or the
+/// outer shell of a building.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcbuildingsystem.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct BuildingSystem {
+    #[inherited]
+    object: Object,
+
+    /// Predefined types of building systems.
+    pub predefined_type: OptionalParameter<BuildingSystemTypeEnum>,
+
+    /// Long name for a building system, used for informal purposes. It
+    /// should be used, if available, in conjunction with the inherited Name
+    /// attribute.
+    pub long_name: OptionalParameter<Label>,
+}
+
+impl BuildingSystem {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            object: Object::new(Root::new(name.into())),
+            predefined_type: OptionalParameter::omitted(),
+            long_name: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn predefined_type(mut self, predefined_type: BuildingSystemTypeEnum) -> Self {
+        self.predefined_type = predefined_type.into();
+        self
+    }
+
+    pub fn long_name(mut self, long_name: impl Into<Label>) -> Self {
+        self.long_name = long_name.into().into();
+        self
+    }
+}
+
+impl RootBuilder for BuildingSystem {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.object
+    }
+}
+
+impl ObjectBuilder for BuildingSystem {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.object
+    }
+}
+
+impl Deref for BuildingSystem {
+    type Target = Object;
+
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl DerefMut for BuildingSystem {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.object
+    }
+}
+
+impl IFCParse for BuildingSystem {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCBUILDINGSYSTEM("),
+
+                object: Object::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+                _: Comma::parse(),
+                long_name: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for BuildingSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCBUILDINGSYSTEM({},{},{});",
+            self.object, self.predefined_type, self.long_name
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcBuildingSystem")]
+impl IfcType for BuildingSystem {}
+impl RelatableGroup for BuildingSystem {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::BuildingSystem;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn building_system_round_trip() {
+        let example =
+            "IFCBUILDINGSYSTEM('3Wl5Jk8y93$RjkQxc3IGmS',#2,'Structure',$,$,.LOADBEARING.,$);";
+
+        let building_system: BuildingSystem = BuildingSystem::parse().parse(example).unwrap();
+        assert_eq!(example, building_system.to_string());
+    }
+}
@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration identifies the different types of building systems.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcbuildingsystemtypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum BuildingSystemTypeEnum {
+    /// The system of all building elements forming the fenestration of the
+    /// building, e.g. windows, doors and curtain walls.
+    #[strum(to_string = ".FENESTRATION.")]
+    Fenestration,
+
+    /// The system of all building elements forming the foundation.
+    #[strum(to_string = ".FOUNDATION.")]
+    Foundation,
+
+    /// The system of all load bearing building elements.
+    #[strum(to_string = ".LOADBEARING.")]
+    LoadBearing,
+
+    /// The system of all building elements forming the outer shell of the
+    /// building.
+    #[strum(to_string = ".OUTERSHELL.")]
+    OuterShell,
+
+    /// The system of all shading elements.
+    #[strum(to_string = ".SHADING.")]
+    Shading,
+
+    /// The system of all transport elements, e.g. elevators and escalators.
+    #[strum(to_string = ".TRANSPORT.")]
+    Transport,
+
+    /// User-defined building system.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined building system.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for BuildingSystemTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid BuildingSystemTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
@@ -0,0 +1,126 @@
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use type_enum::BuildingElementProxyTypeEnum;
+
+/// The element type IfcBuildingElementProxyType defines commonly shared information for
+/// occurrences of building element proxies. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcBuildingElementProxy.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcbuildingelementproxytype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct BuildingElementProxyType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined type of the building element proxy.
+    pub predefined_type: BuildingElementProxyTypeEnum,
+}
+
+impl BuildingElementProxyType {
+    pub fn new(name: impl Into<Label>, predefined_type: BuildingElementProxyTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for BuildingElementProxyType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for BuildingElementProxyType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for BuildingElementProxyType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for BuildingElementProxyType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for BuildingElementProxyType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for BuildingElementProxyType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCBUILDINGELEMENTPROXYTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: BuildingElementProxyTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for BuildingElementProxyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCBUILDINGELEMENTPROXYTYPE({},{});",
+            self.element_type, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcBuildingElementProxyType")]
+impl IfcType for BuildingElementProxyType {}
+impl MaterialRelatable for BuildingElementProxyType {}
+impl ClassificationRelatable for BuildingElementProxyType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::BuildingElementProxyType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn building_element_proxy_type_round_trip() {
+        let example =
+            "IFCBUILDINGELEMENTPROXYTYPE('3Fh0cRaW7Hd2pX9sL0mNb2',#2,'Kitchen',$,$,$,(#120),$,$,.ELEMENT.);";
+
+        let parsed: BuildingElementProxyType =
+            BuildingElementProxyType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
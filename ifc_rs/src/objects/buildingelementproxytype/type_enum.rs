@@ -0,0 +1,62 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the different types of building element proxies.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcbuildingelementproxytypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum BuildingElementProxyTypeEnum {
+    /// A complex element proxy aggregating other element proxies.
+    #[strum(to_string = ".COMPLEX.")]
+    Complex,
+
+    /// An element proxy representing a whole element.
+    #[strum(to_string = ".ELEMENT.")]
+    Element,
+
+    /// An element proxy being a part of another element proxy.
+    #[strum(to_string = ".PARTIAL.")]
+    Partial,
+
+    /// A placeholder for a void to be cut later into a building element.
+    #[strum(to_string = ".PROVISIONFORVOID.")]
+    ProvisionForVoid,
+
+    /// A placeholder reserving space, e.g. for a later installation.
+    #[strum(to_string = ".PROVISIONFORSPACE.")]
+    ProvisionForSpace,
+
+    /// User-defined type.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined type.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for BuildingElementProxyTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| {
+                    (
+                        v,
+                        Self::from_str(v).expect("valid BuildingElementProxyTypeEnum"),
+                    )
+                })
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
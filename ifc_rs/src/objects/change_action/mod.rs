@@ -1,5 +1,6 @@
 use crate::parser::{p_space_or_comment, IFCParse, IFCParser};
 
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use winnow::Parser;
 
@@ -17,7 +18,7 @@ use winnow::combinator::{alt, delimited};
 /// but should not be used.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcutilityresource/lexical/ifcchangeactionenum.htm
-#[derive(Debug, EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(Debug, EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
 pub enum ChangeAction {
     /// Object has not been modified. This is the default state.
     #[strum(to_string = ".NOCHANGE.")]
@@ -0,0 +1,146 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    relations::rel_associates_classification::RelatableClassification,
+    IFC,
+};
+
+/// An IfcClassification is used for the arrangement of objects into a class
+/// or category according to a common purpose or their possession of common
+/// characteristics, e.g. Uniclass, OmniClass or DIN 276.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcclassification.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Classification {
+    /// Source (or publisher) for this classification.
+    pub source: OptionalParameter<Label>,
+    /// The edition or version of the classification system from which the
+    /// classification notation is derived.
+    pub edition: OptionalParameter<Label>,
+    /// The date on which the edition of the classification used became valid.
+    pub edition_date: OptionalParameter<Label>,
+    /// The name or label by which the classification used is normally known.
+    pub name: Label,
+    /// Additional description provided for the classification.
+    pub description: OptionalParameter<Label>,
+    /// Resource identifier or locator, provided as URI, URN or URL, of the
+    /// classification.
+    pub location: OptionalParameter<Label>,
+    /// The delimiter tokens that are used to mark the boundaries of
+    /// individual facets (substrings) in a classification reference.
+    pub reference_tokens: OptionalParameter<IfcList<Label>>,
+}
+
+impl Classification {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            source: OptionalParameter::omitted(),
+            edition: OptionalParameter::omitted(),
+            edition_date: OptionalParameter::omitted(),
+            name: name.into(),
+            description: OptionalParameter::omitted(),
+            location: OptionalParameter::omitted(),
+            reference_tokens: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn source(mut self, source: impl Into<Label>) -> Self {
+        self.source = source.into().into();
+        self
+    }
+
+    pub fn edition(mut self, edition: impl Into<Label>) -> Self {
+        self.edition = edition.into().into();
+        self
+    }
+
+    pub fn edition_date(mut self, edition_date: impl Into<Label>) -> Self {
+        self.edition_date = edition_date.into().into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<Label>) -> Self {
+        self.description = description.into().into();
+        self
+    }
+
+    pub fn location(mut self, location: impl Into<Label>) -> Self {
+        self.location = location.into().into();
+        self
+    }
+}
+
+impl IFCParse for Classification {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCLASSIFICATION("),
+
+                source: OptionalParameter::parse(),
+                _: Comma::parse(),
+                edition: OptionalParameter::parse(),
+                _: Comma::parse(),
+                edition_date: OptionalParameter::parse(),
+                _: Comma::parse(),
+                name: Label::parse(),
+                _: Comma::parse(),
+                description: OptionalParameter::parse(),
+                _: Comma::parse(),
+                location: OptionalParameter::parse(),
+                _: Comma::parse(),
+                reference_tokens: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for Classification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCLASSIFICATION({},{},{},{},{},{},{});",
+            self.source,
+            self.edition,
+            self.edition_date,
+            self.name,
+            self.description,
+            self.location,
+            self.reference_tokens,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcClassification")]
+impl IfcType for Classification {}
+impl RelatableClassification for Classification {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Classification;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn classification_round_trip() {
+        let examples = [
+            "IFCCLASSIFICATION('NBS','2015','2015-01-01','Uniclass',$,'https://www.thenbs.com/our-tools/uniclass-2015',('_'));",
+            "IFCCLASSIFICATION($,'2018-12',$,'DIN 276',$,$,$);",
+        ];
+
+        for example in examples {
+            let parsed: Classification = Classification::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::rel_associates_classification::RelatableClassification,
+};
+
+/// An IfcClassificationReference is a reference into a classification
+/// system or source, e.g. the code of a single class of a classification.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcclassificationreference.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct ClassificationReference {
+    /// Location, where the external source (classification, document or
+    /// library) can be accessed by electronic means.
+    pub location: OptionalParameter<Label>,
+    /// The identifier of the class within the classification system, e.g.
+    /// the notation `Ss_25_10_30` or the cost group `331`.
+    pub identification: OptionalParameter<Label>,
+    /// Optional name to further specify the reference, e.g. the class title.
+    pub name: OptionalParameter<Label>,
+    /// The classification system or the parent classification reference
+    /// from which the classification notation is derived.
+    #[ifc_types(Classification, ClassificationReference)]
+    pub referenced_source: OptionalParameter<Id>,
+    /// Description of the classification reference.
+    pub description: OptionalParameter<Label>,
+    /// Optional identifier to sort classification references.
+    pub sort: OptionalParameter<Label>,
+}
+
+impl ClassificationReference {
+    pub fn new(identification: impl Into<Label>) -> Self {
+        Self {
+            location: OptionalParameter::omitted(),
+            identification: identification.into().into(),
+            name: OptionalParameter::omitted(),
+            referenced_source: OptionalParameter::omitted(),
+            description: OptionalParameter::omitted(),
+            sort: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<Label>) -> Self {
+        self.name = name.into().into();
+        self
+    }
+
+    pub fn location(mut self, location: impl Into<Label>) -> Self {
+        self.location = location.into().into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<Label>) -> Self {
+        self.description = description.into().into();
+        self
+    }
+
+    pub fn referenced_source<S: RelatableClassification>(
+        mut self,
+        referenced_source: impl Into<IdOr<S>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.referenced_source = referenced_source.into().or_insert(ifc).id().into();
+        self
+    }
+
+    /// The classification system the reference belongs to, following the
+    /// chain of parent references.
+    pub fn classification<'a>(&'a self, ifc: &'a IFC) -> Option<&'a Classification> {
+        let source = ifc.data.get_untyped(*self.referenced_source.custom()?);
+
+        match source.downcast_ref::<Classification>() {
+            Some(classification) => Some(classification),
+            None => source
+                .downcast_ref::<ClassificationReference>()
+                .and_then(|parent| parent.classification(ifc)),
+        }
+    }
+}
+
+impl IFCParse for ClassificationReference {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCLASSIFICATIONREFERENCE("),
+
+                location: OptionalParameter::parse(),
+                _: Comma::parse(),
+                identification: OptionalParameter::parse(),
+                _: Comma::parse(),
+                name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                referenced_source: OptionalParameter::parse(),
+                _: Comma::parse(),
+                description: OptionalParameter::parse(),
+                _: Comma::parse(),
+                sort: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for ClassificationReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCLASSIFICATIONREFERENCE({},{},{},{},{},{});",
+            self.location,
+            self.identification,
+            self.name,
+            self.referenced_source,
+            self.description,
+            self.sort,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcClassificationReference")]
+impl IfcType for ClassificationReference {}
+impl RelatableClassification for ClassificationReference {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::ClassificationReference;
+    use crate::{parser::IFCParse, prelude::*};
+
+    #[test]
+    fn classification_reference_round_trip() {
+        let examples = [
+            "IFCCLASSIFICATIONREFERENCE($,'000','Allgemeines',$,$,$);",
+            "IFCCLASSIFICATIONREFERENCE($,'331','Tragende Aussenwaende',#42,$,$);",
+        ];
+
+        for example in examples {
+            let parsed: ClassificationReference =
+                ClassificationReference::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn classification_of_nested_reference() {
+        let mut ifc = IFC::default();
+
+        let din_276 = ifc.data.insert_new(Classification::new("DIN 276"));
+        let group = ClassificationReference::new("330")
+            .name("Aussenwaende")
+            .referenced_source(din_276, &mut ifc);
+        let reference = ClassificationReference::new("331")
+            .name("Tragende Aussenwaende")
+            .referenced_source(group, &mut ifc);
+
+        let classification = reference.classification(&ifc).unwrap();
+        assert_eq!(classification.name.0, "DIN 276");
+    }
+}
@@ -0,0 +1,23 @@
+use comma::Comma;
+use optional::OptionalParameter;
+use winnow::combinator::alt;
+
+use crate::{objects::shared::element::Element, parser::*};
+
+use super::Column;
+
+impl IFCParse for Column {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: alt((p_space_or_comment_surrounded("IFCCOLUMN("), p_space_or_comment_surrounded("IFCCOLUMNSTANDARDCASE("))),
+
+                element: Element::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
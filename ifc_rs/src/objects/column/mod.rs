@@ -0,0 +1,153 @@
+mod deserialize;
+mod serialize;
+
+use std::ops::{Deref, DerefMut};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{label::Label, optional::OptionalParameter},
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable, rel_connects_elements::ConnectionRelatable,
+        rel_space_boundary::SpaceBoundaryRelatable,
+    },
+};
+
+use super::StructureType;
+
+/// An IfcColumn is a vertical structural member which often is aligned with a
+/// structural grid intersection. It represents a vertical, or nearly
+/// vertical, element that transmits, through compression, the weight of the
+/// structure above to other structural elements below. It represents such a
+/// member from an architectural point of view. It is not required to be load
+/// bearing.
+///
+/// The representation of a column is usually given by an extrusion of a
+/// profile along the column axis. A common profile may be assigned through an
+/// IfcMaterialProfileSetUsage, using the IfcRelAssociatesMaterial
+/// relationship.
+///
+/// A particular usage type for the IfcColumn can be given (if type
+/// information is available) by referring to the type object IfcColumnType,
+/// using the `IfcRelDefinesByType` relationship, or (if only occurrence
+/// information is given) by using the PredefinedType attribute.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifccolumn.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Column {
+    #[inherited]
+    element: Element,
+
+    /// Predefined generic type for a column that is specified in an
+    /// enumeration. There may be a property set given specificly for the
+    /// predefined types.
+    ///
+    /// Note: The use of the predefined type directly at the occurrence object
+    /// level of IfcColumn is only permitted, if no type object `IfcColumnType`
+    /// is assigned.
+    pub predefined_type: OptionalParameter<ColumnTypeEnum>,
+}
+
+impl Column {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+            predefined_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn predefined_type(mut self, column_type: ColumnTypeEnum) -> Self {
+        self.predefined_type = column_type.into();
+        self
+    }
+}
+
+impl RootBuilder for Column {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for Column {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for Column {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for Column {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for Column {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for Column {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+#[typetag::serde(name = "IfcColumn")]
+impl IfcType for Column {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for Column {
+    fn structure_type(&self) -> Option<StructureType<'_>> {
+        Some(StructureType::Column(self))
+    }
+}
+impl MaterialRelatable for Column {}
+impl ClassificationRelatable for Column {}
+impl GroupRelatable for Column {}
+impl SpaceBoundaryRelatable for Column {}
+impl ConnectionRelatable for Column {}
+
+impl TransformableType for Column {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Column;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn column_round_trip() {
+        let examples = [
+            "IFCCOLUMN('2Ax5kI9xP1SgHnCeMZRXbu',#2,'Column',$,$,#35,#52,$,$);",
+            "IFCCOLUMN('0w6lsUbL9BAe6FEzH6lTAl',#12,'Stuetze-001',$,$,#61234,#61265,'3A9C2F4E-7B1D-4E20-8C-3B-0F6A2D9E1C55',.COLUMN.);",
+        ];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let column: Column = Column::parse().parse(example).unwrap();
+            let str_column = column.to_string();
+
+            assert_eq!(example, str_column, "example {} failed", index);
+        }
+    }
+}
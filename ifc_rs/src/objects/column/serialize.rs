@@ -0,0 +1,9 @@
+use std::fmt::Display;
+
+use super::Column;
+
+impl Display for Column {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCCOLUMN({},{});", self.element, self.predefined_type,)
+    }
+}
@@ -0,0 +1,22 @@
+use comma::Comma;
+
+use crate::objects::columntype::type_enum::ColumnTypeEnum;
+use crate::{objects::shared::element_type::ElementType, parser::*};
+
+use super::ColumnType;
+
+impl IFCParse for ColumnType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCOLUMNTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: ColumnTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::ops::Deref;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+use type_enum::ColumnTypeEnum;
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::label::Label,
+    prelude::{ElementTypeBuilder, Root, RootBuilder, TypeObject, TypeProduct},
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+    IFC,
+};
+
+use super::shared::{
+    element_type::ElementType, type_object::TypeObjectBuilder, type_product::TypeProductBuilder,
+};
+
+mod deserialize;
+mod serialize;
+pub mod type_enum;
+
+/// The element type IfcColumnType defines commonly shared information for
+/// occurrences of columns. The set of shared information may include:
+///
+/// * common properties within shared property sets
+/// * common material information
+/// * common profile definitions
+/// * common shape representations
+///
+/// It is used to define a column specification (i.e. the specific product
+/// information, that is common to all occurrences of that product type).
+/// Column types may be exchanged without being already assigned to
+/// occurrences.
+///
+/// The occurrences of the IfcColumnType within building models are
+/// represented by instances of IfcColumn or IfcColumnStandardCase.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifccolumntype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct ColumnType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined types of a column element from which the
+    /// type required may be set.
+    pub predefined_type: ColumnTypeEnum,
+}
+
+impl ColumnType {
+    pub fn new(name: impl Into<Label>, predefined_type: ColumnTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for ColumnType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for ColumnType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for ColumnType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for ColumnType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for ColumnType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+#[typetag::serde(name = "IfcColumnType")]
+impl IfcType for ColumnType {}
+impl MaterialRelatable for ColumnType {}
+impl ClassificationRelatable for ColumnType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use crate::parser::IFCParse;
+
+    use super::ColumnType;
+
+    #[test]
+    fn column_type_round_trip() {
+        let example = "IFCCOLUMNTYPE('1Ru9oBL2j3qOGTlRk2AaxZ',#2,'HEB 240',$,$,$,$,$,$,.COLUMN.);";
+
+        let column_type: ColumnType = ColumnType::parse().parse(example).unwrap();
+        let str_column_type = column_type.to_string();
+
+        assert_eq!(example, str_column_type);
+    }
+}
@@ -0,0 +1,13 @@
+use std::fmt::Display;
+
+use super::ColumnType;
+
+impl Display for ColumnType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCOLUMNTYPE({},{});",
+            self.element_type, self.predefined_type,
+        )
+    }
+}
@@ -0,0 +1,49 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the different predefined types of an IfcColumn or
+/// IfcColumnType object can fulfill.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifccolumntypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
+pub enum ColumnTypeEnum {
+    /// A standard member usually vertical and requiring resistance to vertical
+    /// forces by compression but also sometimes to lateral forces.
+    #[strum(to_string = ".COLUMN.")]
+    Column,
+
+    /// A member usually vertical and requiring resistance to vertical forces by
+    /// compression but also sometimes to lateral forces. It is sometimes used for
+    /// decorative purposes and often integrated in a wall.
+    #[strum(to_string = ".PILASTER.")]
+    Pilaster,
+
+    /// User-defined linear element.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined linear element.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for ColumnTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid ColumnTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
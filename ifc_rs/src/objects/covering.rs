@@ -0,0 +1,156 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+/// A covering is an element which covers some part of another element and
+/// is fully dependent on that other element. Coverings include flooring,
+/// ceilings and claddings.
+///
+/// Coverings of building elements are assigned with
+/// `IfcRelCoversBldgElements`, coverings of spaces with
+/// `IfcRelCoversSpaces`.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccovering.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Covering {
+    #[inherited]
+    element: Element,
+
+    /// Predefined generic type of the covering.
+    ///
+    /// Note: The use of the predefined type directly at the occurrence object
+    /// level is only permitted, if no type object `IfcCoveringType` is
+    /// assigned.
+    pub predefined_type: OptionalParameter<CoveringTypeEnum>,
+}
+
+impl Covering {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+            predefined_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn predefined_type(mut self, predefined_type: CoveringTypeEnum) -> Self {
+        self.predefined_type = predefined_type.into();
+        self
+    }
+}
+
+impl RootBuilder for Covering {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for Covering {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for Covering {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for Covering {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for Covering {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for Covering {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl IFCParse for Covering {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCOVERING("),
+
+                element: Element::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for Covering {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCCOVERING({},{});", self.element, self.predefined_type)
+    }
+}
+
+#[typetag::serde(name = "IfcCovering")]
+impl IfcType for Covering {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for Covering {}
+impl MaterialRelatable for Covering {}
+impl ClassificationRelatable for Covering {}
+impl GroupRelatable for Covering {}
+
+impl TransformableType for Covering {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Covering;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn covering_round_trip() {
+        let examples = [
+            "IFCCOVERING('2Yq0b9cX3mNb2Fh7Jd1pQ4',#2,'Parquet',$,$,#37,#54,$,.FLOORING.);",
+            "IFCCOVERING('0cR4aW7Hd2pX9sL0mNb3Fh',#12,'Decke',$,$,#70311,#70342,'D-01',$);",
+        ];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: Covering = Covering::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
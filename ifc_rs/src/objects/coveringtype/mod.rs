@@ -0,0 +1,125 @@
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use type_enum::CoveringTypeEnum;
+
+/// The element type IfcCoveringType defines commonly shared information for
+/// occurrences of coverings. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcCovering.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccoveringtype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct CoveringType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined type of the covering.
+    pub predefined_type: CoveringTypeEnum,
+}
+
+impl CoveringType {
+    pub fn new(name: impl Into<Label>, predefined_type: CoveringTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for CoveringType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for CoveringType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for CoveringType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for CoveringType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for CoveringType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for CoveringType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCOVERINGTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: CoveringTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for CoveringType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCOVERINGTYPE({},{});",
+            self.element_type, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcCoveringType")]
+impl IfcType for CoveringType {}
+impl MaterialRelatable for CoveringType {}
+impl ClassificationRelatable for CoveringType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::CoveringType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn covering_type_round_trip() {
+        let example =
+            "IFCCOVERINGTYPE('0Xb7aT2mD4hQ9vL1kC8nRe',#2,'Parquet',$,$,$,$,$,$,.FLOORING.);";
+
+        let parsed: CoveringType = CoveringType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
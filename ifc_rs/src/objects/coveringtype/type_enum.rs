@@ -0,0 +1,81 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the different types of coverings.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccoveringtypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum CoveringTypeEnum {
+    /// The covering is used to represent a ceiling.
+    #[strum(to_string = ".CEILING.")]
+    Ceiling,
+
+    /// The covering is used to represent a flooring.
+    #[strum(to_string = ".FLOORING.")]
+    Flooring,
+
+    /// The covering is used to represent a cladding.
+    #[strum(to_string = ".CLADDING.")]
+    Cladding,
+
+    /// The covering is used to represent a roof.
+    #[strum(to_string = ".ROOFING.")]
+    Roofing,
+
+    /// The covering is used to represent a molding being a strip of material
+    /// to cover the transition of surfaces (often between wall cladding and
+    /// ceiling).
+    #[strum(to_string = ".MOLDING.")]
+    Molding,
+
+    /// The covering is used to represent a skirting board being a strip of
+    /// material to cover the transition between the wall cladding and the
+    /// flooring.
+    #[strum(to_string = ".SKIRTINGBOARD.")]
+    SkirtingBoard,
+
+    /// The covering is used for thermal or acoustic insulation.
+    #[strum(to_string = ".INSULATION.")]
+    Insulation,
+
+    /// The covering is used for waterproofing or vapour barrier.
+    #[strum(to_string = ".MEMBRANE.")]
+    Membrane,
+
+    /// The covering is used for insulating or protecting a pipe or duct.
+    #[strum(to_string = ".SLEEVING.")]
+    Sleeving,
+
+    /// The covering is used for wrapping particularly of a pipe or duct.
+    #[strum(to_string = ".WRAPPING.")]
+    Wrapping,
+
+    /// User-defined type.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined type.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for CoveringTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid CoveringTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
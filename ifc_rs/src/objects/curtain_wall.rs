@@ -0,0 +1,158 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+/// A curtain wall is an exterior wall of a building which is an assembly of
+/// components, hung from the edge of the floor/roof structure rather than
+/// bearing on a floor.
+///
+/// The curtain wall is a container aggregating its parts, the panels and
+/// mullions, with `IfcRelAggregates`. The parts carry the shape
+/// representations, the curtain wall itself may have none.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccurtainwall.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct CurtainWall {
+    #[inherited]
+    element: Element,
+
+    /// Predefined generic type of the curtain wall.
+    ///
+    /// Note: The use of the predefined type directly at the occurrence object
+    /// level is only permitted, if no type object `IfcCurtainWallType` is
+    /// assigned.
+    pub predefined_type: OptionalParameter<CurtainWallTypeEnum>,
+}
+
+impl CurtainWall {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+            predefined_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn predefined_type(mut self, predefined_type: CurtainWallTypeEnum) -> Self {
+        self.predefined_type = predefined_type.into();
+        self
+    }
+}
+
+impl RootBuilder for CurtainWall {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for CurtainWall {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for CurtainWall {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for CurtainWall {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for CurtainWall {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for CurtainWall {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl IFCParse for CurtainWall {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCURTAINWALL("),
+
+                element: Element::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for CurtainWall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCURTAINWALL({},{});",
+            self.element, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcCurtainWall")]
+impl IfcType for CurtainWall {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for CurtainWall {}
+impl MaterialRelatable for CurtainWall {}
+impl ClassificationRelatable for CurtainWall {}
+impl GroupRelatable for CurtainWall {}
+
+impl TransformableType for CurtainWall {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::CurtainWall;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn curtain_wall_round_trip() {
+        let examples =
+            ["IFCCURTAINWALL('1pQ4aV9sL0cX3mNb2Fh7Jd',#2,'Facade',$,$,#37,$,$,.NOTDEFINED.);"];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: CurtainWall = CurtainWall::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use type_enum::CurtainWallTypeEnum;
+
+/// The element type IfcCurtainWallType defines commonly shared information for
+/// occurrences of curtain walls. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcCurtainWall.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccurtainwalltype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct CurtainWallType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined type of the curtain wall.
+    pub predefined_type: CurtainWallTypeEnum,
+}
+
+impl CurtainWallType {
+    pub fn new(name: impl Into<Label>, predefined_type: CurtainWallTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for CurtainWallType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for CurtainWallType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for CurtainWallType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for CurtainWallType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for CurtainWallType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for CurtainWallType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCURTAINWALLTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: CurtainWallTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for CurtainWallType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCURTAINWALLTYPE({},{});",
+            self.element_type, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcCurtainWallType")]
+impl IfcType for CurtainWallType {}
+impl MaterialRelatable for CurtainWallType {}
+impl ClassificationRelatable for CurtainWallType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::CurtainWallType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn curtain_wall_type_round_trip() {
+        let example =
+            "IFCCURTAINWALLTYPE('2Wd5Lk8pF3tR0xN7aQ1cVm',#2,'Facade',$,$,$,$,$,$,.USERDEFINED.);";
+
+        let parsed: CurtainWallType = CurtainWallType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,48 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the flow direction at a connection point, as
+/// seen from the element the port belongs to.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcflowdirectionenum.htm
+#[derive(
+    EnumString, VariantNames, Display, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum FlowDirectionEnum {
+    /// The flow leaves the element at the port.
+    #[strum(to_string = ".SOURCE.")]
+    Source,
+
+    /// The flow enters the element at the port.
+    #[strum(to_string = ".SINK.")]
+    Sink,
+
+    /// The flow may leave or enter the element at the port.
+    #[strum(to_string = ".SOURCEANDSINK.")]
+    SourceAndSink,
+
+    /// The flow direction is not defined.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for FlowDirectionEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid FlowDirectionEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
@@ -0,0 +1,160 @@
+pub mod flow_direction_enum;
+pub mod type_enum;
+
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+use flow_direction_enum::FlowDirectionEnum;
+use type_enum::DistributionPortTypeEnum;
+
+/// A distribution port is an inlet or outlet of a distribution element,
+/// through which a particular substance may flow. Ports are nested to their
+/// element with `IfcRelNests` and connected to the ports of other elements
+/// with `IfcRelConnectsPorts`. The placement of a port is usually relative
+/// to the placement of its element.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcdistributionport.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct DistributionPort {
+    #[inherited]
+    product: Product,
+
+    /// Enumeration that identifies if this port is a Sink (inlet), a Source
+    /// (outlet) or both a SinkAndSource.
+    pub flow_direction: OptionalParameter<FlowDirectionEnum>,
+
+    /// Enumeration that identifies the type of segment, which is connected
+    /// to the port.
+    pub predefined_type: OptionalParameter<DistributionPortTypeEnum>,
+
+    /// Enumeration that identifies the system type. If a system type is
+    /// defined, the port may only be connected to ports of the same system
+    /// type.
+    pub system_type: OptionalParameter<DistributionSystemEnum>,
+}
+
+impl DistributionPort {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            product: Product::new(Object::new(Root::new(name.into()))),
+            flow_direction: OptionalParameter::omitted(),
+            predefined_type: OptionalParameter::omitted(),
+            system_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn flow_direction(mut self, flow_direction: FlowDirectionEnum) -> Self {
+        self.flow_direction = flow_direction.into();
+        self
+    }
+
+    pub fn predefined_type(mut self, predefined_type: DistributionPortTypeEnum) -> Self {
+        self.predefined_type = predefined_type.into();
+        self
+    }
+
+    pub fn system_type(mut self, system_type: DistributionSystemEnum) -> Self {
+        self.system_type = system_type.into();
+        self
+    }
+}
+
+impl RootBuilder for DistributionPort {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.product
+    }
+}
+
+impl ObjectBuilder for DistributionPort {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.product
+    }
+}
+
+impl ProductBuilder for DistributionPort {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.product
+    }
+}
+
+impl Deref for DistributionPort {
+    type Target = Product;
+
+    fn deref(&self) -> &Self::Target {
+        &self.product
+    }
+}
+
+impl DerefMut for DistributionPort {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.product
+    }
+}
+
+impl IFCParse for DistributionPort {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCDISTRIBUTIONPORT("),
+
+                product: Product::parse(),
+                _: Comma::parse(),
+                flow_direction: OptionalParameter::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+                _: Comma::parse(),
+                system_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for DistributionPort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCDISTRIBUTIONPORT({},{},{},{});",
+            self.product, self.flow_direction, self.predefined_type, self.system_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcDistributionPort")]
+impl IfcType for DistributionPort {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::DistributionPort;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn distribution_port_round_trip() {
+        let examples = [
+            "IFCDISTRIBUTIONPORT('0xTq5m2L97WhC$3nUf8RkS',#2,'Inlet',$,$,#120,$,.SINK.,.PIPE.,.HEATING.);",
+            "IFCDISTRIBUTIONPORT('2Fh7Jd1pQ4aV9sL0cX3mNb',#2,'Port',$,$,$,$,$,$,$);",
+        ];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: DistributionPort = DistributionPort::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration identifies the different types of distribution ports,
+/// being the kind of segment which is connected to the port.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcdistributionporttypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum DistributionPortTypeEnum {
+    /// A port for cables.
+    #[strum(to_string = ".CABLE.")]
+    Cable,
+
+    /// A port for cable carriers, e.g. cable trays.
+    #[strum(to_string = ".CABLECARRIER.")]
+    CableCarrier,
+
+    /// A port for ducts.
+    #[strum(to_string = ".DUCT.")]
+    Duct,
+
+    /// A port for pipes.
+    #[strum(to_string = ".PIPE.")]
+    Pipe,
+
+    /// User-defined type.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined type.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for DistributionPortTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| {
+                    (
+                        v,
+                        Self::from_str(v).expect("valid DistributionPortTypeEnum"),
+                    )
+                })
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
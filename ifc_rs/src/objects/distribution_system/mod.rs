@@ -0,0 +1,136 @@
+pub mod type_enum;
+
+use std::ops::DerefMut;
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::rel_assigns_to_group::RelatableGroup,
+};
+
+use type_enum::DistributionSystemEnum;
+
+/// A distribution system is a network designed to receive, store, maintain,
+/// distribute or control the flow of a distribution media, e.g. the pipes
+/// of a heating system or the ducts of a ventilation system. The
+/// distribution elements of the system are assigned with
+/// `IfcRelAssignsToGroup`.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcdistributionsystem.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct DistributionSystem {
+    #[inherited]
+    object: Object,
+
+    /// Long name for a distribution system, used for informal purposes. It
+    /// should be used, if available, in conjunction with the inherited Name
+    /// attribute.
+    pub long_name: OptionalParameter<Label>,
+
+    /// Predefined types of distribution systems.
+    pub predefined_type: OptionalParameter<DistributionSystemEnum>,
+}
+
+impl DistributionSystem {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            object: Object::new(Root::new(name.into())),
+            long_name: OptionalParameter::omitted(),
+            predefined_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn long_name(mut self, long_name: impl Into<Label>) -> Self {
+        self.long_name = long_name.into().into();
+        self
+    }
+
+    pub fn predefined_type(mut self, predefined_type: DistributionSystemEnum) -> Self {
+        self.predefined_type = predefined_type.into();
+        self
+    }
+}
+
+impl RootBuilder for DistributionSystem {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.object
+    }
+}
+
+impl ObjectBuilder for DistributionSystem {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.object
+    }
+}
+
+impl Deref for DistributionSystem {
+    type Target = Object;
+
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl DerefMut for DistributionSystem {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.object
+    }
+}
+
+impl IFCParse for DistributionSystem {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCDISTRIBUTIONSYSTEM("),
+
+                object: Object::parse(),
+                _: Comma::parse(),
+                long_name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for DistributionSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCDISTRIBUTIONSYSTEM({},{},{});",
+            self.object, self.long_name, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcDistributionSystem")]
+impl IfcType for DistributionSystem {}
+impl RelatableGroup for DistributionSystem {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::DistributionSystem;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn distribution_system_round_trip() {
+        let example =
+            "IFCDISTRIBUTIONSYSTEM('1B3kQm4vX0Hg8tWz5LcN2p',#2,'Heating',$,$,'Heating circuit 1',.HEATING.);";
+
+        let distribution_system: DistributionSystem =
+            DistributionSystem::parse().parse(example).unwrap();
+        assert_eq!(example, distribution_system.to_string());
+    }
+}
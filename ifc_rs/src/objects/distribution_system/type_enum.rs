@@ -0,0 +1,206 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration identifies the different types of distribution
+/// systems, e.g. the medium distributed by a system of pipes or ducts.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcdistributionsystemenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum DistributionSystemEnum {
+    /// Heating, cooling and ventilation of the air.
+    #[strum(to_string = ".AIRCONDITIONING.")]
+    AirConditioning,
+
+    /// Audio and video distribution.
+    #[strum(to_string = ".AUDIOVISUAL.")]
+    AudioVisual,
+
+    /// Distribution of chemicals.
+    #[strum(to_string = ".CHEMICAL.")]
+    Chemical,
+
+    /// Chilled water for cooling.
+    #[strum(to_string = ".CHILLEDWATER.")]
+    ChilledWater,
+
+    /// Communication, e.g. intercoms and paging.
+    #[strum(to_string = ".COMMUNICATION.")]
+    Communication,
+
+    /// Distribution of compressed air.
+    #[strum(to_string = ".COMPRESSEDAIR.")]
+    CompressedAir,
+
+    /// Water from condensers of cooling plants.
+    #[strum(to_string = ".CONDENSERWATER.")]
+    CondenserWater,
+
+    /// Signals of building automation and control.
+    #[strum(to_string = ".CONTROL.")]
+    Control,
+
+    /// Transport of goods or people, e.g. pneumatic tubes.
+    #[strum(to_string = ".CONVEYING.")]
+    Conveying,
+
+    /// Data networks.
+    #[strum(to_string = ".DATA.")]
+    Data,
+
+    /// Disposal of waste.
+    #[strum(to_string = ".DISPOSAL.")]
+    Disposal,
+
+    /// Cold drinking water.
+    #[strum(to_string = ".DOMESTICCOLDWATER.")]
+    DomesticColdWater,
+
+    /// Hot drinking water.
+    #[strum(to_string = ".DOMESTICHOTWATER.")]
+    DomesticHotWater,
+
+    /// Drainage of water.
+    #[strum(to_string = ".DRAINAGE.")]
+    Drainage,
+
+    /// Earthing of electrical installations.
+    #[strum(to_string = ".EARTHING.")]
+    Earthing,
+
+    /// Electrical power distribution.
+    #[strum(to_string = ".ELECTRICAL.")]
+    Electrical,
+
+    /// Public address and sound systems.
+    #[strum(to_string = ".ELECTROACOUSTIC.")]
+    ElectroAcoustic,
+
+    /// Exhaust air.
+    #[strum(to_string = ".EXHAUST.")]
+    Exhaust,
+
+    /// Fire protection, e.g. sprinklers.
+    #[strum(to_string = ".FIREPROTECTION.")]
+    FireProtection,
+
+    /// Fuel supply.
+    #[strum(to_string = ".FUEL.")]
+    Fuel,
+
+    /// Gas supply.
+    #[strum(to_string = ".GAS.")]
+    Gas,
+
+    /// Hazardous substances.
+    #[strum(to_string = ".HAZARDOUS.")]
+    Hazardous,
+
+    /// Heating, e.g. hot water heating.
+    #[strum(to_string = ".HEATING.")]
+    Heating,
+
+    /// Lighting installations.
+    #[strum(to_string = ".LIGHTING.")]
+    Lighting,
+
+    /// Lightning protection.
+    #[strum(to_string = ".LIGHTNINGPROTECTION.")]
+    LightningProtection,
+
+    /// Collection of solid waste.
+    #[strum(to_string = ".MUNICIPALSOLIDWASTE.")]
+    MunicipalSolidWaste,
+
+    /// Oil supply.
+    #[strum(to_string = ".OIL.")]
+    Oil,
+
+    /// Operational fluids, e.g. hydraulics.
+    #[strum(to_string = ".OPERATIONAL.")]
+    Operational,
+
+    /// Generation of electrical power.
+    #[strum(to_string = ".POWERGENERATION.")]
+    PowerGeneration,
+
+    /// Collection of rain water.
+    #[strum(to_string = ".RAINWATER.")]
+    RainWater,
+
+    /// Refrigerants of cooling plants.
+    #[strum(to_string = ".REFRIGERATION.")]
+    Refrigeration,
+
+    /// Security installations, e.g. access control.
+    #[strum(to_string = ".SECURITY.")]
+    Security,
+
+    /// Sewage.
+    #[strum(to_string = ".SEWAGE.")]
+    Sewage,
+
+    /// Signal transmission.
+    #[strum(to_string = ".SIGNAL.")]
+    Signal,
+
+    /// Storm water.
+    #[strum(to_string = ".STORMWATER.")]
+    StormWater,
+
+    /// Telephone networks.
+    #[strum(to_string = ".TELEPHONE.")]
+    Telephone,
+
+    /// Television distribution.
+    #[strum(to_string = ".TV.")]
+    Tv,
+
+    /// Vacuum, e.g. central vacuum cleaning.
+    #[strum(to_string = ".VACUUM.")]
+    Vacuum,
+
+    /// Venting of drainage systems.
+    #[strum(to_string = ".VENT.")]
+    Vent,
+
+    /// Ventilation with supply and extract air.
+    #[strum(to_string = ".VENTILATION.")]
+    Ventilation,
+
+    /// Waste water.
+    #[strum(to_string = ".WASTEWATER.")]
+    WasteWater,
+
+    /// Water supply.
+    #[strum(to_string = ".WATERSUPPLY.")]
+    WaterSupply,
+
+    /// User-defined type.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined type.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for DistributionSystemEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid DistributionSystemEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
@@ -0,0 +1,182 @@
+mod deserialize;
+mod serialize;
+
+use std::ops::{Deref, DerefMut};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    shared::{
+        element::{Element, ElementBuilder},
+        object::{Object, ObjectBuilder},
+        product::{Product, ProductBuilder},
+        root::{Root, RootBuilder},
+    },
+    Structure, StructureType,
+};
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    objects::doortype::{
+        door_type_enum::DoorTypeEnum, door_type_operation_enum::DoorTypeOperationEnum,
+    },
+    parser::{ifc_float::IfcFloat, label::Label, optional::OptionalParameter},
+    prelude::{ProductDefinitionShape, TransformableType},
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable, rel_connects_elements::ConnectionRelatable,
+        rel_space_boundary::SpaceBoundaryRelatable,
+    },
+    IFC,
+};
+
+/// The door is a built element that is predominately used to provide
+/// controlled access for people, goods, animals and vehicles. It includes
+/// constructions with hinged, pivoted, sliding, and additionally revolving
+/// and folding operations. A door consists of a lining and one or several
+/// panels.
+///
+/// The IfcDoor defines a particular occurrence of a door inserted in the
+/// spatial context of a project. A door can:
+///
+/// * be inserted into an IfcOpeningElement using the IfcRelFillsElement
+///   relationship, then the IfcDoor has an inverse attribute FillsVoids provided,
+/// * be part of an element assembly, often an IfcCurtainWall, using the
+///   IfcRelAggregates relationship, then the inverse attribute Decomposes is provided.
+/// * or be a "free standing" door, then the IfcDoor has no inverse
+///   attributes FillsVoids or Decomposes provided.
+///
+/// The actual parameter of the door and/or its shape is defined at the
+/// IfcDoor as the occurrence definition (or project instance), or by the
+/// IfcDoorType as the specific definition (or project type). The IfcDoor
+/// specifies the door width and height and the door opening direction (by
+/// the positive y-axis of the ObjectPlacement), the IfcDoorType the
+/// operation type (swing, sliding, folding, etc.) and the hinge side.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifcdoor.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Door {
+    #[inherited]
+    element: Element,
+
+    /// Overall measure of the height, it reflects the Z Dimension of a bounding
+    /// box, enclosing the body of the door opening. If omitted, the
+    /// OverallHeight should be taken from the geometric representation of the
+    /// IfcOpening in which the door is inserted.
+    pub overall_height: OptionalParameter<IfcFloat>,
+
+    /// Overall measure of the width, it reflects the X Dimension of a bounding
+    /// box, enclosing the body of the door opening. If omitted, the
+    /// OverallWidth should be taken from the geometric representation of the
+    /// IfcOpening in which the door is inserted.
+    pub overall_width: OptionalParameter<IfcFloat>,
+
+    /// Predefined generic type for a door that is specified in an
+    /// enumeration. There may be a property set given specificly for the
+    /// predefined types.
+    pub predefined_type: OptionalParameter<DoorTypeEnum>,
+
+    /// Type defining the general layout and operation of the door type in
+    /// terms of the partitioning of panels and panel operations.
+    pub operation_type: OptionalParameter<DoorTypeOperationEnum>,
+
+    /// Designator for the user defined operation type, shall only be
+    /// provided, if the value of OperationType is set to USERDEFINED.
+    pub user_defined_operation_type: OptionalParameter<Label>,
+}
+
+impl Door {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+            overall_height: OptionalParameter::omitted(),
+            overall_width: OptionalParameter::omitted(),
+            predefined_type: OptionalParameter::omitted(),
+            operation_type: OptionalParameter::omitted(),
+            user_defined_operation_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn overall_height(mut self, overall_height: impl Into<IfcFloat>) -> Self {
+        self.overall_height = overall_height.into().into();
+        self
+    }
+
+    pub fn overall_width(mut self, overall_width: impl Into<IfcFloat>) -> Self {
+        self.overall_width = overall_width.into().into();
+        self
+    }
+
+    pub fn predefined_type(mut self, predefined_type: impl Into<DoorTypeEnum>) -> Self {
+        self.predefined_type = predefined_type.into().into();
+        self
+    }
+
+    pub fn operation_type(mut self, operation_type: impl Into<DoorTypeOperationEnum>) -> Self {
+        self.operation_type = operation_type.into().into();
+        self
+    }
+}
+
+impl RootBuilder for Door {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for Door {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for Door {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for Door {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for Door {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for Door {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+#[typetag::serde(name = "IfcDoor")]
+impl IfcType for Door {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for Door {
+    fn structure_type(&self) -> Option<StructureType<'_>> {
+        Some(StructureType::Door(self))
+    }
+}
+impl MaterialRelatable for Door {}
+impl ClassificationRelatable for Door {}
+impl GroupRelatable for Door {}
+impl SpaceBoundaryRelatable for Door {}
+impl ConnectionRelatable for Door {}
+
+impl TransformableType for Door {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
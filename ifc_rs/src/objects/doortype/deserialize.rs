@@ -0,0 +1,34 @@
+use comma::Comma;
+use optional::OptionalParameter;
+
+use crate::{
+    objects::{
+        doortype::{door_type_enum::DoorTypeEnum, door_type_operation_enum::DoorTypeOperationEnum},
+        shared::element_type::ElementType,
+    },
+    parser::*,
+};
+
+use super::DoorType;
+
+impl IFCParse for DoorType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCDOORTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: DoorTypeEnum::parse(),
+                _: Comma::parse(),
+                operation_type: DoorTypeOperationEnum::parse(),
+                _: Comma::parse(),
+                parameter_takes_precedence: OptionalParameter::parse(),
+                _: Comma::parse(),
+                user_defined_operation_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the basic ways to describe how doors operate.
+/// The hinge side (left or right) is determined as seen from the direction
+/// of the y-axis of the door placement, the door opens into the direction of
+/// the positive y-axis.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifcdoortypeoperationenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DoorTypeOperationEnum {
+    /// Door with one panel that opens (swings) to the left. The hinges are
+    /// on the left side as viewed in the direction of the positive y-axis.
+    #[strum(to_string = ".SINGLE_SWING_LEFT.")]
+    SingleSwingLeft,
+
+    /// Door with one panel that opens (swings) to the right. The hinges are
+    /// on the right side as viewed in the direction of the positive y-axis.
+    #[strum(to_string = ".SINGLE_SWING_RIGHT.")]
+    SingleSwingRight,
+
+    /// Door with two panels, one opens (swings) to the left the other opens
+    /// (swings) to the right.
+    #[strum(to_string = ".DOUBLE_DOOR_SINGLE_SWING.")]
+    DoubleDoorSingleSwing,
+
+    /// Door with two panels, one opens (swings) to the left the other opens
+    /// (swings) to the right in opposite directions.
+    #[strum(to_string = ".DOUBLE_DOOR_SINGLE_SWING_OPPOSITE_LEFT.")]
+    DoubleDoorSingleSwingOppositeLeft,
+
+    /// Door with two panels, one opens (swings) to the left the other opens
+    /// (swings) to the right in opposite directions.
+    #[strum(to_string = ".DOUBLE_DOOR_SINGLE_SWING_OPPOSITE_RIGHT.")]
+    DoubleDoorSingleSwingOppositeRight,
+
+    /// Door with one panel that swings in both directions and to the left in
+    /// the main trafic direction.
+    #[strum(to_string = ".DOUBLE_SWING_LEFT.")]
+    DoubleSwingLeft,
+
+    /// Door with one panel that swings in both directions and to the right
+    /// in the main trafic direction.
+    #[strum(to_string = ".DOUBLE_SWING_RIGHT.")]
+    DoubleSwingRight,
+
+    /// Door with two panels, one swings in both directions and to the right
+    /// in the main trafic direction the other swings also in both directions
+    /// and to the left in the main trafic direction.
+    #[strum(to_string = ".DOUBLE_DOOR_DOUBLE_SWING.")]
+    DoubleDoorDoubleSwing,
+
+    /// Door with one panel that is sliding to the left.
+    #[strum(to_string = ".SLIDING_TO_LEFT.")]
+    SlidingToLeft,
+
+    /// Door with one panel that is sliding to the right.
+    #[strum(to_string = ".SLIDING_TO_RIGHT.")]
+    SlidingToRight,
+
+    /// Door with two panels, one is sliding to the left the other is sliding
+    /// to the right.
+    #[strum(to_string = ".DOUBLE_DOOR_SLIDING.")]
+    DoubleDoorSliding,
+
+    /// Door with one panel that is folding to the left.
+    #[strum(to_string = ".FOLDING_TO_LEFT.")]
+    FoldingToLeft,
+
+    /// Door with one panel that is folding to the right.
+    #[strum(to_string = ".FOLDING_TO_RIGHT.")]
+    FoldingToRight,
+
+    /// Door with two panels, one is folding to the left the other is folding
+    /// to the right.
+    #[strum(to_string = ".DOUBLE_DOOR_FOLDING.")]
+    DoubleDoorFolding,
+
+    /// An entrance door consisting of four leaves set in a form of a cross
+    /// and revolving around a central vertical axis.
+    #[strum(to_string = ".REVOLVING.")]
+    Revolving,
+
+    /// Door that opens by rolling up.
+    #[strum(to_string = ".ROLLINGUP.")]
+    RollingUp,
+
+    /// Door with one panel that opens (swings) to the left and one fixed
+    /// panel.
+    #[strum(to_string = ".SWING_FIXED_LEFT.")]
+    SwingFixedLeft,
+
+    /// Door with one panel that opens (swings) to the right and one fixed
+    /// panel.
+    #[strum(to_string = ".SWING_FIXED_RIGHT.")]
+    SwingFixedRight,
+
+    /// User defined operation type.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// An operation type that is not defined.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for DoorTypeOperationEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid DoorTypeOperationEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
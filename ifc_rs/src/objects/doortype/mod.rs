@@ -0,0 +1,146 @@
+use std::ops::Deref;
+
+use door_type_enum::DoorTypeEnum;
+use door_type_operation_enum::DoorTypeOperationEnum;
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{bool::IfcBool, label::Label, optional::OptionalParameter},
+    prelude::{ElementTypeBuilder, Root, RootBuilder, TypeObject, TypeProduct},
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+    IFC,
+};
+
+use super::shared::{
+    element_type::ElementType, type_object::TypeObjectBuilder, type_product::TypeProductBuilder,
+};
+
+mod deserialize;
+pub mod door_type_enum;
+pub mod door_type_operation_enum;
+mod serialize;
+
+/// The element type IfcDoorType defines commonly shared information for
+/// occurrences of doors. The set of shared information may include:
+///
+/// * common properties within shared property sets
+/// * common material information
+/// * common operation type definitions
+/// * common shape representations
+///
+/// A door type defines the particular parameter of the lining and one (or
+/// several) panels through the IfcDoorLiningProperties and the
+/// IfcDoorPanelProperties as predefined property sets applicable to doors
+/// only.
+///
+/// It is used to define a door specification, or door style (the specific
+/// product information that is common to all occurrences of that door type).
+/// Door types may be exchanged without being already assigned to occurrences.
+///
+/// Occurrences of the IfcDoorType within building models are represented by
+/// instances of IfcDoor or IfcDoorStandardCase.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifcdoortype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct DoorType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined types of a door element from which the type
+    /// required may be set.
+    pub predefined_type: DoorTypeEnum,
+
+    /// Type defining the general layout and operation of the door type in
+    /// terms of the partitioning of panels and panel operations.
+    pub operation_type: DoorTypeOperationEnum,
+
+    /// The Boolean value reflects, whether the parameter given in the attached
+    /// lining and panel properties exactly define the geometry (TRUE), or
+    /// whether the attached style shape take precedence (FALSE). In the last
+    /// case the parameter have only informative value. If not provided, no
+    /// such information can be infered.
+    pub parameter_takes_precedence: OptionalParameter<IfcBool>,
+
+    /// Designator for the user defined operation type, shall only be
+    /// provided, if the value of OperationType is set to USERDEFINED.
+    pub user_defined_operation_type: OptionalParameter<Label>,
+}
+
+impl DoorType {
+    pub fn new(
+        name: impl Into<Label>,
+        predefined_type: DoorTypeEnum,
+        operation_type: DoorTypeOperationEnum,
+    ) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+            operation_type,
+            parameter_takes_precedence: OptionalParameter::omitted(),
+            user_defined_operation_type: OptionalParameter::omitted(),
+        }
+    }
+}
+
+impl ElementTypeBuilder for DoorType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for DoorType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for DoorType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for DoorType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for DoorType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+#[typetag::serde(name = "IfcDoorType")]
+impl IfcType for DoorType {}
+impl MaterialRelatable for DoorType {}
+impl ClassificationRelatable for DoorType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use crate::parser::IFCParse;
+
+    use super::DoorType;
+
+    #[test]
+    fn door_type_round_trip() {
+        let example = "IFCDOORTYPE('0Ps4H3X0nAxfqkHNemLE6g',#2,'Door for Test Example','Description of Door Type',$,$,$,$,$,.DOOR.,.SINGLE_SWING_RIGHT.,$,$);";
+
+        let door_type: DoorType = DoorType::parse().parse(example).unwrap();
+        let str_door_type = door_type.to_string();
+
+        assert_eq!(example, str_door_type);
+    }
+}
@@ -0,0 +1,158 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+/// A duct segment is used to typically join two sections of a duct
+/// network. Consecutive segments are connected through the
+/// `IfcDistributionPort`s nested at their ends.
+///
+/// A particular usage type can be given by referring to the type object
+/// `IfcDuctSegmentType` using the `IfcRelDefinesByType` relationship, or by
+/// the PredefinedType attribute.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcductsegment.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct DuctSegment {
+    #[inherited]
+    element: Element,
+
+    /// Predefined generic type of the duct segment.
+    ///
+    /// Note: The use of the predefined type directly at the occurrence object
+    /// level is only permitted, if no type object `IfcDuctSegmentType` is
+    /// assigned.
+    pub predefined_type: OptionalParameter<DuctSegmentTypeEnum>,
+}
+
+impl DuctSegment {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+            predefined_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn predefined_type(mut self, predefined_type: DuctSegmentTypeEnum) -> Self {
+        self.predefined_type = predefined_type.into();
+        self
+    }
+}
+
+impl RootBuilder for DuctSegment {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for DuctSegment {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for DuctSegment {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for DuctSegment {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for DuctSegment {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for DuctSegment {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl IFCParse for DuctSegment {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCDUCTSEGMENT("),
+
+                element: Element::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for DuctSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCDUCTSEGMENT({},{});",
+            self.element, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcDuctSegment")]
+impl IfcType for DuctSegment {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for DuctSegment {}
+impl MaterialRelatable for DuctSegment {}
+impl ClassificationRelatable for DuctSegment {}
+impl GroupRelatable for DuctSegment {}
+
+impl TransformableType for DuctSegment {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::DuctSegment;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn duct_segment_round_trip() {
+        let examples =
+            ["IFCDUCTSEGMENT('1Vg2YO$MX4xv5uCqZZG05x',#2,'Duct',$,$,#37,#54,$,.RIGIDSEGMENT.);"];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: DuctSegment = DuctSegment::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
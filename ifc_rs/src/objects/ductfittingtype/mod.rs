@@ -0,0 +1,126 @@
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use type_enum::DuctFittingTypeEnum;
+
+/// The element type IfcDuctFittingType defines commonly shared information for
+/// occurrences of duct fittings. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcFlowFitting or its
+/// specific subtype IfcDuctFitting.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcductfittingtype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct DuctFittingType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined type of the duct fitting.
+    pub predefined_type: DuctFittingTypeEnum,
+}
+
+impl DuctFittingType {
+    pub fn new(name: impl Into<Label>, predefined_type: DuctFittingTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for DuctFittingType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for DuctFittingType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for DuctFittingType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for DuctFittingType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for DuctFittingType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for DuctFittingType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCDUCTFITTINGTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: DuctFittingTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for DuctFittingType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCDUCTFITTINGTYPE({},{});",
+            self.element_type, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcDuctFittingType")]
+impl IfcType for DuctFittingType {}
+impl MaterialRelatable for DuctFittingType {}
+impl ClassificationRelatable for DuctFittingType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::DuctFittingType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn duct_fitting_type_round_trip() {
+        let example =
+            "IFCDUCTFITTINGTYPE('3Ya1Rk7nG0uL5dT2pXm8wQ',#2,'Tee',$,$,$,$,$,$,.JUNCTION.);";
+
+        let parsed: DuctFittingType = DuctFittingType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,65 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the different types of duct fittings.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcductfittingtypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum DuctFittingTypeEnum {
+    /// A fitting changing the direction of the air flow.
+    #[strum(to_string = ".BEND.")]
+    Bend,
+
+    /// A fitting connecting two segments of the same size.
+    #[strum(to_string = ".CONNECTOR.")]
+    Connector,
+
+    /// A fitting at the entry of the air flow into the system.
+    #[strum(to_string = ".ENTRY.")]
+    Entry,
+
+    /// A fitting at the exit of the air flow from the system.
+    #[strum(to_string = ".EXIT.")]
+    Exit,
+
+    /// A fitting joining or dividing flows, e.g. a tee or a cross.
+    #[strum(to_string = ".JUNCTION.")]
+    Junction,
+
+    /// A fitting restricting the flow, e.g. an orifice plate.
+    #[strum(to_string = ".OBSTRUCTION.")]
+    Obstruction,
+
+    /// A fitting changing the size or the shape of the cross section.
+    #[strum(to_string = ".TRANSITION.")]
+    Transition,
+
+    /// User-defined type.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined type.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for DuctFittingTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid DuctFittingTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
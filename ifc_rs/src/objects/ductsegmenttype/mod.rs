@@ -0,0 +1,125 @@
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use type_enum::DuctSegmentTypeEnum;
+
+/// The element type IfcDuctSegmentType defines commonly shared information for
+/// occurrences of duct segments. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcDuctSegment.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcductsegmenttype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct DuctSegmentType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined type of the duct segment.
+    pub predefined_type: DuctSegmentTypeEnum,
+}
+
+impl DuctSegmentType {
+    pub fn new(name: impl Into<Label>, predefined_type: DuctSegmentTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for DuctSegmentType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for DuctSegmentType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for DuctSegmentType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for DuctSegmentType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for DuctSegmentType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for DuctSegmentType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCDUCTSEGMENTTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: DuctSegmentTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for DuctSegmentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCDUCTSEGMENTTYPE({},{});",
+            self.element_type, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcDuctSegmentType")]
+impl IfcType for DuctSegmentType {}
+impl MaterialRelatable for DuctSegmentType {}
+impl ClassificationRelatable for DuctSegmentType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::DuctSegmentType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn duct_segment_type_round_trip() {
+        let example =
+            "IFCDUCTSEGMENTTYPE('2Qm5xWb7H1tC9vZ0LkE3aR',#2,'Spiro 200',$,$,$,$,$,$,.RIGIDSEGMENT.);";
+
+        let parsed: DuctSegmentType = DuctSegmentType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,132 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+/// A flow fitting is a junction or transition in a distribution system,
+/// e.g. a bend, a tee or a reducer connecting flow segments. Its usage can
+/// be given by a type object like `IfcPipeFittingType` or
+/// `IfcDuctFittingType` related with `IfcRelDefinesByType`.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcflowfitting.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct FlowFitting {
+    #[inherited]
+    element: Element,
+}
+
+impl FlowFitting {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+        }
+    }
+}
+
+impl RootBuilder for FlowFitting {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for FlowFitting {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for FlowFitting {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for FlowFitting {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for FlowFitting {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for FlowFitting {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl IFCParse for FlowFitting {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCFLOWFITTING("),
+
+                element: Element::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for FlowFitting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCFLOWFITTING({});", self.element)
+    }
+}
+
+#[typetag::serde(name = "IfcFlowFitting")]
+impl IfcType for FlowFitting {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for FlowFitting {}
+impl MaterialRelatable for FlowFitting {}
+impl ClassificationRelatable for FlowFitting {}
+impl GroupRelatable for FlowFitting {}
+
+impl TransformableType for FlowFitting {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::FlowFitting;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn flow_fitting_round_trip() {
+        let examples = ["IFCFLOWFITTING('0pL7vLJ1X0WvS4K9QmN3e2',#2,'Bend',$,$,#37,#54,$);"];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: FlowFitting = FlowFitting::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+/// A flow segment is a section of a distribution system that is used to
+/// convey the distribution media, e.g. a straight pipe or duct. In IFC4 the
+/// more specific subtypes like `IfcPipeSegment` and `IfcDuctSegment` are
+/// preferred, the flow segment itself is used by older exports.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcflowsegment.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct FlowSegment {
+    #[inherited]
+    element: Element,
+}
+
+impl FlowSegment {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+        }
+    }
+}
+
+impl RootBuilder for FlowSegment {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for FlowSegment {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for FlowSegment {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for FlowSegment {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for FlowSegment {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for FlowSegment {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl IFCParse for FlowSegment {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCFLOWSEGMENT("),
+
+                element: Element::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for FlowSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCFLOWSEGMENT({});", self.element)
+    }
+}
+
+#[typetag::serde(name = "IfcFlowSegment")]
+impl IfcType for FlowSegment {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for FlowSegment {}
+impl MaterialRelatable for FlowSegment {}
+impl ClassificationRelatable for FlowSegment {}
+impl GroupRelatable for FlowSegment {}
+
+impl TransformableType for FlowSegment {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::FlowSegment;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn flow_segment_round_trip() {
+        let examples = ["IFCFLOWSEGMENT('2Xk8PqTs91Jf0aW4cR7hLm',#2,'Segment',$,$,#37,#54,$);"];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: FlowSegment = FlowSegment::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
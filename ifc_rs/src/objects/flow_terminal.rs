@@ -0,0 +1,133 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+/// A flow terminal acts as a terminus or beginning element in a
+/// distribution system, e.g. an air outlet or a sanitary fixture. Its usage
+/// can be given by a type object like `IfcAirTerminalType` related with
+/// `IfcRelDefinesByType`.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcflowterminal.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct FlowTerminal {
+    #[inherited]
+    element: Element,
+}
+
+impl FlowTerminal {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+        }
+    }
+}
+
+impl RootBuilder for FlowTerminal {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for FlowTerminal {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for FlowTerminal {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for FlowTerminal {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for FlowTerminal {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for FlowTerminal {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl IFCParse for FlowTerminal {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCFLOWTERMINAL("),
+
+                element: Element::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for FlowTerminal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCFLOWTERMINAL({});", self.element)
+    }
+}
+
+#[typetag::serde(name = "IfcFlowTerminal")]
+impl IfcType for FlowTerminal {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for FlowTerminal {}
+impl MaterialRelatable for FlowTerminal {}
+impl ClassificationRelatable for FlowTerminal {}
+impl GroupRelatable for FlowTerminal {}
+
+impl TransformableType for FlowTerminal {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::FlowTerminal;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn flow_terminal_round_trip() {
+        let examples =
+            ["IFCFLOWTERMINAL('2cR4ZVb0n3gQ$0xH1pDfa8',#2,'Air outlet',$,$,#37,#54,'AT-01');"];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: FlowTerminal = FlowTerminal::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+/// An IfcFurnishingElement is a generalization of all furniture related
+/// objects. Furnishing objects are characterized as being
+///
+/// - free-standing or mounted,
+/// - not part of the building structure or its services.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcfurnishingelement.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct FurnishingElement {
+    #[inherited]
+    element: Element,
+}
+
+impl FurnishingElement {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+        }
+    }
+}
+
+impl RootBuilder for FurnishingElement {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for FurnishingElement {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for FurnishingElement {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for FurnishingElement {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for FurnishingElement {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for FurnishingElement {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl IFCParse for FurnishingElement {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCFURNISHINGELEMENT("),
+
+                element: Element::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for FurnishingElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCFURNISHINGELEMENT({});", self.element)
+    }
+}
+
+#[typetag::serde(name = "IfcFurnishingElement")]
+impl IfcType for FurnishingElement {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for FurnishingElement {}
+impl MaterialRelatable for FurnishingElement {}
+impl ClassificationRelatable for FurnishingElement {}
+impl GroupRelatable for FurnishingElement {}
+
+impl TransformableType for FurnishingElement {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::FurnishingElement;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn furnishing_element_round_trip() {
+        let examples =
+            ["IFCFURNISHINGELEMENT('1pX9sL0mNb3Fh0cRaW7Hd2',#2,'Cupboard',$,$,#37,#54,$);"];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: FurnishingElement = FurnishingElement::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+/// The element type IfcFurnishingElementType defines commonly shared information for
+/// occurrences of furnishing elements. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcFurnishingElement.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcfurnishingelementtype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct FurnishingElementType {
+    #[inherited]
+    element_type: ElementType,
+}
+
+impl FurnishingElementType {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+        }
+    }
+}
+
+impl ElementTypeBuilder for FurnishingElementType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for FurnishingElementType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for FurnishingElementType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for FurnishingElementType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for FurnishingElementType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for FurnishingElementType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCFURNISHINGELEMENTTYPE("),
+
+                element_type: ElementType::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for FurnishingElementType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCFURNISHINGELEMENTTYPE({});", self.element_type)
+    }
+}
+
+#[typetag::serde(name = "IfcFurnishingElementType")]
+impl IfcType for FurnishingElementType {}
+impl MaterialRelatable for FurnishingElementType {}
+impl ClassificationRelatable for FurnishingElementType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::FurnishingElementType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn furnishing_element_type_round_trip() {
+        let example =
+            "IFCFURNISHINGELEMENTTYPE('0cRaW7Hd2pX9sL0mNb3Fh2',#2,'Cupboard',$,$,$,$,$,$);";
+
+        let parsed: FurnishingElementType = FurnishingElementType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,152 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+/// Furniture defines complete furnishing elements which can be directly
+/// used in a building, e.g. a table, a chair or a bed.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcfurniture.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Furniture {
+    #[inherited]
+    element: Element,
+
+    /// Predefined generic type of the furniture.
+    ///
+    /// Note: The use of the predefined type directly at the occurrence object
+    /// level is only permitted, if no type object `IfcFurnitureType` is
+    /// assigned.
+    pub predefined_type: OptionalParameter<FurnitureTypeEnum>,
+}
+
+impl Furniture {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+            predefined_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn predefined_type(mut self, predefined_type: FurnitureTypeEnum) -> Self {
+        self.predefined_type = predefined_type.into();
+        self
+    }
+}
+
+impl RootBuilder for Furniture {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for Furniture {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for Furniture {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for Furniture {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for Furniture {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for Furniture {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl IFCParse for Furniture {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCFURNITURE("),
+
+                element: Element::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for Furniture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCFURNITURE({},{});",
+            self.element, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcFurniture")]
+impl IfcType for Furniture {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for Furniture {}
+impl MaterialRelatable for Furniture {}
+impl ClassificationRelatable for Furniture {}
+impl GroupRelatable for Furniture {}
+
+impl TransformableType for Furniture {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Furniture;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn furniture_round_trip() {
+        let examples = ["IFCFURNITURE('2mNb3Fh0cRaW7Hd2pX9sL0',#2,'Chair',$,$,#37,#54,$,.CHAIR.);"];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: Furniture = Furniture::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+pub mod assembly_place_enum;
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use assembly_place_enum::AssemblyPlaceEnum;
+use type_enum::FurnitureTypeEnum;
+
+/// The element type IfcFurnitureType defines commonly shared information for
+/// occurrences of furniture. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcFurniture.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcfurnituretype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct FurnitureType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// A designation of where the assembly is intended to take place.
+    pub assembly_place: AssemblyPlaceEnum,
+
+    /// Identifies the predefined type of the furniture.
+    pub predefined_type: OptionalParameter<FurnitureTypeEnum>,
+}
+
+impl FurnitureType {
+    pub fn new(name: impl Into<Label>, predefined_type: FurnitureTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            assembly_place: AssemblyPlaceEnum::NotDefined,
+            predefined_type: predefined_type.into(),
+        }
+    }
+
+    pub fn assembly_place(mut self, assembly_place: AssemblyPlaceEnum) -> Self {
+        self.assembly_place = assembly_place;
+        self
+    }
+}
+
+impl ElementTypeBuilder for FurnitureType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for FurnitureType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for FurnitureType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for FurnitureType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for FurnitureType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for FurnitureType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCFURNITURETYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                assembly_place: AssemblyPlaceEnum::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for FurnitureType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCFURNITURETYPE({},{},{});",
+            self.element_type, self.assembly_place, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcFurnitureType")]
+impl IfcType for FurnitureType {}
+impl MaterialRelatable for FurnitureType {}
+impl ClassificationRelatable for FurnitureType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::FurnitureType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn furniture_type_round_trip() {
+        let example =
+            "IFCFURNITURETYPE('2pX9sL0mNb3Fh0cRaW7Hd2',#2,'Chair',$,$,$,(#120),$,$,.NOTDEFINED.,.CHAIR.);";
+
+        let parsed: FurnitureType = FurnitureType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
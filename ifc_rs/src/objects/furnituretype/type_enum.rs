@@ -0,0 +1,65 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the different types of furniture.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcfurnituretypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum FurnitureTypeEnum {
+    /// A piece of furniture for sitting on.
+    #[strum(to_string = ".CHAIR.")]
+    Chair,
+
+    /// A piece of furniture with a flat top and legs.
+    #[strum(to_string = ".TABLE.")]
+    Table,
+
+    /// A table designed for working at, often with drawers.
+    #[strum(to_string = ".DESK.")]
+    Desk,
+
+    /// A piece of furniture for sleeping on.
+    #[strum(to_string = ".BED.")]
+    Bed,
+
+    /// A cabinet for storing files and documents.
+    #[strum(to_string = ".FILECABINET.")]
+    FileCabinet,
+
+    /// A flat board for holding or displaying objects.
+    #[strum(to_string = ".SHELF.")]
+    Shelf,
+
+    /// A long upholstered seat for more than one person.
+    #[strum(to_string = ".SOFA.")]
+    Sofa,
+
+    /// User-defined type.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined type.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for FurnitureTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid FurnitureTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
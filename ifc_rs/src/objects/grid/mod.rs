@@ -0,0 +1,175 @@
+pub mod type_enum;
+
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+use type_enum::GridTypeEnum;
+
+/// IfcGrid is a planar design grid defined in 3D space used as an aid in
+/// locating structural and design elements. The position of the grid is
+/// defined by the object placement; the grid axes are defined in the xy
+/// plane of it. Elements are placed at grid intersections via an
+/// IfcGridPlacement.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcgrid.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Grid {
+    #[inherited]
+    product: Product,
+
+    /// List of grid axes defining the first row of grid lines.
+    pub u_axes: IfcList<TypedId<GridAxis>>,
+    /// List of grid axes defining the second row of grid lines.
+    pub v_axes: IfcList<TypedId<GridAxis>>,
+    /// List of grid axes defining an optional third row of grid lines.
+    pub w_axes: OptionalParameter<IfcList<TypedId<GridAxis>>>,
+    /// Predefined types to define the particular type of the grid.
+    pub predefined_type: OptionalParameter<GridTypeEnum>,
+}
+
+impl Grid {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            product: Product::new(Object::new(Root::new(name.into()))),
+            u_axes: IfcList::empty(),
+            v_axes: IfcList::empty(),
+            w_axes: OptionalParameter::omitted(),
+            predefined_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn predefined_type(mut self, predefined_type: GridTypeEnum) -> Self {
+        self.predefined_type = predefined_type.into();
+        self
+    }
+
+    pub fn add_u_axis(mut self, axis: impl Into<IdOr<GridAxis>>, ifc: &mut IFC) -> Self {
+        self.u_axes.0.push(axis.into().or_insert(ifc));
+        self
+    }
+
+    pub fn add_v_axis(mut self, axis: impl Into<IdOr<GridAxis>>, ifc: &mut IFC) -> Self {
+        self.v_axes.0.push(axis.into().or_insert(ifc));
+        self
+    }
+
+    /// All axes of the grid, U axes first, followed by the V and W axes.
+    pub fn axes(&self) -> impl Iterator<Item = &TypedId<GridAxis>> {
+        self.u_axes.iter().chain(self.v_axes.iter()).chain(
+            self.w_axes
+                .custom()
+                .into_iter()
+                .flat_map(|axes| axes.iter()),
+        )
+    }
+
+    /// Axis with the given tag.
+    pub fn axis(&self, tag: &str, ifc: &IFC) -> Option<TypedId<GridAxis>> {
+        self.axes()
+            .find(|axis| ifc.data.get(**axis).has_tag(tag))
+            .copied()
+    }
+}
+
+impl RootBuilder for Grid {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.product
+    }
+}
+
+impl ObjectBuilder for Grid {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.product
+    }
+}
+
+impl ProductBuilder for Grid {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.product
+    }
+}
+
+impl Deref for Grid {
+    type Target = Product;
+
+    fn deref(&self) -> &Self::Target {
+        &self.product
+    }
+}
+
+impl DerefMut for Grid {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.product
+    }
+}
+
+impl IFCParse for Grid {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCGRID("),
+
+                product: Product::parse(),
+                _: Comma::parse(),
+                u_axes: IfcList::parse(),
+                _: Comma::parse(),
+                v_axes: IfcList::parse(),
+                _: Comma::parse(),
+                w_axes: OptionalParameter::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCGRID({},{},{},{},{});",
+            self.product, self.u_axes, self.v_axes, self.w_axes, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcGrid")]
+impl IfcType for Grid {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for Grid {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Grid;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn grid_round_trip() {
+        let example = "IFCGRID('2MEqtSg2D2Dh9GxURYLZR6',#2,'Grid',$,$,#40,#80,(#44,#48),(#52,#56),$,.RECTANGULAR.);";
+
+        let grid: Grid = Grid::parse().parse(example).unwrap();
+        assert_eq!(example, grid.to_string());
+    }
+}
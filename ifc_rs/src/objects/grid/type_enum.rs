@@ -0,0 +1,55 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the different types of grids.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcgridtypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum GridTypeEnum {
+    /// A grid with straight U and V axes, which are orthogonal to each other.
+    #[strum(to_string = ".RECTANGULAR.")]
+    Rectangular,
+
+    /// A grid with straight U axes radiating from a common center and
+    /// circular V axes around this center.
+    #[strum(to_string = ".RADIAL.")]
+    Radial,
+
+    /// A grid with straight U, V and W axes at an angle of 60 degrees to
+    /// each other.
+    #[strum(to_string = ".TRIANGULAR.")]
+    Triangular,
+
+    /// A grid with axes of arbitrary shape.
+    #[strum(to_string = ".IRREGULAR.")]
+    Irregular,
+
+    /// User-defined grid.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined grid.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for GridTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid GridTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
@@ -0,0 +1,97 @@
+use std::ops::DerefMut;
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::rel_assigns_to_group::RelatableGroup,
+};
+
+/// An IfcGroup is an generalization of any arbitrary group. A group is a
+/// logical collection of objects. It does not have its own position, nor
+/// can it hold its own shape representation.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcgroup.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Group {
+    #[inherited]
+    object: Object,
+}
+
+impl Group {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            object: Object::new(Root::new(name.into())),
+        }
+    }
+}
+
+impl RootBuilder for Group {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.object
+    }
+}
+
+impl ObjectBuilder for Group {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.object
+    }
+}
+
+impl Deref for Group {
+    type Target = Object;
+
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl DerefMut for Group {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.object
+    }
+}
+
+impl IFCParse for Group {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCGROUP("),
+
+                object: Object::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for Group {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCGROUP({});", self.object)
+    }
+}
+
+#[typetag::serde(name = "IfcGroup")]
+impl IfcType for Group {}
+impl RelatableGroup for Group {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Group;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn group_round_trip() {
+        let example = "IFCGROUP('2x9GRQ4Fr0Ge$2Ez_GSCZ8',#2,'Apartment 1',$,$);";
+
+        let group: Group = Group::parse().parse(example).unwrap();
+        assert_eq!(example, group.to_string());
+    }
+}
@@ -0,0 +1,154 @@
+mod deserialize;
+mod serialize;
+
+use std::ops::{Deref, DerefMut};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{label::Label, optional::OptionalParameter},
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable, rel_connects_elements::ConnectionRelatable,
+        rel_space_boundary::SpaceBoundaryRelatable,
+    },
+};
+
+use super::StructureType;
+
+/// An IfcMember is a structural member designed to carry loads between or
+/// beyond points of support. It is not required to be load bearing. The
+/// orientation of the member (being horizontal, vertical or sloped) is not
+/// relevant to its definition (in contrary to IfcBeam and IfcColumn). An
+/// IfcMember represents a linear structural element from an architectural or
+/// structural modeling point of view and shall be used if it cannot be
+/// expressed more specifically as either an IfcBeam or an IfcColumn.
+///
+/// The representation of a member is usually given by an extrusion of a
+/// profile along the member axis. A common profile may be assigned through an
+/// IfcMaterialProfileSetUsage, using the IfcRelAssociatesMaterial
+/// relationship.
+///
+/// A particular usage type for the IfcMember can be given (if type
+/// information is available) by referring to the type object IfcMemberType,
+/// using the `IfcRelDefinesByType` relationship, or (if only occurrence
+/// information is given) by using the PredefinedType attribute.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifcmember.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Member {
+    #[inherited]
+    element: Element,
+
+    /// Predefined generic type for a member that is specified in an
+    /// enumeration. There may be a property set given specificly for the
+    /// predefined types.
+    ///
+    /// Note: The use of the predefined type directly at the occurrence object
+    /// level of IfcMember is only permitted, if no type object `IfcMemberType`
+    /// is assigned.
+    pub predefined_type: OptionalParameter<MemberTypeEnum>,
+}
+
+impl Member {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+            predefined_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn predefined_type(mut self, member_type: MemberTypeEnum) -> Self {
+        self.predefined_type = member_type.into();
+        self
+    }
+}
+
+impl RootBuilder for Member {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for Member {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for Member {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for Member {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for Member {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for Member {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+#[typetag::serde(name = "IfcMember")]
+impl IfcType for Member {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for Member {
+    fn structure_type(&self) -> Option<StructureType<'_>> {
+        Some(StructureType::Member(self))
+    }
+}
+impl MaterialRelatable for Member {}
+impl ClassificationRelatable for Member {}
+impl GroupRelatable for Member {}
+impl SpaceBoundaryRelatable for Member {}
+impl ConnectionRelatable for Member {}
+
+impl TransformableType for Member {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Member;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn member_round_trip() {
+        let examples = [
+            "IFCMEMBER('3vB2YO$MX4xv5uCqZZG05x',#2,'Member',$,$,#37,#54,$,$);",
+            "IFCMEMBER('2t8Qd1VKr6$9zWQ3pFZ0Ej',#12,'Sparren',$,$,#70311,#70342,'9D3E6A1C-0F4B-4C7E-A2-5D-8B1E3C7F2A90',.RAFTER.);",
+        ];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let member: Member = Member::parse().parse(example).unwrap();
+            let str_member = member.to_string();
+
+            assert_eq!(example, str_member, "example {} failed", index);
+        }
+    }
+}
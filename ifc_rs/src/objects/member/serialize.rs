@@ -0,0 +1,9 @@
+use std::fmt::Display;
+
+use super::Member;
+
+impl Display for Member {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCMEMBER({},{});", self.element, self.predefined_type,)
+    }
+}
@@ -0,0 +1,22 @@
+use comma::Comma;
+
+use crate::objects::membertype::type_enum::MemberTypeEnum;
+use crate::{objects::shared::element_type::ElementType, parser::*};
+
+use super::MemberType;
+
+impl IFCParse for MemberType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCMEMBERTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: MemberTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
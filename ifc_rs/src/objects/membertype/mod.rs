@@ -0,0 +1,119 @@
+use std::ops::Deref;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+use type_enum::MemberTypeEnum;
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::label::Label,
+    prelude::{ElementTypeBuilder, Root, RootBuilder, TypeObject, TypeProduct},
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+    IFC,
+};
+
+use super::shared::{
+    element_type::ElementType, type_object::TypeObjectBuilder, type_product::TypeProductBuilder,
+};
+
+mod deserialize;
+mod serialize;
+pub mod type_enum;
+
+/// The element type IfcMemberType defines commonly shared information for
+/// occurrences of members. The set of shared information may include:
+///
+/// * common properties within shared property sets
+/// * common material information
+/// * common profile definitions
+/// * common shape representations
+///
+/// It is used to define a member specification (i.e. the specific product
+/// information, that is common to all occurrences of that product type).
+/// Member types may be exchanged without being already assigned to
+/// occurrences.
+///
+/// The occurrences of the IfcMemberType within building models are
+/// represented by instances of IfcMember or IfcMemberStandardCase.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifcmembertype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct MemberType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined types of a member element from which the
+    /// type required may be set.
+    pub predefined_type: MemberTypeEnum,
+}
+
+impl MemberType {
+    pub fn new(name: impl Into<Label>, predefined_type: MemberTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for MemberType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for MemberType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for MemberType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for MemberType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for MemberType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+#[typetag::serde(name = "IfcMemberType")]
+impl IfcType for MemberType {}
+impl MaterialRelatable for MemberType {}
+impl ClassificationRelatable for MemberType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use crate::parser::IFCParse;
+
+    use super::MemberType;
+
+    #[test]
+    fn member_type_round_trip() {
+        let example =
+            "IFCMEMBERTYPE('0aZ7vLJ1X0WvS4K9QmN3e2',#2,'Rafter 80/200',$,$,$,$,$,$,.RAFTER.);";
+
+        let member_type: MemberType = MemberType::parse().parse(example).unwrap();
+        let str_member_type = member_type.to_string();
+
+        assert_eq!(example, str_member_type);
+    }
+}
@@ -0,0 +1,13 @@
+use std::fmt::Display;
+
+use super::MemberType;
+
+impl Display for MemberType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCMEMBERTYPE({},{});",
+            self.element_type, self.predefined_type,
+        )
+    }
+}
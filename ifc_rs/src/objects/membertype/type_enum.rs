@@ -0,0 +1,95 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the different predefined types of an IfcMember or
+/// IfcMemberType object can fulfill.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifcmembertypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
+pub enum MemberTypeEnum {
+    /// A linear element (usually sloped) often used for bracing of a girder or
+    /// truss.
+    #[strum(to_string = ".BRACE.")]
+    Brace,
+
+    /// Upper or lower longitudinal member of a truss, used horizontally or
+    /// sloped.
+    #[strum(to_string = ".CHORD.")]
+    Chord,
+
+    /// A linear element (usually used horizontally) within a roof structure to
+    /// connect rafters and posts.
+    #[strum(to_string = ".COLLAR.")]
+    Collar,
+
+    /// A linear element within a girder or truss with no further meaning.
+    #[strum(to_string = ".MEMBER.")]
+    Member,
+
+    /// A linear element within a curtain wall system to connect two (or more)
+    /// panels.
+    #[strum(to_string = ".MULLION.")]
+    Mullion,
+
+    /// A linear continuous horizontal element in wall framing, such as a head
+    /// piece or a sole plate.
+    #[strum(to_string = ".PLATE.")]
+    Plate,
+
+    /// A linear member (usually used vertically) within a roof structure to
+    /// support purlins.
+    #[strum(to_string = ".POST.")]
+    Post,
+
+    /// A linear element (usually used horizontally) within a roof structure to
+    /// support rafters.
+    #[strum(to_string = ".PURLIN.")]
+    Purlin,
+
+    /// A linear elements used to support roof slabs or roof covering, usually
+    /// used with slope.
+    #[strum(to_string = ".RAFTER.")]
+    Rafter,
+
+    /// A linear element used to support stair or ramp flights, usually used with
+    /// slope.
+    #[strum(to_string = ".STRINGER.")]
+    Stringer,
+
+    /// A linear element often used within a girder or truss.
+    #[strum(to_string = ".STRUT.")]
+    Strut,
+
+    /// Vertical element in wall framing.
+    #[strum(to_string = ".STUD.")]
+    Stud,
+
+    /// User-defined linear element.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined linear element.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for MemberTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid MemberTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
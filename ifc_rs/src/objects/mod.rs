@@ -1,3 +1,7 @@
+use beam::Beam;
+use column::Column;
+use door::Door;
+use member::Member;
 use roof::Roof;
 use slab::Slab;
 use wall::Wall;
@@ -12,16 +16,59 @@ use crate::{
 pub mod access_state;
 pub mod actor_role;
 pub mod address;
+pub mod airterminaltype;
 pub mod application;
+pub mod beam;
+pub mod beamtype;
 pub mod building;
+pub mod building_element_proxy;
+pub mod building_system;
+pub mod buildingelementproxytype;
 pub mod change_action;
+pub mod classification;
+pub mod classification_reference;
+pub mod column;
+pub mod columntype;
+pub mod covering;
+pub mod coveringtype;
+pub mod curtain_wall;
+pub mod curtainwalltype;
+pub mod distribution_port;
+pub mod distribution_system;
+pub mod door;
+pub mod doortype;
+pub mod duct_segment;
+pub mod ductfittingtype;
+pub mod ductsegmenttype;
+pub mod flow_fitting;
+pub mod flow_segment;
+pub mod flow_terminal;
+pub mod furnishing_element;
+pub mod furnishingelementtype;
+pub mod furniture;
+pub mod furnituretype;
+pub mod grid;
+pub mod group;
+pub mod member;
+pub mod membertype;
 pub mod opening_element;
 pub mod organization;
 pub mod owner_history;
 pub mod person;
 pub mod person_and_org;
+pub mod pipe_segment;
+pub mod pipefittingtype;
+pub mod pipesegmenttype;
+pub mod plate;
+pub mod platetype;
 pub mod prelude;
 pub mod project;
+pub mod railing;
+pub mod railingtype;
+pub mod ramp;
+pub mod ramp_flight;
+pub mod rampflighttype;
+pub mod ramptype;
 pub mod roof;
 pub mod rooftype;
 pub mod shared;
@@ -30,17 +77,27 @@ pub mod slab;
 pub mod slabtype;
 pub mod space;
 pub mod spacetype;
+pub mod stair;
+pub mod stair_flight;
+pub mod stairflighttype;
+pub mod stairtype;
 pub mod storey;
+pub mod system;
 pub mod wall;
 pub mod walltype;
 pub mod window;
 pub mod windowtype;
+pub mod zone;
 
 pub enum StructureType<'a> {
     Wall(&'a Wall),
     Slab(&'a Slab),
     Roof(&'a Roof),
     Window(&'a Window),
+    Door(&'a Door),
+    Beam(&'a Beam),
+    Column(&'a Column),
+    Member(&'a Member),
 }
 
 pub trait Structure: IfcType {
@@ -78,6 +135,61 @@ impl Objects {
                 project::Project::parse_any(),
                 space::Space::parse_any(),
                 spacetype::SpaceType::parse_any(),
+                door::Door::parse_any(),
+                doortype::DoorType::parse_any(),
+                beam::Beam::parse_any(),
+                beamtype::BeamType::parse_any(),
+                column::Column::parse_any(),
+                columntype::ColumnType::parse_any(),
+                member::Member::parse_any(),
+                membertype::MemberType::parse_any(),
+                classification::Classification::parse_any(),
+                classification_reference::ClassificationReference::parse_any(),
+                grid::Grid::parse_any(),
+                group::Group::parse_any(),
+                system::System::parse_any(),
+                zone::Zone::parse_any(),
+                building_system::BuildingSystem::parse_any(),
+                alt((
+                    distribution_system::DistributionSystem::parse_any(),
+                    flow_segment::FlowSegment::parse_any(),
+                    pipe_segment::PipeSegment::parse_any(),
+                    pipesegmenttype::PipeSegmentType::parse_any(),
+                    duct_segment::DuctSegment::parse_any(),
+                    ductsegmenttype::DuctSegmentType::parse_any(),
+                    flow_fitting::FlowFitting::parse_any(),
+                    pipefittingtype::PipeFittingType::parse_any(),
+                    ductfittingtype::DuctFittingType::parse_any(),
+                    flow_terminal::FlowTerminal::parse_any(),
+                    airterminaltype::AirTerminalType::parse_any(),
+                    distribution_port::DistributionPort::parse_any(),
+                    alt((
+                        stair::Stair::parse_any(),
+                        stairtype::StairType::parse_any(),
+                        stair_flight::StairFlight::parse_any(),
+                        stairflighttype::StairFlightType::parse_any(),
+                        ramp::Ramp::parse_any(),
+                        ramptype::RampType::parse_any(),
+                        ramp_flight::RampFlight::parse_any(),
+                        rampflighttype::RampFlightType::parse_any(),
+                        railing::Railing::parse_any(),
+                        railingtype::RailingType::parse_any(),
+                        covering::Covering::parse_any(),
+                        coveringtype::CoveringType::parse_any(),
+                        curtain_wall::CurtainWall::parse_any(),
+                        curtainwalltype::CurtainWallType::parse_any(),
+                        plate::Plate::parse_any(),
+                        platetype::PlateType::parse_any(),
+                        alt((
+                            building_element_proxy::BuildingElementProxy::parse_any(),
+                            buildingelementproxytype::BuildingElementProxyType::parse_any(),
+                            furnishing_element::FurnishingElement::parse_any(),
+                            furnishingelementtype::FurnishingElementType::parse_any(),
+                            furniture::Furniture::parse_any(),
+                            furnituretype::FurnitureType::parse_any(),
+                        )),
+                    )),
+                )),
             )),
         ))
     }
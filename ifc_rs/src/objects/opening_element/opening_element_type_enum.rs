@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, VariantNames};
 use winnow::combinator::{alt, delimited};
 use winnow::Parser;
@@ -7,7 +8,7 @@ use winnow::Parser;
 use crate::parser::*;
 
 /// This enumeration defines the basic types for opening elements.
-#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
 pub enum OpeningElementTypeEnum {
     /// An opening as subtraction feature that cuts through the element it
     /// voids. It thereby creates a hole. An opening in addiion have a
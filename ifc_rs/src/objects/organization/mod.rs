@@ -2,6 +2,7 @@ mod deserialize;
 mod serialize;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::id::{Id, IdOr, TypedId};
 use crate::ifc_type::{IfcType, IfcVerify};
@@ -16,7 +17,7 @@ use super::address::Address;
 /// A named and structured grouping with a corporate identity.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcactorresource/lexical/ifcorganization.htm
-#[derive(Debug, Clone, IfcVerify)]
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
 pub struct Organization {
     /// Identification of the organization.
     pub id: OptionalParameter<Label>,
@@ -75,4 +76,5 @@ impl Organization {
     }
 }
 
+#[typetag::serde(name = "IfcOrganization")]
 impl IfcType for Organization {}
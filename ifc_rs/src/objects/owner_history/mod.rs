@@ -2,6 +2,7 @@ mod deserialize;
 mod serialize;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{IdOr, TypedId},
@@ -19,7 +20,7 @@ use super::person_and_org::PersonAndOrganization;
 ///  independent objects, relationships and properties.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcutilityresource/lexical/ifcownerhistory.htm
-#[derive(Debug, Clone, IfcVerify)]
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
 pub struct OwnerHistory {
     /// Direct reference to the end user who currently "owns" this object.
     /// Note that IFC includes the concept of ownership transfer from one
@@ -108,4 +109,5 @@ impl OwnerHistory {
     }
 }
 
+#[typetag::serde(name = "IfcOwnerHistory")]
 impl IfcType for OwnerHistory {}
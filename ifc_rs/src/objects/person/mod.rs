@@ -2,6 +2,7 @@ mod deserialize;
 mod serialize;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::id::{Id, IdOr, TypedId};
 use crate::ifc_type::{IfcType, IfcVerify};
@@ -16,7 +17,7 @@ use super::address::Address;
 /// An individual human being.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcactorresource/lexical/ifcperson.htm
-#[derive(Debug, Clone, IfcVerify)]
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
 pub struct Person {
     /// Identification of the person.
     pub id: OptionalParameter<Label>,
@@ -141,4 +142,5 @@ impl Person {
     }
 }
 
+#[typetag::serde(name = "IfcPerson")]
 impl IfcType for Person {}
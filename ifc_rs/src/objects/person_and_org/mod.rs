@@ -2,6 +2,7 @@ mod deserialize;
 mod serialize;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{IdOr, TypedId},
@@ -13,7 +14,7 @@ use crate::{
 /// Identification of a person within an organization.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcactorresource/lexical/ifcpersonandorganization.htm
-#[derive(Debug, Clone, IfcVerify)]
+#[derive(Debug, Clone, IfcVerify, Serialize, Deserialize)]
 pub struct PersonAndOrganization {
     /// The person who is related to the organization.
     pub the_person: TypedId<Person>,
@@ -51,6 +52,7 @@ impl PersonAndOrganization {
     }
 }
 
+#[typetag::serde(name = "IfcPersonAndOrganization")]
 impl IfcType for PersonAndOrganization {}
 
 pub trait PersonOrOrg: IfcType {}
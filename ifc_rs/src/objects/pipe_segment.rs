@@ -0,0 +1,160 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+/// A pipe segment is used to typically join two sections of a piping
+/// network. Consecutive segments are connected through the
+/// `IfcDistributionPort`s nested at their ends.
+///
+/// A particular usage type can be given by referring to the type object
+/// `IfcPipeSegmentType` using the `IfcRelDefinesByType` relationship, or by
+/// the PredefinedType attribute.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpipesegment.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PipeSegment {
+    #[inherited]
+    element: Element,
+
+    /// Predefined generic type of the pipe segment.
+    ///
+    /// Note: The use of the predefined type directly at the occurrence object
+    /// level is only permitted, if no type object `IfcPipeSegmentType` is
+    /// assigned.
+    pub predefined_type: OptionalParameter<PipeSegmentTypeEnum>,
+}
+
+impl PipeSegment {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+            predefined_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn predefined_type(mut self, predefined_type: PipeSegmentTypeEnum) -> Self {
+        self.predefined_type = predefined_type.into();
+        self
+    }
+}
+
+impl RootBuilder for PipeSegment {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for PipeSegment {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for PipeSegment {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for PipeSegment {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for PipeSegment {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for PipeSegment {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl IFCParse for PipeSegment {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPIPESEGMENT("),
+
+                element: Element::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PipeSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCPIPESEGMENT({},{});",
+            self.element, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcPipeSegment")]
+impl IfcType for PipeSegment {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for PipeSegment {}
+impl MaterialRelatable for PipeSegment {}
+impl ClassificationRelatable for PipeSegment {}
+impl GroupRelatable for PipeSegment {}
+
+impl TransformableType for PipeSegment {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::PipeSegment;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn pipe_segment_round_trip() {
+        let examples = [
+            "IFCPIPESEGMENT('0Fh1oKxQ55Z9b8WjH3tD1m',#2,'Pipe',$,$,#37,#54,$,.RIGIDSEGMENT.);",
+            "IFCPIPESEGMENT('3t9Pd0VKr6$9zWQ3pFZ0Ej',#12,'Pipe',$,$,#70311,#70342,'A-12',$);",
+        ];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: PipeSegment = PipeSegment::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use type_enum::PipeFittingTypeEnum;
+
+/// The element type IfcPipeFittingType defines commonly shared information for
+/// occurrences of pipe fittings. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcFlowFitting or its
+/// specific subtype IfcPipeFitting.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpipefittingtype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PipeFittingType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined type of the pipe fitting.
+    pub predefined_type: PipeFittingTypeEnum,
+}
+
+impl PipeFittingType {
+    pub fn new(name: impl Into<Label>, predefined_type: PipeFittingTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for PipeFittingType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for PipeFittingType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for PipeFittingType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for PipeFittingType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for PipeFittingType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for PipeFittingType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPIPEFITTINGTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: PipeFittingTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PipeFittingType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCPIPEFITTINGTYPE({},{});",
+            self.element_type, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcPipeFittingType")]
+impl IfcType for PipeFittingType {}
+impl MaterialRelatable for PipeFittingType {}
+impl ClassificationRelatable for PipeFittingType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::PipeFittingType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn pipe_fitting_type_round_trip() {
+        let example =
+            "IFCPIPEFITTINGTYPE('0Jd8Ks3mF6yQ1bW9hVn2cP',#2,'Elbow 90',$,$,$,$,$,$,.BEND.);";
+
+        let parsed: PipeFittingType = PipeFittingType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
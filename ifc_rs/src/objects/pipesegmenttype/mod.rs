@@ -0,0 +1,125 @@
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use type_enum::PipeSegmentTypeEnum;
+
+/// The element type IfcPipeSegmentType defines commonly shared information for
+/// occurrences of pipe segments. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcPipeSegment.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpipesegmenttype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PipeSegmentType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined type of the pipe segment.
+    pub predefined_type: PipeSegmentTypeEnum,
+}
+
+impl PipeSegmentType {
+    pub fn new(name: impl Into<Label>, predefined_type: PipeSegmentTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for PipeSegmentType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for PipeSegmentType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for PipeSegmentType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for PipeSegmentType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for PipeSegmentType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for PipeSegmentType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPIPESEGMENTTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: PipeSegmentTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PipeSegmentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCPIPESEGMENTTYPE({},{});",
+            self.element_type, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcPipeSegmentType")]
+impl IfcType for PipeSegmentType {}
+impl MaterialRelatable for PipeSegmentType {}
+impl ClassificationRelatable for PipeSegmentType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::PipeSegmentType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn pipe_segment_type_round_trip() {
+        let example =
+            "IFCPIPESEGMENTTYPE('1e$Xq0f2D5iP7nR3a8sBkT',#2,'Copper 22',$,$,$,$,$,$,.RIGIDSEGMENT.);";
+
+        let parsed: PipeSegmentType = PipeSegmentType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
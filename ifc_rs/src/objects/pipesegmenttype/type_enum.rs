@@ -0,0 +1,57 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the different types of pipe segments.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpipesegmenttypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum PipeSegmentTypeEnum {
+    /// A pipe segment to convey water beneath a road, railway or similar.
+    #[strum(to_string = ".CULVERT.")]
+    Culvert,
+
+    /// A flexible pipe segment.
+    #[strum(to_string = ".FLEXIBLESEGMENT.")]
+    FlexibleSegment,
+
+    /// A rigid pipe segment.
+    #[strum(to_string = ".RIGIDSEGMENT.")]
+    RigidSegment,
+
+    /// A gutter collecting rain water, e.g. at the eaves of a roof.
+    #[strum(to_string = ".GUTTER.")]
+    Gutter,
+
+    /// A prefabricated section of pipe.
+    #[strum(to_string = ".SPOOL.")]
+    Spool,
+
+    /// User-defined type.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined type.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for PipeSegmentTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid PipeSegmentTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
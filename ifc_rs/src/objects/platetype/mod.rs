@@ -0,0 +1,125 @@
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use type_enum::PlateTypeEnum;
+
+/// The element type IfcPlateType defines commonly shared information for
+/// occurrences of plates. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcPlate.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcplatetype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PlateType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined type of the plate.
+    pub predefined_type: PlateTypeEnum,
+}
+
+impl PlateType {
+    pub fn new(name: impl Into<Label>, predefined_type: PlateTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for PlateType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for PlateType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for PlateType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for PlateType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for PlateType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for PlateType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPLATETYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: PlateTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PlateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCPLATETYPE({},{});",
+            self.element_type, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcPlateType")]
+impl IfcType for PlateType {}
+impl MaterialRelatable for PlateType {}
+impl ClassificationRelatable for PlateType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::PlateType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn plate_type_round_trip() {
+        let example =
+            "IFCPLATETYPE('1Fn3Gh6kM9pS2vY5bE8rTw',#2,'Glass panel',$,$,$,$,$,$,.CURTAIN_PANEL.);";
+
+        let parsed: PlateType = PlateType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -1,15 +1,66 @@
 pub use super::access_state::AccessState;
 pub use super::actor_role::{ActorRole, Role};
 pub use super::address::*;
+pub use super::airterminaltype::{type_enum::AirTerminalTypeEnum, AirTerminalType};
 pub use super::application::Application;
+pub use super::beam::Beam;
+pub use super::beamtype::{type_enum::BeamTypeEnum, BeamType};
 pub use super::building::Building;
+pub use super::building_element_proxy::BuildingElementProxy;
+pub use super::building_system::{type_enum::BuildingSystemTypeEnum, BuildingSystem};
+pub use super::buildingelementproxytype::{
+    type_enum::BuildingElementProxyTypeEnum, BuildingElementProxyType,
+};
 pub use super::change_action::ChangeAction;
+pub use super::classification::Classification;
+pub use super::classification_reference::ClassificationReference;
+pub use super::column::Column;
+pub use super::columntype::{type_enum::ColumnTypeEnum, ColumnType};
+pub use super::covering::Covering;
+pub use super::coveringtype::{type_enum::CoveringTypeEnum, CoveringType};
+pub use super::curtain_wall::CurtainWall;
+pub use super::curtainwalltype::{type_enum::CurtainWallTypeEnum, CurtainWallType};
+pub use super::distribution_port::{
+    flow_direction_enum::FlowDirectionEnum, type_enum::DistributionPortTypeEnum, DistributionPort,
+};
+pub use super::distribution_system::{type_enum::DistributionSystemEnum, DistributionSystem};
+pub use super::door::Door;
+pub use super::doortype::{
+    door_type_enum::DoorTypeEnum, door_type_operation_enum::DoorTypeOperationEnum, DoorType,
+};
+pub use super::duct_segment::DuctSegment;
+pub use super::ductfittingtype::{type_enum::DuctFittingTypeEnum, DuctFittingType};
+pub use super::ductsegmenttype::{type_enum::DuctSegmentTypeEnum, DuctSegmentType};
+pub use super::flow_fitting::FlowFitting;
+pub use super::flow_segment::FlowSegment;
+pub use super::flow_terminal::FlowTerminal;
+pub use super::furnishing_element::FurnishingElement;
+pub use super::furnishingelementtype::FurnishingElementType;
+pub use super::furniture::Furniture;
+pub use super::furnituretype::{
+    assembly_place_enum::AssemblyPlaceEnum, type_enum::FurnitureTypeEnum, FurnitureType,
+};
+pub use super::grid::{type_enum::GridTypeEnum, Grid};
+pub use super::group::Group;
+pub use super::member::Member;
+pub use super::membertype::{type_enum::MemberTypeEnum, MemberType};
 pub use super::opening_element::OpeningElement;
 pub use super::organization::Organization;
 pub use super::owner_history::OwnerHistory;
 pub use super::person::Person;
 pub use super::person_and_org::PersonAndOrganization;
+pub use super::pipe_segment::PipeSegment;
+pub use super::pipefittingtype::{type_enum::PipeFittingTypeEnum, PipeFittingType};
+pub use super::pipesegmenttype::{type_enum::PipeSegmentTypeEnum, PipeSegmentType};
+pub use super::plate::Plate;
+pub use super::platetype::{type_enum::PlateTypeEnum, PlateType};
 pub use super::project::Project;
+pub use super::railing::Railing;
+pub use super::railingtype::{type_enum::RailingTypeEnum, RailingType};
+pub use super::ramp::Ramp;
+pub use super::ramp_flight::RampFlight;
+pub use super::rampflighttype::{type_enum::RampFlightTypeEnum, RampFlightType};
+pub use super::ramptype::{type_enum::RampTypeEnum, RampType};
 pub use super::roof::Roof;
 pub use super::rooftype::{type_enum::RoofTypeEnum, RoofType};
 pub use super::shared::{
@@ -31,7 +82,12 @@ pub use super::slab::Slab;
 pub use super::slabtype::{type_enum::SlabTypeEnum, SlabType};
 pub use super::space::Space;
 pub use super::spacetype::{type_enum::SpaceTypeEnum, SpaceType};
+pub use super::stair::Stair;
+pub use super::stair_flight::StairFlight;
+pub use super::stairflighttype::{type_enum::StairFlightTypeEnum, StairFlightType};
+pub use super::stairtype::{type_enum::StairTypeEnum, StairType};
 pub use super::storey::Storey;
+pub use super::system::System;
 pub use super::wall::Wall;
 pub use super::walltype::{type_enum::WallTypeEnum, WallType};
 pub use super::window::Window;
@@ -39,4 +95,5 @@ pub use super::windowtype::{
     window_partitioning_type_enum::WindowPartitioningTypeEnum, window_type_enum::WindowTypeEnum,
     WindowType,
 };
+pub use super::zone::Zone;
 pub use super::{Structure, StructureType};
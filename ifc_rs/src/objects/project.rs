@@ -2,11 +2,13 @@ use std::ops::DerefMut;
 use std::{fmt::Display, ops::Deref};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::ifc_type::{IfcType, IfcVerify};
 use crate::parser::label::Label;
 use crate::parser::{p_space_or_comment_surrounded, IFCParse, IFCParser};
 use crate::prelude::*;
+use crate::relations::rel_associates_classification::ClassificationRelatable;
 
 use super::shared::context::Context;
 use super::shared::root::Root;
@@ -20,7 +22,7 @@ use super::shared::root::Root;
 /// information items included.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcproject.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Project {
     #[inherited]
     context: Context,
@@ -80,7 +82,9 @@ impl Display for Project {
     }
 }
 
+#[typetag::serde(name = "IfcProject")]
 impl IfcType for Project {}
+impl ClassificationRelatable for Project {}
 
 #[cfg(test)]
 mod test {
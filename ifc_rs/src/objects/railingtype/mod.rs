@@ -0,0 +1,125 @@
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use type_enum::RailingTypeEnum;
+
+/// The element type IfcRailingType defines commonly shared information for
+/// occurrences of railings. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcRailing.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrailingtype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RailingType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined type of the railing.
+    pub predefined_type: RailingTypeEnum,
+}
+
+impl RailingType {
+    pub fn new(name: impl Into<Label>, predefined_type: RailingTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for RailingType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for RailingType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for RailingType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for RailingType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for RailingType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for RailingType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRAILINGTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: RailingTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RailingType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCRAILINGTYPE({},{});",
+            self.element_type, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRailingType")]
+impl IfcType for RailingType {}
+impl MaterialRelatable for RailingType {}
+impl ClassificationRelatable for RailingType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::RailingType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn railing_type_round_trip() {
+        let example =
+            "IFCRAILINGTYPE('1e$Xq0f2D5iP7nR3a8sBkT',#2,'Handrail',$,$,$,$,$,$,.HANDRAIL.);";
+
+        let parsed: RailingType = RailingType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
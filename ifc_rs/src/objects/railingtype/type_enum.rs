@@ -0,0 +1,52 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the different types of railings.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrailingtypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum RailingTypeEnum {
+    /// A type of railing designed to serve as an optional structural
+    /// support for loads applied by human occupants, e.g. at a stair.
+    #[strum(to_string = ".HANDRAIL.")]
+    Handrail,
+
+    /// A type of railing designed to guard human occupants from falling,
+    /// e.g. at the edge of a landing.
+    #[strum(to_string = ".GUARDRAIL.")]
+    Guardrail,
+
+    /// Similar to the definition of a guardrail but typically placed at the
+    /// edge of a balcony or a gallery.
+    #[strum(to_string = ".BALUSTRADE.")]
+    Balustrade,
+
+    /// User-defined type.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined type.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for RailingTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid RailingTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
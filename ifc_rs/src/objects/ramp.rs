@@ -0,0 +1,153 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+/// A ramp is a vertical passageway which provides a human circulation link
+/// between one floor level and another floor level at a different
+/// elevation. It may include a landing as an intermediate floor slab.
+///
+/// The ramp is a container aggregating its parts, the ramp flights,
+/// landings and railings, with `IfcRelAggregates`.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcramp.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Ramp {
+    #[inherited]
+    element: Element,
+
+    /// Predefined generic type of the ramp.
+    ///
+    /// Note: The use of the predefined type directly at the occurrence object
+    /// level is only permitted, if no type object `IfcRampType` is
+    /// assigned.
+    pub predefined_type: OptionalParameter<RampTypeEnum>,
+}
+
+impl Ramp {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+            predefined_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn predefined_type(mut self, predefined_type: RampTypeEnum) -> Self {
+        self.predefined_type = predefined_type.into();
+        self
+    }
+}
+
+impl RootBuilder for Ramp {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for Ramp {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for Ramp {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for Ramp {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for Ramp {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for Ramp {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl IFCParse for Ramp {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRAMP("),
+
+                element: Element::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for Ramp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCRAMP({},{});", self.element, self.predefined_type)
+    }
+}
+
+#[typetag::serde(name = "IfcRamp")]
+impl IfcType for Ramp {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for Ramp {}
+impl MaterialRelatable for Ramp {}
+impl ClassificationRelatable for Ramp {}
+impl GroupRelatable for Ramp {}
+
+impl TransformableType for Ramp {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Ramp;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn ramp_round_trip() {
+        let examples =
+            ["IFCRAMP('0kX3mNb2Fh7Jd1pQ4aV9sL',#2,'Ramp',$,$,#37,$,$,.STRAIGHT_RUN_RAMP.);"];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: Ramp = Ramp::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
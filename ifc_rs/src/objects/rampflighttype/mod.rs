@@ -0,0 +1,125 @@
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use type_enum::RampFlightTypeEnum;
+
+/// The element type IfcRampFlightType defines commonly shared information for
+/// occurrences of ramp flights. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcRampFlight.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrampflighttype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RampFlightType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined type of the ramp flight.
+    pub predefined_type: RampFlightTypeEnum,
+}
+
+impl RampFlightType {
+    pub fn new(name: impl Into<Label>, predefined_type: RampFlightTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for RampFlightType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for RampFlightType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for RampFlightType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for RampFlightType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for RampFlightType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for RampFlightType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRAMPFLIGHTTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: RampFlightTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RampFlightType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCRAMPFLIGHTTYPE({},{});",
+            self.element_type, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRampFlightType")]
+impl IfcType for RampFlightType {}
+impl MaterialRelatable for RampFlightType {}
+impl ClassificationRelatable for RampFlightType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::RampFlightType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn ramp_flight_type_round_trip() {
+        let example =
+            "IFCRAMPFLIGHTTYPE('2Qm5xWb7H1tC9vZ0LkE3aR',#2,'Ramp flight',$,$,$,$,$,$,.SPIRAL.);";
+
+        let parsed: RampFlightType = RampFlightType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,125 @@
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use type_enum::RampTypeEnum;
+
+/// The element type IfcRampType defines commonly shared information for
+/// occurrences of ramps. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcRamp.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcramptype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RampType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined type of the ramp.
+    pub predefined_type: RampTypeEnum,
+}
+
+impl RampType {
+    pub fn new(name: impl Into<Label>, predefined_type: RampTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for RampType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for RampType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for RampType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for RampType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for RampType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for RampType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRAMPTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: RampTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RampType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCRAMPTYPE({},{});",
+            self.element_type, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRampType")]
+impl IfcType for RampType {}
+impl MaterialRelatable for RampType {}
+impl ClassificationRelatable for RampType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::RampType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn ramp_type_round_trip() {
+        let example =
+            "IFCRAMPTYPE('1Hs6Vp2kB8eN4fR0tYq7zD',#2,'Ramp',$,$,$,$,$,$,.STRAIGHT_RUN_RAMP.);";
+
+        let parsed: RampType = RampType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,67 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the basic configuration of the ramp type in
+/// terms of the number and shape of ramp flights and landings.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcramptypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum RampTypeEnum {
+    /// A ramp which extends from one level to another without turns.
+    #[strum(to_string = ".STRAIGHT_RUN_RAMP.")]
+    StraightRunRamp,
+
+    /// A ramp consisting of two straight flights without turns but with one
+    /// landing.
+    #[strum(to_string = ".TWO_STRAIGHT_RUN_RAMP.")]
+    TwoStraightRunRamp,
+
+    /// A ramp making a 90° turn, consisting of two straight flights
+    /// connected by a quarterspace landing.
+    #[strum(to_string = ".QUARTER_TURN_RAMP.")]
+    QuarterTurnRamp,
+
+    /// A ramp making a 180° turn, consisting of three straight flights
+    /// connected by two quarterspace landings.
+    #[strum(to_string = ".TWO_QUARTER_TURN_RAMP.")]
+    TwoQuarterTurnRamp,
+
+    /// A ramp making a 180° turn, consisting of two straight flights
+    /// connected by a halfspace landing.
+    #[strum(to_string = ".HALF_TURN_RAMP.")]
+    HalfTurnRamp,
+
+    /// A ramp constructed around a circular or elliptical well without
+    /// newels and landings.
+    #[strum(to_string = ".SPIRAL_RAMP.")]
+    SpiralRamp,
+
+    /// User-defined type.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined type.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for RampTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid RampTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
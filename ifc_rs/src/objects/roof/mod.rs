@@ -4,6 +4,7 @@ mod serialize;
 use std::ops::{Deref, DerefMut};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use super::{
     shared::{
@@ -19,7 +20,12 @@ use crate::{
     ifc_type::{IfcType, IfcVerify},
     parser::{label::Label, optional::OptionalParameter},
     prelude::{ProductDefinitionShape, RoofTypeEnum, TransformableType},
-    relations::rel_associates_material::MaterialRelatable,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable, rel_connects_elements::ConnectionRelatable,
+        rel_space_boundary::SpaceBoundaryRelatable,
+    },
     IFC,
 };
 
@@ -29,9 +35,9 @@ use crate::{
 /// The IfcRoof shall either be represented:
 ///
 /// * as a roof assembly that aggregates all parts (slabs, rafters and purlins,
-/// or other included roofs, such as dormers) with own shape representaion, or
+///   or other included roofs, such as dormers) with own shape representaion, or
 /// * as a single roof without decomposition including all shape representations
-/// directly at the roof entity.
+///   directly at the roof entity.
 ///
 /// Note: In case of an IfcRoof being the assembly of all parts of the roof the
 /// aggregation is handled by the IfcRelAggregates relationship, relating an
@@ -40,7 +46,7 @@ use crate::{
 /// as dormers (represented by IfcRoof).
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcroof.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Roof {
     #[inherited]
     element: Element,
@@ -106,6 +112,7 @@ impl DerefMut for Roof {
     }
 }
 
+#[typetag::serde(name = "IfcRoof")]
 impl IfcType for Roof {
     fn to_structure(&self) -> Option<&dyn Structure> {
         Some(self)
@@ -117,6 +124,10 @@ impl Structure for Roof {
     }
 }
 impl MaterialRelatable for Roof {}
+impl ClassificationRelatable for Roof {}
+impl GroupRelatable for Roof {}
+impl SpaceBoundaryRelatable for Roof {}
+impl ConnectionRelatable for Roof {}
 
 impl TransformableType for Roof {
     fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
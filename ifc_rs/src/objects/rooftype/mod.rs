@@ -1,13 +1,17 @@
 use std::ops::Deref;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 use type_enum::RoofTypeEnum;
 
 use crate::{
     ifc_type::{IfcType, IfcVerify},
     parser::label::Label,
     prelude::{ElementTypeBuilder, Root, RootBuilder, TypeObject, TypeProduct},
-    relations::rel_associates_material::MaterialRelatable,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
     IFC,
 };
 
@@ -23,7 +27,7 @@ pub mod type_enum;
 /// for occurrences of roofs.
 ///
 /// https://standards.buildingsmart.org/MVD/RELEASE/IFC4/ADD2_TC1/RV1_2/HTML/schema/ifcsharedbldgelements/lexical/ifcrooftype.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct RoofType {
     #[inherited]
     element_type: ElementType,
@@ -76,8 +80,10 @@ impl Deref for RoofType {
     }
 }
 
+#[typetag::serde(name = "IfcRoofType")]
 impl IfcType for RoofType {}
 impl MaterialRelatable for RoofType {}
+impl ClassificationRelatable for RoofType {}
 
 #[cfg(test)]
 mod test {
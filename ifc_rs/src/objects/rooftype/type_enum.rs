@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, VariantNames};
 use winnow::combinator::{alt, delimited};
 use winnow::Parser;
@@ -13,7 +14,7 @@ use crate::parser::*;
 /// non-regular shapes (free form roofs) have the type FREEFORM.
 ///
 /// https://standards.buildingsmart.org/MVD/RELEASE/IFC4/ADD2_TC1/RV1_2/HTML/schema/ifcsharedbldgelements/lexical/ifcrooftypeenum.htm
-#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
 pub enum RoofTypeEnum {
     /// A roof having no slope, or one with only a slight pitch so as to drain
     /// rainwater.
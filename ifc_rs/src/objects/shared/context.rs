@@ -2,6 +2,7 @@ use std::ops::DerefMut;
 use std::{fmt::Display, ops::Deref};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{IdOr, TypedId},
@@ -23,7 +24,7 @@ use super::root::Root;
 /// IfcProjectLibrary as the context of that library.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccontext.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Context {
     #[inherited]
     root: Root,
@@ -4,6 +4,7 @@ use std::{
 };
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     ifc_type::IfcVerify,
@@ -16,7 +17,7 @@ use super::product::Product;
 /// An element is a generalization of all components that make up an AEC product.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcproductextension/lexical/ifcelement.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Element {
     #[inherited]
     product: Product,
@@ -2,6 +2,7 @@ use std::ops::DerefMut;
 use std::{fmt::Display, ops::Deref};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     ifc_type::IfcVerify,
@@ -18,7 +19,7 @@ use super::type_product::TypeProduct;
 /// all occurrences of that product type).
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcproductextension/lexical/ifcelementtype.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct ElementType {
     #[inherited]
     type_product: TypeProduct,
@@ -2,6 +2,7 @@ use std::ops::DerefMut;
 use std::{fmt::Display, ops::Deref};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     ifc_type::IfcVerify,
@@ -15,7 +16,7 @@ use super::root::Root;
 /// thing or process. Objects are things as they appear - i.e. occurrences.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifckernel/lexical/ifcobject.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Object {
     #[inherited]
     root: Root,
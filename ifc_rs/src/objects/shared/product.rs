@@ -4,6 +4,7 @@ use std::{
 };
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     geometry::{
@@ -30,7 +31,7 @@ use super::object::Object;
 /// (with or without underlying geometry of the topological items).
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifckernel/lexical/ifcproduct.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Product {
     #[inherited]
     object: Object,
@@ -42,7 +43,7 @@ pub struct Product {
     /// the various subtypes of IfcObjectPlacement, which includes the
     /// axis placement information to determine the transformation for
     /// the object coordinate system.
-    #[ifc_types(Axis3D, Point3D, LocalPlacement)]
+    #[ifc_types(Axis3D, Point3D, LocalPlacement, GridPlacement)]
     pub object_placement: OptionalParameter<Id>,
 
     /// Reference to the representations of the product, being either a
@@ -91,6 +92,16 @@ pub trait ProductBuilder: Sized {
         self
     }
 
+    /// Places the product at a grid intersection.
+    fn grid_placement(
+        mut self,
+        grid_placement: impl Into<IdOr<GridPlacement>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.product_mut().object_placement = grid_placement.into().or_insert(ifc).id().into();
+        self
+    }
+
     fn representation(
         mut self,
         representation: impl Into<IdOr<ProductDefinitionShape>>,
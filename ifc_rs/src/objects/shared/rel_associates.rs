@@ -2,6 +2,7 @@ use std::ops::DerefMut;
 use std::{fmt::Display, ops::Deref};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{Id, IdOr},
@@ -18,7 +19,7 @@ use super::root::Root;
 /// There is no dependency implied by the association.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelassociates.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct RelAssociates {
     #[inherited]
     root: Root,
@@ -4,6 +4,7 @@ use comma::Comma;
 use ifc_rs_verify_derive::IfcVerify;
 use label::Label;
 use optional::OptionalParameter;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
@@ -22,7 +23,7 @@ use crate::{
 /// that are not subtypes of IfcRoot, are not supposed to be independent entities.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifckernel/lexical/ifcroot.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Root {
     /// Assignment of a globally unique identifier within the entire software world.
     pub global_id: Label,
@@ -4,6 +4,7 @@ use std::{
 };
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     ifc_type::IfcVerify,
@@ -17,7 +18,7 @@ use super::product::Product;
 /// might be used to define a spatial structure or to define spatial zones.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcproductextension/lexical/ifcspatialelement.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct SpatialElement {
     #[inherited]
     product: Product,
@@ -4,11 +4,12 @@ use std::{
 };
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::Id,
     ifc_type::IfcVerify,
-    parser::{comma::Comma, optional::OptionalParameter, IFCParse, IFCParser},
+    parser::{comma::Comma, list::IfcList, optional::OptionalParameter, IFCParse, IFCParser},
     prelude::*,
 };
 
@@ -21,7 +22,7 @@ use super::root::Root;
 /// object instances (the occurrences) via the IfcRelDefinesByType relationship.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifckernel/lexical/ifctypeobject.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct TypeObject {
     #[inherited]
     root: Root,
@@ -43,7 +44,7 @@ pub struct TypeObject {
     /// Set list of unique property sets, that are associated with the
     /// object type and are common to all object occurrences referring
     /// to this object type.
-    pub has_property_sets: OptionalParameter<Id>,
+    pub has_property_sets: OptionalParameter<IfcList<Id>>,
 }
 
 impl TypeObject {
@@ -54,11 +55,28 @@ impl TypeObject {
             has_property_sets: OptionalParameter::omitted(),
         }
     }
+
+    pub(crate) fn push_property_set(&mut self, property_set: Id) {
+        match self.has_property_sets.custom_mut() {
+            Some(property_sets) => property_sets.0.push(property_set),
+            None => self.has_property_sets = IfcList(vec![property_set]).into(),
+        }
+    }
 }
 
 pub trait TypeObjectBuilder: Sized {
     fn type_object_mut(&mut self) -> &mut TypeObject;
 
+    fn add_property_set(
+        mut self,
+        property_set: impl Into<IdOr<PropertySet>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        let property_set = property_set.into().or_insert(ifc).id();
+        self.type_object_mut().push_property_set(property_set);
+        self
+    }
+
     // TODO
     // fn applicable_occurence(mut self, )
 }
@@ -4,9 +4,10 @@ use std::{
 };
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    id::TypedId,
+    id::{IdOr, TypedId},
     ifc_type::IfcVerify,
     parser::{
         comma::Comma, label::Label, list::IfcList, optional::OptionalParameter, IFCParse, IFCParser,
@@ -24,7 +25,7 @@ use super::type_object::TypeObject;
 /// of that product type.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifckernel/lexical/ifctypeproduct.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct TypeProduct {
     #[inherited]
     type_object: TypeObject,
@@ -49,16 +50,31 @@ impl TypeProduct {
             tag: OptionalParameter::omitted(),
         }
     }
+
+    pub(crate) fn push_representation_map(
+        &mut self,
+        representation_map: TypedId<RepresentationMap>,
+    ) {
+        match self.representation_maps.custom_mut() {
+            Some(representation_maps) => representation_maps.0.push(representation_map),
+            None => self.representation_maps = IfcList(vec![representation_map]).into(),
+        }
+    }
 }
 
 pub trait TypeProductBuilder: Sized {
     fn type_product_mut(&mut self) -> &mut TypeProduct;
 
-    // TODO
-    // fn representation_maps(mut self, representation_maps: impl Into<IdOr< /* TODO */ >>, ifc: &mut IFC) -> Self {
-    //     self.type_product_mut().representation_maps = representation_maps.into().or_insert(ifc).id().into();
-    //     self
-    // }
+    fn add_representation_map(
+        mut self,
+        representation_map: impl Into<IdOr<RepresentationMap>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        let representation_map = representation_map.into().or_insert(ifc);
+        self.type_product_mut()
+            .push_representation_map(representation_map);
+        self
+    }
 
     fn tag(mut self, tag: impl Into<Label>) -> Self {
         self.type_product_mut().tag = tag.into().into();
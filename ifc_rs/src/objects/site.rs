@@ -4,6 +4,7 @@ use std::{
 };
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{IdOr, TypedId},
@@ -51,7 +52,7 @@ use crate::{
 /// serves as the primary project breakdown and is required to be hierarchical).
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcproductextension/lexical/ifcsite.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Site {
     #[inherited]
     spatial_element_structure: SpatialStructureElement,
@@ -205,6 +206,7 @@ impl Display for Site {
     }
 }
 
+#[typetag::serde(name = "IfcSite")]
 impl IfcType for Site {
     fn to_structure(&self) -> Option<&dyn Structure> {
         Some(self)
@@ -4,13 +4,19 @@ mod serialize;
 use std::ops::{Deref, DerefMut};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::TypedId,
     ifc_type::{IfcType, IfcVerify},
     parser::{label::Label, optional::OptionalParameter},
     prelude::*,
-    relations::rel_associates_material::MaterialRelatable,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable, rel_connects_elements::ConnectionRelatable,
+        rel_space_boundary::SpaceBoundaryRelatable,
+    },
 };
 
 use super::StructureType;
@@ -38,7 +44,7 @@ use super::StructureType;
 /// given at the attribute ObjectType.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcsharedbldgelements/lexical/ifcslab.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Slab {
     #[inherited]
     element: Element,
@@ -104,6 +110,7 @@ impl DerefMut for Slab {
     }
 }
 
+#[typetag::serde(name = "IfcSlab")]
 impl IfcType for Slab {
     fn to_structure(&self) -> Option<&dyn Structure> {
         Some(self)
@@ -115,6 +122,10 @@ impl Structure for Slab {
     }
 }
 impl MaterialRelatable for Slab {}
+impl ClassificationRelatable for Slab {}
+impl GroupRelatable for Slab {}
+impl SpaceBoundaryRelatable for Slab {}
+impl ConnectionRelatable for Slab {}
 
 impl TransformableType for Slab {
     fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
@@ -1,13 +1,17 @@
 use std::ops::Deref;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 use type_enum::SlabTypeEnum;
 
 use crate::{
     ifc_type::{IfcType, IfcVerify},
     parser::label::Label,
     prelude::{ElementTypeBuilder, Root, RootBuilder, TypeObject, TypeProduct},
-    relations::rel_associates_material::MaterialRelatable,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
     IFC,
 };
 
@@ -43,7 +47,7 @@ pub mod type_enum;
 /// by instances of IfcSlabStandardCase if the IfcSlabType has a single
 /// associated IfcMaterialLayerSet; otherwise they are represented by instances
 /// of IfcSlab, or IfcSlabElementedCase.
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct SlabType {
     #[inherited]
     element_type: ElementType,
@@ -96,8 +100,10 @@ impl Deref for SlabType {
     }
 }
 
+#[typetag::serde(name = "IfcSlabType")]
 impl IfcType for SlabType {}
 impl MaterialRelatable for SlabType {}
+impl ClassificationRelatable for SlabType {}
 
 #[cfg(test)]
 mod test {
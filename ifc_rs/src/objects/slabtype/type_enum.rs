@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, VariantNames};
 use winnow::combinator::{alt, delimited};
 use winnow::Parser;
@@ -10,7 +11,7 @@ use crate::parser::*;
 /// IfcSlabTypeEnum can be used for slab occurrences, IfcSlab, and slab
 /// types, IfcSlabType. A special property set definition may be provided
 /// for each predefined type.
-#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
 pub enum SlabTypeEnum {
     /// The slab is used to represent a floor slab.
     #[strum(to_string = ".FLOOR.")]
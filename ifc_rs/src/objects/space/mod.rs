@@ -4,6 +4,7 @@ mod serialize;
 use std::ops::{Deref, DerefMut};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use super::shared::{
     object::{Object, ObjectBuilder},
@@ -17,6 +18,10 @@ use crate::{
         SpaceTypeEnum, SpatialElement, SpatialElementBuilder, SpatialStructureElement,
         SpatialStructureElementBuilder, Structure,
     },
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+    },
     IFC,
 };
 
@@ -44,7 +49,7 @@ use crate::{
 /// relationship IfcRelAggregates.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcproductextension/lexical/ifcspace.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Space {
     #[inherited]
     spatial_element_structure: SpatialStructureElement,
@@ -127,12 +132,15 @@ impl DerefMut for Space {
     }
 }
 
+#[typetag::serde(name = "IfcSpace")]
 impl IfcType for Space {
     fn to_structure(&self) -> Option<&dyn Structure> {
         Some(self)
     }
 }
 impl Structure for Space {}
+impl ClassificationRelatable for Space {}
+impl GroupRelatable for Space {}
 
 #[cfg(test)]
 pub mod test {
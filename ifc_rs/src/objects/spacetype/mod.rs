@@ -1,12 +1,14 @@
 use std::ops::Deref;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 pub use type_enum::SpaceTypeEnum;
 
 use crate::{
     ifc_type::{IfcType, IfcVerify},
     parser::{label::Label, optional::OptionalParameter},
     prelude::{ElementTypeBuilder, Root, RootBuilder, TypeObject, TypeProduct},
+    relations::rel_associates_classification::ClassificationRelatable,
     IFC,
 };
 
@@ -40,7 +42,7 @@ pub mod type_enum;
 /// The occurrences of IfcSpaceType are represented by instances of IfcSpace.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcproductextension/lexical/ifcspacetype.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct SpaceType {
     #[inherited]
     element_type: ElementType,
@@ -98,7 +100,9 @@ impl Deref for SpaceType {
     }
 }
 
+#[typetag::serde(name = "IfcSpaceType")]
 impl IfcType for SpaceType {}
+impl ClassificationRelatable for SpaceType {}
 
 #[cfg(test)]
 mod test {
@@ -0,0 +1,156 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+/// A stair is a vertical passageway allowing occupants to walk (step) from
+/// one floor level to another floor level at a different elevation. It may
+/// include a landing as an intermediate floor slab.
+///
+/// The stair is a container aggregating its parts, the stair flights,
+/// landings and railings, with `IfcRelAggregates`. The parts carry the
+/// shape representations, the stair itself may have none.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcstair.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Stair {
+    #[inherited]
+    element: Element,
+
+    /// Predefined generic type of the stair.
+    ///
+    /// Note: The use of the predefined type directly at the occurrence object
+    /// level is only permitted, if no type object `IfcStairType` is
+    /// assigned.
+    pub predefined_type: OptionalParameter<StairTypeEnum>,
+}
+
+impl Stair {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+            predefined_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn predefined_type(mut self, predefined_type: StairTypeEnum) -> Self {
+        self.predefined_type = predefined_type.into();
+        self
+    }
+}
+
+impl RootBuilder for Stair {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for Stair {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for Stair {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for Stair {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for Stair {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for Stair {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl IFCParse for Stair {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCSTAIR("),
+
+                element: Element::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for Stair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCSTAIR({},{});", self.element, self.predefined_type)
+    }
+}
+
+#[typetag::serde(name = "IfcStair")]
+impl IfcType for Stair {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for Stair {}
+impl MaterialRelatable for Stair {}
+impl ClassificationRelatable for Stair {}
+impl GroupRelatable for Stair {}
+
+impl TransformableType for Stair {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Stair;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn stair_round_trip() {
+        let examples = [
+            "IFCSTAIR('1Wq2YO$MX4xv5uCqZZG05x',#2,'Stair',$,$,#37,$,$,.STRAIGHT_RUN_STAIR.);",
+            "IFCSTAIR('2Hd7Jd1pQ4aV9sL0cX3mNb',#12,'Treppe',$,$,#70311,#70342,'T-01',$);",
+        ];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: Stair = Stair::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::TypedId,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, ifc_integer::IfcInteger, label::Label,
+        optional::OptionalParameter, p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+/// A stair flight is an assembly of building components in a single "run"
+/// of stair steps (not interrupted by a landing). The stair steps and any
+/// stringers are included in the stair flight. It is an aggregated part of
+/// an `IfcStair`.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcstairflight.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct StairFlight {
+    #[inherited]
+    element: Element,
+
+    /// Number of the risers included in the stair flight.
+    pub number_of_risers: OptionalParameter<IfcInteger>,
+
+    /// Number of treads included in the stair flight.
+    pub number_of_treads: OptionalParameter<IfcInteger>,
+
+    /// Vertical distance from tread to tread. The riser height is supposed
+    /// to be equal for all steps of a stair or stair flight.
+    pub riser_height: OptionalParameter<IfcFloat>,
+
+    /// Horizontal distance from the front of the thread to the front of the
+    /// next tread. The tread length is supposed to be equal for all steps of
+    /// the stair or stair flight at the walking line.
+    pub tread_length: OptionalParameter<IfcFloat>,
+
+    /// Predefined generic type of the stair flight.
+    ///
+    /// Note: The use of the predefined type directly at the occurrence object
+    /// level is only permitted, if no type object `IfcStairFlightType` is
+    /// assigned.
+    pub predefined_type: OptionalParameter<StairFlightTypeEnum>,
+}
+
+impl StairFlight {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            element: Element::new(Product::new(Object::new(Root::new(name.into())))),
+            number_of_risers: OptionalParameter::omitted(),
+            number_of_treads: OptionalParameter::omitted(),
+            riser_height: OptionalParameter::omitted(),
+            tread_length: OptionalParameter::omitted(),
+            predefined_type: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn number_of_risers(mut self, number_of_risers: impl Into<IfcInteger>) -> Self {
+        self.number_of_risers = number_of_risers.into().into();
+        self
+    }
+
+    pub fn number_of_treads(mut self, number_of_treads: impl Into<IfcInteger>) -> Self {
+        self.number_of_treads = number_of_treads.into().into();
+        self
+    }
+
+    pub fn riser_height(mut self, riser_height: impl Into<IfcFloat>) -> Self {
+        self.riser_height = riser_height.into().into();
+        self
+    }
+
+    pub fn tread_length(mut self, tread_length: impl Into<IfcFloat>) -> Self {
+        self.tread_length = tread_length.into().into();
+        self
+    }
+
+    pub fn predefined_type(mut self, predefined_type: StairFlightTypeEnum) -> Self {
+        self.predefined_type = predefined_type.into();
+        self
+    }
+}
+
+impl RootBuilder for StairFlight {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element
+    }
+}
+
+impl ObjectBuilder for StairFlight {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.element
+    }
+}
+
+impl ProductBuilder for StairFlight {
+    fn product_mut(&mut self) -> &mut Product {
+        &mut self.element
+    }
+}
+
+impl ElementBuilder for StairFlight {
+    fn element_mut(&mut self) -> &mut Element {
+        &mut self.element
+    }
+}
+
+impl Deref for StairFlight {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for StairFlight {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl IFCParse for StairFlight {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCSTAIRFLIGHT("),
+
+                element: Element::parse(),
+                _: Comma::parse(),
+                number_of_risers: OptionalParameter::parse(),
+                _: Comma::parse(),
+                number_of_treads: OptionalParameter::parse(),
+                _: Comma::parse(),
+                riser_height: OptionalParameter::parse(),
+                _: Comma::parse(),
+                tread_length: OptionalParameter::parse(),
+                _: Comma::parse(),
+                predefined_type: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for StairFlight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCSTAIRFLIGHT({},{},{},{},{},{});",
+            self.element,
+            self.number_of_risers,
+            self.number_of_treads,
+            self.riser_height,
+            self.tread_length,
+            self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcStairFlight")]
+impl IfcType for StairFlight {
+    fn to_structure(&self) -> Option<&dyn Structure> {
+        Some(self)
+    }
+}
+impl Structure for StairFlight {}
+impl MaterialRelatable for StairFlight {}
+impl ClassificationRelatable for StairFlight {}
+impl GroupRelatable for StairFlight {}
+
+impl TransformableType for StairFlight {
+    fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
+        self.representation.custom().cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::StairFlight;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn stair_flight_round_trip() {
+        let examples = [
+            "IFCSTAIRFLIGHT('3aV9sL0cX3mNb2Fh7Jd1pQ',#2,'Flight',$,$,#37,#54,$,16,15,0.175,0.28,.STRAIGHT.);",
+            "IFCSTAIRFLIGHT('0cX3mNb2Fh7Jd1pQ4aV9sL',#12,'Lauf',$,$,#70311,#70342,$,$,$,$,$,$);",
+        ];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: StairFlight = StairFlight::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use type_enum::StairFlightTypeEnum;
+
+/// The element type IfcStairFlightType defines commonly shared information for
+/// occurrences of stair flights. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcStairFlight.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcstairflighttype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct StairFlightType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined type of the stair flight.
+    pub predefined_type: StairFlightTypeEnum,
+}
+
+impl StairFlightType {
+    pub fn new(name: impl Into<Label>, predefined_type: StairFlightTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for StairFlightType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for StairFlightType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for StairFlightType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for StairFlightType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for StairFlightType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for StairFlightType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCSTAIRFLIGHTTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: StairFlightTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for StairFlightType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCSTAIRFLIGHTTYPE({},{});",
+            self.element_type, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcStairFlightType")]
+impl IfcType for StairFlightType {}
+impl MaterialRelatable for StairFlightType {}
+impl ClassificationRelatable for StairFlightType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::StairFlightType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn stair_flight_type_round_trip() {
+        let example =
+            "IFCSTAIRFLIGHTTYPE('3Ya1Rk7nG0uL5dT2pXm8wQ',#2,'Flight',$,$,$,$,$,$,.STRAIGHT.);";
+
+        let parsed: StairFlightType = StairFlightType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
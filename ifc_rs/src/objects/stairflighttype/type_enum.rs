@@ -0,0 +1,57 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the different types of stair flights.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcstairflighttypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum StairFlightTypeEnum {
+    /// A stair flight with a straight walking line.
+    #[strum(to_string = ".STRAIGHT.")]
+    Straight,
+
+    /// A stair flight with winders, changing its direction.
+    #[strum(to_string = ".WINDER.")]
+    Winder,
+
+    /// A stair flight with a circular or elliptic walking line.
+    #[strum(to_string = ".SPIRAL.")]
+    Spiral,
+
+    /// A stair flight with a curved walking line.
+    #[strum(to_string = ".CURVED.")]
+    Curved,
+
+    /// A stair flight with a free form walking line.
+    #[strum(to_string = ".FREEFORM.")]
+    FreeForm,
+
+    /// User-defined type.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined type.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for StairFlightTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid StairFlightTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
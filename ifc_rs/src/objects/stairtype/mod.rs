@@ -0,0 +1,125 @@
+pub mod type_enum;
+
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
+};
+
+use type_enum::StairTypeEnum;
+
+/// The element type IfcStairType defines commonly shared information for
+/// occurrences of stairs. The set of shared information may include
+/// common properties, materials and shape representations.
+///
+/// The occurrences are represented by instances of IfcStair.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcstairtype.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct StairType {
+    #[inherited]
+    element_type: ElementType,
+
+    /// Identifies the predefined type of the stair.
+    pub predefined_type: StairTypeEnum,
+}
+
+impl StairType {
+    pub fn new(name: impl Into<Label>, predefined_type: StairTypeEnum) -> Self {
+        Self {
+            element_type: ElementType::new(TypeProduct::new(TypeObject::new(Root::new(
+                name.into(),
+            )))),
+            predefined_type,
+        }
+    }
+}
+
+impl ElementTypeBuilder for StairType {
+    fn element_type_mut(&mut self) -> &mut ElementType {
+        &mut self.element_type
+    }
+}
+
+impl TypeProductBuilder for StairType {
+    fn type_product_mut(&mut self) -> &mut TypeProduct {
+        &mut self.element_type
+    }
+}
+
+impl TypeObjectBuilder for StairType {
+    fn type_object_mut(&mut self) -> &mut TypeObject {
+        &mut self.element_type
+    }
+}
+
+impl RootBuilder for StairType {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.element_type
+    }
+}
+
+impl Deref for StairType {
+    type Target = ElementType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element_type
+    }
+}
+
+impl IFCParse for StairType {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCSTAIRTYPE("),
+
+                element_type: ElementType::parse(),
+                _: Comma::parse(),
+                predefined_type: StairTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for StairType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCSTAIRTYPE({},{});",
+            self.element_type, self.predefined_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcStairType")]
+impl IfcType for StairType {}
+impl MaterialRelatable for StairType {}
+impl ClassificationRelatable for StairType {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::StairType;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn stair_type_round_trip() {
+        let example =
+            "IFCSTAIRTYPE('0Jd8Ks3mF6yQ1bW9hVn2cP',#2,'Straight stair',$,$,$,$,$,$,.STRAIGHT_RUN_STAIR.);";
+
+        let parsed: StairType = StairType::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,107 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// This enumeration defines the basic configuration of the stair type in
+/// terms of the number and shape of stair flights and landings.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcstairtypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum StairTypeEnum {
+    /// A stair extending from one level to another without turns or winders.
+    #[strum(to_string = ".STRAIGHT_RUN_STAIR.")]
+    StraightRunStair,
+
+    /// A straight stair consisting of two straight flights without turns
+    /// but with one landing.
+    #[strum(to_string = ".TWO_STRAIGHT_RUN_STAIR.")]
+    TwoStraightRunStair,
+
+    /// A stair consisting of one flight with a quarter winder, which makes
+    /// a 90° turn.
+    #[strum(to_string = ".QUARTER_WINDING_STAIR.")]
+    QuarterWindingStair,
+
+    /// A stair making a 90° turn, consisting of two straight flights
+    /// connected by a quarterspace landing.
+    #[strum(to_string = ".QUARTER_TURN_STAIR.")]
+    QuarterTurnStair,
+
+    /// A stair consisting of one flight with one half winder, which makes a
+    /// 180° turn.
+    #[strum(to_string = ".HALF_WINDING_STAIR.")]
+    HalfWindingStair,
+
+    /// A stair making a 180° turn, consisting of two straight flights
+    /// connected by a halfspace landing.
+    #[strum(to_string = ".HALF_TURN_STAIR.")]
+    HalfTurnStair,
+
+    /// A stair consisting of one flight with two quarter winders, which
+    /// make a 90° turn.
+    #[strum(to_string = ".TWO_QUARTER_WINDING_STAIR.")]
+    TwoQuarterWindingStair,
+
+    /// A stair making a 180° turn, consisting of three straight flights
+    /// connected by two quarterspace landings.
+    #[strum(to_string = ".TWO_QUARTER_TURN_STAIR.")]
+    TwoQuarterTurnStair,
+
+    /// A stair consisting of one flight with three quarter winders, which
+    /// make a 270° turn.
+    #[strum(to_string = ".THREE_QUARTER_WINDING_STAIR.")]
+    ThreeQuarterWindingStair,
+
+    /// A stair making a 270° turn, consisting of four straight flights
+    /// connected by three quarterspace landings.
+    #[strum(to_string = ".THREE_QUARTER_TURN_STAIR.")]
+    ThreeQuarterTurnStair,
+
+    /// A stair constructed with winders around a circular newel, often
+    /// without landings.
+    #[strum(to_string = ".SPIRAL_STAIR.")]
+    SpiralStair,
+
+    /// A stair having one straight flight to a wide quarterspace landing,
+    /// and two side flights from that landing into opposite directions.
+    #[strum(to_string = ".DOUBLE_RETURN_STAIR.")]
+    DoubleReturnStair,
+
+    /// A stair extending from one level to another without landings,
+    /// following a curved line in plan projection.
+    #[strum(to_string = ".CURVED_RUN_STAIR.")]
+    CurvedRunStair,
+
+    /// A stair extending from one level to another without landings,
+    /// following a curved line with two different radii.
+    #[strum(to_string = ".TWO_CURVED_RUN_STAIR.")]
+    TwoCurvedRunStair,
+
+    /// User-defined type.
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    /// Undefined type.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for StairTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid StairTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
@@ -4,6 +4,7 @@ use std::{
 };
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use super::{
     shared::{
@@ -52,7 +53,7 @@ use crate::{
 /// relationships are given in view definitions and implementer agreements.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcbuildingstorey.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Storey {
     #[inherited]
     spatial_element_structure: SpatialStructureElement,
@@ -149,6 +150,7 @@ impl Display for Storey {
     }
 }
 
+#[typetag::serde(name = "IfcBuildingStorey")]
 impl IfcType for Storey {
     fn to_structure(&self) -> Option<&dyn Structure> {
         Some(self)
@@ -0,0 +1,98 @@
+use std::ops::DerefMut;
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+    relations::rel_assigns_to_group::RelatableGroup,
+};
+
+/// A system is an organized combination of related parts within an AEC
+/// product, composed for a common purpose or function or to provide a
+/// service. A system is essentially a functionally related aggregation of
+/// products.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcsystem.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct System {
+    #[inherited]
+    object: Object,
+}
+
+impl System {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            object: Object::new(Root::new(name.into())),
+        }
+    }
+}
+
+impl RootBuilder for System {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.object
+    }
+}
+
+impl ObjectBuilder for System {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.object
+    }
+}
+
+impl Deref for System {
+    type Target = Object;
+
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl DerefMut for System {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.object
+    }
+}
+
+impl IFCParse for System {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCSYSTEM("),
+
+                object: Object::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for System {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCSYSTEM({});", self.object)
+    }
+}
+
+#[typetag::serde(name = "IfcSystem")]
+impl IfcType for System {}
+impl RelatableGroup for System {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::System;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn system_round_trip() {
+        let example = "IFCSYSTEM('1Ck0Hj3rb0Sv2B$fgMvGC9',#2,'Heating',$,$);";
+
+        let system: System = System::parse().parse(example).unwrap();
+        assert_eq!(example, system.to_string());
+    }
+}
@@ -4,13 +4,19 @@ mod serialize;
 use std::ops::{Deref, DerefMut};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{IdOr, TypedId},
     ifc_type::{IfcType, IfcVerify},
     parser::{label::Label, optional::OptionalParameter},
     prelude::*,
-    relations::rel_associates_material::MaterialRelatable,
+    relations::{
+        rel_assigns_to_group::GroupRelatable,
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable, rel_connects_elements::ConnectionRelatable,
+        rel_space_boundary::SpaceBoundaryRelatable,
+    },
 };
 
 use super::StructureType;
@@ -21,7 +27,7 @@ use super::StructureType;
 /// A wall is however not required to be load bearing.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcwall.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Wall {
     #[inherited]
     element: Element,
@@ -90,6 +96,7 @@ impl DerefMut for Wall {
     }
 }
 
+#[typetag::serde(name = "IfcWall")]
 impl IfcType for Wall {
     fn to_structure(&self) -> Option<&dyn Structure> {
         Some(self)
@@ -101,6 +108,10 @@ impl Structure for Wall {
     }
 }
 impl MaterialRelatable for Wall {}
+impl ClassificationRelatable for Wall {}
+impl GroupRelatable for Wall {}
+impl SpaceBoundaryRelatable for Wall {}
+impl ConnectionRelatable for Wall {}
 
 impl TransformableType for Wall {
     fn shape(&self) -> Option<TypedId<ProductDefinitionShape>> {
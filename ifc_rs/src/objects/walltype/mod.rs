@@ -1,13 +1,17 @@
 use std::ops::Deref;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 use type_enum::WallTypeEnum;
 
 use crate::{
     ifc_type::{IfcType, IfcVerify},
     parser::label::Label,
     prelude::{ElementTypeBuilder, Root, RootBuilder, TypeObject, TypeProduct},
-    relations::rel_associates_material::MaterialRelatable,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
     IFC,
 };
 
@@ -27,7 +31,7 @@ pub mod type_enum;
 ///   * common shape representations
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcwalltype.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct WallType {
     #[inherited]
     element_type: ElementType,
@@ -80,8 +84,10 @@ impl Deref for WallType {
     }
 }
 
+#[typetag::serde(name = "IfcWallType")]
 impl IfcType for WallType {}
 impl MaterialRelatable for WallType {}
+impl ClassificationRelatable for WallType {}
 
 #[cfg(test)]
 mod test {
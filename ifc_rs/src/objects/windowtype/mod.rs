@@ -1,6 +1,7 @@
 use std::ops::Deref;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 use window_partitioning_type_enum::WindowPartitioningTypeEnum;
 use window_type_enum::WindowTypeEnum;
 
@@ -8,7 +9,10 @@ use crate::{
     ifc_type::{IfcType, IfcVerify},
     parser::{bool::IfcBool, label::Label, optional::OptionalParameter},
     prelude::{ElementTypeBuilder, Root, RootBuilder, TypeObject, TypeProduct},
-    relations::rel_associates_material::MaterialRelatable,
+    relations::{
+        rel_associates_classification::ClassificationRelatable,
+        rel_associates_material::MaterialRelatable,
+    },
     IFC,
 };
 
@@ -43,7 +47,7 @@ pub mod window_type_enum;
 /// instances of IfcWindow or IfcWindowStandardCase.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifcwindowtype.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct WindowType {
     #[inherited]
     element_type: ElementType,
@@ -118,8 +122,10 @@ impl Deref for WindowType {
     }
 }
 
+#[typetag::serde(name = "IfcWindowType")]
 impl IfcType for WindowType {}
 impl MaterialRelatable for WindowType {}
+impl ClassificationRelatable for WindowType {}
 
 #[cfg(test)]
 mod test {
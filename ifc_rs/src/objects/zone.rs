@@ -0,0 +1,116 @@
+use std::ops::DerefMut;
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+    relations::rel_assigns_to_group::{GroupRelatable, RelatableGroup},
+};
+
+/// A zone is a group of spaces, partial spaces or other zones. Zone
+/// structures may not be hierarchical (in contrary to the spatial structure
+/// of a project - see IfcSpatialStructureElement), i.e. one individual
+/// IfcSpace may be associated with zero, one, or several IfcZone's.
+/// Examples are fire compartments, apartments or HVAC zones.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifczone.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Zone {
+    #[inherited]
+    object: Object,
+
+    /// Long name for a zone, used for informal purposes. It should be used,
+    /// if available, in conjunction with the inherited Name attribute.
+    pub long_name: OptionalParameter<Label>,
+}
+
+impl Zone {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            object: Object::new(Root::new(name.into())),
+            long_name: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn long_name(mut self, long_name: impl Into<Label>) -> Self {
+        self.long_name = long_name.into().into();
+        self
+    }
+}
+
+impl RootBuilder for Zone {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.object
+    }
+}
+
+impl ObjectBuilder for Zone {
+    fn object_mut(&mut self) -> &mut Object {
+        &mut self.object
+    }
+}
+
+impl Deref for Zone {
+    type Target = Object;
+
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl DerefMut for Zone {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.object
+    }
+}
+
+impl IFCParse for Zone {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCZONE("),
+
+                object: Object::parse(),
+                _: Comma::parse(),
+                long_name: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for Zone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCZONE({},{});", self.object, self.long_name)
+    }
+}
+
+#[typetag::serde(name = "IfcZone")]
+impl IfcType for Zone {}
+impl RelatableGroup for Zone {}
+impl GroupRelatable for Zone {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Zone;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn zone_round_trip() {
+        let example =
+            "IFCZONE('0aDNjhUNn6fgoxDHBA8cGx',#2,'FC-01',$,$,'Fire compartment ground floor');";
+
+        let zone: Zone = Zone::parse().parse(example).unwrap();
+        assert_eq!(example, zone.to_string());
+    }
+}
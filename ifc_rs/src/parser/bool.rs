@@ -1,11 +1,12 @@
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
 use winnow::combinator::{alt, delimited};
 use winnow::Parser;
 
 use crate::parser::*;
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
 pub enum IfcBool {
     True,
     False,
@@ -1,20 +1,29 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     ifc_type::{IfcType, IfcVerify},
+    schema::EntityDef,
     IFC,
 };
 
 use super::IFCParse;
 
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct Dummy {
     pub s: String,
 }
 
-impl IfcType for Dummy {}
+#[typetag::serde(name = "Dummy")]
+impl IfcType for Dummy {
+    /// Unparsed entities are looked up by the name in front of the attributes.
+    fn entity_definition(&self) -> Option<&'static EntityDef> {
+        let (name, _) = self.s.split_once('(')?;
+        EntityDef::find(name.trim())
+    }
+}
 
 impl IFCParse for Dummy {
     fn parse<'a>() -> impl super::IFCParser<'a, Self>
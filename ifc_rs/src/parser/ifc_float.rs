@@ -2,6 +2,7 @@ use std::fmt::Display;
 use std::ops::Deref;
 
 use glam::{DVec2, DVec3};
+use serde::{Deserialize, Serialize};
 use winnow::ascii::float;
 use winnow::Parser;
 
@@ -9,7 +10,7 @@ use crate::ifc_type::{IfcType, IfcVerify};
 use crate::parser::geometry::{p_vec2, p_vec3};
 use crate::parser::{IFCParse, IFCParser};
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct IfcFloat(pub f64);
 
 impl IFCParse for IfcFloat {
@@ -22,6 +23,7 @@ impl IFCParse for IfcFloat {
 }
 
 impl IfcVerify for IfcFloat {}
+#[typetag::serde(name = "IfcFloat")]
 impl IfcType for IfcFloat {}
 
 impl From<f64> for IfcFloat {
@@ -42,7 +44,7 @@ impl Display for IfcFloat {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct IfcDVec2(pub(crate) DVec2);
 
 impl IFCParse for IfcDVec2 {
@@ -78,7 +80,7 @@ impl From<DVec2> for IfcDVec2 {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct IfcDVec3(pub(crate) DVec3);
 
 impl IFCParse for IfcDVec3 {
@@ -130,7 +132,7 @@ pub fn format_double(d: f64) -> String {
     } else {
         format!(
             "{d}{opt_p}",
-            opt_p = (d.fract() == 0.0).then_some(".").unwrap_or_default()
+            opt_p = if d.fract() == 0.0 { "." } else { "" }
         )
     }
 }
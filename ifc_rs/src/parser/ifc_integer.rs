@@ -1,11 +1,12 @@
 use std::fmt::Display;
 
+use serde::{Deserialize, Serialize};
 use winnow::ascii::dec_int;
 use winnow::Parser;
 
 use crate::parser::{IFCParse, IFCParser};
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct IfcInteger(pub i64);
 
 impl IFCParse for IfcInteger {
@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use serde::{Deserialize, Serialize};
 use winnow::Parser;
 
 use crate::parser::{p_quote_word, IFCParse, IFCParser};
@@ -8,7 +9,7 @@ use crate::parser::{p_quote_word, IFCParse, IFCParser};
 /// It is a string which represents the human-interpretable name of something and shall have a natural-language meaning.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcmeasureresource/lexical/ifclabel.htm
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Label(pub String);
 
 impl<S: AsRef<str>> From<S> for Label {
@@ -1,6 +1,7 @@
 use std::{fmt::Display, slice::Iter};
 
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 use winnow::{
     combinator::{opt, preceded, repeat_till, terminated},
     Parser,
@@ -10,7 +11,7 @@ use crate::parser::IFCParse;
 
 use super::p_space_or_comment_surrounded;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IfcList<T>(pub Vec<T>);
 
 impl<T> IfcList<T> {
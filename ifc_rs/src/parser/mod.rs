@@ -8,6 +8,7 @@ pub mod label;
 pub mod list;
 pub mod optional;
 pub mod place_holder;
+pub mod step_value;
 pub mod timestamp;
 
 use std::fmt::Display;
@@ -16,11 +17,14 @@ use optional::OptionalParameter;
 use winnow::ascii::*;
 use winnow::combinator::*;
 use winnow::token::*;
+#[allow(deprecated)]
 use winnow::{error::ErrorKind, Parser};
 
 use crate::ifc_type::IfcType;
 
+#[allow(deprecated)]
 pub trait IFCParser<'a, T>: Parser<&'a str, T, ErrorKind> {}
+#[allow(deprecated)]
 impl<'a, T, P: Parser<&'a str, T, ErrorKind>> IFCParser<'a, T> for P {}
 
 pub trait IFCParse: Display {
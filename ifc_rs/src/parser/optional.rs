@@ -1,12 +1,13 @@
 use std::fmt::Display;
 
+use serde::{Deserialize, Serialize};
 use winnow::{combinator::alt, Parser};
 
 use crate::parser::{IFCParse, IFCParser};
 
 use super::place_holder::{Inherited, Omitted};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum OptionalParameter<T: IFCParse> {
     Omitted(Omitted),
     Inherited(Inherited),
@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use serde::{Deserialize, Serialize};
 use winnow::Parser;
 
 use crate::parser::IFCParse;
@@ -10,7 +11,7 @@ use super::IFCParser;
 ///
 /// - omitted values (`$`)
 /// - inherited values (`*`)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Omitted;
 
 impl IFCParse for Omitted {
@@ -29,7 +30,7 @@ impl Display for Omitted {
 ///
 /// - omitted values (`$`)
 /// - inherited values (`*`)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Inherited;
 
 impl IFCParse for Inherited {
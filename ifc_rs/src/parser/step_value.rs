@@ -0,0 +1,306 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use winnow::{
+    ascii::{digit1, multispace0},
+    combinator::{alt, delimited, preceded, repeat, separated},
+    token::take_while,
+    Parser,
+};
+
+use crate::{id::Id, ifc_type::IfcType};
+
+use super::{p_space_or_comment_surrounded, IFCParse, IFCParser};
+
+/// Schema agnostic representation of a single attribute value of a STEP
+/// (ISO 10303-21) entity instance.
+///
+/// Every entity of the data section can be represented with these values
+/// regardless of whether it has a typed implementation in this crate or not.
+/// This makes it the common ground for the alternative encodings (ifcXML,
+/// ifcJSON) which are written and read through it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StepValue {
+    /// `$`
+    Omitted,
+    /// `*`
+    Inherited,
+    /// String in its encoded form (`''` and `\X2\...\X0\` escapes are kept)
+    /// without the surrounding quotes.
+    String(String),
+    /// Enumeration or logical value without the surrounding dots.
+    Enum(String),
+    /// Integer or real number as it was written.
+    Number(String),
+    /// Reference to another entity instance.
+    Ref(Id),
+    /// Aggregation of values.
+    List(Vec<StepValue>),
+    /// Typed value as used for selects, e.g. `IFCLABEL('abc')`.
+    Typed(String, Box<StepValue>),
+}
+
+/// Schema agnostic representation of an entity instance like
+/// `IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,$,$,$,#33,#25,$,$);`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepEntity {
+    /// Upper case STEP name of the entity, e.g. `IFCWALL`.
+    pub name: String,
+    pub attributes: Vec<StepValue>,
+}
+
+impl StepEntity {
+    /// Converts any entity of a [`DataMap`](crate::meta::datamap::DataMap) into its
+    /// schema agnostic representation.
+    pub fn from_ifc_type(ifc_type: &dyn IfcType) -> anyhow::Result<Self> {
+        let step = ifc_type.to_string();
+        let entity = Self::parse()
+            .parse(step.as_str())
+            .map_err(|err| anyhow::anyhow!("failed to read entity {step}: {err}"))?;
+        Ok(entity)
+    }
+}
+
+impl StepValue {
+    /// Decodes the STEP string encoding (`''`, `\\`, `\S\`, `\X\`, `\X2\` and
+    /// `\X4\` directives) of a [`StepValue::String`] into a rust string.
+    pub fn decode_string(encoded: &str) -> String {
+        let mut decoded = String::with_capacity(encoded.len());
+        let mut rest = encoded;
+
+        while let Some(c) = rest.chars().next() {
+            if let Some(tail) = rest.strip_prefix("''") {
+                decoded.push('\'');
+                rest = tail;
+            } else if let Some(tail) = rest.strip_prefix("\\\\") {
+                decoded.push('\\');
+                rest = tail;
+            } else if let Some((chars, tail)) = Self::decode_extended(rest) {
+                decoded.push_str(&chars);
+                rest = tail;
+            } else {
+                decoded.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+
+        decoded
+    }
+
+    fn decode_extended(s: &str) -> Option<(String, &str)> {
+        fn hex_chars(hex: &str, width: usize) -> Option<String> {
+            if !hex.len().is_multiple_of(width) {
+                return None;
+            }
+            (0..hex.len())
+                .step_by(width)
+                .map(|i| {
+                    u32::from_str_radix(&hex[i..i + width], 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                })
+                .collect()
+        }
+
+        if let Some(tail) = s.strip_prefix("\\X2\\") {
+            let (hex, tail) = tail.split_once("\\X0\\")?;
+            return Some((hex_chars(hex, 4)?, tail));
+        }
+        if let Some(tail) = s.strip_prefix("\\X4\\") {
+            let (hex, tail) = tail.split_once("\\X0\\")?;
+            return Some((hex_chars(hex, 8)?, tail));
+        }
+        if let Some(tail) = s.strip_prefix("\\X\\") {
+            let hex = tail.get(..2)?;
+            return Some((hex_chars(hex, 2)?, &tail[2..]));
+        }
+        if let Some(tail) = s.strip_prefix("\\S\\") {
+            let c = tail.chars().next()?;
+            let c = char::from_u32(c as u32 + 0x80)?;
+            return Some((c.to_string(), &tail[1..]));
+        }
+
+        None
+    }
+
+    /// Encodes a rust string into the STEP string encoding. Non ASCII
+    /// characters are written as `\X2\` (or `\X4\`) directives.
+    pub fn encode_string(decoded: &str) -> String {
+        let mut encoded = String::with_capacity(decoded.len());
+        let mut chars = decoded.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\'' => encoded.push_str("''"),
+                '\\' => encoded.push_str("\\\\"),
+                ' '..='~' => encoded.push(c),
+                c if (c as u32) <= 0xFFFF => {
+                    encoded.push_str("\\X2\\");
+                    encoded.push_str(&format!("{:04X}", c as u32));
+                    while let Some(next) = chars.next_if(|n| !n.is_ascii() && *n as u32 <= 0xFFFF) {
+                        encoded.push_str(&format!("{:04X}", next as u32));
+                    }
+                    encoded.push_str("\\X0\\");
+                }
+                c => {
+                    encoded.push_str("\\X4\\");
+                    encoded.push_str(&format!("{:08X}", c as u32));
+                    encoded.push_str("\\X0\\");
+                }
+            }
+        }
+
+        encoded
+    }
+
+    fn p_string<'a>() -> impl IFCParser<'a, String> {
+        delimited(
+            "'",
+            repeat(
+                ..,
+                alt((
+                    "''".map(|s: &str| s.to_owned()),
+                    take_while(1.., |c| c != '\'').map(|s: &str| s.to_owned()),
+                )),
+            )
+            .map(|parts: Vec<String>| parts.concat()),
+            "'",
+        )
+    }
+
+    fn p_enum<'a>() -> impl IFCParser<'a, String> {
+        delimited(
+            ".",
+            take_while(1.., |c: char| c.is_ascii_alphanumeric() || c == '_'),
+            ".",
+        )
+        .map(|s: &str| s.to_owned())
+    }
+
+    fn p_number<'a>() -> impl IFCParser<'a, String> {
+        take_while(1.., |c: char| {
+            c.is_ascii_digit() || ['+', '-', '.', 'e', 'E'].contains(&c)
+        })
+        .map(|s: &str| s.to_owned())
+    }
+
+    fn p_keyword<'a>() -> impl IFCParser<'a, String> {
+        (
+            take_while(1, |c: char| c.is_ascii_alphabetic()),
+            take_while(.., |c: char| c.is_ascii_alphanumeric() || c == '_'),
+        )
+            .take()
+            .map(|s: &str| s.to_owned())
+    }
+}
+
+impl IFCParse for StepValue {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        alt((
+            "$".map(|_| Self::Omitted),
+            "*".map(|_| Self::Inherited),
+            preceded("#", digit1)
+                .try_map(|id: &str| id.parse::<usize>())
+                .map(|id| Self::Ref(Id(id))),
+            Self::p_string().map(Self::String),
+            Self::p_enum().map(Self::Enum),
+            Self::p_number().map(Self::Number),
+            Self::p_list.map(Self::List),
+            (
+                Self::p_keyword(),
+                delimited(("(", multispace0), Self::parse_boxed, (multispace0, ")")),
+            )
+                .map(|(name, value)| Self::Typed(name, value)),
+        ))
+    }
+}
+
+#[allow(deprecated)]
+impl StepValue {
+    // indirection which breaks the recursive `impl Trait` type
+    fn p_list(input: &mut &str) -> winnow::PResult<Vec<StepValue>, winnow::error::ErrorKind> {
+        Self::p_list_impl().parse_next(input)
+    }
+
+    fn p_list_impl<'a>() -> impl IFCParser<'a, Vec<StepValue>> {
+        delimited(
+            ("(", multispace0),
+            separated(.., p_space_or_comment_surrounded(Self::parse_boxed), ",")
+                .map(|values: Vec<Box<StepValue>>| values.into_iter().map(|v| *v).collect()),
+            (multispace0, ")"),
+        )
+    }
+
+    fn parse_boxed(input: &mut &str) -> winnow::PResult<Box<StepValue>, winnow::error::ErrorKind> {
+        Self::parse().map(Box::new).parse_next(input)
+    }
+}
+
+impl IFCParse for StepEntity {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                name: p_space_or_comment_surrounded(StepValue::p_keyword()),
+                attributes: StepValue::p_list,
+                _: p_space_or_comment_surrounded(";"),
+            }
+        }
+    }
+}
+
+impl Display for StepValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StepValue::Omitted => write!(f, "$"),
+            StepValue::Inherited => write!(f, "*"),
+            StepValue::String(s) => write!(f, "'{s}'"),
+            StepValue::Enum(e) => write!(f, ".{e}."),
+            StepValue::Number(n) => write!(f, "{n}"),
+            StepValue::Ref(id) => write!(f, "{id}"),
+            StepValue::List(values) => write!(f, "({})", values.iter().join(",")),
+            StepValue::Typed(name, value) => write!(f, "{name}({value})"),
+        }
+    }
+}
+
+impl Display for StepEntity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({});", self.name, self.attributes.iter().join(","))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::{StepEntity, StepValue};
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn step_entity_round_trip() {
+        let examples = [
+            "IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,$,$,$,#33,#25,$,.NOTDEFINED.);",
+            "IFCPROPERTYSINGLEVALUE('GS_TimeStamp',$,IFCTIMESTAMP(9685146),$);",
+            "IFCSITE('1cwlDi_hLEvPsClAelBNnz',#12,'Gel\\X2\\00E4\\X0\\nde',$,$,#35,#28,$,.ELEMENT.,(49,6,1,566000),(8,26,9,952000),110.,$,$);",
+            "IFCCARTESIANPOINTLIST2D(((0.,0.),(1.E-05,2.5)),$);",
+            "IFCPROPERTYSINGLEVALUE('Text',$,IFCLABEL('it''s'),$);",
+            "IFCPERSON($,'Nicht definiert',$,(),$,$,$,$);",
+            "IFCGEOMETRICREPRESENTATIONSUBCONTEXT('Axis','Model',*,*,*,*,#102,$,.GRAPH_VIEW.,$);",
+        ];
+
+        for example in examples {
+            let entity = StepEntity::parse().parse(example).unwrap();
+            assert_eq!(example, entity.to_string());
+        }
+    }
+
+    #[test]
+    fn step_string_encoding() {
+        let encoded = "Gel\\X2\\00E400FC\\X0\\nde it''s \\\\";
+        let decoded = StepValue::decode_string(encoded);
+
+        assert_eq!("Geläünde it's \\", decoded);
+        assert_eq!(encoded, StepValue::encode_string(&decoded));
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use chrono::Utc;
+use serde::{Deserialize, Serialize};
 use winnow::ascii::dec_int;
 use winnow::Parser;
 
@@ -10,7 +11,7 @@ use crate::parser::{IFCParse, IFCParser};
 /// have elapsed since the beginning of the year 1970.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcmeasureresource/lexical/ifctimestamp.htm
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct IfcTimestamp(pub chrono::DateTime<Utc>);
 
 impl IfcTimestamp {
@@ -0,0 +1,95 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    IFC,
+};
+
+/// IfcColourRgb is an expression of colour based on the three additive
+/// components red, green and blue, each given as a normalised ratio in the
+/// range from 0 to 1.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccolourrgb.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct ColourRgb {
+    /// Optional name given to a particular colour specification.
+    pub name: OptionalParameter<Label>,
+    /// The intensity of the red colour component.
+    pub red: IfcFloat,
+    /// The intensity of the green colour component.
+    pub green: IfcFloat,
+    /// The intensity of the blue colour component.
+    pub blue: IfcFloat,
+}
+
+impl ColourRgb {
+    pub fn new(red: f64, green: f64, blue: f64) -> Self {
+        Self {
+            name: OptionalParameter::omitted(),
+            red: red.into(),
+            green: green.into(),
+            blue: blue.into(),
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<Label>) -> Self {
+        self.name = name.into().into();
+        self
+    }
+}
+
+impl IFCParse for ColourRgb {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCOLOURRGB("),
+
+                name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                red: IfcFloat::parse(),
+                _: Comma::parse(),
+                green: IfcFloat::parse(),
+                _: Comma::parse(),
+                blue: IfcFloat::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for ColourRgb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCOLOURRGB({},{},{},{});",
+            self.name, self.red, self.green, self.blue
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcColourRgb")]
+impl IfcType for ColourRgb {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::ColourRgb;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn colour_rgb_round_trip() {
+        let example = "IFCCOLOURRGB($,0.2,0.55,0.16);";
+
+        let parsed: ColourRgb = ColourRgb::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use winnow::combinator::alt;
+
+use crate::{
+    ifc_type::IfcType,
+    parser::{IFCParse, IFCParser},
+};
+
+pub mod colour_rgb;
+pub mod prelude;
+pub mod presentation_layer_assignment;
+pub mod presentation_style_assignment;
+pub mod styled_item;
+pub mod styled_representation;
+pub mod surface_style;
+pub mod surface_style_rendering;
+
+/// Effective colour of a surface, each component being in the range from
+/// 0 to 1. A transparency of 0 is opaque.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SurfaceColour {
+    pub red: f64,
+    pub green: f64,
+    pub blue: f64,
+    pub transparency: f64,
+}
+
+impl SurfaceColour {
+    pub fn new(red: f64, green: f64, blue: f64) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            transparency: 0.0,
+        }
+    }
+
+    pub fn transparency(mut self, transparency: f64) -> Self {
+        self.transparency = transparency;
+        self
+    }
+}
+
+pub struct Presentation;
+
+impl Presentation {
+    pub fn parse<'a>() -> impl IFCParser<'a, Box<dyn IfcType>> {
+        alt((
+            colour_rgb::ColourRgb::parse_any(),
+            presentation_layer_assignment::PresentationLayerAssignment::parse_any(),
+            presentation_layer_assignment::PresentationLayerWithStyle::parse_any(),
+            presentation_style_assignment::PresentationStyleAssignment::parse_any(),
+            styled_item::StyledItem::parse_any(),
+            styled_representation::StyledRepresentation::parse_any(),
+            surface_style::SurfaceStyle::parse_any(),
+            surface_style_rendering::SurfaceStyleRendering::parse_any(),
+            surface_style_rendering::SurfaceStyleShading::parse_any(),
+        ))
+    }
+}
@@ -0,0 +1,13 @@
+pub use super::colour_rgb::ColourRgb;
+pub use super::presentation_layer_assignment::{
+    as_presentation_layer, PresentationLayer, PresentationLayerAssignment,
+    PresentationLayerBuilder, PresentationLayerWithStyle,
+};
+pub use super::presentation_style_assignment::PresentationStyleAssignment;
+pub use super::styled_item::StyledItem;
+pub use super::styled_representation::StyledRepresentation;
+pub use super::surface_style::{SurfaceSide, SurfaceStyle};
+pub use super::surface_style_rendering::{
+    ColourOrFactor, ReflectanceMethod, SurfaceStyleRendering, SurfaceStyleShading,
+};
+pub use super::SurfaceColour;
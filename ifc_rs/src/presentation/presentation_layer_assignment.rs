@@ -0,0 +1,329 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        bool::IfcBool, comma::Comma, label::Label, list::IfcList, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// The attributes shared by IfcPresentationLayerAssignment and its subtype
+/// IfcPresentationLayerWithStyle.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpresentationlayerassignment.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PresentationLayer {
+    /// Name of the layer.
+    pub name: Label,
+
+    /// Additional description of the layer.
+    pub description: OptionalParameter<Label>,
+
+    /// The set of layered items, which are assigned to this layer. These
+    /// are either representations or representation items.
+    pub assigned_items: IfcList<Id>,
+
+    /// An (internal) identifier assigned to the layer.
+    pub identifier: OptionalParameter<Label>,
+}
+
+impl PresentationLayer {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            name: name.into(),
+            description: OptionalParameter::omitted(),
+            assigned_items: IfcList::empty(),
+            identifier: OptionalParameter::omitted(),
+        }
+    }
+}
+
+pub trait PresentationLayerBuilder: Sized {
+    fn presentation_layer_mut(&mut self) -> &mut PresentationLayer;
+
+    fn description(mut self, description: impl Into<Label>) -> Self {
+        self.presentation_layer_mut().description = description.into().into();
+        self
+    }
+
+    fn identifier(mut self, identifier: impl Into<Label>) -> Self {
+        self.presentation_layer_mut().identifier = identifier.into().into();
+        self
+    }
+
+    /// Assigns a representation or a representation item to the layer.
+    fn add_item<T: IfcType>(mut self, item: impl Into<IdOr<T>>, ifc: &mut IFC) -> Self {
+        let item = item.into().or_insert(ifc).id();
+        self.presentation_layer_mut().assigned_items.0.push(item);
+        self
+    }
+}
+
+impl IFCParse for PresentationLayer {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                name: Label::parse(),
+                _: Comma::parse(),
+                description: OptionalParameter::parse(),
+                _: Comma::parse(),
+                assigned_items: IfcList::parse(),
+                _: Comma::parse(),
+                identifier: OptionalParameter::parse(),
+            }
+        }
+    }
+}
+
+impl Display for PresentationLayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.name, self.description, self.assigned_items, self.identifier
+        )
+    }
+}
+
+/// The presentation layer assignment provides the layer name (and
+/// optionally a description and an identifier) for a collection of
+/// geometric representation items or representations. Layers are used by
+/// CAD oriented applications to organize and to toggle the visibility of
+/// the geometry.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpresentationlayerassignment.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PresentationLayerAssignment {
+    #[inherited]
+    presentation_layer: PresentationLayer,
+}
+
+impl PresentationLayerAssignment {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            presentation_layer: PresentationLayer::new(name),
+        }
+    }
+}
+
+impl PresentationLayerBuilder for PresentationLayerAssignment {
+    fn presentation_layer_mut(&mut self) -> &mut PresentationLayer {
+        &mut self.presentation_layer
+    }
+}
+
+impl Deref for PresentationLayerAssignment {
+    type Target = PresentationLayer;
+
+    fn deref(&self) -> &Self::Target {
+        &self.presentation_layer
+    }
+}
+
+impl DerefMut for PresentationLayerAssignment {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.presentation_layer
+    }
+}
+
+impl IFCParse for PresentationLayerAssignment {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPRESENTATIONLAYERASSIGNMENT("),
+
+                presentation_layer: PresentationLayer::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PresentationLayerAssignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCPRESENTATIONLAYERASSIGNMENT({});",
+            self.presentation_layer
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcPresentationLayerAssignment")]
+impl IfcType for PresentationLayerAssignment {}
+
+/// The presentation layer with style is a presentation layer assignment
+/// with the additional ability to switch the layer on or off, to freeze it
+/// and to block it, and to assign styles to all items of the layer.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpresentationlayerwithstyle.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PresentationLayerWithStyle {
+    #[inherited]
+    presentation_layer: PresentationLayer,
+
+    /// A logical setting, TRUE indicates that the layer is set to 'On',
+    /// FALSE that the layer is set to 'Off', UNKNOWN that such information
+    /// is not available.
+    pub layer_on: IfcBool,
+
+    /// A logical setting, TRUE indicates that the layer is set to 'Frozen',
+    /// FALSE that the layer is set to 'Not frozen', UNKNOWN that such
+    /// information is not available.
+    pub layer_frozen: IfcBool,
+
+    /// A logical setting, TRUE indicates that the layer is set to 'Blocked',
+    /// FALSE that the layer is set to 'Not blocked', UNKNOWN that such
+    /// information is not available.
+    pub layer_blocked: IfcBool,
+
+    /// Set of presentation styles, which are applied to the items of the
+    /// layer unless they are styled themselves.
+    #[ifc_types(SurfaceStyle)]
+    pub layer_styles: IfcList<Id>,
+}
+
+impl PresentationLayerWithStyle {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            presentation_layer: PresentationLayer::new(name),
+            layer_on: IfcBool::True,
+            layer_frozen: IfcBool::False,
+            layer_blocked: IfcBool::False,
+            layer_styles: IfcList::empty(),
+        }
+    }
+
+    pub fn layer_on(mut self, layer_on: bool) -> Self {
+        self.layer_on = layer_on.into();
+        self
+    }
+
+    pub fn layer_frozen(mut self, layer_frozen: bool) -> Self {
+        self.layer_frozen = layer_frozen.into();
+        self
+    }
+
+    pub fn layer_blocked(mut self, layer_blocked: bool) -> Self {
+        self.layer_blocked = layer_blocked.into();
+        self
+    }
+
+    pub fn add_style<S: IfcType>(mut self, style: impl Into<IdOr<S>>, ifc: &mut IFC) -> Self {
+        self.layer_styles.0.push(style.into().or_insert(ifc).id());
+        self
+    }
+}
+
+impl PresentationLayerBuilder for PresentationLayerWithStyle {
+    fn presentation_layer_mut(&mut self) -> &mut PresentationLayer {
+        &mut self.presentation_layer
+    }
+}
+
+impl Deref for PresentationLayerWithStyle {
+    type Target = PresentationLayer;
+
+    fn deref(&self) -> &Self::Target {
+        &self.presentation_layer
+    }
+}
+
+impl DerefMut for PresentationLayerWithStyle {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.presentation_layer
+    }
+}
+
+impl IFCParse for PresentationLayerWithStyle {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPRESENTATIONLAYERWITHSTYLE("),
+
+                presentation_layer: PresentationLayer::parse(),
+                _: Comma::parse(),
+                layer_on: IfcBool::parse(),
+                _: Comma::parse(),
+                layer_frozen: IfcBool::parse(),
+                _: Comma::parse(),
+                layer_blocked: IfcBool::parse(),
+                _: Comma::parse(),
+                layer_styles: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PresentationLayerWithStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCPRESENTATIONLAYERWITHSTYLE({},{},{},{},{});",
+            self.presentation_layer,
+            self.layer_on,
+            self.layer_frozen,
+            self.layer_blocked,
+            self.layer_styles
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcPresentationLayerWithStyle")]
+impl IfcType for PresentationLayerWithStyle {}
+
+/// The shared layer attributes of any of the presentation layer entities,
+/// `None` if the entity isn't a presentation layer.
+pub fn as_presentation_layer(ifc_type: &dyn IfcType) -> Option<&PresentationLayer> {
+    if let Some(layer) = ifc_type.downcast_ref::<PresentationLayerAssignment>() {
+        Some(layer)
+    } else {
+        ifc_type
+            .downcast_ref::<PresentationLayerWithStyle>()
+            .map(|layer| &**layer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::{PresentationLayerAssignment, PresentationLayerWithStyle};
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn presentation_layer_assignment_round_trip() {
+        let examples = [
+            "IFCPRESENTATIONLAYERASSIGNMENT('Treppen',$,(#14488),$);",
+            "IFCPRESENTATIONLAYERASSIGNMENT('A-WALL',' Walls',(#15016,#15033),'A-WALL-01');",
+        ];
+
+        for example in examples {
+            let parsed: PresentationLayerAssignment =
+                PresentationLayerAssignment::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn presentation_layer_with_style_round_trip() {
+        let example =
+            "IFCPRESENTATIONLAYERWITHSTYLE('A-SLAB',$,(#34495,#59278),$,.TRUE.,.FALSE.,.FALSE.,(#180));";
+
+        let parsed: PresentationLayerWithStyle =
+            PresentationLayerWithStyle::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{list::IfcList, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+};
+
+/// The presentation style assignment is a set of styles which are assigned
+/// to styled items for the purpose of presenting these styled items.
+///
+/// NOTE  The entity is deprecated since IFC4, styles can be assigned to a
+/// styled item directly. It is kept for reading files of older exporters.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpresentationstyleassignment.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PresentationStyleAssignment {
+    /// A set of presentation styles that are assigned to styled items.
+    #[ifc_types(SurfaceStyle)]
+    pub styles: IfcList<Id>,
+}
+
+impl PresentationStyleAssignment {
+    pub fn new() -> Self {
+        Self {
+            styles: IfcList::empty(),
+        }
+    }
+
+    pub fn add_style<S: IfcType>(mut self, style: impl Into<IdOr<S>>, ifc: &mut IFC) -> Self {
+        self.styles.0.push(style.into().or_insert(ifc).id());
+        self
+    }
+}
+
+impl Default for PresentationStyleAssignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IFCParse for PresentationStyleAssignment {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPRESENTATIONSTYLEASSIGNMENT("),
+
+                styles: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PresentationStyleAssignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCPRESENTATIONSTYLEASSIGNMENT({});", self.styles)
+    }
+}
+
+#[typetag::serde(name = "IfcPresentationStyleAssignment")]
+impl IfcType for PresentationStyleAssignment {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::PresentationStyleAssignment;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn presentation_style_assignment_round_trip() {
+        let example = "IFCPRESENTATIONSTYLEASSIGNMENT((#177));";
+
+        let parsed: PresentationStyleAssignment =
+            PresentationStyleAssignment::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
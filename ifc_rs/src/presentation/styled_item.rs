@@ -0,0 +1,155 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// The IfcStyledItem is an assignment of style for presentation to a
+/// geometric representation item as it is used in a representation.
+/// Styled items without an item are used to define the styles of materials.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcstyleditem.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct StyledItem {
+    /// A geometric representation item to which the style is assigned.
+    pub item: OptionalParameter<Id>,
+    /// Representation styles which are assigned, either to an geometric
+    /// representation item, or to a material definition.
+    #[ifc_types(SurfaceStyle, PresentationStyleAssignment)]
+    pub styles: IfcList<Id>,
+    /// The word, or group of words, by which the styled item is referred to.
+    pub name: OptionalParameter<Label>,
+}
+
+impl StyledItem {
+    pub fn new() -> Self {
+        Self {
+            item: OptionalParameter::omitted(),
+            styles: IfcList::empty(),
+            name: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn item(mut self, item: impl Into<Id>) -> Self {
+        self.item = item.into().into();
+        self
+    }
+
+    pub fn add_style<S: IfcType>(mut self, style: impl Into<IdOr<S>>, ifc: &mut IFC) -> Self {
+        self.styles.0.push(style.into().or_insert(ifc).id());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<Label>) -> Self {
+        self.name = name.into().into();
+        self
+    }
+
+    /// The surface styles of the item, resolving presentation style
+    /// assignments.
+    pub fn surface_styles<'a>(&'a self, ifc: &'a IFC) -> impl Iterator<Item = &'a SurfaceStyle> {
+        self.styles
+            .iter()
+            .flat_map(|style| {
+                let style = ifc.data.get_untyped(*style);
+
+                match style.downcast_ref::<PresentationStyleAssignment>() {
+                    Some(assignment) => assignment
+                        .styles
+                        .iter()
+                        .map(|style| ifc.data.get_untyped(*style))
+                        .collect(),
+                    None => vec![style],
+                }
+            })
+            .filter_map(|style| style.downcast_ref::<SurfaceStyle>())
+    }
+
+    /// Colour and transparency of the first surface style defining a colour.
+    pub fn colour(&self, ifc: &IFC) -> Option<SurfaceColour> {
+        self.surface_styles(ifc)
+            .find_map(|surface_style| surface_style.colour(ifc))
+    }
+}
+
+impl Default for StyledItem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IFCParse for StyledItem {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCSTYLEDITEM("),
+
+                item: OptionalParameter::parse(),
+                _: Comma::parse(),
+                styles: IfcList::parse(),
+                _: Comma::parse(),
+                name: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for StyledItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCSTYLEDITEM({},{},{});",
+            self.item, self.styles, self.name
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcStyledItem")]
+impl IfcType for StyledItem {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::StyledItem;
+    use crate::{parser::IFCParse, prelude::*};
+
+    #[test]
+    fn styled_item_round_trip() {
+        let examples = [
+            "IFCSTYLEDITEM(#174,(#179),$);",
+            "IFCSTYLEDITEM($,(#177),'Anstrich');",
+        ];
+
+        for example in examples {
+            let parsed: StyledItem = StyledItem::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn styled_item_colour_through_assignment() {
+        let mut ifc = IFC::default();
+
+        let shading = SurfaceStyleShading::new(ColourRgb::new(1.0, 0.0, 0.0), &mut ifc);
+        let style = SurfaceStyle::new(SurfaceSide::Both).add_style(shading, &mut ifc);
+        let assignment = PresentationStyleAssignment::new().add_style(style, &mut ifc);
+        let styled_item = StyledItem::new().add_style(assignment, &mut ifc);
+
+        assert_eq!(
+            styled_item.colour(&ifc),
+            Some(SurfaceColour::new(1.0, 0.0, 0.0))
+        );
+    }
+}
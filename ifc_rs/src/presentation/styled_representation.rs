@@ -0,0 +1,112 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// The IfcStyledRepresentation represents the concept of a styled
+/// presentation being a representation of a product or a material, given
+/// by styled items without geometry.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcstyledrepresentation.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct StyledRepresentation {
+    /// Definition of the representation context for which the different
+    /// subtypes of representation are valid.
+    #[ifc_types(GeometricRepresentationContext, GeometricRepresentationSubContext)]
+    pub context_of_items: Id,
+    /// The optional identifier of the representation as used within a
+    /// project.
+    pub representation_identifier: OptionalParameter<Label>,
+    /// The description of the type of a representation context.
+    pub representation_type: OptionalParameter<Label>,
+    /// Set of styled items that are defined for this representation.
+    pub items: IfcList<TypedId<StyledItem>>,
+}
+
+impl StyledRepresentation {
+    pub fn new<C: IfcType>(context: impl Into<IdOr<C>>, ifc: &mut IFC) -> Self {
+        Self {
+            context_of_items: context.into().or_insert(ifc).id(),
+            representation_identifier: OptionalParameter::omitted(),
+            representation_type: OptionalParameter::omitted(),
+            items: IfcList::empty(),
+        }
+    }
+
+    pub fn identifier(mut self, identifier: impl Into<Label>) -> Self {
+        self.representation_identifier = identifier.into().into();
+        self
+    }
+
+    pub fn repr_type(mut self, repr_type: impl Into<Label>) -> Self {
+        self.representation_type = repr_type.into().into();
+        self
+    }
+
+    pub fn add_item(mut self, item: impl Into<IdOr<StyledItem>>, ifc: &mut IFC) -> Self {
+        self.items.0.push(item.into().or_insert(ifc));
+        self
+    }
+}
+
+impl IFCParse for StyledRepresentation {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCSTYLEDREPRESENTATION("),
+
+                context_of_items: Id::parse(),
+                _: Comma::parse(),
+                representation_identifier: OptionalParameter::parse(),
+                _: Comma::parse(),
+                representation_type: OptionalParameter::parse(),
+                _: Comma::parse(),
+                items: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for StyledRepresentation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCSTYLEDREPRESENTATION({},{},{},{});",
+            self.context_of_items,
+            self.representation_identifier,
+            self.representation_type,
+            self.items
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcStyledRepresentation")]
+impl IfcType for StyledRepresentation {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::StyledRepresentation;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn styled_representation_round_trip() {
+        let example = "IFCSTYLEDREPRESENTATION(#118,$,$,(#15047));";
+
+        let parsed: StyledRepresentation = StyledRepresentation::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -0,0 +1,171 @@
+use std::{fmt::Display, str::FromStr};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::{
+    combinator::{alt, delimited},
+    Parser,
+};
+
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter, p_space_or_comment,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// Indicates to which side of a surface a surface style applies.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcsurfaceside.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
+pub enum SurfaceSide {
+    /// The side of the surface in the direction of the surface normal.
+    #[strum(to_string = ".POSITIVE.")]
+    Positive,
+
+    /// The side of the surface in the opposite direction of the surface
+    /// normal.
+    #[strum(to_string = ".NEGATIVE.")]
+    Negative,
+
+    /// Both sides of the surface.
+    #[strum(to_string = ".BOTH.")]
+    Both,
+}
+
+impl IFCParse for SurfaceSide {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid SurfaceSide")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+/// An IfcSurfaceStyle is an assignment of one or many surface style elements
+/// to a surface, defined by subtypes of IfcSurface, IfcFaceBasedSurfaceModel,
+/// IfcShellBasedSurfaceModel, or by subtypes of IfcSolidModel.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcsurfacestyle.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct SurfaceStyle {
+    /// Name of the presentation style.
+    pub name: OptionalParameter<Label>,
+    /// An indication of which side of the surface to apply the style.
+    pub side: SurfaceSide,
+    /// A collection of different surface styles.
+    #[ifc_types(SurfaceStyleShading, SurfaceStyleRendering)]
+    pub styles: IfcList<Id>,
+}
+
+impl SurfaceStyle {
+    pub fn new(side: SurfaceSide) -> Self {
+        Self {
+            name: OptionalParameter::omitted(),
+            side,
+            styles: IfcList::empty(),
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<Label>) -> Self {
+        self.name = name.into().into();
+        self
+    }
+
+    pub fn add_style<S: IfcType>(mut self, style: impl Into<IdOr<S>>, ifc: &mut IFC) -> Self {
+        self.styles.0.push(style.into().or_insert(ifc).id());
+        self
+    }
+
+    /// Colour and transparency of the first shading or rendering style.
+    pub fn colour(&self, ifc: &IFC) -> Option<SurfaceColour> {
+        self.styles.iter().find_map(|style| {
+            let style = ifc.data.get_untyped(*style);
+
+            let (surface_colour, transparency) =
+                if let Some(rendering) = style.downcast_ref::<SurfaceStyleRendering>() {
+                    (rendering.surface_colour, &rendering.transparency)
+                } else {
+                    let shading = style.downcast_ref::<SurfaceStyleShading>()?;
+                    (shading.surface_colour, &shading.transparency)
+                };
+
+            let colour = ifc.data.get(surface_colour);
+            Some(
+                SurfaceColour::new(colour.red.0, colour.green.0, colour.blue.0)
+                    .transparency(transparency.custom().map_or(0.0, |value| value.0)),
+            )
+        })
+    }
+}
+
+impl IFCParse for SurfaceStyle {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCSURFACESTYLE("),
+
+                name: OptionalParameter::parse(),
+                _: Comma::parse(),
+                side: SurfaceSide::parse(),
+                _: Comma::parse(),
+                styles: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for SurfaceStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCSURFACESTYLE({},{},{});",
+            self.name, self.side, self.styles
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcSurfaceStyle")]
+impl IfcType for SurfaceStyle {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::SurfaceStyle;
+    use crate::{parser::IFCParse, prelude::*};
+
+    #[test]
+    fn surface_style_round_trip() {
+        let example = "IFCSURFACESTYLE('Gras',.BOTH.,(#176));";
+
+        let parsed: SurfaceStyle = SurfaceStyle::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn surface_style_colour() {
+        let mut ifc = IFC::default();
+
+        let rendering =
+            SurfaceStyleRendering::new(ColourRgb::new(0.5, 0.25, 1.0), &mut ifc).transparency(0.3);
+        let style = SurfaceStyle::new(SurfaceSide::Both).add_style(rendering, &mut ifc);
+
+        assert_eq!(
+            style.colour(&ifc),
+            Some(SurfaceColour::new(0.5, 0.25, 1.0).transparency(0.3))
+        );
+    }
+}
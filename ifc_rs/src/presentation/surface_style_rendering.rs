@@ -0,0 +1,317 @@
+use std::{fmt::Display, str::FromStr};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::{
+    combinator::{alt, delimited},
+    Parser,
+};
+
+use crate::{
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, optional::OptionalParameter, p_space_or_comment,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// Either a colour or a factor applied to the surface colour.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccolourorfactor.htm
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ColourOrFactor {
+    /// `IfcColourRgb` used instead of the surface colour.
+    Colour(TypedId<ColourRgb>),
+    /// `IfcNormalisedRatioMeasure` the surface colour is multiplied with.
+    Factor(IfcFloat),
+}
+
+impl IFCParse for ColourOrFactor {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        alt((
+            Id::parse().map(|id| Self::Colour(TypedId::new(id))),
+            delimited(
+                p_space_or_comment_surrounded("IFCNORMALISEDRATIOMEASURE("),
+                IfcFloat::parse(),
+                p_space_or_comment_surrounded(")"),
+            )
+            .map(Self::Factor),
+        ))
+    }
+}
+
+impl Display for ColourOrFactor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColourOrFactor::Colour(id) => write!(f, "{id}"),
+            ColourOrFactor::Factor(value) => write!(f, "IFCNORMALISEDRATIOMEASURE({value})"),
+        }
+    }
+}
+
+/// The reflectance method is an indication of which reflectance model is
+/// used for the rendering of a surface.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcreflectancemethodenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
+pub enum ReflectanceMethod {
+    /// A reflectance model providing a specular component that is based on
+    /// the halfway vector between light and view direction.
+    #[strum(to_string = ".BLINN.")]
+    Blinn,
+
+    /// A reflectance model that only applies ambient light.
+    #[strum(to_string = ".FLAT.")]
+    Flat,
+
+    /// A reflectance model simulating the refraction of glass.
+    #[strum(to_string = ".GLASS.")]
+    Glass,
+
+    /// A matte surface, only applying diffuse reflectance.
+    #[strum(to_string = ".MATT.")]
+    Matt,
+
+    /// A metallic surface.
+    #[strum(to_string = ".METAL.")]
+    Metal,
+
+    /// A perfectly mirroring surface.
+    #[strum(to_string = ".MIRROR.")]
+    Mirror,
+
+    /// A reflectance model providing a specular component based on the
+    /// reflected light direction.
+    #[strum(to_string = ".PHONG.")]
+    Phong,
+
+    /// A surface made of plastic.
+    #[strum(to_string = ".PLASTIC.")]
+    Plastic,
+
+    /// A reflectance model for metallic and non-metallic appearance.
+    #[strum(to_string = ".STRAUSS.")]
+    Strauss,
+
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for ReflectanceMethod {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid ReflectanceMethod")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+/// The IfcSurfaceStyleShading is used to define the colour and transparency
+/// of a surface for shaded presentations.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcsurfacestyleshading.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct SurfaceStyleShading {
+    /// The colour used to render the surface.
+    pub surface_colour: TypedId<ColourRgb>,
+    /// The transparency of the surface, 0.0 being opaque and 1.0 being
+    /// completely transparent. If omitted the surface is opaque.
+    pub transparency: OptionalParameter<IfcFloat>,
+}
+
+impl SurfaceStyleShading {
+    pub fn new(surface_colour: impl Into<IdOr<ColourRgb>>, ifc: &mut IFC) -> Self {
+        Self {
+            surface_colour: surface_colour.into().or_insert(ifc),
+            transparency: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn transparency(mut self, transparency: f64) -> Self {
+        self.transparency = IfcFloat(transparency).into();
+        self
+    }
+}
+
+impl IFCParse for SurfaceStyleShading {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCSURFACESTYLESHADING("),
+
+                surface_colour: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                transparency: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for SurfaceStyleShading {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCSURFACESTYLESHADING({},{});",
+            self.surface_colour, self.transparency
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcSurfaceStyleShading")]
+impl IfcType for SurfaceStyleShading {}
+
+/// The IfcSurfaceStyleRendering holds the properties for visualisation
+/// related to a particular surface side style, adding the reflectance
+/// properties of the surface to the shading.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcsurfacestylerendering.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct SurfaceStyleRendering {
+    /// The colour used to render the surface.
+    pub surface_colour: TypedId<ColourRgb>,
+    /// The transparency of the surface, 0.0 being opaque and 1.0 being
+    /// completely transparent. If omitted the surface is opaque.
+    pub transparency: OptionalParameter<IfcFloat>,
+    /// The diffuse part of the reflectance equation.
+    pub diffuse_colour: OptionalParameter<ColourOrFactor>,
+    /// The transmission colour of the surface.
+    pub transmission_colour: OptionalParameter<ColourOrFactor>,
+    /// The diffuse transmission colour of the surface.
+    pub diffuse_transmission_colour: OptionalParameter<ColourOrFactor>,
+    /// The reflection colour of the surface.
+    pub reflection_colour: OptionalParameter<ColourOrFactor>,
+    /// The specular part of the reflectance equation.
+    pub specular_colour: OptionalParameter<ColourOrFactor>,
+    /// The exponent or roughness of the specular reflection.
+    pub specular_highlight: OptionalParameter<IfcValue>,
+    /// Identifies the type of reflectance method used for the rendering.
+    pub reflectance_method: ReflectanceMethod,
+}
+
+impl SurfaceStyleRendering {
+    pub fn new(surface_colour: impl Into<IdOr<ColourRgb>>, ifc: &mut IFC) -> Self {
+        Self {
+            surface_colour: surface_colour.into().or_insert(ifc),
+            transparency: OptionalParameter::omitted(),
+            diffuse_colour: OptionalParameter::omitted(),
+            transmission_colour: OptionalParameter::omitted(),
+            diffuse_transmission_colour: OptionalParameter::omitted(),
+            reflection_colour: OptionalParameter::omitted(),
+            specular_colour: OptionalParameter::omitted(),
+            specular_highlight: OptionalParameter::omitted(),
+            reflectance_method: ReflectanceMethod::NotDefined,
+        }
+    }
+
+    pub fn transparency(mut self, transparency: f64) -> Self {
+        self.transparency = IfcFloat(transparency).into();
+        self
+    }
+
+    pub fn diffuse_colour(mut self, diffuse_colour: ColourOrFactor) -> Self {
+        self.diffuse_colour = diffuse_colour.into();
+        self
+    }
+
+    pub fn specular_colour(mut self, specular_colour: ColourOrFactor) -> Self {
+        self.specular_colour = specular_colour.into();
+        self
+    }
+
+    pub fn reflectance_method(mut self, reflectance_method: ReflectanceMethod) -> Self {
+        self.reflectance_method = reflectance_method;
+        self
+    }
+}
+
+impl IFCParse for SurfaceStyleRendering {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCSURFACESTYLERENDERING("),
+
+                surface_colour: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                transparency: OptionalParameter::parse(),
+                _: Comma::parse(),
+                diffuse_colour: OptionalParameter::parse(),
+                _: Comma::parse(),
+                transmission_colour: OptionalParameter::parse(),
+                _: Comma::parse(),
+                diffuse_transmission_colour: OptionalParameter::parse(),
+                _: Comma::parse(),
+                reflection_colour: OptionalParameter::parse(),
+                _: Comma::parse(),
+                specular_colour: OptionalParameter::parse(),
+                _: Comma::parse(),
+                specular_highlight: OptionalParameter::parse(),
+                _: Comma::parse(),
+                reflectance_method: ReflectanceMethod::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for SurfaceStyleRendering {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCSURFACESTYLERENDERING({},{},{},{},{},{},{},{},{});",
+            self.surface_colour,
+            self.transparency,
+            self.diffuse_colour,
+            self.transmission_colour,
+            self.diffuse_transmission_colour,
+            self.reflection_colour,
+            self.specular_colour,
+            self.specular_highlight,
+            self.reflectance_method,
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcSurfaceStyleRendering")]
+impl IfcType for SurfaceStyleRendering {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::{SurfaceStyleRendering, SurfaceStyleShading};
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn surface_style_shading_round_trip() {
+        let example = "IFCSURFACESTYLESHADING(#175,0.5);";
+
+        let parsed: SurfaceStyleShading = SurfaceStyleShading::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn surface_style_rendering_round_trip() {
+        let examples = [
+            "IFCSURFACESTYLERENDERING(#175,0.,IFCNORMALISEDRATIOMEASURE(0.95),$,$,$,IFCNORMALISEDRATIOMEASURE(0.),$,.NOTDEFINED.);",
+            "IFCSURFACESTYLERENDERING(#175,$,#176,$,$,$,$,IFCSPECULAREXPONENT(64.),.PHONG.);",
+        ];
+
+        for example in examples {
+            let parsed: SurfaceStyleRendering =
+                SurfaceStyleRendering::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    property::{Property, PropertyBuilder},
+    value::IfcValue,
+};
+use crate::{
+    id::Id,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// A property with a range of values given by an upper and/or lower bound
+/// and an optional set point within that range.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpropertyboundedvalue.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PropertyBoundedValue {
+    #[inherited]
+    property: Property,
+
+    /// Upper bound value for the interval defining the property value.
+    pub upper_bound_value: OptionalParameter<IfcValue>,
+
+    /// Lower bound value for the interval defining the property value.
+    pub lower_bound_value: OptionalParameter<IfcValue>,
+
+    /// Unit for the bounded values, if not given, the default value for the
+    /// measure type is used as defined by the global unit assignment.
+    #[ifc_types(SiUnit, ConversionBasedUnit, DerivedUnit, MonetaryUnit)]
+    pub unit: OptionalParameter<Id>,
+
+    /// Set point value as typically used for operational value setting.
+    pub set_point_value: OptionalParameter<IfcValue>,
+}
+
+impl PropertyBoundedValue {
+    pub fn new(
+        name: impl Into<Label>,
+        lower_bound: Option<IfcValue>,
+        upper_bound: Option<IfcValue>,
+    ) -> Self {
+        Self {
+            property: Property::new(name),
+            upper_bound_value: upper_bound.into(),
+            lower_bound_value: lower_bound.into(),
+            unit: OptionalParameter::omitted(),
+            set_point_value: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn set_point(mut self, set_point: impl Into<IfcValue>) -> Self {
+        self.set_point_value = set_point.into().into();
+        self
+    }
+}
+
+impl PropertyBuilder for PropertyBoundedValue {
+    fn property_mut(&mut self) -> &mut Property {
+        &mut self.property
+    }
+}
+
+impl Deref for PropertyBoundedValue {
+    type Target = Property;
+
+    fn deref(&self) -> &Self::Target {
+        &self.property
+    }
+}
+
+impl DerefMut for PropertyBoundedValue {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.property
+    }
+}
+
+impl IFCParse for PropertyBoundedValue {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPROPERTYBOUNDEDVALUE("),
+
+                property: Property::parse(),
+                _: Comma::parse(),
+                upper_bound_value: OptionalParameter::parse(),
+                _: Comma::parse(),
+                lower_bound_value: OptionalParameter::parse(),
+                _: Comma::parse(),
+                unit: OptionalParameter::parse(),
+                _: Comma::parse(),
+                set_point_value: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PropertyBoundedValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCPROPERTYBOUNDEDVALUE({},{},{},{},{});",
+            self.property,
+            self.upper_bound_value,
+            self.lower_bound_value,
+            self.unit,
+            self.set_point_value
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcPropertyBoundedValue")]
+impl IfcType for PropertyBoundedValue {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::PropertyBoundedValue;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn property_bounded_value_round_trip() {
+        let example = "IFCPROPERTYBOUNDEDVALUE('Temperature',$,IFCTHERMODYNAMICTEMPERATUREMEASURE(300.),IFCTHERMODYNAMICTEMPERATUREMEASURE(280.),$,$);";
+
+        let parsed: PropertyBoundedValue = PropertyBoundedValue::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
@@ -0,0 +1,128 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::property::{Property, PropertyBuilder};
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, p_space_or_comment_surrounded, IFCParse,
+        IFCParser,
+    },
+    prelude::*,
+};
+
+/// A property which is a collection of other properties, e.g. the layers
+/// of a construction with their material and thickness each.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccomplexproperty.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct ComplexProperty {
+    #[inherited]
+    property: Property,
+
+    /// Usage description of the complex property.
+    pub usage_name: Label,
+
+    /// Set of properties that can be used within this complex property.
+    #[ifc_types(
+        PropertySingleValue,
+        PropertyEnumeratedValue,
+        PropertyListValue,
+        PropertyBoundedValue,
+        PropertyTableValue,
+        ComplexProperty
+    )]
+    pub has_properties: IfcList<Id>,
+}
+
+impl ComplexProperty {
+    pub fn new(name: impl Into<Label>, usage_name: impl Into<Label>) -> Self {
+        Self {
+            property: Property::new(name),
+            usage_name: usage_name.into(),
+            has_properties: IfcList::empty(),
+        }
+    }
+
+    pub fn add_property<P: IfcType>(mut self, property: impl Into<IdOr<P>>, ifc: &mut IFC) -> Self {
+        self.has_properties
+            .0
+            .push(property.into().or_insert(ifc).id());
+        self
+    }
+}
+
+impl PropertyBuilder for ComplexProperty {
+    fn property_mut(&mut self) -> &mut Property {
+        &mut self.property
+    }
+}
+
+impl Deref for ComplexProperty {
+    type Target = Property;
+
+    fn deref(&self) -> &Self::Target {
+        &self.property
+    }
+}
+
+impl DerefMut for ComplexProperty {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.property
+    }
+}
+
+impl IFCParse for ComplexProperty {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCCOMPLEXPROPERTY("),
+
+                property: Property::parse(),
+                _: Comma::parse(),
+                usage_name: Label::parse(),
+                _: Comma::parse(),
+                has_properties: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for ComplexProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCCOMPLEXPROPERTY({},{},{});",
+            self.property, self.usage_name, self.has_properties
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcComplexProperty")]
+impl IfcType for ComplexProperty {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::ComplexProperty;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn complex_property_round_trip() {
+        let example = "IFCCOMPLEXPROPERTY('Layer1',$,'Layer',(#10,#11));";
+
+        let parsed: ComplexProperty = ComplexProperty::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
@@ -0,0 +1,47 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// Interpolation of the values between the defining values of a
+/// property table.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccurveinterpolationenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum CurveInterpolationEnum {
+    /// Linear interpolation between the given values.
+    #[strum(to_string = ".LINEAR.")]
+    Linear,
+
+    /// Logarithmic interpolation of the defined values and linear
+    /// interpolation of the defining values.
+    #[strum(to_string = ".LOG_LINEAR.")]
+    LogLinear,
+
+    /// Logarithmic interpolation of both, defining and defined values.
+    #[strum(to_string = ".LOG_LOG.")]
+    LogLog,
+
+    /// The interpolation is not defined.
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for CurveInterpolationEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid CurveInterpolationEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
@@ -0,0 +1,132 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    enumeration::PropertyEnumeration,
+    property::{Property, PropertyBuilder},
+    value::IfcValue,
+};
+use crate::{
+    id::{IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// A property with one or more values selected from a list of allowed
+/// values given by an [`PropertyEnumeration`].
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpropertyenumeratedvalue.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PropertyEnumeratedValue {
+    #[inherited]
+    property: Property,
+
+    /// Enumeration values, which shall be listed in the referenced
+    /// enumeration.
+    pub enumeration_values: OptionalParameter<IfcList<IfcValue>>,
+
+    /// Enumeration from which the enumeration values are selected.
+    pub enumeration_reference: OptionalParameter<TypedId<PropertyEnumeration>>,
+}
+
+impl PropertyEnumeratedValue {
+    pub fn new(
+        name: impl Into<Label>,
+        values: impl IntoIterator<Item = impl Into<IfcValue>>,
+    ) -> Self {
+        Self {
+            property: Property::new(name),
+            enumeration_values: IfcList(values.into_iter().map(Into::into).collect()).into(),
+            enumeration_reference: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn enumeration_reference(
+        mut self,
+        enumeration: impl Into<IdOr<PropertyEnumeration>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.enumeration_reference = enumeration.into().or_insert(ifc).into();
+        self
+    }
+}
+
+impl PropertyBuilder for PropertyEnumeratedValue {
+    fn property_mut(&mut self) -> &mut Property {
+        &mut self.property
+    }
+}
+
+impl Deref for PropertyEnumeratedValue {
+    type Target = Property;
+
+    fn deref(&self) -> &Self::Target {
+        &self.property
+    }
+}
+
+impl DerefMut for PropertyEnumeratedValue {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.property
+    }
+}
+
+impl IFCParse for PropertyEnumeratedValue {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPROPERTYENUMERATEDVALUE("),
+
+                property: Property::parse(),
+                _: Comma::parse(),
+                enumeration_values: OptionalParameter::parse(),
+                _: Comma::parse(),
+                enumeration_reference: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PropertyEnumeratedValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCPROPERTYENUMERATEDVALUE({},{},{});",
+            self.property, self.enumeration_values, self.enumeration_reference
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcPropertyEnumeratedValue")]
+impl IfcType for PropertyEnumeratedValue {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::PropertyEnumeratedValue;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn property_enumerated_value_round_trip() {
+        let example =
+            "IFCPROPERTYENUMERATEDVALUE('Status',$,(IFCLABEL('NEW'),IFCLABEL('EXISTING')),#42);";
+
+        let parsed: PropertyEnumeratedValue =
+            PropertyEnumeratedValue::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
@@ -0,0 +1,96 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::value::IfcValue;
+use crate::{
+    id::Id,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// A collection of simple or measure values that define a prescribed set
+/// of alternatives from which enumerated values are selected.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpropertyenumeration.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PropertyEnumeration {
+    /// Name of this enumeration.
+    pub name: Label,
+
+    /// List of values that form the enumeration.
+    pub enumeration_values: IfcList<IfcValue>,
+
+    /// Unit for the enumerator values, if not given, the default value for
+    /// the measure type is used as defined by the global unit assignment.
+    #[ifc_types(SiUnit, ConversionBasedUnit, DerivedUnit, MonetaryUnit)]
+    pub unit: OptionalParameter<Id>,
+}
+
+impl PropertyEnumeration {
+    pub fn new(
+        name: impl Into<Label>,
+        values: impl IntoIterator<Item = impl Into<IfcValue>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            enumeration_values: IfcList(values.into_iter().map(Into::into).collect()),
+            unit: OptionalParameter::omitted(),
+        }
+    }
+}
+
+impl IFCParse for PropertyEnumeration {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPROPERTYENUMERATION("),
+
+                name: Label::parse(),
+                _: Comma::parse(),
+                enumeration_values: IfcList::parse(),
+                _: Comma::parse(),
+                unit: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PropertyEnumeration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCPROPERTYENUMERATION({},{},{});",
+            self.name, self.enumeration_values, self.unit
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcPropertyEnumeration")]
+impl IfcType for PropertyEnumeration {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::PropertyEnumeration;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn property_enumeration_round_trip() {
+        let example =
+            "IFCPROPERTYENUMERATION('PEnum_ElementStatus',(IFCLABEL('NEW'),IFCLABEL('EXISTING')),$);";
+
+        let parsed: PropertyEnumeration = PropertyEnumeration::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
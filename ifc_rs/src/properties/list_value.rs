@@ -0,0 +1,121 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    property::{Property, PropertyBuilder},
+    value::IfcValue,
+};
+use crate::{
+    id::Id,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// A property with an ordered list of values, all of the same type.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpropertylistvalue.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PropertyListValue {
+    #[inherited]
+    property: Property,
+
+    /// List of values.
+    pub list_values: OptionalParameter<IfcList<IfcValue>>,
+
+    /// Unit for the list values, if not given, the default value for the
+    /// measure type is used as defined by the global unit assignment.
+    #[ifc_types(SiUnit, ConversionBasedUnit, DerivedUnit, MonetaryUnit)]
+    pub unit: OptionalParameter<Id>,
+}
+
+impl PropertyListValue {
+    pub fn new(
+        name: impl Into<Label>,
+        values: impl IntoIterator<Item = impl Into<IfcValue>>,
+    ) -> Self {
+        Self {
+            property: Property::new(name),
+            list_values: IfcList(values.into_iter().map(Into::into).collect()).into(),
+            unit: OptionalParameter::omitted(),
+        }
+    }
+}
+
+impl PropertyBuilder for PropertyListValue {
+    fn property_mut(&mut self) -> &mut Property {
+        &mut self.property
+    }
+}
+
+impl Deref for PropertyListValue {
+    type Target = Property;
+
+    fn deref(&self) -> &Self::Target {
+        &self.property
+    }
+}
+
+impl DerefMut for PropertyListValue {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.property
+    }
+}
+
+impl IFCParse for PropertyListValue {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPROPERTYLISTVALUE("),
+
+                property: Property::parse(),
+                _: Comma::parse(),
+                list_values: OptionalParameter::parse(),
+                _: Comma::parse(),
+                unit: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PropertyListValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCPROPERTYLISTVALUE({},{},{});",
+            self.property, self.list_values, self.unit
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcPropertyListValue")]
+impl IfcType for PropertyListValue {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::PropertyListValue;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn property_list_value_round_trip() {
+        let example =
+            "IFCPROPERTYLISTVALUE('Layers',$,(IFCLENGTHMEASURE(0.2),IFCLENGTHMEASURE(0.05)),$);";
+
+        let parsed: PropertyListValue = PropertyListValue::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
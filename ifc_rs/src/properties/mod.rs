@@ -0,0 +1,36 @@
+use winnow::combinator::alt;
+
+use crate::{
+    ifc_type::IfcType,
+    parser::{IFCParse, IFCParser},
+};
+
+pub mod bounded_value;
+pub mod complex_property;
+pub mod curve_interpolation_enum;
+pub mod enumerated_value;
+pub mod enumeration;
+pub mod list_value;
+pub mod prelude;
+pub mod property;
+pub mod property_set;
+pub mod single_value;
+pub mod table_value;
+pub mod value;
+
+pub struct Properties;
+
+impl Properties {
+    pub fn parse<'a>() -> impl IFCParser<'a, Box<dyn IfcType>> {
+        alt((
+            property_set::PropertySet::parse_any(),
+            single_value::PropertySingleValue::parse_any(),
+            enumerated_value::PropertyEnumeratedValue::parse_any(),
+            enumeration::PropertyEnumeration::parse_any(),
+            list_value::PropertyListValue::parse_any(),
+            bounded_value::PropertyBoundedValue::parse_any(),
+            table_value::PropertyTableValue::parse_any(),
+            complex_property::ComplexProperty::parse_any(),
+        ))
+    }
+}
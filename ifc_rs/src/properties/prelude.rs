@@ -0,0 +1,11 @@
+pub use super::bounded_value::PropertyBoundedValue;
+pub use super::complex_property::ComplexProperty;
+pub use super::curve_interpolation_enum::CurveInterpolationEnum;
+pub use super::enumerated_value::PropertyEnumeratedValue;
+pub use super::enumeration::PropertyEnumeration;
+pub use super::list_value::PropertyListValue;
+pub use super::property::{Property, PropertyBuilder};
+pub use super::property_set::PropertySet;
+pub use super::single_value::PropertySingleValue;
+pub use super::table_value::PropertyTableValue;
+pub use super::value::IfcValue;
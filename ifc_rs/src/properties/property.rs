@@ -0,0 +1,61 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ifc_type::IfcVerify,
+    parser::{comma::Comma, label::Label, optional::OptionalParameter, IFCParse, IFCParser},
+    IFC,
+};
+
+/// IfcProperty is an abstract generalization for all types of properties
+/// that can be associated with IFC objects through the property set
+/// mechanism.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcproperty.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct Property {
+    /// Name for this property. This label is the significant name string
+    /// that defines the semantic meaning for the property.
+    pub name: Label,
+
+    /// Informative text to explain the property.
+    pub description: OptionalParameter<Label>,
+}
+
+impl Property {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            name: name.into(),
+            description: OptionalParameter::omitted(),
+        }
+    }
+}
+
+pub trait PropertyBuilder: Sized {
+    fn property_mut(&mut self) -> &mut Property;
+
+    fn description(mut self, description: impl Into<Label>) -> Self {
+        self.property_mut().description = description.into().into();
+        self
+    }
+}
+
+impl IFCParse for Property {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                name: Label::parse(),
+                _: Comma::parse(),
+                description: OptionalParameter::parse(),
+            }
+        }
+    }
+}
+
+impl Display for Property {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.name, self.description)
+    }
+}
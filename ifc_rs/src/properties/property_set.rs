@@ -0,0 +1,143 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::{property::Property, single_value::PropertySingleValue, value::IfcValue};
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, p_space_or_comment_surrounded, IFCParse,
+        IFCParser,
+    },
+    prelude::*,
+};
+
+/// The IfcPropertySet is a container that holds properties within a
+/// property tree. These properties are interpreted according to their name
+/// attribute. Property sets, defining a particular type of object, are
+/// assigned to occurrences by IfcRelDefinesByProperties or to types by
+/// IfcTypeObject.HasPropertySets.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpropertyset.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PropertySet {
+    #[inherited]
+    root: Root,
+
+    /// Contained set of properties. For property sets defined as part of
+    /// the IFC specification, the property names are unique within the set.
+    #[ifc_types(
+        PropertySingleValue,
+        PropertyEnumeratedValue,
+        PropertyListValue,
+        PropertyBoundedValue,
+        PropertyTableValue,
+        ComplexProperty
+    )]
+    pub has_properties: IfcList<Id>,
+}
+
+impl PropertySet {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            root: Root::new(name.into()),
+            has_properties: IfcList::empty(),
+        }
+    }
+
+    pub fn add_property<P: IfcType>(mut self, property: impl Into<IdOr<P>>, ifc: &mut IFC) -> Self {
+        self.has_properties
+            .0
+            .push(property.into().or_insert(ifc).id());
+        self
+    }
+
+    /// All properties of this set.
+    pub fn properties<'a>(&'a self, ifc: &'a IFC) -> impl Iterator<Item = &'a Property> {
+        self.has_properties
+            .iter()
+            .filter_map(|id| ifc.data.get_untyped(*id).as_property())
+    }
+
+    /// The nominal value of the single value property with the given name.
+    pub fn single_value<'a>(&self, name: &str, ifc: &'a IFC) -> Option<&'a IfcValue> {
+        self.has_properties
+            .iter()
+            .filter_map(|id| {
+                ifc.data
+                    .get_untyped(*id)
+                    .downcast_ref::<PropertySingleValue>()
+            })
+            .find(|property| property.name.0 == name)
+            .and_then(|property| property.nominal_value.custom())
+    }
+}
+
+impl RootBuilder for PropertySet {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.root
+    }
+}
+
+impl Deref for PropertySet {
+    type Target = Root;
+
+    fn deref(&self) -> &Self::Target {
+        &self.root
+    }
+}
+
+impl DerefMut for PropertySet {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.root
+    }
+}
+
+impl IFCParse for PropertySet {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPROPERTYSET("),
+
+                root: Root::parse(),
+                _: Comma::parse(),
+                has_properties: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PropertySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCPROPERTYSET({},{});", self.root, self.has_properties)
+    }
+}
+
+#[typetag::serde(name = "IfcPropertySet")]
+impl IfcType for PropertySet {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::PropertySet;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn property_set_round_trip() {
+        let example =
+            "IFCPROPERTYSET('2HlE6XRD5D2u$q88QiyvHI',#42,'Pset_WallCommon',$,(#365,#366,#367));";
+
+        let parsed: PropertySet = PropertySet::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
@@ -0,0 +1,118 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    property::{Property, PropertyBuilder},
+    value::IfcValue,
+};
+use crate::{
+    id::Id,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// A property with a single value assigned, e.g. `IsExternal` of
+/// `Pset_WallCommon`.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpropertysinglevalue.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PropertySingleValue {
+    #[inherited]
+    property: Property,
+
+    /// Value and measure type of this property.
+    pub nominal_value: OptionalParameter<IfcValue>,
+
+    /// Unit for the nominal value, if not given, the default value for the
+    /// measure type is used as defined by the global unit assignment.
+    #[ifc_types(SiUnit, ConversionBasedUnit, DerivedUnit, MonetaryUnit)]
+    pub unit: OptionalParameter<Id>,
+}
+
+impl PropertySingleValue {
+    pub fn new(name: impl Into<Label>, value: impl Into<IfcValue>) -> Self {
+        Self {
+            property: Property::new(name),
+            nominal_value: value.into().into(),
+            unit: OptionalParameter::omitted(),
+        }
+    }
+}
+
+impl PropertyBuilder for PropertySingleValue {
+    fn property_mut(&mut self) -> &mut Property {
+        &mut self.property
+    }
+}
+
+impl Deref for PropertySingleValue {
+    type Target = Property;
+
+    fn deref(&self) -> &Self::Target {
+        &self.property
+    }
+}
+
+impl DerefMut for PropertySingleValue {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.property
+    }
+}
+
+impl IFCParse for PropertySingleValue {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPROPERTYSINGLEVALUE("),
+
+                property: Property::parse(),
+                _: Comma::parse(),
+                nominal_value: OptionalParameter::parse(),
+                _: Comma::parse(),
+                unit: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PropertySingleValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCPROPERTYSINGLEVALUE({},{},{});",
+            self.property, self.nominal_value, self.unit
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcPropertySingleValue")]
+impl IfcType for PropertySingleValue {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::PropertySingleValue;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn property_single_value_round_trip() {
+        let example = "IFCPROPERTYSINGLEVALUE('ThermalTransmittance',$,IFCTHERMALTRANSMITTANCEMEASURE(0.24),$);";
+
+        let parsed: PropertySingleValue = PropertySingleValue::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
@@ -0,0 +1,181 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    curve_interpolation_enum::CurveInterpolationEnum,
+    property::{Property, PropertyBuilder},
+    value::IfcValue,
+};
+use crate::{
+    id::Id,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// A property whose values are given by a table of defining values (e.g.
+/// temperatures) and the corresponding defined values (e.g. conductivities).
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpropertytablevalue.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PropertyTableValue {
+    #[inherited]
+    property: Property,
+
+    /// List of defining values, which determine the defined values.
+    pub defining_values: OptionalParameter<IfcList<IfcValue>>,
+
+    /// Defined values which are applicable for the scope as defined by the
+    /// defining values. Both lists have the same length.
+    pub defined_values: OptionalParameter<IfcList<IfcValue>>,
+
+    /// Expression for the derivation of the defined values from the
+    /// defining values.
+    pub expression: OptionalParameter<Label>,
+
+    /// Unit for the defining values.
+    #[ifc_types(SiUnit, ConversionBasedUnit, DerivedUnit, MonetaryUnit)]
+    pub defining_unit: OptionalParameter<Id>,
+
+    /// Unit for the defined values.
+    #[ifc_types(SiUnit, ConversionBasedUnit, DerivedUnit, MonetaryUnit)]
+    pub defined_unit: OptionalParameter<Id>,
+
+    /// Interpolation of the curve between the values.
+    pub curve_interpolation: OptionalParameter<CurveInterpolationEnum>,
+}
+
+impl PropertyTableValue {
+    pub fn new(
+        name: impl Into<Label>,
+        rows: impl IntoIterator<Item = (impl Into<IfcValue>, impl Into<IfcValue>)>,
+    ) -> Self {
+        let (defining_values, defined_values): (Vec<_>, Vec<_>) = rows
+            .into_iter()
+            .map(|(defining, defined)| (defining.into(), defined.into()))
+            .unzip();
+
+        Self {
+            property: Property::new(name),
+            defining_values: IfcList(defining_values).into(),
+            defined_values: IfcList(defined_values).into(),
+            expression: OptionalParameter::omitted(),
+            defining_unit: OptionalParameter::omitted(),
+            defined_unit: OptionalParameter::omitted(),
+            curve_interpolation: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn curve_interpolation(mut self, curve_interpolation: CurveInterpolationEnum) -> Self {
+        self.curve_interpolation = curve_interpolation.into();
+        self
+    }
+
+    /// Pairs of defining and defined values.
+    pub fn rows(&self) -> impl Iterator<Item = (&IfcValue, &IfcValue)> {
+        let defining = self
+            .defining_values
+            .custom()
+            .into_iter()
+            .flat_map(|l| l.iter());
+        let defined = self
+            .defined_values
+            .custom()
+            .into_iter()
+            .flat_map(|l| l.iter());
+
+        defining.zip(defined)
+    }
+}
+
+impl PropertyBuilder for PropertyTableValue {
+    fn property_mut(&mut self) -> &mut Property {
+        &mut self.property
+    }
+}
+
+impl Deref for PropertyTableValue {
+    type Target = Property;
+
+    fn deref(&self) -> &Self::Target {
+        &self.property
+    }
+}
+
+impl DerefMut for PropertyTableValue {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.property
+    }
+}
+
+impl IFCParse for PropertyTableValue {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCPROPERTYTABLEVALUE("),
+
+                property: Property::parse(),
+                _: Comma::parse(),
+                defining_values: OptionalParameter::parse(),
+                _: Comma::parse(),
+                defined_values: OptionalParameter::parse(),
+                _: Comma::parse(),
+                expression: OptionalParameter::parse(),
+                _: Comma::parse(),
+                defining_unit: OptionalParameter::parse(),
+                _: Comma::parse(),
+                defined_unit: OptionalParameter::parse(),
+                _: Comma::parse(),
+                curve_interpolation: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for PropertyTableValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCPROPERTYTABLEVALUE({},{},{},{},{},{},{});",
+            self.property,
+            self.defining_values,
+            self.defined_values,
+            self.expression,
+            self.defining_unit,
+            self.defined_unit,
+            self.curve_interpolation
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcPropertyTableValue")]
+impl IfcType for PropertyTableValue {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::PropertyTableValue;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn property_table_value_round_trip() {
+        let example = "IFCPROPERTYTABLEVALUE('Conductivity',$,(IFCTHERMODYNAMICTEMPERATUREMEASURE(273.),IFCTHERMODYNAMICTEMPERATUREMEASURE(293.)),(IFCREAL(0.5),IFCREAL(0.6)),$,$,$,.LINEAR.);";
+
+        let parsed: PropertyTableValue = PropertyTableValue::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+        assert_eq!(parsed.rows().count(), 2);
+    }
+}
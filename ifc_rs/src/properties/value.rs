@@ -0,0 +1,218 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use winnow::{
+    combinator::{alt, delimited, dispatch, fail, terminated},
+    token::take_while,
+    Parser,
+};
+
+use crate::parser::{
+    bool::IfcBool, ifc_float::IfcFloat, ifc_integer::IfcInteger, label::Label, p_space_or_comment,
+    p_space_or_comment_surrounded, step_value::StepValue, timestamp::IfcTimestamp, IFCParse,
+    IFCParser,
+};
+
+macro_rules! ifc_values {
+    ($($(#[$meta:meta])* $variant:ident($value:ty) => $step_name:literal,)*) => {
+        /// A value with its defined type, as used by the IfcValue select of
+        /// properties, e.g. `IFCLABEL('abc')` or `IFCLENGTHMEASURE(0.3)`.
+        ///
+        /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcvalue.htm
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        pub enum IfcValue {
+            $(
+                $(#[$meta])*
+                $variant($value),
+            )*
+            /// Any other defined type, kept as it was read.
+            Other(StepValue),
+        }
+
+        impl IfcValue {
+            fn parse_typed<'a>() -> impl IFCParser<'a, Self> {
+                let mut p_type_name = terminated(
+                    take_while(1.., |c: char| c.is_ascii_alphanumeric() || c == '_'),
+                    p_space_or_comment_surrounded("("),
+                );
+
+                terminated(
+                    dispatch! { p_type_name;
+                        $(
+                            $step_name => <$value>::parse().map(Self::$variant),
+                        )*
+                        _ => fail,
+                    },
+                    p_space_or_comment_surrounded(")"),
+                )
+            }
+        }
+
+        impl Display for IfcValue {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(
+                        Self::$variant(value) => write!(f, "{}({value})", $step_name),
+                    )*
+                    Self::Other(value) => write!(f, "{value}"),
+                }
+            }
+        }
+    };
+}
+
+ifc_values! {
+    Boolean(IfcBool) => "IFCBOOLEAN",
+    Logical(IfcBool) => "IFCLOGICAL",
+    Label(Label) => "IFCLABEL",
+    Identifier(Label) => "IFCIDENTIFIER",
+    Text(Label) => "IFCTEXT",
+    Integer(IfcInteger) => "IFCINTEGER",
+    Real(IfcFloat) => "IFCREAL",
+    /// Seconds since 1970-01-01.
+    Timestamp(IfcTimestamp) => "IFCTIMESTAMP",
+    LengthMeasure(IfcFloat) => "IFCLENGTHMEASURE",
+    PositiveLengthMeasure(IfcFloat) => "IFCPOSITIVELENGTHMEASURE",
+    AreaMeasure(IfcFloat) => "IFCAREAMEASURE",
+    VolumeMeasure(IfcFloat) => "IFCVOLUMEMEASURE",
+    CountMeasure(IfcFloat) => "IFCCOUNTMEASURE",
+    PlaneAngleMeasure(IfcFloat) => "IFCPLANEANGLEMEASURE",
+    RatioMeasure(IfcFloat) => "IFCRATIOMEASURE",
+    PositiveRatioMeasure(IfcFloat) => "IFCPOSITIVERATIOMEASURE",
+    MassMeasure(IfcFloat) => "IFCMASSMEASURE",
+    MassDensityMeasure(IfcFloat) => "IFCMASSDENSITYMEASURE",
+    ThermalTransmittanceMeasure(IfcFloat) => "IFCTHERMALTRANSMITTANCEMEASURE",
+    ThermodynamicTemperatureMeasure(IfcFloat) => "IFCTHERMODYNAMICTEMPERATUREMEASURE",
+}
+
+impl IfcValue {
+    /// Value of boolean and logical values, `None` for unknown logicals.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(value) | Self::Logical(value) => match value {
+                IfcBool::True => Some(true),
+                IfcBool::False => Some(false),
+                IfcBool::Unknown => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Value of all numeric values including integers.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(value) => Some(value.0 as f64),
+            Self::Real(value)
+            | Self::LengthMeasure(value)
+            | Self::PositiveLengthMeasure(value)
+            | Self::AreaMeasure(value)
+            | Self::VolumeMeasure(value)
+            | Self::CountMeasure(value)
+            | Self::PlaneAngleMeasure(value)
+            | Self::RatioMeasure(value)
+            | Self::PositiveRatioMeasure(value)
+            | Self::MassMeasure(value)
+            | Self::MassDensityMeasure(value)
+            | Self::ThermalTransmittanceMeasure(value)
+            | Self::ThermodynamicTemperatureMeasure(value) => Some(value.0),
+            _ => None,
+        }
+    }
+
+    /// Value of labels, identifiers and texts.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Label(value) | Self::Identifier(value) | Self::Text(value) => Some(&value.0),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for IfcValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value.into())
+    }
+}
+
+impl From<&str> for IfcValue {
+    fn from(value: &str) -> Self {
+        Self::Label(value.into())
+    }
+}
+
+impl From<String> for IfcValue {
+    fn from(value: String) -> Self {
+        Self::Label(value.into())
+    }
+}
+
+impl From<i64> for IfcValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value.into())
+    }
+}
+
+impl From<f64> for IfcValue {
+    fn from(value: f64) -> Self {
+        Self::Real(IfcFloat(value))
+    }
+}
+
+impl IFCParse for IfcValue {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        delimited(
+            p_space_or_comment(),
+            alt((
+                Self::parse_typed(),
+                StepValue::parse()
+                    .verify(|value| matches!(value, StepValue::Typed(..)))
+                    .map(Self::Other),
+            )),
+            p_space_or_comment(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::IfcValue;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn ifc_value_round_trip() {
+        let examples = [
+            "IFCLABEL('Massivhaus')",
+            "IFCIDENTIFIER('1234')",
+            "IFCBOOLEAN(.TRUE.)",
+            "IFCINTEGER(5)",
+            "IFCTHERMALTRANSMITTANCEMEASURE(0.24)",
+            "IFCPRESSUREMEASURE(2.5)",
+            "IFCDESCRIPTIVEMEASURE('F90')",
+        ];
+
+        for example in examples {
+            let parsed = IfcValue::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+
+    #[test]
+    fn ifc_value_typed_access() {
+        let is_external = IfcValue::parse().parse("IFCBOOLEAN(.T.)").unwrap();
+        assert_eq!(is_external.as_bool(), Some(true));
+
+        let fire_rating = IfcValue::parse().parse("IFCLABEL('F90')").unwrap();
+        assert_eq!(fire_rating.as_str(), Some("F90"));
+
+        let u_value = IfcValue::parse()
+            .parse("IFCTHERMALTRANSMITTANCEMEASURE(0.24)")
+            .unwrap();
+        assert_eq!(u_value, IfcValue::ThermalTransmittanceMeasure(0.24.into()));
+        assert_eq!(u_value.as_f64(), Some(0.24));
+
+        let other = IfcValue::parse().parse("IFCPRESSUREMEASURE(2.5)").unwrap();
+        assert!(matches!(other, IfcValue::Other(_)));
+    }
+}
@@ -0,0 +1,176 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::physical_simple_quantity::PhysicalSimpleQuantity;
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// An IfcElementQuantity defines a set of derived measures of an element's
+/// physical property. Elements could be spatial structure elements (like
+/// buildings, storeys, or spaces) or building elements (like walls, slabs,
+/// finishes). The IfcElementQuantity gets assigned to the element by
+/// using the relationship object IfcRelDefinesByProperties.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcelementquantity.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct ElementQuantity {
+    #[inherited]
+    root: Root,
+
+    /// Name of the method of measurement used to calculate the element
+    /// quantity. The method of measurement attribute has to be made
+    /// recognizable by further agreements.
+    pub method_of_measurement: OptionalParameter<Label>,
+
+    /// The individual quantities for the element, can be a set of length,
+    /// area, volume, weight or count based quantities.
+    #[ifc_types(
+        QuantityLength,
+        QuantityArea,
+        QuantityVolume,
+        QuantityCount,
+        QuantityWeight
+    )]
+    pub quantities: IfcList<Id>,
+}
+
+impl ElementQuantity {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            root: Root::new(name.into()),
+            method_of_measurement: OptionalParameter::omitted(),
+            quantities: IfcList::empty(),
+        }
+    }
+
+    pub fn method_of_measurement(mut self, method_of_measurement: impl Into<Label>) -> Self {
+        self.method_of_measurement = method_of_measurement.into().into();
+        self
+    }
+
+    pub fn add_quantity<Q: IfcType>(mut self, quantity: impl Into<IdOr<Q>>, ifc: &mut IFC) -> Self {
+        self.quantities.0.push(quantity.into().or_insert(ifc).id());
+        self
+    }
+
+    /// All quantities of this set.
+    pub fn quantities<'a>(
+        &'a self,
+        ifc: &'a IFC,
+    ) -> impl Iterator<Item = &'a PhysicalSimpleQuantity> {
+        self.quantities
+            .iter()
+            .filter_map(|id| ifc.data.get_untyped(*id).as_physical_simple_quantity())
+    }
+
+    /// The value of the quantity with the given name, regardless of its
+    /// kind.
+    pub fn quantity_value(&self, name: &str, ifc: &IFC) -> Option<f64> {
+        self.quantities
+            .iter()
+            .map(|id| ifc.data.get_untyped(*id))
+            .find(|quantity| {
+                quantity
+                    .as_physical_simple_quantity()
+                    .is_some_and(|quantity| quantity.name.0 == name)
+            })
+            .and_then(simple_quantity_value)
+    }
+}
+
+fn simple_quantity_value(quantity: &dyn IfcType) -> Option<f64> {
+    if let Some(length) = quantity.downcast_ref::<QuantityLength>() {
+        Some(length.length_value.0)
+    } else if let Some(area) = quantity.downcast_ref::<QuantityArea>() {
+        Some(area.area_value.0)
+    } else if let Some(volume) = quantity.downcast_ref::<QuantityVolume>() {
+        Some(volume.volume_value.0)
+    } else if let Some(count) = quantity.downcast_ref::<QuantityCount>() {
+        Some(count.count_value.0)
+    } else {
+        quantity
+            .downcast_ref::<QuantityWeight>()
+            .map(|weight| weight.weight_value.0)
+    }
+}
+
+impl RootBuilder for ElementQuantity {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.root
+    }
+}
+
+impl Deref for ElementQuantity {
+    type Target = Root;
+
+    fn deref(&self) -> &Self::Target {
+        &self.root
+    }
+}
+
+impl DerefMut for ElementQuantity {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.root
+    }
+}
+
+impl IFCParse for ElementQuantity {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCELEMENTQUANTITY("),
+
+                root: Root::parse(),
+                _: Comma::parse(),
+                method_of_measurement: OptionalParameter::parse(),
+                _: Comma::parse(),
+                quantities: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for ElementQuantity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCELEMENTQUANTITY({},{},{});",
+            self.root, self.method_of_measurement, self.quantities
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcElementQuantity")]
+impl IfcType for ElementQuantity {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::ElementQuantity;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn element_quantity_round_trip() {
+        let example = "IFCELEMENTQUANTITY('3UOpoePQalpg9mPJGweMae',#12,'BaseQuantities',$,'ARCHICAD BIM Base Quantities',(#15160,#15161,#15162));";
+
+        let parsed: ElementQuantity = ElementQuantity::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
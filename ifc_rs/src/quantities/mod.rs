@@ -0,0 +1,30 @@
+use winnow::combinator::alt;
+
+use crate::{
+    ifc_type::IfcType,
+    parser::{IFCParse, IFCParser},
+};
+
+pub mod element_quantity;
+pub mod physical_simple_quantity;
+pub mod prelude;
+pub mod quantity_area;
+pub mod quantity_count;
+pub mod quantity_length;
+pub mod quantity_volume;
+pub mod quantity_weight;
+
+pub struct Quantities;
+
+impl Quantities {
+    pub fn parse<'a>() -> impl IFCParser<'a, Box<dyn IfcType>> {
+        alt((
+            element_quantity::ElementQuantity::parse_any(),
+            quantity_length::QuantityLength::parse_any(),
+            quantity_area::QuantityArea::parse_any(),
+            quantity_volume::QuantityVolume::parse_any(),
+            quantity_count::QuantityCount::parse_any(),
+            quantity_weight::QuantityWeight::parse_any(),
+        ))
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::Display;
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::IfcVerify,
+    parser::{comma::Comma, label::Label, optional::OptionalParameter, IFCParse, IFCParser},
+    prelude::*,
+};
+
+/// The physical quantity, IfcPhysicalSimpleQuantity, is an entity that
+/// holds a single quantity measure value (as defined at the subtypes of
+/// IfcPhysicalSimpleQuantity) that is a physical quantity of an element.
+/// It covers the attributes of the abstract IfcPhysicalQuantity as well.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcphysicalsimplequantity.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct PhysicalSimpleQuantity {
+    /// Name of the element quantity or measure. The name attribute has
+    /// to be made recognizable by further agreements.
+    pub name: Label,
+
+    /// Further explanation that might be given to the quantity.
+    pub description: OptionalParameter<Label>,
+
+    /// Optional assignment of a unit. If no unit is given, then the global
+    /// unit assignment, as established at the IfcProject, applies.
+    #[ifc_types(SiUnit, ConversionBasedUnit, DerivedUnit)]
+    pub unit: OptionalParameter<Id>,
+}
+
+impl PhysicalSimpleQuantity {
+    pub fn new(name: impl Into<Label>) -> Self {
+        Self {
+            name: name.into(),
+            description: OptionalParameter::omitted(),
+            unit: OptionalParameter::omitted(),
+        }
+    }
+}
+
+pub trait PhysicalSimpleQuantityBuilder: Sized {
+    fn physical_simple_quantity_mut(&mut self) -> &mut PhysicalSimpleQuantity;
+
+    fn description(mut self, description: impl Into<Label>) -> Self {
+        self.physical_simple_quantity_mut().description = description.into().into();
+        self
+    }
+
+    fn unit(mut self, unit: impl Into<IdOr<SiUnit>>, ifc: &mut IFC) -> Self {
+        self.physical_simple_quantity_mut().unit = unit.into().or_insert(ifc).id().into();
+        self
+    }
+}
+
+impl IFCParse for PhysicalSimpleQuantity {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                name: Label::parse(),
+                _: Comma::parse(),
+                description: OptionalParameter::parse(),
+                _: Comma::parse(),
+                unit: OptionalParameter::parse(),
+            }
+        }
+    }
+}
+
+impl Display for PhysicalSimpleQuantity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.name, self.description, self.unit)
+    }
+}
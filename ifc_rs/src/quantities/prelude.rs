@@ -0,0 +1,7 @@
+pub use super::element_quantity::ElementQuantity;
+pub use super::physical_simple_quantity::{PhysicalSimpleQuantity, PhysicalSimpleQuantityBuilder};
+pub use super::quantity_area::QuantityArea;
+pub use super::quantity_count::QuantityCount;
+pub use super::quantity_length::QuantityLength;
+pub use super::quantity_volume::QuantityVolume;
+pub use super::quantity_weight::QuantityWeight;
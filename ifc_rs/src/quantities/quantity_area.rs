@@ -0,0 +1,114 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::physical_simple_quantity::{PhysicalSimpleQuantity, PhysicalSimpleQuantityBuilder};
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcQuantityArea is a physical quantity that defines a derived area
+/// measure to provide an element's physical property. It is normally
+/// derived from the physical properties of the element under the specific
+/// measure rules given by a method of measurement.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcquantityarea.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct QuantityArea {
+    #[inherited]
+    physical_simple_quantity: PhysicalSimpleQuantity,
+
+    /// Area measure value of this quantity.
+    pub area_value: IfcFloat,
+
+    /// A formula by which the quantity has been calculated.
+    pub formula: OptionalParameter<Label>,
+}
+
+impl QuantityArea {
+    pub fn new(name: impl Into<Label>, area_value: f64) -> Self {
+        Self {
+            physical_simple_quantity: PhysicalSimpleQuantity::new(name),
+            area_value: area_value.into(),
+            formula: OptionalParameter::omitted(),
+        }
+    }
+}
+
+impl PhysicalSimpleQuantityBuilder for QuantityArea {
+    fn physical_simple_quantity_mut(&mut self) -> &mut PhysicalSimpleQuantity {
+        &mut self.physical_simple_quantity
+    }
+}
+
+impl Deref for QuantityArea {
+    type Target = PhysicalSimpleQuantity;
+
+    fn deref(&self) -> &Self::Target {
+        &self.physical_simple_quantity
+    }
+}
+
+impl DerefMut for QuantityArea {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.physical_simple_quantity
+    }
+}
+
+impl IFCParse for QuantityArea {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCQUANTITYAREA("),
+
+                physical_simple_quantity: PhysicalSimpleQuantity::parse(),
+                _: Comma::parse(),
+                area_value: IfcFloat::parse(),
+                _: Comma::parse(),
+                formula: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for QuantityArea {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCQUANTITYAREA({},{},{});",
+            self.physical_simple_quantity, self.area_value, self.formula
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcQuantityArea")]
+impl IfcType for QuantityArea {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::QuantityArea;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn quantity_area_round_trip() {
+        let example = "IFCQUANTITYAREA('GrossSideArea',$,$,10.425,$);";
+
+        let parsed: QuantityArea = QuantityArea::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
@@ -0,0 +1,114 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::physical_simple_quantity::{PhysicalSimpleQuantity, PhysicalSimpleQuantityBuilder};
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcQuantityCount is a physical quantity that defines a derived count
+/// measure to provide an element's physical property. It is normally
+/// derived from the physical properties of the element under the specific
+/// measure rules given by a method of measurement.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcquantitycount.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct QuantityCount {
+    #[inherited]
+    physical_simple_quantity: PhysicalSimpleQuantity,
+
+    /// Count measure value of this quantity.
+    pub count_value: IfcFloat,
+
+    /// A formula by which the quantity has been calculated.
+    pub formula: OptionalParameter<Label>,
+}
+
+impl QuantityCount {
+    pub fn new(name: impl Into<Label>, count_value: f64) -> Self {
+        Self {
+            physical_simple_quantity: PhysicalSimpleQuantity::new(name),
+            count_value: count_value.into(),
+            formula: OptionalParameter::omitted(),
+        }
+    }
+}
+
+impl PhysicalSimpleQuantityBuilder for QuantityCount {
+    fn physical_simple_quantity_mut(&mut self) -> &mut PhysicalSimpleQuantity {
+        &mut self.physical_simple_quantity
+    }
+}
+
+impl Deref for QuantityCount {
+    type Target = PhysicalSimpleQuantity;
+
+    fn deref(&self) -> &Self::Target {
+        &self.physical_simple_quantity
+    }
+}
+
+impl DerefMut for QuantityCount {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.physical_simple_quantity
+    }
+}
+
+impl IFCParse for QuantityCount {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCQUANTITYCOUNT("),
+
+                physical_simple_quantity: PhysicalSimpleQuantity::parse(),
+                _: Comma::parse(),
+                count_value: IfcFloat::parse(),
+                _: Comma::parse(),
+                formula: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for QuantityCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCQUANTITYCOUNT({},{},{});",
+            self.physical_simple_quantity, self.count_value, self.formula
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcQuantityCount")]
+impl IfcType for QuantityCount {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::QuantityCount;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn quantity_count_round_trip() {
+        let example = "IFCQUANTITYCOUNT('Anzahl der Fenster',$,$,0.,$);";
+
+        let parsed: QuantityCount = QuantityCount::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
@@ -0,0 +1,114 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::physical_simple_quantity::{PhysicalSimpleQuantity, PhysicalSimpleQuantityBuilder};
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcQuantityLength is a physical quantity that defines a derived length
+/// measure to provide an element's physical property. It is normally
+/// derived from the physical properties of the element under the specific
+/// measure rules given by a method of measurement.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcquantitylength.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct QuantityLength {
+    #[inherited]
+    physical_simple_quantity: PhysicalSimpleQuantity,
+
+    /// Length measure value of this quantity.
+    pub length_value: IfcFloat,
+
+    /// A formula by which the quantity has been calculated.
+    pub formula: OptionalParameter<Label>,
+}
+
+impl QuantityLength {
+    pub fn new(name: impl Into<Label>, length_value: f64) -> Self {
+        Self {
+            physical_simple_quantity: PhysicalSimpleQuantity::new(name),
+            length_value: length_value.into(),
+            formula: OptionalParameter::omitted(),
+        }
+    }
+}
+
+impl PhysicalSimpleQuantityBuilder for QuantityLength {
+    fn physical_simple_quantity_mut(&mut self) -> &mut PhysicalSimpleQuantity {
+        &mut self.physical_simple_quantity
+    }
+}
+
+impl Deref for QuantityLength {
+    type Target = PhysicalSimpleQuantity;
+
+    fn deref(&self) -> &Self::Target {
+        &self.physical_simple_quantity
+    }
+}
+
+impl DerefMut for QuantityLength {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.physical_simple_quantity
+    }
+}
+
+impl IFCParse for QuantityLength {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCQUANTITYLENGTH("),
+
+                physical_simple_quantity: PhysicalSimpleQuantity::parse(),
+                _: Comma::parse(),
+                length_value: IfcFloat::parse(),
+                _: Comma::parse(),
+                formula: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for QuantityLength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCQUANTITYLENGTH({},{},{});",
+            self.physical_simple_quantity, self.length_value, self.formula
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcQuantityLength")]
+impl IfcType for QuantityLength {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::QuantityLength;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn quantity_length_round_trip() {
+        let example = "IFCQUANTITYLENGTH('Height',$,$,2.5,$);";
+
+        let parsed: QuantityLength = QuantityLength::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
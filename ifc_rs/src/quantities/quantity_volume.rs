@@ -0,0 +1,114 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::physical_simple_quantity::{PhysicalSimpleQuantity, PhysicalSimpleQuantityBuilder};
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcQuantityVolume is a physical quantity that defines a derived volume
+/// measure to provide an element's physical property. It is normally
+/// derived from the physical properties of the element under the specific
+/// measure rules given by a method of measurement.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcquantityvolume.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct QuantityVolume {
+    #[inherited]
+    physical_simple_quantity: PhysicalSimpleQuantity,
+
+    /// Volume measure value of this quantity.
+    pub volume_value: IfcFloat,
+
+    /// A formula by which the quantity has been calculated.
+    pub formula: OptionalParameter<Label>,
+}
+
+impl QuantityVolume {
+    pub fn new(name: impl Into<Label>, volume_value: f64) -> Self {
+        Self {
+            physical_simple_quantity: PhysicalSimpleQuantity::new(name),
+            volume_value: volume_value.into(),
+            formula: OptionalParameter::omitted(),
+        }
+    }
+}
+
+impl PhysicalSimpleQuantityBuilder for QuantityVolume {
+    fn physical_simple_quantity_mut(&mut self) -> &mut PhysicalSimpleQuantity {
+        &mut self.physical_simple_quantity
+    }
+}
+
+impl Deref for QuantityVolume {
+    type Target = PhysicalSimpleQuantity;
+
+    fn deref(&self) -> &Self::Target {
+        &self.physical_simple_quantity
+    }
+}
+
+impl DerefMut for QuantityVolume {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.physical_simple_quantity
+    }
+}
+
+impl IFCParse for QuantityVolume {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCQUANTITYVOLUME("),
+
+                physical_simple_quantity: PhysicalSimpleQuantity::parse(),
+                _: Comma::parse(),
+                volume_value: IfcFloat::parse(),
+                _: Comma::parse(),
+                formula: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for QuantityVolume {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCQUANTITYVOLUME({},{},{});",
+            self.physical_simple_quantity, self.volume_value, self.formula
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcQuantityVolume")]
+impl IfcType for QuantityVolume {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::QuantityVolume;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn quantity_volume_round_trip() {
+        let example = "IFCQUANTITYVOLUME('GrossVolume',$,$,2.25,$);";
+
+        let parsed: QuantityVolume = QuantityVolume::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
@@ -0,0 +1,114 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use super::physical_simple_quantity::{PhysicalSimpleQuantity, PhysicalSimpleQuantityBuilder};
+use crate::{
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_float::IfcFloat, label::Label, optional::OptionalParameter,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcQuantityWeight is a physical element quantity that defines a derived
+/// weight measure to provide an element's physical property. It is
+/// normally derived from the physical properties of the element under the
+/// specific measure rules given by a method of measurement.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcquantityweight.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct QuantityWeight {
+    #[inherited]
+    physical_simple_quantity: PhysicalSimpleQuantity,
+
+    /// Mass measure value of this quantity.
+    pub weight_value: IfcFloat,
+
+    /// A formula by which the quantity has been calculated.
+    pub formula: OptionalParameter<Label>,
+}
+
+impl QuantityWeight {
+    pub fn new(name: impl Into<Label>, weight_value: f64) -> Self {
+        Self {
+            physical_simple_quantity: PhysicalSimpleQuantity::new(name),
+            weight_value: weight_value.into(),
+            formula: OptionalParameter::omitted(),
+        }
+    }
+}
+
+impl PhysicalSimpleQuantityBuilder for QuantityWeight {
+    fn physical_simple_quantity_mut(&mut self) -> &mut PhysicalSimpleQuantity {
+        &mut self.physical_simple_quantity
+    }
+}
+
+impl Deref for QuantityWeight {
+    type Target = PhysicalSimpleQuantity;
+
+    fn deref(&self) -> &Self::Target {
+        &self.physical_simple_quantity
+    }
+}
+
+impl DerefMut for QuantityWeight {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.physical_simple_quantity
+    }
+}
+
+impl IFCParse for QuantityWeight {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCQUANTITYWEIGHT("),
+
+                physical_simple_quantity: PhysicalSimpleQuantity::parse(),
+                _: Comma::parse(),
+                weight_value: IfcFloat::parse(),
+                _: Comma::parse(),
+                formula: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for QuantityWeight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCQUANTITYWEIGHT({},{},{});",
+            self.physical_simple_quantity, self.weight_value, self.formula
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcQuantityWeight")]
+impl IfcType for QuantityWeight {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::QuantityWeight;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn quantity_weight_round_trip() {
+        let example = "IFCQUANTITYWEIGHT('GrossWeight',$,$,1250.5,$);";
+
+        let parsed: QuantityWeight = QuantityWeight::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
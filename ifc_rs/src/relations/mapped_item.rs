@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{IdOr, TypedId},
@@ -39,7 +40,7 @@ pub trait TransformableType: IfcType {
 ///   A mapped item shall not be self-defining by participating in the definition of the
 ///   representation being mapped. The dimensionality of the mapping source and the mapping
 ///   target has to be the same, if the mapping source is a geometric representation item.
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct MappedItem {
     /// A representation map that is the source of the mapped item. It can be seen as a block (or
     /// cell or marco) definition.
@@ -65,6 +66,7 @@ impl MappedItem {
     }
 }
 
+#[typetag::serde(name = "IfcMappedItem")]
 impl IfcType for MappedItem {}
 impl ShapeItem for MappedItem {}
 
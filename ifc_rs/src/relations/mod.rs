@@ -8,11 +8,20 @@ use crate::{
 pub mod mapped_item;
 pub mod prelude;
 pub mod rel_aggregates;
+pub mod rel_assigns_to_group;
+pub mod rel_associates_classification;
 pub mod rel_associates_material;
+pub mod rel_connects_elements;
+pub mod rel_connects_ports;
 pub mod rel_contained_in_spatial_structure;
+pub mod rel_covers_bldg_elements;
+pub mod rel_covers_spaces;
 pub mod rel_declares;
+pub mod rel_defines_by_properties;
 pub mod rel_defines_by_type;
 pub mod rel_fills_element;
+pub mod rel_nests;
+pub mod rel_space_boundary;
 pub mod rel_voids_element;
 pub mod representation_map;
 
@@ -22,14 +31,26 @@ impl Relation {
     pub fn parse<'a>() -> impl IFCParser<'a, Box<dyn IfcType>> {
         alt((
             rel_aggregates::RelAggregates::parse_any(),
+            rel_assigns_to_group::RelAssignsToGroup::parse_any(),
+            rel_associates_classification::RelAssociatesClassification::parse_any(),
             rel_associates_material::RelAssociatesMaterial::parse_any(),
             rel_contained_in_spatial_structure::RelContainedInSpatialStructure::parse_any(),
             rel_declares::RelDeclares::parse_any(),
             rel_defines_by_type::RelDefinesByType::parse_any(),
+            rel_defines_by_properties::RelDefinesByProperties::parse_any(),
             rel_voids_element::RelVoidsElement::parse_any(),
             rel_fills_element::RelFillsElement::parse_any(),
             mapped_item::MappedItem::parse_any(),
             representation_map::RepresentationMap::parse_any(),
+            rel_space_boundary::RelSpaceBoundary::parse_any(),
+            rel_space_boundary::RelSpaceBoundary1stLevel::parse_any(),
+            rel_space_boundary::RelSpaceBoundary2ndLevel::parse_any(),
+            rel_connects_elements::RelConnectsElements::parse_any(),
+            rel_connects_elements::RelConnectsPathElements::parse_any(),
+            rel_nests::RelNests::parse_any(),
+            rel_connects_ports::RelConnectsPorts::parse_any(),
+            rel_covers_bldg_elements::RelCoversBldgElements::parse_any(),
+            rel_covers_spaces::RelCoversSpaces::parse_any(),
         ))
     }
 }
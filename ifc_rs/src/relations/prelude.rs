@@ -1,9 +1,24 @@
 pub use super::mapped_item::{MappedItem, TransformableType};
 pub use super::rel_aggregates::RelAggregates;
+pub use super::rel_assigns_to_group::{ObjectTypeEnum, RelAssignsToGroup};
+pub use super::rel_associates_classification::RelAssociatesClassification;
 pub use super::rel_associates_material::RelAssociatesMaterial;
+pub use super::rel_connects_elements::{
+    as_connects_elements, ConnectionTypeEnum, ConnectsElements, ConnectsElementsBuilder,
+    RelConnectsElements, RelConnectsPathElements,
+};
+pub use super::rel_connects_ports::RelConnectsPorts;
 pub use super::rel_contained_in_spatial_structure::RelContainedInSpatialStructure;
+pub use super::rel_covers_bldg_elements::RelCoversBldgElements;
+pub use super::rel_covers_spaces::RelCoversSpaces;
 pub use super::rel_declares::RelDeclares;
+pub use super::rel_defines_by_properties::RelDefinesByProperties;
 pub use super::rel_defines_by_type::RelDefinesByType;
 pub use super::rel_fills_element::RelFillsElement;
+pub use super::rel_nests::RelNests;
+pub use super::rel_space_boundary::{
+    as_space_boundary, InternalOrExternalEnum, PhysicalOrVirtualEnum, RelSpaceBoundary,
+    RelSpaceBoundary1stLevel, RelSpaceBoundary2ndLevel, SpaceBoundary, SpaceBoundaryBuilder,
+};
 pub use super::rel_voids_element::RelVoidsElement;
 pub use super::representation_map::RepresentationMap;
@@ -1,6 +1,7 @@
 use std::{fmt::Display, ops::Deref};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::Id,
@@ -18,21 +19,33 @@ use crate::{
 /// all subtypes of IfcObjectDefinition.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelaggregates.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct RelAggregates {
+    #[inherited]
     root: Root,
 
     /// The object definition, either an object type or an object
     /// occurrence, that represents the aggregation. It is the whole
     /// within the whole/part relationship.
-    #[ifc_types(Project, Site, Building, Storey)]
+    #[ifc_types(Project, Site, Building, Storey, Stair, Ramp, CurtainWall)]
     pub relating_object: Id,
 
     /// The object definitions, either object occurrences or object
     /// types, that are being aggregated. They are defined as the
     /// parts in the whole/part relationship. No order is implied
     /// between the parts.
-    #[ifc_types(Site, Building, Storey, Space)]
+    #[ifc_types(
+        Site,
+        Building,
+        Storey,
+        Space,
+        StairFlight,
+        RampFlight,
+        Slab,
+        Railing,
+        Member,
+        Plate
+    )]
     pub related_objects: IfcList<Id>,
 }
 
@@ -88,6 +101,7 @@ impl Display for RelAggregates {
     }
 }
 
+#[typetag::serde(name = "IfcRelAggregates")]
 impl IfcType for RelAggregates {}
 
 #[cfg(test)]
@@ -0,0 +1,202 @@
+use std::{fmt::Display, ops::Deref, str::FromStr};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, optional::OptionalParameter, p_space_or_comment,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// Groups, systems & zones which objects can be assigned to.
+pub trait RelatableGroup: IfcType {}
+
+/// Objects which can be assigned to groups
+pub trait GroupRelatable: IfcType {}
+
+/// This enumeration defines the applicable object categories (i.e. the
+/// subtypes at the 2nd level of the IFC inheritance tree).
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcobjecttypeenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum ObjectTypeEnum {
+    #[strum(to_string = ".PRODUCT.")]
+    Product,
+
+    #[strum(to_string = ".PROCESS.")]
+    Process,
+
+    #[strum(to_string = ".CONTROL.")]
+    Control,
+
+    #[strum(to_string = ".RESOURCE.")]
+    Resource,
+
+    #[strum(to_string = ".ACTOR.")]
+    Actor,
+
+    #[strum(to_string = ".GROUP.")]
+    Group,
+
+    #[strum(to_string = ".PROJECT.")]
+    Project,
+
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for ObjectTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid ObjectTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+/// This objectified relationship IfcRelAssignsToGroup handles the
+/// assignment of object definitions (individual object occurrences as
+/// well as object types) to a group (e.g. spaces to a zone or elements
+/// to a system).
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelassignstogroup.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RelAssignsToGroup {
+    #[inherited]
+    root: Root,
+
+    /// Related objects, which are assigned to a single object. The
+    /// type of the single (or relating) object is defined in the
+    /// subtypes of IfcRelAssigns.
+    pub related_objects: IfcList<Id>,
+
+    /// Particular type of the assignment relationship. It can constrain
+    /// the applicable object types, used within the role of
+    /// RelatedObjects.
+    pub related_objects_type: OptionalParameter<ObjectTypeEnum>,
+
+    /// Reference to group that finally contains all assigned group members.
+    #[ifc_types(Group, System, Zone, BuildingSystem, DistributionSystem)]
+    pub relating_group: Id,
+}
+
+impl RelAssignsToGroup {
+    pub fn new<G: RelatableGroup>(
+        name: impl Into<Label>,
+        group: impl Into<IdOr<G>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            root: Root::new(name.into()),
+            related_objects: IfcList::empty(),
+            related_objects_type: OptionalParameter::omitted(),
+            relating_group: group.into().or_insert(ifc).id(),
+        }
+    }
+
+    pub fn related_objects_type(mut self, related_objects_type: ObjectTypeEnum) -> Self {
+        self.related_objects_type = related_objects_type.into();
+        self
+    }
+
+    pub fn relate_push<T: GroupRelatable>(&mut self, object: impl Into<IdOr<T>>, ifc: &mut IFC) {
+        self.related_objects
+            .0
+            .push(object.into().or_insert(ifc).id());
+    }
+
+    pub fn relate_obj<T: GroupRelatable>(
+        mut self,
+        object: impl Into<IdOr<T>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.relate_push(object, ifc);
+        self
+    }
+
+    pub fn is_related_to(&self, id: impl Into<Id>) -> bool {
+        self.related_objects.0.contains(&id.into())
+    }
+}
+
+impl RootBuilder for RelAssignsToGroup {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.root
+    }
+}
+
+impl Deref for RelAssignsToGroup {
+    type Target = Root;
+
+    fn deref(&self) -> &Self::Target {
+        &self.root
+    }
+}
+
+impl IFCParse for RelAssignsToGroup {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRELASSIGNSTOGROUP("),
+
+                root: Root::parse(),
+                _: Comma::parse(),
+                related_objects: IfcList::parse(),
+                _: Comma::parse(),
+                related_objects_type: OptionalParameter::parse(),
+                _: Comma::parse(),
+                relating_group: Id::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RelAssignsToGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCRELASSIGNSTOGROUP({},{},{},{});",
+            self.root, self.related_objects, self.related_objects_type, self.relating_group
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRelAssignsToGroup")]
+impl IfcType for RelAssignsToGroup {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::RelAssignsToGroup;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn rel_assigns_to_group_round_trip() {
+        let examples = [
+            "IFCRELASSIGNSTOGROUP('1v2ZBtSNP4TgdJmlvuSLWB',#2,$,$,(#51,#64),$,#70);",
+            "IFCRELASSIGNSTOGROUP('0ZuRT_4Xz0Kh8p8xV$3hQ1',#2,'Zone',$,(#51),.PRODUCT.,#70);",
+        ];
+
+        for example in examples {
+            let parsed: RelAssignsToGroup = RelAssignsToGroup::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{comma::Comma, label::Label, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    prelude::*,
+};
+
+/// Classifications & classification references which can be related to.
+pub trait RelatableClassification: IfcType {}
+
+/// Objects which can be related to classifications
+pub trait ClassificationRelatable: IfcType {}
+
+/// The objectified relationship IfcRelAssociatesClassification handles the
+/// assignment of a classification item (items of the select
+/// IfcClassificationSelect) to objects occurrences (subtypes of IfcObject)
+/// or object types (subtypes of IfcTypeObject).
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelassociatesclassification.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RelAssociatesClassification {
+    #[inherited]
+    rel_associates: RelAssociates,
+
+    /// Classification applied to the objects.
+    #[ifc_types(Classification, ClassificationReference)]
+    pub relating_classification: Id,
+}
+
+impl RelAssociatesClassification {
+    pub fn new<R: RelatableClassification>(
+        name: impl Into<Label>,
+        classification: impl Into<IdOr<R>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            rel_associates: RelAssociates::new(Root::new(name.into())),
+            relating_classification: classification.into().or_insert(ifc).id(),
+        }
+    }
+
+    pub fn is_related_to(&self, id: impl Into<Id>) -> bool {
+        self.rel_associates.related_objects.0.contains(&id.into())
+    }
+}
+
+impl<T: ClassificationRelatable> RelAssociatesBuilder<T> for RelAssociatesClassification {
+    fn rel_associates_mut(&mut self) -> &mut RelAssociates {
+        &mut self.rel_associates
+    }
+}
+
+impl RootBuilder for RelAssociatesClassification {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.rel_associates
+    }
+}
+
+impl Deref for RelAssociatesClassification {
+    type Target = RelAssociates;
+
+    fn deref(&self) -> &Self::Target {
+        &self.rel_associates
+    }
+}
+
+impl IFCParse for RelAssociatesClassification {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRELASSOCIATESCLASSIFICATION("),
+
+                rel_associates: RelAssociates::parse(),
+                _: Comma::parse(),
+                relating_classification: Id::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RelAssociatesClassification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCRELASSOCIATESCLASSIFICATION({},{});",
+            self.rel_associates, self.relating_classification
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRelAssociatesClassification")]
+impl IfcType for RelAssociatesClassification {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::RelAssociatesClassification;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn rel_associates_classification_round_trip() {
+        let example = "IFCRELASSOCIATESCLASSIFICATION('3b9H1tB0QEK36vByFDaPVw',#12,'AC Zone Category',$,(#20909,#21283,#21640),#21169);";
+
+        let parsed: RelAssociatesClassification =
+            RelAssociatesClassification::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -1,6 +1,7 @@
 use std::{fmt::Display, ops::Deref};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{Id, IdOr},
@@ -21,8 +22,9 @@ pub trait MaterialRelatable: IfcType {}
 /// all subtypes of IfcObjectDefinition.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelassociatesmaterial.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct RelAssociatesMaterial {
+    #[inherited]
     rel_associates: RelAssociates,
 
     /// Material definition assigned to the elements or element types.
@@ -30,6 +32,8 @@ pub struct RelAssociatesMaterial {
         MaterialConstituentSet,
         MaterialLayerSetUsage,
         MaterialLayerSet,
+        MaterialProfileSetUsage,
+        MaterialProfileSet,
         Material
     )]
     pub relating_material: Id,
@@ -98,6 +102,7 @@ impl Display for RelAssociatesMaterial {
     }
 }
 
+#[typetag::serde(name = "IfcRelAssociatesMaterial")]
 impl IfcType for RelAssociatesMaterial {}
 
 #[cfg(test)]
@@ -0,0 +1,413 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, ifc_integer::IfcInteger, label::Label, list::IfcList,
+        optional::OptionalParameter, p_space_or_comment, p_space_or_comment_surrounded, IFCParse,
+        IFCParser,
+    },
+    prelude::*,
+};
+
+/// Elements which can be connected to other elements
+pub trait ConnectionRelatable: IfcType {}
+
+/// The IfcConnectionTypeEnum defines the different ways how path based
+/// elements (e.g. walls) can connect.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcconnectiontypeenum.htm
+#[derive(
+    EnumString, VariantNames, Display, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum ConnectionTypeEnum {
+    /// The connection occurs along the path of the element, e.g. the end of
+    /// another wall meets the middle of this wall.
+    #[strum(to_string = ".ATPATH.")]
+    AtPath,
+
+    /// The connection occurs at the start of the element.
+    #[strum(to_string = ".ATSTART.")]
+    AtStart,
+
+    /// The connection occurs at the end of the element.
+    #[strum(to_string = ".ATEND.")]
+    AtEnd,
+
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for ConnectionTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid ConnectionTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+/// The attributes shared by IfcRelConnectsElements and its subtype
+/// IfcRelConnectsPathElements.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelconnectselements.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct ConnectsElements {
+    #[inherited]
+    root: Root,
+
+    /// The geometric shape representation of the connection geometry that
+    /// is provided in the object coordinate system of the RelatingElement.
+    #[ifc_types(ConnectionSurfaceGeometry)]
+    pub connection_geometry: OptionalParameter<Id>,
+
+    /// Reference to a subtype of IfcElement that is connected by the
+    /// connection relationship in the role of RelatingElement.
+    #[ifc_types(Wall, Slab, Beam, Column, Member, Roof, Window, Door)]
+    pub relating_element: Id,
+
+    /// Reference to a subtype of IfcElement that is connected by the
+    /// connection relationship in the role of RelatedElement.
+    #[ifc_types(Wall, Slab, Beam, Column, Member, Roof, Window, Door)]
+    pub related_element: Id,
+}
+
+impl ConnectsElements {
+    pub fn new<A: ConnectionRelatable, B: ConnectionRelatable>(
+        root: Root,
+        relating_element: impl Into<IdOr<A>>,
+        related_element: impl Into<IdOr<B>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            root,
+            connection_geometry: OptionalParameter::omitted(),
+            relating_element: relating_element.into().or_insert(ifc).id(),
+            related_element: related_element.into().or_insert(ifc).id(),
+        }
+    }
+
+    /// The element on the other side of the connection, `None` if `element`
+    /// isn't part of the connection.
+    pub fn other(&self, element: impl Into<Id>) -> Option<Id> {
+        let element = element.into();
+
+        if self.relating_element == element {
+            Some(self.related_element)
+        } else if self.related_element == element {
+            Some(self.relating_element)
+        } else {
+            None
+        }
+    }
+}
+
+pub trait ConnectsElementsBuilder: Sized {
+    fn connects_elements_mut(&mut self) -> &mut ConnectsElements;
+
+    fn connection_geometry(
+        mut self,
+        connection_geometry: impl Into<IdOr<ConnectionSurfaceGeometry>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.connects_elements_mut().connection_geometry =
+            connection_geometry.into().or_insert(ifc).id().into();
+        self
+    }
+}
+
+impl Deref for ConnectsElements {
+    type Target = Root;
+
+    fn deref(&self) -> &Self::Target {
+        &self.root
+    }
+}
+
+impl DerefMut for ConnectsElements {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.root
+    }
+}
+
+impl IFCParse for ConnectsElements {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                root: Root::parse(),
+                _: Comma::parse(),
+                connection_geometry: OptionalParameter::parse(),
+                _: Comma::parse(),
+                relating_element: Id::parse(),
+                _: Comma::parse(),
+                related_element: Id::parse(),
+            }
+        }
+    }
+}
+
+impl Display for ConnectsElements {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.root, self.connection_geometry, self.relating_element, self.related_element
+        )
+    }
+}
+
+/// The IfcRelConnectsElements objectified relationship provides the
+/// generalization of the connectivity between elements. It is a 1 to 1
+/// relationship.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelconnectselements.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RelConnectsElements {
+    #[inherited]
+    connects_elements: ConnectsElements,
+}
+
+impl RelConnectsElements {
+    pub fn new<A: ConnectionRelatable, B: ConnectionRelatable>(
+        name: impl Into<Label>,
+        relating_element: impl Into<IdOr<A>>,
+        related_element: impl Into<IdOr<B>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            connects_elements: ConnectsElements::new(
+                Root::new(name.into()),
+                relating_element,
+                related_element,
+                ifc,
+            ),
+        }
+    }
+}
+
+impl ConnectsElementsBuilder for RelConnectsElements {
+    fn connects_elements_mut(&mut self) -> &mut ConnectsElements {
+        &mut self.connects_elements
+    }
+}
+
+impl RootBuilder for RelConnectsElements {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.connects_elements
+    }
+}
+
+impl Deref for RelConnectsElements {
+    type Target = ConnectsElements;
+
+    fn deref(&self) -> &Self::Target {
+        &self.connects_elements
+    }
+}
+
+impl IFCParse for RelConnectsElements {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRELCONNECTSELEMENTS("),
+
+                connects_elements: ConnectsElements::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RelConnectsElements {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCRELCONNECTSELEMENTS({});", self.connects_elements)
+    }
+}
+
+#[typetag::serde(name = "IfcRelConnectsElements")]
+impl IfcType for RelConnectsElements {}
+
+/// The IfcRelConnectsPathElements relationship provides the connectivity
+/// information between two elements, which have path information, e.g.
+/// walls joining at a corner (AtStart/AtEnd) or a wall ending at the side
+/// of another wall (AtPath).
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelconnectspathelements.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RelConnectsPathElements {
+    #[inherited]
+    connects_elements: ConnectsElements,
+
+    /// Priorities for connection. It refers to the layers of the
+    /// RelatingObject.
+    pub relating_priorities: IfcList<IfcInteger>,
+
+    /// Priorities for connection. It refers to the layers of the
+    /// RelatedObject.
+    pub related_priorities: IfcList<IfcInteger>,
+
+    /// Indication of the connection type in relation to the path of the
+    /// RelatedObject.
+    pub related_connection_type: ConnectionTypeEnum,
+
+    /// Indication of the connection type in relation to the path of the
+    /// RelatingObject.
+    pub relating_connection_type: ConnectionTypeEnum,
+}
+
+impl RelConnectsPathElements {
+    pub fn new<A: ConnectionRelatable, B: ConnectionRelatable>(
+        name: impl Into<Label>,
+        relating_element: impl Into<IdOr<A>>,
+        relating_connection_type: ConnectionTypeEnum,
+        related_element: impl Into<IdOr<B>>,
+        related_connection_type: ConnectionTypeEnum,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            connects_elements: ConnectsElements::new(
+                Root::new(name.into()),
+                relating_element,
+                related_element,
+                ifc,
+            ),
+            relating_priorities: IfcList::empty(),
+            related_priorities: IfcList::empty(),
+            related_connection_type,
+            relating_connection_type,
+        }
+    }
+
+    /// Connection type at the given element, `None` if `element` isn't part
+    /// of the connection.
+    pub fn connection_type(&self, element: impl Into<Id>) -> Option<ConnectionTypeEnum> {
+        let element = element.into();
+
+        if self.relating_element == element {
+            Some(self.relating_connection_type)
+        } else if self.related_element == element {
+            Some(self.related_connection_type)
+        } else {
+            None
+        }
+    }
+}
+
+impl ConnectsElementsBuilder for RelConnectsPathElements {
+    fn connects_elements_mut(&mut self) -> &mut ConnectsElements {
+        &mut self.connects_elements
+    }
+}
+
+impl RootBuilder for RelConnectsPathElements {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.connects_elements
+    }
+}
+
+impl Deref for RelConnectsPathElements {
+    type Target = ConnectsElements;
+
+    fn deref(&self) -> &Self::Target {
+        &self.connects_elements
+    }
+}
+
+impl IFCParse for RelConnectsPathElements {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRELCONNECTSPATHELEMENTS("),
+
+                connects_elements: ConnectsElements::parse(),
+                _: Comma::parse(),
+                relating_priorities: IfcList::parse(),
+                _: Comma::parse(),
+                related_priorities: IfcList::parse(),
+                _: Comma::parse(),
+                related_connection_type: ConnectionTypeEnum::parse(),
+                _: Comma::parse(),
+                relating_connection_type: ConnectionTypeEnum::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RelConnectsPathElements {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCRELCONNECTSPATHELEMENTS({},{},{},{},{});",
+            self.connects_elements,
+            self.relating_priorities,
+            self.related_priorities,
+            self.related_connection_type,
+            self.relating_connection_type
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRelConnectsPathElements")]
+impl IfcType for RelConnectsPathElements {}
+
+/// The shared attributes of both element connection relations, `None` if
+/// the entity isn't an element connection.
+pub fn as_connects_elements(ifc_type: &dyn IfcType) -> Option<&ConnectsElements> {
+    if let Some(connection) = ifc_type.downcast_ref::<RelConnectsElements>() {
+        Some(connection)
+    } else {
+        ifc_type
+            .downcast_ref::<RelConnectsPathElements>()
+            .map(|connection| &**connection)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::{RelConnectsElements, RelConnectsPathElements};
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn rel_connects_elements_round_trip() {
+        let example = "IFCRELCONNECTSELEMENTS('2TqGbP3fX4MPM5vQvVdMh8',#2,$,$,#76,#64,#80);";
+
+        let parsed: RelConnectsElements = RelConnectsElements::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn rel_connects_path_elements_round_trip() {
+        let examples = [
+            "IFCRELCONNECTSPATHELEMENTS('1vGdhxc6IUK2fPFUdKXu4r',#12,$,$,$,#17040,#15042,(),(),.ATSTART.,.ATEND.);",
+            "IFCRELCONNECTSPATHELEMENTS('0G9_XzEilqUeqefHf07Hsx',#12,$,$,$,#17040,#18698,(1,2),(3),.ATEND.,.ATPATH.);",
+        ];
+
+        for example in examples {
+            let parsed: RelConnectsPathElements =
+                RelConnectsPathElements::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string());
+        }
+    }
+}
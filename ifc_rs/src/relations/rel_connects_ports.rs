@@ -0,0 +1,139 @@
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment_surrounded,
+        IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// IfcRelConnectsPorts defines the relationship that is made between two
+/// ports at their point of connection. It may include the connection
+/// geometry between two ports and an element realizing the connection, e.g.
+/// a gasket between two flanges.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelconnectsports.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RelConnectsPorts {
+    #[inherited]
+    root: Root,
+
+    /// Reference to the first port that is connected by the connection
+    /// relationship.
+    pub relating_port: TypedId<DistributionPort>,
+
+    /// Reference to the second port that is connected by the connection
+    /// relationship.
+    pub related_port: TypedId<DistributionPort>,
+
+    /// Defines the element that realizes a port connection relationship.
+    #[ifc_types(FlowSegment, PipeSegment, DuctSegment, FlowFitting, FlowTerminal)]
+    pub realizing_element: OptionalParameter<Id>,
+}
+
+impl RelConnectsPorts {
+    pub fn new(
+        name: impl Into<Label>,
+        relating_port: impl Into<IdOr<DistributionPort>>,
+        related_port: impl Into<IdOr<DistributionPort>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            root: Root::new(name.into()),
+            relating_port: relating_port.into().or_insert(ifc),
+            related_port: related_port.into().or_insert(ifc),
+            realizing_element: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn realizing_element(mut self, element: impl Into<Id>) -> Self {
+        self.realizing_element = element.into().into();
+        self
+    }
+
+    /// The port connected to the given port, `None` if the given port isn't
+    /// part of the connection.
+    pub fn other(&self, port: TypedId<DistributionPort>) -> Option<TypedId<DistributionPort>> {
+        if self.relating_port == port {
+            Some(self.related_port)
+        } else if self.related_port == port {
+            Some(self.relating_port)
+        } else {
+            None
+        }
+    }
+}
+
+impl RootBuilder for RelConnectsPorts {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.root
+    }
+}
+
+impl Deref for RelConnectsPorts {
+    type Target = Root;
+
+    fn deref(&self) -> &Self::Target {
+        &self.root
+    }
+}
+
+impl IFCParse for RelConnectsPorts {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRELCONNECTSPORTS("),
+
+                root: Root::parse(),
+                _: Comma::parse(),
+                relating_port: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                related_port: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                realizing_element: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RelConnectsPorts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCRELCONNECTSPORTS({},{},{},{});",
+            self.root, self.relating_port, self.related_port, self.realizing_element
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRelConnectsPorts")]
+impl IfcType for RelConnectsPorts {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::RelConnectsPorts;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn rel_connects_ports_round_trip() {
+        let examples = [
+            "IFCRELCONNECTSPORTS('1pQ4aV9sL0cX3mNb2Fh7Jd',#2,'PortToPort',$,#142,#168,$);",
+            "IFCRELCONNECTSPORTS('0cX3mNb2Fh7Jd1pQ4aV9sL',#2,$,$,#142,#168,#170);",
+        ];
+
+        for (index, example) in examples.into_iter().enumerate() {
+            let parsed: RelConnectsPorts = RelConnectsPorts::parse().parse(example).unwrap();
+            assert_eq!(example, parsed.to_string(), "example {} failed", index);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::{fmt::Display, ops::Deref};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::id::{Id, IdOr};
 use crate::ifc_type::{IfcType, IfcVerify};
@@ -17,19 +18,76 @@ use crate::prelude::*;
 /// of a particular project and might vary within the various regions.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelcontainedinspatialstructure.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct RelContainedInSpatialStructure {
+    #[inherited]
     root: Root,
 
     /// Set of products, which are contained within this level of the
     /// spatial structure hierarchy.
-    #[ifc_types(Site, Building, Storey, OpeningElement, Slab, Roof, Wall, Window)]
+    #[ifc_types(
+        Site,
+        Building,
+        Storey,
+        OpeningElement,
+        Slab,
+        Roof,
+        Wall,
+        Window,
+        Door,
+        Beam,
+        Column,
+        Member,
+        Grid,
+        FlowSegment,
+        PipeSegment,
+        DuctSegment,
+        FlowFitting,
+        FlowTerminal,
+        Stair,
+        Ramp,
+        Railing,
+        Covering,
+        CurtainWall,
+        Plate,
+        BuildingElementProxy,
+        FurnishingElement,
+        Furniture
+    )]
     pub related_elements: IfcList<Id>,
 
     /// Spatial structure element, within which the element is
     /// contained. Any element can only be contained within one
     /// element of the project spatial structure.
-    #[ifc_types(Site, Building, Storey, OpeningElement, Slab, Roof, Wall, Window)]
+    #[ifc_types(
+        Site,
+        Building,
+        Storey,
+        OpeningElement,
+        Slab,
+        Roof,
+        Wall,
+        Window,
+        Door,
+        Beam,
+        Column,
+        Member,
+        Grid,
+        FlowSegment,
+        PipeSegment,
+        DuctSegment,
+        FlowFitting,
+        FlowTerminal,
+        Stair,
+        Ramp,
+        Railing,
+        Covering,
+        CurtainWall,
+        Plate,
+        BuildingElementProxy,
+        FurnishingElement,
+        Furniture
+    )]
     pub relating_structure: Id,
 }
 
@@ -101,6 +159,7 @@ impl Display for RelContainedInSpatialStructure {
     }
 }
 
+#[typetag::serde(name = "IfcRelContainedInSpatialStructure")]
 impl IfcType for RelContainedInSpatialStructure {}
 
 #[cfg(test)]
@@ -0,0 +1,116 @@
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, p_space_or_comment_surrounded, IFCParse,
+        IFCParser,
+    },
+    prelude::*,
+};
+
+/// The objectified relationship IfcRelCoversBldgElements relates a building
+/// element to one or more coverings, that cover this element, e.g. the
+/// cladding of a wall or the flooring on top of a slab.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelcoversbldgelements.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RelCoversBldgElements {
+    #[inherited]
+    root: Root,
+
+    /// Relationship to the element that is covered.
+    #[ifc_types(Wall, Slab, Roof, Beam, Column, Member, CurtainWall, Plate)]
+    pub relating_building_element: Id,
+
+    /// Set of coverings covering this element.
+    pub related_coverings: IfcList<TypedId<Covering>>,
+}
+
+impl RelCoversBldgElements {
+    pub fn new(
+        name: impl Into<Label>,
+        relating_building_element: impl Into<Id>,
+        coverings: impl IntoIterator<Item = TypedId<Covering>>,
+    ) -> Self {
+        Self {
+            root: Root::new(name.into()),
+            relating_building_element: relating_building_element.into(),
+            related_coverings: IfcList(coverings.into_iter().collect()),
+        }
+    }
+
+    pub fn relate_covering(mut self, covering: TypedId<Covering>) -> Self {
+        self.related_coverings.0.push(covering);
+        self
+    }
+}
+
+impl RootBuilder for RelCoversBldgElements {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.root
+    }
+}
+
+impl Deref for RelCoversBldgElements {
+    type Target = Root;
+
+    fn deref(&self) -> &Self::Target {
+        &self.root
+    }
+}
+
+impl IFCParse for RelCoversBldgElements {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRELCOVERSBLDGELEMENTS("),
+
+                root: Root::parse(),
+                _: Comma::parse(),
+                relating_building_element: Id::parse(),
+                _: Comma::parse(),
+                related_coverings: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RelCoversBldgElements {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCRELCOVERSBLDGELEMENTS({},{},{});",
+            self.root, self.relating_building_element, self.related_coverings
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRelCoversBldgElements")]
+impl IfcType for RelCoversBldgElements {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::RelCoversBldgElements;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn rel_covers_bldg_elements_round_trip() {
+        let example =
+            "IFCRELCOVERSBLDGELEMENTS('1bW9hVn2cP0Jd8Ks3mF6yQ',#2,'SlabToCoverings',$,#120,(#135,#142));";
+
+        let rel_covers_bldg_elements: RelCoversBldgElements =
+            RelCoversBldgElements::parse().parse(example).unwrap();
+        let str_rel_covers_bldg_elements = rel_covers_bldg_elements.to_string();
+
+        assert_eq!(example, str_rel_covers_bldg_elements);
+    }
+}
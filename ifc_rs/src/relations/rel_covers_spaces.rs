@@ -0,0 +1,116 @@
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, p_space_or_comment_surrounded, IFCParse,
+        IFCParser,
+    },
+    prelude::*,
+};
+
+/// The objectified relationship IfcRelCoversSpaces relates a space to one or
+/// more coverings, that cover the surfaces of the space, e.g. its flooring
+/// or its ceiling.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelcoversspaces.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RelCoversSpaces {
+    #[inherited]
+    root: Root,
+
+    /// Relationship to the space object that is covered by one or more
+    /// coverings.
+    #[ifc_types(Space)]
+    pub relating_space: Id,
+
+    /// Set of coverings covering this space.
+    pub related_coverings: IfcList<TypedId<Covering>>,
+}
+
+impl RelCoversSpaces {
+    pub fn new(
+        name: impl Into<Label>,
+        relating_space: impl Into<Id>,
+        coverings: impl IntoIterator<Item = TypedId<Covering>>,
+    ) -> Self {
+        Self {
+            root: Root::new(name.into()),
+            relating_space: relating_space.into(),
+            related_coverings: IfcList(coverings.into_iter().collect()),
+        }
+    }
+
+    pub fn relate_covering(mut self, covering: TypedId<Covering>) -> Self {
+        self.related_coverings.0.push(covering);
+        self
+    }
+}
+
+impl RootBuilder for RelCoversSpaces {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.root
+    }
+}
+
+impl Deref for RelCoversSpaces {
+    type Target = Root;
+
+    fn deref(&self) -> &Self::Target {
+        &self.root
+    }
+}
+
+impl IFCParse for RelCoversSpaces {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRELCOVERSSPACES("),
+
+                root: Root::parse(),
+                _: Comma::parse(),
+                relating_space: Id::parse(),
+                _: Comma::parse(),
+                related_coverings: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RelCoversSpaces {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCRELCOVERSSPACES({},{},{});",
+            self.root, self.relating_space, self.related_coverings
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRelCoversSpaces")]
+impl IfcType for RelCoversSpaces {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::RelCoversSpaces;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn rel_covers_spaces_round_trip() {
+        let example =
+            "IFCRELCOVERSSPACES('2cP0Jd8Ks3mF6yQ1bW9hVn',#2,'SpaceToCoverings',$,#98,(#210));";
+
+        let rel_covers_spaces: RelCoversSpaces = RelCoversSpaces::parse().parse(example).unwrap();
+        let str_rel_covers_spaces = rel_covers_spaces.to_string();
+
+        assert_eq!(example, str_rel_covers_spaces);
+    }
+}
@@ -1,6 +1,7 @@
 use std::{fmt::Display, ops::Deref};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{Id, IdOr, TypedId},
@@ -18,8 +19,9 @@ use crate::{
 /// by IfcProject, or IfcProjectLibrary).
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcreldeclares.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct RelDeclares {
+    #[inherited]
     root: Root,
 
     /// Reference to the IfcProject to which additional information is assigned.
@@ -93,6 +95,7 @@ impl Display for RelDeclares {
     }
 }
 
+#[typetag::serde(name = "IfcRelDeclares")]
 impl IfcType for RelDeclares {}
 
 #[cfg(test)]
@@ -0,0 +1,126 @@
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{Id, IdOr},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, p_space_or_comment_surrounded, IFCParse,
+        IFCParser,
+    },
+    prelude::*,
+};
+
+/// The objectified relationship IfcRelDefinesByProperties defines the
+/// relationships between property set definitions and objects. Properties
+/// are aggregated in property sets. Property sets can be either directly
+/// assigned to occurrence objects using this relationship, or assigned to
+/// an object type and assigned via that type to occurrence objects.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcreldefinesbyproperties.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RelDefinesByProperties {
+    #[inherited]
+    root: Root,
+
+    /// Reference to the objects (or single object) to which the property
+    /// definition applies.
+    pub related_objects: IfcList<Id>,
+
+    /// Reference to the property set definition for that object or set of
+    /// objects.
+    #[ifc_types(PropertySet, ElementQuantity)]
+    pub relating_property_definition: Id,
+}
+
+impl RelDefinesByProperties {
+    pub fn new<P: IfcType>(
+        name: impl Into<Label>,
+        relating_property_definition: impl Into<IdOr<P>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            root: Root::new(name.into()),
+            related_objects: IfcList::empty(),
+            relating_property_definition: relating_property_definition.into().or_insert(ifc).id(),
+        }
+    }
+
+    pub fn relate_push<OBJ: IfcType>(&mut self, object: impl Into<IdOr<OBJ>>, ifc: &mut IFC) {
+        self.related_objects
+            .0
+            .push(object.into().or_insert(ifc).id());
+    }
+
+    pub fn relate_obj<OBJ: IfcType>(mut self, object: impl Into<IdOr<OBJ>>, ifc: &mut IFC) -> Self {
+        self.relate_push(object, ifc);
+        self
+    }
+}
+
+impl RootBuilder for RelDefinesByProperties {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.root
+    }
+}
+
+impl Deref for RelDefinesByProperties {
+    type Target = Root;
+
+    fn deref(&self) -> &Self::Target {
+        &self.root
+    }
+}
+
+impl IFCParse for RelDefinesByProperties {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRELDEFINESBYPROPERTIES("),
+
+                root: Root::parse(),
+                _: Comma::parse(),
+                related_objects: IfcList::parse(),
+                _: Comma::parse(),
+                relating_property_definition: Id::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RelDefinesByProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCRELDEFINESBYPROPERTIES({},{},{});",
+            self.root, self.related_objects, self.relating_property_definition
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRelDefinesByProperties")]
+impl IfcType for RelDefinesByProperties {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::RelDefinesByProperties;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn rel_defines_by_properties_round_trip() {
+        let example =
+            "IFCRELDEFINESBYPROPERTIES('1XHlCNqAFH0tlvMzR6N0fY',#12,$,$,(#15042),#15079);";
+
+        let parsed: RelDefinesByProperties =
+            RelDefinesByProperties::parse().parse(example).unwrap();
+        let str = parsed.to_string();
+
+        assert_eq!(example, str);
+    }
+}
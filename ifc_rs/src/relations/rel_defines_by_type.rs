@@ -1,6 +1,7 @@
 use std::{fmt::Display, ops::Deref};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::id::Id;
 use crate::id::IdOr;
@@ -23,8 +24,9 @@ use crate::IFC;
 /// object type.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcreldefinesbytype.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct RelDefinesByType {
+    #[inherited]
     root: Root,
 
     pub related_objects: IfcList<Id>,
@@ -96,6 +98,7 @@ impl Display for RelDefinesByType {
     }
 }
 
+#[typetag::serde(name = "IfcRelDefinesByType")]
 impl IfcType for RelDefinesByType {}
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{Id, IdOr, TypedId},
@@ -14,14 +15,15 @@ use crate::{
 /// It is an one-to-one relationship.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcproductextension/lexical/ifcrelfillselement.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct RelFillsElement {
+    #[inherited]
     root: Root,
     /// Opening Element being filled by virtue of this relationship.
     relating_opening_element: TypedId<OpeningElement>,
     /// Reference to building element that occupies fully or partially the
     /// associated opening.
-    #[ifc_types(Building, OpeningElement, Slab, Wall, Window)]
+    #[ifc_types(Building, OpeningElement, Slab, Wall, Window, Door)]
     pub related_building_element: Id,
 }
 
@@ -74,6 +76,7 @@ impl Display for RelFillsElement {
     }
 }
 
+#[typetag::serde(name = "IfcRelFillsElement")]
 impl IfcType for RelFillsElement {}
 
 #[cfg(test)]
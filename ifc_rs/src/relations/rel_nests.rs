@@ -0,0 +1,110 @@
+use std::{fmt::Display, ops::Deref};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::Id,
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, list::IfcList, p_space_or_comment_surrounded, IFCParse,
+        IFCParser,
+    },
+    prelude::*,
+};
+
+/// The nesting relationship IfcRelNests is a special type of the general
+/// composition/decomposition (or whole/part) relationship IfcRelDecomposes.
+/// In contrary to aggregations the parts are ordered. It is used to attach
+/// the ports of a distribution element to the element.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelnests.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RelNests {
+    #[inherited]
+    root: Root,
+
+    /// The object definition, either an object type or an object
+    /// occurrence, that represents the nest. It is the whole within the
+    /// whole/part relationship.
+    pub relating_object: Id,
+
+    /// The object definitions, either object occurrences or object types,
+    /// that are being nested. They are defined as the parts in the
+    /// whole/part relationship. The order of the parts is significant.
+    pub related_objects: IfcList<Id>,
+}
+
+impl RelNests {
+    pub fn new(name: impl Into<Label>, parent: Id, children: impl IntoIterator<Item = Id>) -> Self {
+        Self {
+            root: Root::new(name.into()),
+            relating_object: parent,
+            related_objects: IfcList(children.into_iter().collect()),
+        }
+    }
+}
+
+impl RootBuilder for RelNests {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.root
+    }
+}
+
+impl Deref for RelNests {
+    type Target = Root;
+
+    fn deref(&self) -> &Self::Target {
+        &self.root
+    }
+}
+
+impl IFCParse for RelNests {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRELNESTS("),
+
+                root: Root::parse(),
+                _: Comma::parse(),
+                relating_object: Id::parse(),
+                _: Comma::parse(),
+                related_objects: IfcList::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RelNests {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCRELNESTS({},{},{});",
+            self.root, self.relating_object, self.related_objects
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRelNests")]
+impl IfcType for RelNests {}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::RelNests;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn rel_nests_round_trip() {
+        let example =
+            "IFCRELNESTS('3Kf0aW4cR7hLm2Xk8PqTs9',#2,'PipeSegmentToPorts',$,#120,(#135,#142));";
+
+        let rel_nests: RelNests = RelNests::parse().parse(example).unwrap();
+        let str_rel_nests = rel_nests.to_string();
+
+        assert_eq!(example, str_rel_nests);
+    }
+}
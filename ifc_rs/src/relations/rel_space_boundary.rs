@@ -0,0 +1,548 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
+
+use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::{
+    id::{Id, IdOr, TypedId},
+    ifc_type::{IfcType, IfcVerify},
+    parser::{
+        comma::Comma, label::Label, optional::OptionalParameter, p_space_or_comment,
+        p_space_or_comment_surrounded, IFCParse, IFCParser,
+    },
+    prelude::*,
+};
+
+/// Elements which can bound spaces
+pub trait SpaceBoundaryRelatable: IfcType {}
+
+/// This enumeration defines the different types of space boundaries in
+/// terms of its physical manifestation.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcphysicalorvirtualenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum PhysicalOrVirtualEnum {
+    /// The space boundary is provided physically by a building element.
+    #[strum(to_string = ".PHYSICAL.")]
+    Physical,
+
+    /// The space boundary is provided virtually, e.g. by an imaginary line
+    /// dividing two spaces without a separating element.
+    #[strum(to_string = ".VIRTUAL.")]
+    Virtual,
+
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for PhysicalOrVirtualEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid PhysicalOrVirtualEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+/// This enumeration defines the different types of space boundaries in
+/// terms of what lies on the other side of the boundary.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcinternalorexternalenum.htm
+#[derive(
+    EnumString, VariantNames, Display, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum InternalOrExternalEnum {
+    /// The element is used to connect two internal spaces.
+    #[strum(to_string = ".INTERNAL.")]
+    Internal,
+
+    /// The element is used to connect an internal space to the open air.
+    #[strum(to_string = ".EXTERNAL.")]
+    External,
+
+    /// The element is used to connect an internal space to the ground.
+    #[strum(to_string = ".EXTERNAL_EARTH.")]
+    ExternalEarth,
+
+    /// The element is used to connect an internal space to water.
+    #[strum(to_string = ".EXTERNAL_WATER.")]
+    ExternalWater,
+
+    /// The element is used to connect an internal space to an adjacent
+    /// building.
+    #[strum(to_string = ".EXTERNAL_FIRE.")]
+    ExternalFire,
+
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for InternalOrExternalEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid InternalOrExternalEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+/// The attributes shared by IfcRelSpaceBoundary and its level specific
+/// subtypes IfcRelSpaceBoundary1stLevel and IfcRelSpaceBoundary2ndLevel.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelspaceboundary.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct SpaceBoundary {
+    #[inherited]
+    root: Root,
+
+    /// Reference to one spaces that is delimited by this boundary.
+    pub relating_space: TypedId<Space>,
+
+    /// Reference to Building Element, that defines the Space Boundaries.
+    #[ifc_types(Wall, Slab, Window, Door, Roof, Beam, Column, Member)]
+    pub related_building_element: Id,
+
+    /// Physical representation of the space boundary. Provided as a curve or
+    /// surface given within the local coordinate system of the space.
+    #[ifc_types(ConnectionSurfaceGeometry)]
+    pub connection_geometry: OptionalParameter<Id>,
+
+    /// Defines, whether the Space Boundary is physical (Physical) or virtual
+    /// (Virtual).
+    pub physical_or_virtual_boundary: PhysicalOrVirtualEnum,
+
+    /// Defines, whether the Space Boundary is internal (Internal), or
+    /// external, i.e. adjacent to open space (that can be an partially
+    /// enclosed space, such as terrace (External).
+    pub internal_or_external_boundary: InternalOrExternalEnum,
+}
+
+impl SpaceBoundary {
+    pub fn new<E: SpaceBoundaryRelatable>(
+        root: Root,
+        relating_space: impl Into<IdOr<Space>>,
+        related_building_element: impl Into<IdOr<E>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            root,
+            relating_space: relating_space.into().or_insert(ifc),
+            related_building_element: related_building_element.into().or_insert(ifc).id(),
+            connection_geometry: OptionalParameter::omitted(),
+            physical_or_virtual_boundary: PhysicalOrVirtualEnum::Physical,
+            internal_or_external_boundary: InternalOrExternalEnum::NotDefined,
+        }
+    }
+}
+
+pub trait SpaceBoundaryBuilder: Sized {
+    fn space_boundary_mut(&mut self) -> &mut SpaceBoundary;
+
+    fn connection_geometry(
+        mut self,
+        connection_geometry: impl Into<IdOr<ConnectionSurfaceGeometry>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.space_boundary_mut().connection_geometry =
+            connection_geometry.into().or_insert(ifc).id().into();
+        self
+    }
+
+    fn physical_or_virtual(mut self, physical_or_virtual: PhysicalOrVirtualEnum) -> Self {
+        self.space_boundary_mut().physical_or_virtual_boundary = physical_or_virtual;
+        self
+    }
+
+    fn internal_or_external(mut self, internal_or_external: InternalOrExternalEnum) -> Self {
+        self.space_boundary_mut().internal_or_external_boundary = internal_or_external;
+        self
+    }
+}
+
+impl Deref for SpaceBoundary {
+    type Target = Root;
+
+    fn deref(&self) -> &Self::Target {
+        &self.root
+    }
+}
+
+impl DerefMut for SpaceBoundary {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.root
+    }
+}
+
+impl IFCParse for SpaceBoundary {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                root: Root::parse(),
+                _: Comma::parse(),
+                relating_space: Id::parse().map(TypedId::new),
+                _: Comma::parse(),
+                related_building_element: Id::parse(),
+                _: Comma::parse(),
+                connection_geometry: OptionalParameter::parse(),
+                _: Comma::parse(),
+                physical_or_virtual_boundary: PhysicalOrVirtualEnum::parse(),
+                _: Comma::parse(),
+                internal_or_external_boundary: InternalOrExternalEnum::parse(),
+            }
+        }
+    }
+}
+
+impl Display for SpaceBoundary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{}",
+            self.root,
+            self.relating_space,
+            self.related_building_element,
+            self.connection_geometry,
+            self.physical_or_virtual_boundary,
+            self.internal_or_external_boundary
+        )
+    }
+}
+
+/// The space boundary defines the physical or virtual delimiter of a space
+/// by the relationship IfcRelSpaceBoundary to the surrounding elements.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelspaceboundary.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RelSpaceBoundary {
+    #[inherited]
+    space_boundary: SpaceBoundary,
+}
+
+impl RelSpaceBoundary {
+    pub fn new<E: SpaceBoundaryRelatable>(
+        name: impl Into<Label>,
+        relating_space: impl Into<IdOr<Space>>,
+        related_building_element: impl Into<IdOr<E>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            space_boundary: SpaceBoundary::new(
+                Root::new(name.into()),
+                relating_space,
+                related_building_element,
+                ifc,
+            ),
+        }
+    }
+}
+
+impl SpaceBoundaryBuilder for RelSpaceBoundary {
+    fn space_boundary_mut(&mut self) -> &mut SpaceBoundary {
+        &mut self.space_boundary
+    }
+}
+
+impl RootBuilder for RelSpaceBoundary {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.space_boundary
+    }
+}
+
+impl Deref for RelSpaceBoundary {
+    type Target = SpaceBoundary;
+
+    fn deref(&self) -> &Self::Target {
+        &self.space_boundary
+    }
+}
+
+impl IFCParse for RelSpaceBoundary {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRELSPACEBOUNDARY("),
+
+                space_boundary: SpaceBoundary::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RelSpaceBoundary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IFCRELSPACEBOUNDARY({});", self.space_boundary)
+    }
+}
+
+#[typetag::serde(name = "IfcRelSpaceBoundary")]
+impl IfcType for RelSpaceBoundary {}
+
+/// The 1st level space boundary defines the boundary of a space to each
+/// element bounding it, without considering the elements on the other side
+/// of the element or changes in its material.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelspaceboundary1stlevel.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RelSpaceBoundary1stLevel {
+    #[inherited]
+    space_boundary: SpaceBoundary,
+
+    /// Reference to the host, or parent, space boundary within which this
+    /// inner boundary is defined, e.g. the boundary of a wall hosting the
+    /// boundary of a window.
+    #[ifc_types(RelSpaceBoundary1stLevel, RelSpaceBoundary2ndLevel)]
+    pub parent_boundary: OptionalParameter<Id>,
+}
+
+impl RelSpaceBoundary1stLevel {
+    pub fn new<E: SpaceBoundaryRelatable>(
+        name: impl Into<Label>,
+        relating_space: impl Into<IdOr<Space>>,
+        related_building_element: impl Into<IdOr<E>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            space_boundary: SpaceBoundary::new(
+                Root::new(name.into()),
+                relating_space,
+                related_building_element,
+                ifc,
+            ),
+            parent_boundary: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn parent_boundary(
+        mut self,
+        parent_boundary: impl Into<IdOr<RelSpaceBoundary1stLevel>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.parent_boundary = parent_boundary.into().or_insert(ifc).id().into();
+        self
+    }
+}
+
+impl SpaceBoundaryBuilder for RelSpaceBoundary1stLevel {
+    fn space_boundary_mut(&mut self) -> &mut SpaceBoundary {
+        &mut self.space_boundary
+    }
+}
+
+impl RootBuilder for RelSpaceBoundary1stLevel {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.space_boundary
+    }
+}
+
+impl Deref for RelSpaceBoundary1stLevel {
+    type Target = SpaceBoundary;
+
+    fn deref(&self) -> &Self::Target {
+        &self.space_boundary
+    }
+}
+
+impl IFCParse for RelSpaceBoundary1stLevel {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRELSPACEBOUNDARY1STLEVEL("),
+
+                space_boundary: SpaceBoundary::parse(),
+                _: Comma::parse(),
+                parent_boundary: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RelSpaceBoundary1stLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCRELSPACEBOUNDARY1STLEVEL({},{});",
+            self.space_boundary, self.parent_boundary
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRelSpaceBoundary1stLevel")]
+impl IfcType for RelSpaceBoundary1stLevel {}
+
+/// The 2nd level space boundary defines the boundary of a space to each
+/// element bounding it, split up by the elements or spaces on the other side
+/// of the boundary. Each boundary of type 2a has a corresponding boundary on
+/// the other side of the element.
+///
+/// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrelspaceboundary2ndlevel.htm
+#[derive(IfcVerify, Serialize, Deserialize)]
+pub struct RelSpaceBoundary2ndLevel {
+    #[inherited]
+    space_boundary: SpaceBoundary,
+
+    /// Reference to the host, or parent, space boundary within which this
+    /// inner boundary is defined.
+    #[ifc_types(RelSpaceBoundary1stLevel, RelSpaceBoundary2ndLevel)]
+    pub parent_boundary: OptionalParameter<Id>,
+
+    /// Reference to the other space boundary of the pair of two space
+    /// boundaries on either side of a space separating thermal boundary
+    /// element.
+    #[ifc_types(RelSpaceBoundary2ndLevel)]
+    pub corresponding_boundary: OptionalParameter<Id>,
+}
+
+impl RelSpaceBoundary2ndLevel {
+    pub fn new<E: SpaceBoundaryRelatable>(
+        name: impl Into<Label>,
+        relating_space: impl Into<IdOr<Space>>,
+        related_building_element: impl Into<IdOr<E>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        Self {
+            space_boundary: SpaceBoundary::new(
+                Root::new(name.into()),
+                relating_space,
+                related_building_element,
+                ifc,
+            ),
+            parent_boundary: OptionalParameter::omitted(),
+            corresponding_boundary: OptionalParameter::omitted(),
+        }
+    }
+
+    pub fn parent_boundary(
+        mut self,
+        parent_boundary: impl Into<IdOr<RelSpaceBoundary2ndLevel>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.parent_boundary = parent_boundary.into().or_insert(ifc).id().into();
+        self
+    }
+
+    pub fn corresponding_boundary(
+        mut self,
+        corresponding_boundary: impl Into<IdOr<RelSpaceBoundary2ndLevel>>,
+        ifc: &mut IFC,
+    ) -> Self {
+        self.corresponding_boundary = corresponding_boundary.into().or_insert(ifc).id().into();
+        self
+    }
+}
+
+impl SpaceBoundaryBuilder for RelSpaceBoundary2ndLevel {
+    fn space_boundary_mut(&mut self) -> &mut SpaceBoundary {
+        &mut self.space_boundary
+    }
+}
+
+impl RootBuilder for RelSpaceBoundary2ndLevel {
+    fn root_mut(&mut self) -> &mut Root {
+        &mut self.space_boundary
+    }
+}
+
+impl Deref for RelSpaceBoundary2ndLevel {
+    type Target = SpaceBoundary;
+
+    fn deref(&self) -> &Self::Target {
+        &self.space_boundary
+    }
+}
+
+impl IFCParse for RelSpaceBoundary2ndLevel {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        winnow::seq! {
+            Self {
+                _: p_space_or_comment_surrounded("IFCRELSPACEBOUNDARY2NDLEVEL("),
+
+                space_boundary: SpaceBoundary::parse(),
+                _: Comma::parse(),
+                parent_boundary: OptionalParameter::parse(),
+                _: Comma::parse(),
+                corresponding_boundary: OptionalParameter::parse(),
+
+                _: p_space_or_comment_surrounded(");"),
+            }
+        }
+    }
+}
+
+impl Display for RelSpaceBoundary2ndLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IFCRELSPACEBOUNDARY2NDLEVEL({},{},{});",
+            self.space_boundary, self.parent_boundary, self.corresponding_boundary
+        )
+    }
+}
+
+#[typetag::serde(name = "IfcRelSpaceBoundary2ndLevel")]
+impl IfcType for RelSpaceBoundary2ndLevel {}
+
+/// The shared space boundary attributes of any of the space boundary
+/// relations, `None` if the entity isn't a space boundary.
+pub fn as_space_boundary(ifc_type: &dyn IfcType) -> Option<&SpaceBoundary> {
+    if let Some(boundary) = ifc_type.downcast_ref::<RelSpaceBoundary>() {
+        Some(boundary)
+    } else if let Some(boundary) = ifc_type.downcast_ref::<RelSpaceBoundary1stLevel>() {
+        Some(boundary)
+    } else {
+        ifc_type
+            .downcast_ref::<RelSpaceBoundary2ndLevel>()
+            .map(|boundary| &**boundary)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::{RelSpaceBoundary, RelSpaceBoundary1stLevel, RelSpaceBoundary2ndLevel};
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn rel_space_boundary_round_trip() {
+        let example = "IFCRELSPACEBOUNDARY('0F8DHwVIWaA92A8pankadM',#12,'2ndLevel','2a',#20909,#15042,#76510,.PHYSICAL.,.INTERNAL.);";
+
+        let parsed: RelSpaceBoundary = RelSpaceBoundary::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+
+    #[test]
+    fn rel_space_boundary_levels_round_trip() {
+        let example = "IFCRELSPACEBOUNDARY1STLEVEL('2uV2Vt8OH1ogeRWzPmyY8n',#2,'1stLevel',$,#51,#64,#90,.PHYSICAL.,.EXTERNAL.,$);";
+        let parsed: RelSpaceBoundary1stLevel =
+            RelSpaceBoundary1stLevel::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+
+        let example = "IFCRELSPACEBOUNDARY2NDLEVEL('3DhSjYv7T8xOxbQ7_2Hk4q',#2,'2ndLevel','2a',#51,#64,$,.VIRTUAL.,.INTERNAL.,#95,#96);";
+        let parsed: RelSpaceBoundary2ndLevel =
+            RelSpaceBoundary2ndLevel::parse().parse(example).unwrap();
+        assert_eq!(example, parsed.to_string());
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{Id, IdOr, TypedId},
@@ -18,12 +19,23 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcproductextension/lexical/ifcrelvoidselement.htm
 
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct RelVoidsElement {
+    #[inherited]
     root: Root,
     /// Reference to element in which a void is created by associated feature
     /// subtraction element.
-    #[ifc_types(Building, OpeningElement, Slab, Wall, Window)]
+    #[ifc_types(
+        Building,
+        OpeningElement,
+        Slab,
+        Wall,
+        Window,
+        Door,
+        Beam,
+        Column,
+        Member
+    )]
     pub relating_building_element: Id,
     /// Reference to the feature subtraction element which defines a void in
     /// the associated element.
@@ -79,6 +91,7 @@ impl Display for RelVoidsElement {
     }
 }
 
+#[typetag::serde(name = "IfcRelVoidsElement")]
 impl IfcType for RelVoidsElement {}
 
 #[cfg(test)]
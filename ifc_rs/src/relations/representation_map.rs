@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::IdOr,
@@ -32,7 +33,7 @@ use crate::{
 ///   NOTE  Entity adapted from representation_map defined in ISO 10303-43.
 ///
 ///   HISTORY  New entity in IFC2x.
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct RepresentationMap {
     /// An axis2 placement that defines the position about which the mapped representation is mapped.
     pub origin: IdOr<Axis3D>,
@@ -56,6 +57,7 @@ impl RepresentationMap {
     }
 }
 
+#[typetag::serde(name = "IfcRepresentationMap")]
 impl IfcType for RepresentationMap {}
 
 impl Display for RepresentationMap {
@@ -0,0 +1,77 @@
+use super::{AttributeKind, DefinedTypeDef};
+
+macro_rules! defined_type_kind {
+    (List($inner:ident)) => {
+        AttributeKind::List(&AttributeKind::$inner)
+    };
+    ($kind:ident) => {
+        AttributeKind::$kind
+    };
+}
+
+macro_rules! defined_types {
+    ($($name:ident : $kind:ident $(($inner:ident))?),* $(,)?) => {
+        /// Defined types which are commonly used as typed values.
+        pub static DEFINED_TYPES: &[DefinedTypeDef] = &[$(
+            DefinedTypeDef {
+                name: stringify!($name),
+                kind: defined_type_kind!($kind $(($inner))?),
+            }
+        ),*];
+    };
+}
+
+defined_types! {
+    IfcLabel: String,
+    IfcText: String,
+    IfcIdentifier: String,
+    IfcDescriptiveMeasure: String,
+    IfcDate: String,
+    IfcDateTime: String,
+    IfcTime: String,
+    IfcDuration: String,
+    IfcURIReference: String,
+    IfcGloballyUniqueId: String,
+    IfcTextFontName: String,
+    IfcFontStyle: String,
+    IfcFontVariant: String,
+    IfcFontWeight: String,
+    IfcPresentableText: String,
+    IfcBoxAlignment: String,
+    IfcBoolean: Enum,
+    IfcLogical: Enum,
+    IfcInteger: Number,
+    IfcReal: Number,
+    IfcTimeStamp: Number,
+    IfcPositiveInteger: Number,
+    IfcCountMeasure: Number,
+    IfcNumericMeasure: Number,
+    IfcLengthMeasure: Number,
+    IfcPositiveLengthMeasure: Number,
+    IfcNonNegativeLengthMeasure: Number,
+    IfcAreaMeasure: Number,
+    IfcVolumeMeasure: Number,
+    IfcMassMeasure: Number,
+    IfcPlaneAngleMeasure: Number,
+    IfcPositivePlaneAngleMeasure: Number,
+    IfcRatioMeasure: Number,
+    IfcNormalisedRatioMeasure: Number,
+    IfcPositiveRatioMeasure: Number,
+    IfcParameterValue: Number,
+    IfcMonetaryMeasure: Number,
+    IfcPowerMeasure: Number,
+    IfcPressureMeasure: Number,
+    IfcForceMeasure: Number,
+    IfcMassDensityMeasure: Number,
+    IfcSpecificHeatCapacityMeasure: Number,
+    IfcThermalConductivityMeasure: Number,
+    IfcThermalTransmittanceMeasure: Number,
+    IfcThermodynamicTemperatureMeasure: Number,
+    IfcElectricCurrentMeasure: Number,
+    IfcVolumetricFlowRateMeasure: Number,
+    IfcLinearVelocityMeasure: Number,
+    IfcSpecularExponent: Number,
+    IfcSpecularRoughness: Number,
+    IfcLineIndex: List(Number),
+    IfcArcIndex: List(Number),
+}
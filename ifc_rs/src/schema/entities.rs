@@ -0,0 +1,828 @@
+use super::{AttributeDef, AttributeKind, EntityDef};
+
+macro_rules! kind {
+    (List($($inner:tt)+)) => {
+        AttributeKind::List(&kind!($($inner)+))
+    };
+    ($kind:ident) => {
+        AttributeKind::$kind
+    };
+}
+
+macro_rules! supertype {
+    (_) => {
+        None
+    };
+    ($supertype:ident) => {
+        Some(stringify!($supertype))
+    };
+}
+
+macro_rules! item_type {
+    () => {
+        None
+    };
+    ($item_type:ident) => {
+        Some(stringify!($item_type))
+    };
+}
+
+macro_rules! entities {
+    ($(
+        $name:ident : $supertype:tt $(derives [$($derived:ident),* $(,)?])? {
+            $($attribute:ident : $kind:ident $(($($inner:tt)+))? $(as $item_type:ident)?),* $(,)?
+        }
+    ),* $(,)?) => {
+        /// All entities known to this crate (including abstract supertypes).
+        pub static ENTITIES: &[EntityDef] = &[$(
+            EntityDef {
+                name: stringify!($name),
+                supertype: supertype!($supertype),
+                attributes: &[$(
+                    AttributeDef {
+                        name: stringify!($attribute),
+                        kind: kind!($kind $(($($inner)+))?),
+                        item_type: item_type!($($item_type)?),
+                    }
+                ),*],
+                derived: &[$($(stringify!($derived)),*)?],
+            }
+        ),*];
+    };
+}
+
+entities! {
+    // kernel
+    IfcRoot: _ {
+        GlobalId: String,
+        OwnerHistory: Ref,
+        Name: String,
+        Description: String,
+    },
+    IfcObjectDefinition: IfcRoot {},
+    IfcObject: IfcObjectDefinition { ObjectType: String },
+    IfcContext: IfcObjectDefinition {
+        ObjectType: String,
+        LongName: String,
+        Phase: String,
+        RepresentationContexts: List(Ref),
+        UnitsInContext: Ref,
+    },
+    IfcProject: IfcContext {},
+    IfcProduct: IfcObject { ObjectPlacement: Ref, Representation: Ref },
+    IfcTypeObject: IfcObjectDefinition {
+        ApplicableOccurrence: String,
+        HasPropertySets: List(Ref),
+    },
+    IfcTypeProduct: IfcTypeObject { RepresentationMaps: List(Ref), Tag: String },
+    IfcElementType: IfcTypeProduct { ElementType: String },
+    IfcBuildingElementType: IfcElementType {},
+    IfcSpatialElementType: IfcTypeProduct { ElementType: String },
+    IfcSpatialStructureElementType: IfcSpatialElementType {},
+
+    // spatial structure
+    IfcSpatialElement: IfcProduct { LongName: String },
+    IfcSpatialStructureElement: IfcSpatialElement { CompositionType: Enum },
+    IfcSite: IfcSpatialStructureElement {
+        RefLatitude: List(Number),
+        RefLongitude: List(Number),
+        RefElevation: Number,
+        LandTitleNumber: String,
+        SiteAddress: Ref,
+    },
+    IfcBuilding: IfcSpatialStructureElement {
+        ElevationOfRefHeight: Number,
+        ElevationOfTerrain: Number,
+        BuildingAddress: Ref,
+    },
+    IfcBuildingStorey: IfcSpatialStructureElement { Elevation: Number },
+    IfcSpace: IfcSpatialStructureElement {
+        PredefinedType: Enum,
+        ElevationWithFlooring: Number,
+    },
+    IfcSpaceType: IfcSpatialStructureElementType { PredefinedType: Enum, LongName: String },
+
+    // elements
+    IfcElement: IfcProduct { Tag: String },
+    IfcBuildingElement: IfcElement {},
+    IfcWall: IfcBuildingElement { PredefinedType: Enum },
+    IfcWallStandardCase: IfcWall {},
+    IfcWallType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcSlab: IfcBuildingElement { PredefinedType: Enum },
+    IfcSlabType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcRoof: IfcBuildingElement { PredefinedType: Enum },
+    IfcRoofType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcWindow: IfcBuildingElement {
+        OverallHeight: Number,
+        OverallWidth: Number,
+        PredefinedType: Enum,
+        PartitioningType: Enum,
+        UserDefinedPartitioningType: String,
+    },
+    IfcWindowType: IfcBuildingElementType {
+        PredefinedType: Enum,
+        PartitioningType: Enum,
+        ParameterTakesPrecedence: Enum,
+        UserDefinedPartitioningType: String,
+    },
+    IfcDoor: IfcBuildingElement {
+        OverallHeight: Number,
+        OverallWidth: Number,
+        PredefinedType: Enum,
+        OperationType: Enum,
+        UserDefinedOperationType: String,
+    },
+    IfcDoorType: IfcBuildingElementType {
+        PredefinedType: Enum,
+        OperationType: Enum,
+        ParameterTakesPrecedence: Enum,
+        UserDefinedOperationType: String,
+    },
+    IfcBeam: IfcBuildingElement { PredefinedType: Enum },
+    IfcBeamType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcColumn: IfcBuildingElement { PredefinedType: Enum },
+    IfcColumnType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcMember: IfcBuildingElement { PredefinedType: Enum },
+    IfcMemberType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcRailing: IfcBuildingElement { PredefinedType: Enum },
+    IfcRailingType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcStair: IfcBuildingElement { PredefinedType: Enum },
+    IfcStairType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcStairFlight: IfcBuildingElement {
+        NumberOfRisers: Number,
+        NumberOfTreads: Number,
+        RiserHeight: Number,
+        TreadLength: Number,
+        PredefinedType: Enum,
+    },
+    IfcStairFlightType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcRamp: IfcBuildingElement { PredefinedType: Enum },
+    IfcRampType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcRampFlight: IfcBuildingElement { PredefinedType: Enum },
+    IfcRampFlightType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcCovering: IfcBuildingElement { PredefinedType: Enum },
+    IfcCoveringType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcCurtainWall: IfcBuildingElement { PredefinedType: Enum },
+    IfcCurtainWallType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcPlate: IfcBuildingElement { PredefinedType: Enum },
+    IfcPlateType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcBuildingElementProxy: IfcBuildingElement { PredefinedType: Enum },
+    IfcBuildingElementProxyType: IfcBuildingElementType { PredefinedType: Enum },
+    IfcFurnishingElement: IfcElement {},
+    IfcFurnishingElementType: IfcElementType {},
+    IfcFurniture: IfcFurnishingElement { PredefinedType: Enum },
+    IfcFurnitureType: IfcFurnishingElementType { AssemblyPlace: Enum, PredefinedType: Enum },
+    IfcDistributionElement: IfcElement {},
+    IfcDistributionElementType: IfcElementType {},
+    IfcDistributionFlowElement: IfcDistributionElement {},
+    IfcDistributionFlowElementType: IfcDistributionElementType {},
+    IfcFlowSegment: IfcDistributionFlowElement {},
+    IfcFlowSegmentType: IfcDistributionFlowElementType {},
+    IfcPipeSegment: IfcFlowSegment { PredefinedType: Enum },
+    IfcPipeSegmentType: IfcFlowSegmentType { PredefinedType: Enum },
+    IfcDuctSegment: IfcFlowSegment { PredefinedType: Enum },
+    IfcDuctSegmentType: IfcFlowSegmentType { PredefinedType: Enum },
+    IfcFlowFitting: IfcDistributionFlowElement {},
+    IfcFlowFittingType: IfcDistributionFlowElementType {},
+    IfcPipeFittingType: IfcFlowFittingType { PredefinedType: Enum },
+    IfcDuctFittingType: IfcFlowFittingType { PredefinedType: Enum },
+    IfcFlowTerminal: IfcDistributionFlowElement {},
+    IfcFlowTerminalType: IfcDistributionFlowElementType {},
+    IfcAirTerminalType: IfcFlowTerminalType { PredefinedType: Enum },
+    IfcPort: IfcProduct {},
+    IfcDistributionPort: IfcPort {
+        FlowDirection: Enum,
+        PredefinedType: Enum,
+        SystemType: Enum,
+    },
+    IfcFeatureElement: IfcElement {},
+    IfcFeatureElementSubtraction: IfcFeatureElement {},
+    IfcOpeningElement: IfcFeatureElementSubtraction { PredefinedType: Enum },
+    IfcVirtualElement: IfcElement {},
+    IfcAnnotation: IfcProduct {},
+    IfcGrid: IfcProduct {
+        UAxes: List(Ref),
+        VAxes: List(Ref),
+        WAxes: List(Ref),
+        PredefinedType: Enum,
+    },
+
+    // groups
+    IfcGroup: IfcObject {},
+    IfcSystem: IfcGroup {},
+    IfcZone: IfcSystem { LongName: String },
+    IfcBuildingSystem: IfcSystem { PredefinedType: Enum, LongName: String },
+    IfcDistributionSystem: IfcSystem { LongName: String, PredefinedType: Enum },
+
+    // relationships
+    IfcRelationship: IfcRoot {},
+    IfcRelDecomposes: IfcRelationship {},
+    IfcRelAggregates: IfcRelDecomposes { RelatingObject: Ref, RelatedObjects: List(Ref) },
+    IfcRelNests: IfcRelDecomposes { RelatingObject: Ref, RelatedObjects: List(Ref) },
+    IfcRelVoidsElement: IfcRelDecomposes {
+        RelatingBuildingElement: Ref,
+        RelatedOpeningElement: Ref,
+    },
+    IfcRelConnects: IfcRelationship {},
+    IfcRelContainedInSpatialStructure: IfcRelConnects {
+        RelatedElements: List(Ref),
+        RelatingStructure: Ref,
+    },
+    IfcRelFillsElement: IfcRelConnects {
+        RelatingOpeningElement: Ref,
+        RelatedBuildingElement: Ref,
+    },
+    IfcRelSpaceBoundary: IfcRelConnects {
+        RelatingSpace: Ref,
+        RelatedBuildingElement: Ref,
+        ConnectionGeometry: Ref,
+        PhysicalOrVirtualBoundary: Enum,
+        InternalOrExternalBoundary: Enum,
+    },
+    IfcRelSpaceBoundary1stLevel: IfcRelSpaceBoundary { ParentBoundary: Ref },
+    IfcRelSpaceBoundary2ndLevel: IfcRelSpaceBoundary1stLevel { CorrespondingBoundary: Ref },
+    IfcRelConnectsElements: IfcRelConnects {
+        ConnectionGeometry: Ref,
+        RelatingElement: Ref,
+        RelatedElement: Ref,
+    },
+    IfcRelConnectsPathElements: IfcRelConnectsElements {
+        RelatingPriorities: List(Number),
+        RelatedPriorities: List(Number),
+        RelatedConnectionType: Enum,
+        RelatingConnectionType: Enum,
+    },
+    IfcRelConnectsPorts: IfcRelConnects {
+        RelatingPort: Ref,
+        RelatedPort: Ref,
+        RealizingElement: Ref,
+    },
+    IfcRelCoversBldgElements: IfcRelConnects {
+        RelatingBuildingElement: Ref,
+        RelatedCoverings: List(Ref),
+    },
+    IfcRelCoversSpaces: IfcRelConnects { RelatingSpace: Ref, RelatedCoverings: List(Ref) },
+    IfcRelDeclares: IfcRelationship { RelatingContext: Ref, RelatedDefinitions: List(Ref) },
+    IfcRelDefines: IfcRelationship {},
+    IfcRelDefinesByType: IfcRelDefines { RelatedObjects: List(Ref), RelatingType: Ref },
+    IfcRelDefinesByProperties: IfcRelDefines {
+        RelatedObjects: List(Ref),
+        RelatingPropertyDefinition: Ref,
+    },
+    IfcRelAssigns: IfcRelationship { RelatedObjects: List(Ref), RelatedObjectsType: Enum },
+    IfcRelAssignsToGroup: IfcRelAssigns { RelatingGroup: Ref },
+    IfcRelAssociates: IfcRelationship { RelatedObjects: List(Ref) },
+    IfcRelAssociatesMaterial: IfcRelAssociates { RelatingMaterial: Ref },
+    IfcRelAssociatesClassification: IfcRelAssociates { RelatingClassification: Ref },
+
+    // properties and quantities
+    IfcPropertyDefinition: IfcRoot {},
+    IfcPropertySetDefinition: IfcPropertyDefinition {},
+    IfcPropertySet: IfcPropertySetDefinition { HasProperties: List(Ref) },
+    IfcQuantitySet: IfcPropertySetDefinition {},
+    IfcElementQuantity: IfcQuantitySet { MethodOfMeasurement: String, Quantities: List(Ref) },
+    IfcPreDefinedPropertySet: IfcPropertySetDefinition {},
+    IfcDoorLiningProperties: IfcPreDefinedPropertySet {
+        LiningDepth: Number,
+        LiningThickness: Number,
+        ThresholdDepth: Number,
+        ThresholdThickness: Number,
+        TransomThickness: Number,
+        TransomOffset: Number,
+        LiningOffset: Number,
+        ThresholdOffset: Number,
+        CasingThickness: Number,
+        CasingDepth: Number,
+        ShapeAspectStyle: Ref,
+        LiningToPanelOffsetX: Number,
+        LiningToPanelOffsetY: Number,
+    },
+    IfcDoorPanelProperties: IfcPreDefinedPropertySet {
+        PanelDepth: Number,
+        PanelOperation: Enum,
+        PanelWidth: Number,
+        PanelPosition: Enum,
+        ShapeAspectStyle: Ref,
+    },
+    IfcWindowLiningProperties: IfcPreDefinedPropertySet {
+        LiningDepth: Number,
+        LiningThickness: Number,
+        TransomThickness: Number,
+        MullionThickness: Number,
+        FirstTransomOffset: Number,
+        SecondTransomOffset: Number,
+        FirstMullionOffset: Number,
+        SecondMullionOffset: Number,
+        ShapeAspectStyle: Ref,
+        LiningOffset: Number,
+        LiningToPanelOffsetX: Number,
+        LiningToPanelOffsetY: Number,
+    },
+    IfcWindowPanelProperties: IfcPreDefinedPropertySet {
+        OperationType: Enum,
+        PanelPosition: Enum,
+        FrameDepth: Number,
+        FrameThickness: Number,
+        ShapeAspectStyle: Ref,
+    },
+    IfcPropertyAbstraction: _ {},
+    IfcProperty: IfcPropertyAbstraction { Name: String, Description: String },
+    IfcSimpleProperty: IfcProperty {},
+    IfcPropertySingleValue: IfcSimpleProperty { NominalValue: Select, Unit: Ref },
+    IfcPropertyEnumeratedValue: IfcSimpleProperty {
+        EnumerationValues: List(Select),
+        EnumerationReference: Ref,
+    },
+    IfcPropertyListValue: IfcSimpleProperty { ListValues: List(Select), Unit: Ref },
+    IfcPropertyBoundedValue: IfcSimpleProperty {
+        UpperBoundValue: Select,
+        LowerBoundValue: Select,
+        Unit: Ref,
+        SetPointValue: Select,
+    },
+    IfcPropertyTableValue: IfcSimpleProperty {
+        DefiningValues: List(Select),
+        DefinedValues: List(Select),
+        Expression: String,
+        DefiningUnit: Ref,
+        DefinedUnit: Ref,
+        CurveInterpolation: Enum,
+    },
+    IfcComplexProperty: IfcProperty { UsageName: String, HasProperties: List(Ref) },
+    IfcPropertyEnumeration: IfcPropertyAbstraction {
+        Name: String,
+        EnumerationValues: List(Select),
+        Unit: Ref,
+    },
+    IfcExtendedProperties: IfcPropertyAbstraction {
+        Name: String,
+        Description: String,
+        Properties: List(Ref),
+    },
+    IfcMaterialProperties: IfcExtendedProperties { Material: Ref },
+    IfcPhysicalQuantity: _ { Name: String, Description: String },
+    IfcPhysicalSimpleQuantity: IfcPhysicalQuantity { Unit: Ref },
+    IfcQuantityLength: IfcPhysicalSimpleQuantity { LengthValue: Number, Formula: String },
+    IfcQuantityArea: IfcPhysicalSimpleQuantity { AreaValue: Number, Formula: String },
+    IfcQuantityVolume: IfcPhysicalSimpleQuantity { VolumeValue: Number, Formula: String },
+    IfcQuantityCount: IfcPhysicalSimpleQuantity { CountValue: Number, Formula: String },
+    IfcQuantityWeight: IfcPhysicalSimpleQuantity { WeightValue: Number, Formula: String },
+
+    // external references
+    IfcExternalReference: _ { Location: String, Identification: String, Name: String },
+    IfcClassificationReference: IfcExternalReference {
+        ReferencedSource: Ref,
+        Description: String,
+        Sort: String,
+    },
+    IfcExternalInformation: _ {},
+    IfcClassification: IfcExternalInformation {
+        Source: String,
+        Edition: String,
+        EditionDate: String,
+        Name: String,
+        Description: String,
+        Location: String,
+        ReferenceTokens: List(String) as IfcIdentifier,
+    },
+
+    // actors
+    IfcPerson: _ {
+        Identification: String,
+        FamilyName: String,
+        GivenName: String,
+        MiddleNames: List(String) as IfcLabel,
+        PrefixTitles: List(String) as IfcLabel,
+        SuffixTitles: List(String) as IfcLabel,
+        Roles: List(Ref),
+        Addresses: List(Ref),
+    },
+    IfcOrganization: _ {
+        Identification: String,
+        Name: String,
+        Description: String,
+        Roles: List(Ref),
+        Addresses: List(Ref),
+    },
+    IfcPersonAndOrganization: _ { ThePerson: Ref, TheOrganization: Ref, Roles: List(Ref) },
+    IfcApplication: _ {
+        ApplicationDeveloper: Ref,
+        Version: String,
+        ApplicationFullName: String,
+        ApplicationIdentifier: String,
+    },
+    IfcOwnerHistory: _ {
+        OwningUser: Ref,
+        OwningApplication: Ref,
+        State: Enum,
+        ChangeAction: Enum,
+        LastModifiedDate: Number,
+        LastModifyingUser: Ref,
+        LastModifyingApplication: Ref,
+        CreationDate: Number,
+    },
+    IfcActorRole: _ { Role: Enum, UserDefinedRole: String, Description: String },
+    IfcAddress: _ { Purpose: Enum, Description: String, UserDefinedPurpose: String },
+    IfcPostalAddress: IfcAddress {
+        InternalLocation: String,
+        AddressLines: List(String) as IfcLabel,
+        PostalBox: String,
+        Town: String,
+        Region: String,
+        PostalCode: String,
+        Country: String,
+    },
+    IfcTelecomAddress: IfcAddress {
+        TelephoneNumbers: List(String) as IfcLabel,
+        FacsimileNumbers: List(String) as IfcLabel,
+        PagerNumber: String,
+        ElectronicMailAddresses: List(String) as IfcLabel,
+        WWWHomePageURL: String,
+        MessagingIDs: List(String) as IfcURIReference,
+    },
+
+    // units
+    IfcNamedUnit: _ { Dimensions: Ref, UnitType: Enum },
+    IfcSIUnit: IfcNamedUnit derives [Dimensions] { Prefix: Enum, Name: Enum },
+    IfcConversionBasedUnit: IfcNamedUnit { Name: String, ConversionFactor: Ref },
+    IfcDerivedUnit: _ { Elements: List(Ref), UnitType: Enum, UserDefinedType: String },
+    IfcDerivedUnitElement: _ { Unit: Ref, Exponent: Number },
+    IfcDimensionalExponents: _ {
+        LengthExponent: Number,
+        MassExponent: Number,
+        TimeExponent: Number,
+        ElectricCurrentExponent: Number,
+        ThermodynamicTemperatureExponent: Number,
+        AmountOfSubstanceExponent: Number,
+        LuminousIntensityExponent: Number,
+    },
+    IfcMeasureWithUnit: _ { ValueComponent: Select, UnitComponent: Ref },
+    IfcMonetaryUnit: _ { Currency: String },
+    IfcUnitAssignment: _ { Units: List(Ref) },
+
+    // materials
+    IfcMaterialDefinition: _ {},
+    IfcMaterial: IfcMaterialDefinition { Name: String, Description: String, Category: String },
+    IfcMaterialLayer: IfcMaterialDefinition {
+        Material: Ref,
+        LayerThickness: Number,
+        IsVentilated: Enum,
+        Name: String,
+        Description: String,
+        Category: String,
+        Priority: Number,
+    },
+    IfcMaterialLayerSet: IfcMaterialDefinition {
+        MaterialLayers: List(Ref),
+        LayerSetName: String,
+        Description: String,
+    },
+    IfcMaterialUsageDefinition: _ {},
+    IfcMaterialLayerSetUsage: IfcMaterialUsageDefinition {
+        ForLayerSet: Ref,
+        LayerSetDirection: Enum,
+        DirectionSense: Enum,
+        OffsetFromReferenceLine: Number,
+        ReferenceExtent: Number,
+    },
+    IfcMaterialConstituent: IfcMaterialDefinition {
+        Name: String,
+        Description: String,
+        Material: Ref,
+        Fraction: Number,
+        Category: String,
+    },
+    IfcMaterialConstituentSet: IfcMaterialDefinition {
+        Name: String,
+        Description: String,
+        MaterialConstituents: List(Ref),
+    },
+    IfcMaterialProfile: IfcMaterialDefinition {
+        Name: String,
+        Description: String,
+        Material: Ref,
+        Profile: Ref,
+        Priority: Number,
+        Category: String,
+    },
+    IfcMaterialProfileSet: IfcMaterialDefinition {
+        Name: String,
+        Description: String,
+        MaterialProfiles: List(Ref),
+        CompositeProfile: Ref,
+    },
+    IfcMaterialProfileSetUsage: IfcMaterialUsageDefinition {
+        ForProfileSet: Ref,
+        CardinalPoint: Number,
+        ReferenceExtent: Number,
+    },
+    IfcMaterialList: _ { Materials: List(Ref) },
+
+    // representation
+    IfcRepresentationContext: _ { ContextIdentifier: String, ContextType: String },
+    IfcGeometricRepresentationContext: IfcRepresentationContext {
+        CoordinateSpaceDimension: Number,
+        Precision: Number,
+        WorldCoordinateSystem: Ref,
+        TrueNorth: Ref,
+    },
+    IfcGeometricRepresentationSubContext: IfcGeometricRepresentationContext derives [
+        WorldCoordinateSystem,
+        CoordinateSpaceDimension,
+        TrueNorth,
+        Precision,
+    ] {
+        ParentContext: Ref,
+        TargetScale: Number,
+        TargetView: Enum,
+        UserDefinedTargetView: String,
+    },
+    IfcRepresentation: _ {
+        ContextOfItems: Ref,
+        RepresentationIdentifier: String,
+        RepresentationType: String,
+        Items: List(Ref),
+    },
+    IfcShapeModel: IfcRepresentation {},
+    IfcShapeRepresentation: IfcShapeModel {},
+    IfcStyleModel: IfcRepresentation {},
+    IfcStyledRepresentation: IfcStyleModel {},
+    IfcProductRepresentation: _ { Name: String, Description: String, Representations: List(Ref) },
+    IfcProductDefinitionShape: IfcProductRepresentation {},
+    IfcMaterialDefinitionRepresentation: IfcProductRepresentation { RepresentedMaterial: Ref },
+    IfcRepresentationMap: _ { MappingOrigin: Ref, MappedRepresentation: Ref },
+    IfcRepresentationItem: _ {},
+    IfcMappedItem: IfcRepresentationItem { MappingSource: Ref, MappingTarget: Ref },
+    IfcStyledItem: IfcRepresentationItem { Item: Ref, Styles: List(Ref), Name: String },
+    IfcPresentationLayerAssignment: _ {
+        Name: String,
+        Description: String,
+        AssignedItems: List(Ref),
+        Identifier: String,
+    },
+    IfcPresentationLayerWithStyle: IfcPresentationLayerAssignment {
+        LayerOn: Enum,
+        LayerFrozen: Enum,
+        LayerBlocked: Enum,
+        LayerStyles: List(Ref),
+    },
+
+    // geometry
+    IfcGeometricRepresentationItem: IfcRepresentationItem {},
+    IfcPoint: IfcGeometricRepresentationItem {},
+    IfcCartesianPoint: IfcPoint { Coordinates: List(Number) },
+    IfcDirection: IfcGeometricRepresentationItem { DirectionRatios: List(Number) },
+    IfcPlacement: IfcGeometricRepresentationItem { Location: Ref },
+    IfcAxis1Placement: IfcPlacement { Axis: Ref },
+    IfcAxis2Placement2D: IfcPlacement { RefDirection: Ref },
+    IfcAxis2Placement3D: IfcPlacement { Axis: Ref, RefDirection: Ref },
+    IfcObjectPlacement: _ {},
+    IfcLocalPlacement: IfcObjectPlacement { PlacementRelTo: Ref, RelativePlacement: Ref },
+    IfcGridPlacement: IfcObjectPlacement { PlacementLocation: Ref, PlacementRefDirection: Ref },
+    IfcGridAxis: _ { AxisTag: String, AxisCurve: Ref, SameSense: Enum },
+    IfcVirtualGridIntersection: _ { IntersectingAxes: List(Ref), OffsetDistances: List(Number) },
+    IfcCartesianPointList: IfcGeometricRepresentationItem {},
+    IfcCartesianPointList2D: IfcCartesianPointList {
+        CoordList: List(List(Number)) as IfcLengthMeasure,
+        TagList: List(String) as IfcLabel,
+    },
+    IfcCartesianPointList3D: IfcCartesianPointList {
+        CoordList: List(List(Number)) as IfcLengthMeasure,
+        TagList: List(String) as IfcLabel,
+    },
+    IfcCartesianTransformationOperator: IfcGeometricRepresentationItem {
+        Axis1: Ref,
+        Axis2: Ref,
+        LocalOrigin: Ref,
+        Scale: Number,
+    },
+    IfcCartesianTransformationOperator3D: IfcCartesianTransformationOperator { Axis3: Ref },
+    IfcCartesianTransformationOperator3DnonUniform: IfcCartesianTransformationOperator3D {
+        Scale2: Number,
+        Scale3: Number,
+    },
+    IfcCurve: IfcGeometricRepresentationItem {},
+    IfcBoundedCurve: IfcCurve {},
+    IfcPolyline: IfcBoundedCurve { Points: List(Ref) },
+    IfcIndexedPolyCurve: IfcBoundedCurve {
+        Points: Ref,
+        Segments: List(Select),
+        SelfIntersect: Enum,
+    },
+    IfcCompositeCurve: IfcBoundedCurve { Segments: List(Ref), SelfIntersect: Enum },
+    IfcCompositeCurveSegment: IfcGeometricRepresentationItem {
+        Transition: Enum,
+        SameSense: Enum,
+        ParentCurve: Ref,
+    },
+    IfcTrimmedCurve: IfcBoundedCurve {
+        BasisCurve: Ref,
+        Trim1: List(Select),
+        Trim2: List(Select),
+        SenseAgreement: Enum,
+        MasterRepresentation: Enum,
+    },
+    IfcLine: IfcCurve { Pnt: Ref, Dir: Ref },
+    IfcConic: IfcCurve { Position: Ref },
+    IfcCircle: IfcConic { Radius: Number },
+    IfcEllipse: IfcConic { SemiAxis1: Number, SemiAxis2: Number },
+    IfcVector: IfcGeometricRepresentationItem { Orientation: Ref, Magnitude: Number },
+    IfcProfileDef: _ { ProfileType: Enum, ProfileName: String },
+    IfcParameterizedProfileDef: IfcProfileDef { Position: Ref },
+    IfcRectangleProfileDef: IfcParameterizedProfileDef { XDim: Number, YDim: Number },
+    IfcArbitraryClosedProfileDef: IfcProfileDef { OuterCurve: Ref },
+    IfcArbitraryProfileDefWithVoids: IfcArbitraryClosedProfileDef { InnerCurves: List(Ref) },
+    IfcRectangleHollowProfileDef: IfcRectangleProfileDef {
+        WallThickness: Number,
+        InnerFilletRadius: Number,
+        OuterFilletRadius: Number,
+    },
+    IfcCircleProfileDef: IfcParameterizedProfileDef { Radius: Number },
+    IfcCircleHollowProfileDef: IfcCircleProfileDef { WallThickness: Number },
+    IfcIShapeProfileDef: IfcParameterizedProfileDef {
+        OverallWidth: Number,
+        OverallDepth: Number,
+        WebThickness: Number,
+        FlangeThickness: Number,
+        FilletRadius: Number,
+        FlangeEdgeRadius: Number,
+        FlangeSlope: Number,
+    },
+    IfcLShapeProfileDef: IfcParameterizedProfileDef {
+        Depth: Number,
+        Width: Number,
+        Thickness: Number,
+        FilletRadius: Number,
+        EdgeRadius: Number,
+        LegSlope: Number,
+    },
+    IfcTShapeProfileDef: IfcParameterizedProfileDef {
+        Depth: Number,
+        FlangeWidth: Number,
+        WebThickness: Number,
+        FlangeThickness: Number,
+        FilletRadius: Number,
+        FlangeEdgeRadius: Number,
+        WebEdgeRadius: Number,
+        WebSlope: Number,
+        FlangeSlope: Number,
+    },
+    IfcUShapeProfileDef: IfcParameterizedProfileDef {
+        Depth: Number,
+        FlangeWidth: Number,
+        WebThickness: Number,
+        FlangeThickness: Number,
+        FilletRadius: Number,
+        EdgeRadius: Number,
+        FlangeSlope: Number,
+    },
+    IfcCShapeProfileDef: IfcParameterizedProfileDef {
+        Depth: Number,
+        Width: Number,
+        WallThickness: Number,
+        Girth: Number,
+        InternalFilletRadius: Number,
+    },
+    IfcZShapeProfileDef: IfcParameterizedProfileDef {
+        Depth: Number,
+        FlangeWidth: Number,
+        WebThickness: Number,
+        FlangeThickness: Number,
+        FilletRadius: Number,
+        EdgeRadius: Number,
+    },
+    IfcCompositeProfileDef: IfcProfileDef { Profiles: List(Ref), Label: String },
+    IfcSolidModel: IfcGeometricRepresentationItem {},
+    IfcSweptAreaSolid: IfcSolidModel { SweptArea: Ref, Position: Ref },
+    IfcExtrudedAreaSolid: IfcSweptAreaSolid { ExtrudedDirection: Ref, Depth: Number },
+    IfcExtrudedAreaSolidTapered: IfcExtrudedAreaSolid { EndSweptArea: Ref },
+    IfcRevolvedAreaSolid: IfcSweptAreaSolid { Axis: Ref, Angle: Number },
+    IfcSurfaceCurveSweptAreaSolid: IfcSweptAreaSolid {
+        Directrix: Ref,
+        StartParam: Number,
+        EndParam: Number,
+        ReferenceSurface: Ref,
+    },
+    IfcFixedReferenceSweptAreaSolid: IfcSweptAreaSolid {
+        Directrix: Ref,
+        StartParam: Number,
+        EndParam: Number,
+        FixedReference: Ref,
+    },
+    IfcSweptDiskSolid: IfcSolidModel {
+        Directrix: Ref,
+        Radius: Number,
+        InnerRadius: Number,
+        StartParam: Number,
+        EndParam: Number,
+    },
+    IfcManifoldSolidBrep: IfcSolidModel { Outer: Ref },
+    IfcFacetedBrep: IfcManifoldSolidBrep {},
+    IfcTopologicalRepresentationItem: IfcRepresentationItem {},
+    IfcConnectedFaceSet: IfcTopologicalRepresentationItem { CfsFaces: List(Ref) },
+    IfcClosedShell: IfcConnectedFaceSet {},
+    IfcFace: IfcTopologicalRepresentationItem { Bounds: List(Ref) },
+    IfcFaceBound: IfcTopologicalRepresentationItem { Bound: Ref, Orientation: Enum },
+    IfcFaceOuterBound: IfcFaceBound {},
+    IfcLoop: IfcTopologicalRepresentationItem {},
+    IfcPolyLoop: IfcLoop { Polygon: List(Ref) },
+    IfcTessellatedItem: IfcGeometricRepresentationItem {},
+    IfcTessellatedFaceSet: IfcTessellatedItem { Coordinates: Ref },
+    IfcTriangulatedFaceSet: IfcTessellatedFaceSet {
+        Normals: List(List(Number)) as IfcParameterValue,
+        Closed: Enum,
+        CoordIndex: List(List(Number)) as IfcPositiveInteger,
+        PnIndex: List(Number),
+    },
+    IfcPolygonalFaceSet: IfcTessellatedFaceSet {
+        Closed: Enum,
+        Faces: List(Ref),
+        PnIndex: List(Number),
+    },
+    IfcIndexedPolygonalFace: IfcTessellatedItem { CoordIndex: List(Number) },
+    IfcIndexedPolygonalFaceWithVoids: IfcIndexedPolygonalFace {
+        InnerCoordIndices: List(List(Number)) as IfcPositiveInteger,
+    },
+    IfcBooleanResult: IfcGeometricRepresentationItem {
+        Operator: Enum,
+        FirstOperand: Ref,
+        SecondOperand: Ref,
+    },
+    IfcBooleanClippingResult: IfcBooleanResult {},
+    IfcHalfSpaceSolid: IfcGeometricRepresentationItem { BaseSurface: Ref, AgreementFlag: Enum },
+    IfcPolygonalBoundedHalfSpace: IfcHalfSpaceSolid { Position: Ref, PolygonalBoundary: Ref },
+    IfcSurface: IfcGeometricRepresentationItem {},
+    IfcElementarySurface: IfcSurface { Position: Ref },
+    IfcPlane: IfcElementarySurface {},
+    IfcBoundedSurface: IfcSurface {},
+    IfcCurveBoundedPlane: IfcBoundedSurface {
+        BasisSurface: Ref,
+        OuterBoundary: Ref,
+        InnerBoundaries: List(Ref),
+    },
+    IfcGeometricSet: IfcGeometricRepresentationItem { Elements: List(Ref) },
+    IfcGeometricCurveSet: IfcGeometricSet {},
+    IfcBoundingBox: IfcGeometricRepresentationItem {
+        Corner: Ref,
+        XDim: Number,
+        YDim: Number,
+        ZDim: Number,
+    },
+    IfcConnectionGeometry: _ {},
+    IfcConnectionSurfaceGeometry: IfcConnectionGeometry {
+        SurfaceOnRelatingElement: Ref,
+        SurfaceOnRelatedElement: Ref,
+    },
+    IfcTextLiteral: IfcGeometricRepresentationItem { Literal: String, Placement: Ref, Path: Enum },
+    IfcTextLiteralWithExtent: IfcTextLiteral { Extent: Ref, BoxAlignment: String },
+    IfcPlanarExtent: IfcGeometricRepresentationItem { SizeInX: Number, SizeInY: Number },
+
+    // presentation
+    IfcPresentationStyleAssignment: _ { Styles: List(Ref) },
+    IfcPresentationStyle: _ { Name: String },
+    IfcSurfaceStyle: IfcPresentationStyle { Side: Enum, Styles: List(Ref) },
+    IfcCurveStyle: IfcPresentationStyle {
+        CurveFont: Ref,
+        CurveWidth: Select,
+        CurveColour: Ref,
+        ModelOrDraughting: Enum,
+    },
+    IfcTextStyle: IfcPresentationStyle {
+        TextCharacterAppearance: Ref,
+        TextStyle: Ref,
+        TextFontStyle: Ref,
+        ModelOrDraughting: Enum,
+    },
+    IfcTextStyleForDefinedFont: _ { Colour: Ref, BackgroundColour: Ref },
+    IfcPresentationItem: _ {},
+    IfcColourSpecification: IfcPresentationItem { Name: String },
+    IfcColourRgb: IfcColourSpecification { Red: Number, Green: Number, Blue: Number },
+    IfcSurfaceStyleShading: IfcPresentationItem { SurfaceColour: Ref, Transparency: Number },
+    IfcSurfaceStyleRendering: IfcSurfaceStyleShading {
+        DiffuseColour: Select,
+        TransmissionColour: Select,
+        DiffuseTransmissionColour: Select,
+        ReflectionColour: Select,
+        SpecularColour: Select,
+        SpecularHighlight: Select,
+        ReflectanceMethod: Enum,
+    },
+    IfcCurveStyleFont: IfcPresentationItem { Name: String, PatternList: List(Ref) },
+    IfcCurveStyleFontPattern: IfcPresentationItem {
+        VisibleSegmentLength: Number,
+        InvisibleSegmentLength: Number,
+    },
+    IfcPreDefinedItem: IfcPresentationItem { Name: String },
+    IfcPreDefinedCurveFont: IfcPreDefinedItem {},
+    IfcDraughtingPreDefinedCurveFont: IfcPreDefinedCurveFont {},
+    IfcPreDefinedColour: IfcPreDefinedItem {},
+    IfcDraughtingPreDefinedColour: IfcPreDefinedColour {},
+    IfcPreDefinedTextFont: IfcPreDefinedItem {},
+    IfcTextStyleFontModel: IfcPreDefinedTextFont {
+        FontFamily: List(String) as IfcTextFontName,
+        FontStyle: String,
+        FontVariant: String,
+        FontWeight: String,
+        FontSize: Select,
+    },
+}
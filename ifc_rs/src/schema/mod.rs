@@ -0,0 +1,273 @@
+mod defined_types;
+mod entities;
+
+use std::{collections::HashMap, sync::OnceLock};
+
+use crate::parser::step_value::{StepEntity, StepValue};
+
+pub use defined_types::DEFINED_TYPES;
+pub use entities::ENTITIES;
+
+/// The kind of value an entity attribute holds according to the IFC schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeKind {
+    /// Strings, labels, identifiers, ...
+    String,
+    /// Enumerations, booleans and logicals.
+    Enum,
+    /// Integers and reals.
+    Number,
+    /// Reference to another entity instance.
+    Ref,
+    /// Select which might hold an entity reference or a typed value like
+    /// `IFCLABEL('abc')`.
+    Select,
+    /// Aggregation of values of the inner kind.
+    List(&'static AttributeKind),
+}
+
+impl AttributeKind {
+    /// Whether the value is valid for an attribute of this kind.
+    pub fn accepts(&self, value: &StepValue) -> bool {
+        match (self, value) {
+            (_, StepValue::Omitted | StepValue::Inherited) => true,
+            (AttributeKind::String, StepValue::String(_))
+            | (AttributeKind::Enum, StepValue::Enum(_))
+            | (AttributeKind::Number, StepValue::Number(_))
+            | (AttributeKind::Ref, StepValue::Ref(_)) => true,
+            (AttributeKind::Select, value) => !matches!(value, StepValue::List(_)),
+            (AttributeKind::List(inner), StepValue::List(values)) => values.iter().all(|value| {
+                !matches!(value, StepValue::Omitted | StepValue::Inherited) && inner.accepts(value)
+            }),
+            _ => false,
+        }
+    }
+}
+
+/// Explicit attribute of an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttributeDef {
+    /// Name of the attribute as written in the IFC specification, e.g. `GlobalId`.
+    pub name: &'static str,
+    pub kind: AttributeKind,
+    /// Defined type of the values of aggregations which ifcXML writes as
+    /// wrapper elements, e.g. `IfcLabel` for the lines of an address.
+    pub item_type: Option<&'static str>,
+}
+
+/// Minimal description of an IFC entity as defined by the IFC4 specification.
+///
+/// Only the explicit attributes which are declared by the entity itself are
+/// listed. The inherited ones are resolved through the supertype chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntityDef {
+    /// Name of the entity as written in the IFC specification, e.g. `IfcWall`.
+    pub name: &'static str,
+    pub supertype: Option<&'static str>,
+    pub attributes: &'static [AttributeDef],
+    /// Inherited attributes which the entity redeclares as derived, they are
+    /// written as `*` in STEP files.
+    pub derived: &'static [&'static str],
+}
+
+impl EntityDef {
+    /// Looks up an entity by its name. The lookup is case insensitive so both
+    /// `IfcWall` and the STEP name `IFCWALL` work.
+    pub fn find(name: &str) -> Option<&'static EntityDef> {
+        static LOOKUP: OnceLock<HashMap<String, &'static EntityDef>> = OnceLock::new();
+
+        LOOKUP
+            .get_or_init(|| {
+                ENTITIES
+                    .iter()
+                    .map(|entity| (entity.name.to_uppercase(), entity))
+                    .collect()
+            })
+            .get(&name.to_uppercase())
+            .copied()
+    }
+
+    /// Upper case name as used in STEP files, e.g. `IFCWALL`.
+    pub fn step_name(&self) -> String {
+        self.name.to_uppercase()
+    }
+
+    pub fn supertype(&self) -> Option<&'static EntityDef> {
+        self.supertype.and_then(Self::find)
+    }
+
+    /// Whether the entity is the given entity or one of its subtypes. The
+    /// comparison is case insensitive.
+    pub fn is_subtype_of(&self, supertype: &str) -> bool {
+        self.name.eq_ignore_ascii_case(supertype)
+            || self
+                .supertype()
+                .is_some_and(|entity| entity.is_subtype_of(supertype))
+    }
+
+    /// Whether the inherited attribute is derived for this entity or one of
+    /// its supertypes.
+    pub fn is_derived(&self, attribute: &str) -> bool {
+        self.derived.contains(&attribute)
+            || self
+                .supertype()
+                .is_some_and(|entity| entity.is_derived(attribute))
+    }
+
+    /// All explicit attributes of the entity including the inherited ones in
+    /// the order in which they are written in STEP files.
+    pub fn all_attributes(&self) -> Vec<&'static AttributeDef> {
+        let mut attributes = self
+            .supertype()
+            .map(|supertype| supertype.all_attributes())
+            .unwrap_or_default();
+        attributes.extend(self.attributes.iter());
+        attributes
+    }
+
+    /// All attributes of the entity if the values of the given instance fit
+    /// to them.
+    pub fn matching_attributes(&self, entity: &StepEntity) -> Option<Vec<&'static AttributeDef>> {
+        let attributes = self.all_attributes();
+
+        (attributes.len() == entity.attributes.len()
+            && attributes
+                .iter()
+                .zip(entity.attributes.iter())
+                .all(|(attribute, value)| attribute.kind.accepts(value)))
+        .then_some(attributes)
+    }
+}
+
+/// Defined type like `IfcLabel` or `IfcLengthMeasure` which wraps a simple value
+/// and is written as typed value (e.g. `IFCLABEL('abc')`) when used in selects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DefinedTypeDef {
+    pub name: &'static str,
+    pub kind: AttributeKind,
+}
+
+impl DefinedTypeDef {
+    /// Case insensitive lookup of a defined type by its name.
+    pub fn find(name: &str) -> Option<&'static DefinedTypeDef> {
+        DEFINED_TYPES
+            .iter()
+            .find(|defined_type| defined_type.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Kind of the wrapped value. Unknown defined types are assumed to be
+    /// measures and therefore numbers.
+    pub fn kind_of(name: &str) -> AttributeKind {
+        Self::find(name)
+            .map(|defined_type| defined_type.kind)
+            .unwrap_or(AttributeKind::Number)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use super::{AttributeKind, EntityDef, ENTITIES};
+
+    /// Names given to `#[typetag::serde(name = ...)]` in the source files.
+    fn typetag_names(dir: &Path, names: &mut Vec<String>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                typetag_names(&path, names);
+            } else if path.extension().is_some_and(|extension| extension == "rs") {
+                let source = fs::read_to_string(&path).unwrap();
+                names.extend(
+                    source
+                        .split("#[typetag::serde(name = \"")
+                        .skip(1)
+                        .filter_map(|rest| rest.split_once('"'))
+                        .map(|(name, _)| name.to_owned()),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn all_entities_are_in_the_schema() {
+        // types which aren't entities or are split by dimension in this crate
+        const NOT_IN_SCHEMA: [&str; 9] = [
+            "Dummy",
+            "Footer",
+            "Header",
+            "IfcFloat",
+            "IfcPlaneAngleMeasure",
+            "IfcCartesianPoint2D",
+            "IfcCartesianPoint3D",
+            "IfcDirection2D",
+            "IfcDirection3D",
+        ];
+
+        let mut names = Vec::new();
+        typetag_names(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &mut names,
+        );
+        assert!(names.len() > 100);
+
+        for name in names {
+            assert!(
+                NOT_IN_SCHEMA.contains(&name.as_str()) || EntityDef::find(&name).is_some(),
+                "{name} has no schema definition"
+            );
+        }
+
+        assert!(EntityDef::find("IfcCartesianPoint").is_some());
+        assert!(EntityDef::find("IfcDirection").is_some());
+    }
+
+    #[test]
+    fn supertypes_are_known() {
+        for entity in ENTITIES {
+            if let Some(supertype) = entity.supertype {
+                assert!(
+                    EntityDef::find(supertype).is_some(),
+                    "unknown supertype {supertype} of {}",
+                    entity.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn inherited_attributes_are_resolved() {
+        let wall = EntityDef::find("IFCWALL").unwrap();
+        let names = wall
+            .all_attributes()
+            .iter()
+            .map(|attribute| attribute.name)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            names,
+            [
+                "GlobalId",
+                "OwnerHistory",
+                "Name",
+                "Description",
+                "ObjectType",
+                "ObjectPlacement",
+                "Representation",
+                "Tag",
+                "PredefinedType"
+            ]
+        );
+        assert_eq!(wall.all_attributes()[8].kind, AttributeKind::Enum);
+    }
+
+    #[test]
+    fn subtypes_are_resolved() {
+        let wall = EntityDef::find("IfcWallStandardCase").unwrap();
+
+        assert!(wall.is_subtype_of("IfcWall"));
+        assert!(wall.is_subtype_of("IFCBUILDINGELEMENT"));
+        assert!(wall.is_subtype_of("IfcRoot"));
+        assert!(!wall.is_subtype_of("IfcSlab"));
+    }
+}
@@ -1,3 +1,5 @@
+use glam::DAffine3;
+
 use crate::{ifc_type::IfcType, prelude::*};
 
 pub trait RelativePlacement: IfcType {
@@ -29,11 +31,109 @@ impl RelativePlacement for Wall {
         self.object_placement.custom().copied()
     }
 }
+impl RelativePlacement for Roof {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for Beam {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for Column {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for Member {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for Grid {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for Space {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for Slab {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for Stair {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for StairFlight {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for Ramp {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for RampFlight {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for Railing {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for Covering {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for CurtainWall {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for Plate {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for BuildingElementProxy {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for FurnishingElement {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
+impl RelativePlacement for Furniture {
+    fn placement_id(&self) -> Option<Id> {
+        self.object_placement.custom().copied()
+    }
+}
 
 impl IFC {
     pub fn get_placement_id<T: RelativePlacement>(&self, id: TypedId<T>) -> Option<Id> {
         self.data.get::<T>(id).placement_id()
     }
+
+    /// Transformation from the local coordinate system of the placed object
+    /// into the world coordinate system.
+    pub fn placement_transform<T: RelativePlacement>(&self, id: TypedId<T>) -> DAffine3 {
+        self.get_placement_id(id)
+            .map(|placement| object_placement_transform(placement, self))
+            .unwrap_or(DAffine3::IDENTITY)
+    }
 }
 
 impl LocalPlacement {
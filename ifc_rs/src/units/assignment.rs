@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     id::IdOr,
@@ -16,7 +17,7 @@ use crate::{
 /// within an IfcUnitAssignment.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcunitassignment.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct UnitAssigment {
     /// Units to be included within a unit assignment.
     #[ifc_types(SiUnit, ConversionBasedUnit, DerivedUnit, MonetaryUnit)]
@@ -51,6 +52,7 @@ impl Display for UnitAssigment {
     }
 }
 
+#[typetag::serde(name = "IfcUnitAssignment")]
 impl IfcType for UnitAssigment {}
 
 #[cfg(test)]
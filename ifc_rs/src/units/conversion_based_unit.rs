@@ -1,6 +1,7 @@
 use std::{fmt::Display, ops::Deref};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use super::{label::Label, shared::named_unit::NamedUnit, IFCParse, IFCParser};
 use crate::{
@@ -16,7 +17,7 @@ use crate::{
 /// (case insensitive) for the Name attribute are indicated in Table 697.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcconversionbasedunit.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct ConversionBasedUnit {
     #[inherited]
     named_unit: NamedUnit,
@@ -64,6 +65,7 @@ impl Display for ConversionBasedUnit {
     }
 }
 
+#[typetag::serde(name = "IfcConversionBasedUnit")]
 impl IfcType for ConversionBasedUnit {}
 
 #[cfg(test)]
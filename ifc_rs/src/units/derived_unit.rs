@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     ifc_type::{IfcType, IfcVerify},
@@ -11,7 +12,7 @@ use crate::{
 use super::{label::Label, list::IfcList, optional::OptionalParameter, IFCParse, IFCParser};
 
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcderivedunit.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct DerivedUnit {
     /// The group of units and their exponents that define the derived unit.
     pub elements: IfcList<TypedId<DerivedUnitElement>>,
@@ -51,6 +52,7 @@ impl Display for DerivedUnit {
     }
 }
 
+#[typetag::serde(name = "IfcDerivedUnit")]
 impl IfcType for DerivedUnit {}
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     ifc_type::{IfcType, IfcVerify},
@@ -11,7 +12,7 @@ use crate::{
 use super::{ifc_integer::IfcInteger, IFCParse, IFCParser};
 
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcderivedunitelement.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct DerivedUnitElement {
     /// The fixed quantity which is used as the mathematical factor.
     pub unit: TypedId<SiUnit>,
@@ -42,6 +43,7 @@ impl Display for DerivedUnitElement {
     }
 }
 
+#[typetag::serde(name = "IfcDerivedUnitElement")]
 impl IfcType for DerivedUnitElement {}
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, VariantNames};
 use winnow::combinator::{alt, delimited};
 use winnow::Parser;
@@ -9,7 +10,7 @@ use crate::parser::*;
 /// IfcDerivedUnitEnum is an enumeration type for allowed types of derived units.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcderivedunitenum.htm
-#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
 pub enum DerivedUnitEnum {
     #[strum(to_string = ".ANGULARVELOCITYUNIT.")]
     ANGULARVELOCITYUNIT,
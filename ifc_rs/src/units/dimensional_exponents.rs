@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 use winnow::ascii::dec_int;
 
 use crate::{
@@ -29,7 +30,7 @@ type ExponentType = i32;
 /// # EXAMPLE
 /// A velocity of 2 millimetres per second has a length exponent of 1 and a time exponent of -1.
 /// The remaining exponents are equal to 0.
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct DimensionalExponents {
     /// The power of the length base quantity.
     pub length: ExponentType,
@@ -63,6 +64,7 @@ impl Display for DimensionalExponents {
     }
 }
 
+#[typetag::serde(name = "IfcDimensionalExponents")]
 impl IfcType for DimensionalExponents {}
 
 impl IFCParse for DimensionalExponents {
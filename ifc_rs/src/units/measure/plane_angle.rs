@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     ifc_type::{IfcType, IfcVerify},
@@ -11,7 +12,7 @@ use crate::{
 /// An IfcPlaneAngleMeasure is the value of an angle in a plane.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcplaneanglemeasure.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct PlaneAngleMeasure {
     pub value: IfcFloat,
 }
@@ -36,6 +37,7 @@ impl Display for PlaneAngleMeasure {
     }
 }
 
+#[typetag::serde(name = "IfcPlaneAngleMeasure")]
 impl IfcType for PlaneAngleMeasure {}
 
 #[cfg(test)]
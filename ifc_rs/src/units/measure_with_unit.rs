@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     ifc_type::{IfcType, IfcVerify},
@@ -16,7 +17,7 @@ use super::{IFCParse, IFCParser};
 ///  2. For conversion based unit to give the conversion rate and its base.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmeasurewithunit.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct MeasureWithUnit {
     /// The value of the physical quantity when expressed in the specified units.
     pub value: IdOr<PlaneAngleMeasure>,
@@ -47,6 +48,7 @@ impl Display for MeasureWithUnit {
     }
 }
 
+#[typetag::serde(name = "IfcMeasureWithUnit")]
 impl IfcType for MeasureWithUnit {}
 
 #[cfg(test)]
@@ -16,6 +16,7 @@ pub mod unit_enum;
 
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, VariantNames};
 use winnow::combinator::{alt, delimited};
 use winnow::Parser;
@@ -42,7 +43,7 @@ impl Units {
 }
 
 // TODO: there are a lot more (mostly imperial units)
-#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
 pub enum ConversionUnit {
     #[strum(to_string = "'DEGREE'")]
     Degree,
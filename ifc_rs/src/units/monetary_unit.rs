@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use super::{label::Label, IFCParse, IFCParser};
 use crate::{
@@ -12,13 +13,14 @@ use crate::{
 /// IfcMonetaryUnit is a unit to define currency for money.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmonetaryunit.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct MonetaryUnit {
     /// Code or name of the currency. Permissible values are the three-letter
     /// alphabetic currency codes as per ISO 4217, for example CNY, EUR, GBP, JPY, USD.
     pub currency: Label,
 }
 
+#[typetag::serde(name = "IfcMonetaryUnit")]
 impl IfcType for MonetaryUnit {}
 
 impl IFCParse for MonetaryUnit {
@@ -3,11 +3,12 @@ use std::fmt::Display;
 use comma::Comma;
 use ifc_rs_verify_derive::IfcVerify;
 use optional::OptionalParameter;
+use serde::{Deserialize, Serialize};
 
 use crate::{id::Id, ifc_type::IfcVerify, parser::*, prelude::*, units::unit_enum::IfcUnitEnum};
 
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcnamedunit.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct NamedUnit {
     /// The dimensional exponents of the SI base units by which the named unit is defined.
     pub dimensions: OptionalParameter<Id>,
@@ -1,6 +1,7 @@
 use std::{fmt::Display, ops::Deref};
 
 use ifc_rs_verify_derive::IfcVerify;
+use serde::{Deserialize, Serialize};
 
 use super::{
     name::IfcUnitName, optional::OptionalParameter, place_holder::Inherited, prefix::IfcPrefix,
@@ -16,7 +17,7 @@ use crate::{
 /// and derived SI units such as Pascal, square meter and cubic meter.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcsiunit.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, Serialize, Deserialize)]
 pub struct SiUnit {
     #[inherited]
     named_unit: NamedUnit,
@@ -45,6 +46,7 @@ impl SiUnit {
     }
 }
 
+#[typetag::serde(name = "IfcSIUnit")]
 impl IfcType for SiUnit {}
 
 impl Deref for SiUnit {
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString, VariantNames};
 use winnow::combinator::{alt, delimited};
 use winnow::Parser;
@@ -9,7 +10,7 @@ use crate::parser::*;
 /// IfcUnitEnum is an enumeration type for allowed unit types of IfcNamedUnit.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcmeasureresource/lexical/ifcunitenum.htm
-#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(EnumString, VariantNames, Display, Clone, Copy, Serialize, Deserialize)]
 pub enum IfcUnitEnum {
     #[strum(to_string = ".ABSORBEDDOSEUNIT.")]
     AbsorbedDoseUnit,
@@ -1,9 +1,92 @@
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
-use syn::Ident;
+use quote::{format_ident, quote, ToTokens};
+use syn::{Ident, Path, Type};
+
+/// Supertypes which can be accessed from any of their subtypes, as
+/// (accessor, type name, module path within the `ifc_rs` crate).
+const SUPERTYPES: &[(&str, &str, &str)] = &[
+    ("as_root", "Root", "objects::shared::root"),
+    ("as_object", "Object", "objects::shared::object"),
+    ("as_context", "Context", "objects::shared::context"),
+    ("as_product", "Product", "objects::shared::product"),
+    ("as_element", "Element", "objects::shared::element"),
+    (
+        "as_spatial_element",
+        "SpatialElement",
+        "objects::shared::spatial_element",
+    ),
+    (
+        "as_spatial_structure_element",
+        "SpatialStructureElement",
+        "objects::shared::spatial_structure_element",
+    ),
+    (
+        "as_type_object",
+        "TypeObject",
+        "objects::shared::type_object",
+    ),
+    (
+        "as_type_product",
+        "TypeProduct",
+        "objects::shared::type_product",
+    ),
+    (
+        "as_element_type",
+        "ElementType",
+        "objects::shared::element_type",
+    ),
+    ("as_property", "Property", "properties::property"),
+    (
+        "as_physical_simple_quantity",
+        "PhysicalSimpleQuantity",
+        "quantities::physical_simple_quantity",
+    ),
+];
 
 pub struct InheritedField {
     pub variable_name: Ident,
+    pub field_type: Type,
+}
+
+impl InheritedField {
+    fn type_name(&self) -> Option<String> {
+        match &self.field_type {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .map(|segment| segment.ident.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Upcasting accessors of `IfcVerify`: the supertype stored in the field
+    /// is returned directly, all others are forwarded to the field.
+    pub fn accessors(&self) -> TokenStream {
+        let var_name = &self.variable_name;
+        let type_name = self.type_name();
+
+        SUPERTYPES
+            .iter()
+            .map(|(accessor, supertype, module)| {
+                let accessor = format_ident!("{accessor}");
+                let supertype_path: Path = syn::parse_str(&format!("crate::{module}::{supertype}"))
+                    .expect("valid supertype path");
+
+                let body = if type_name.as_deref() == Some(*supertype) {
+                    quote! { Some(&self.#var_name) }
+                } else {
+                    quote! { self.#var_name.#accessor() }
+                };
+
+                quote! {
+                    fn #accessor(&self) -> Option<&#supertype_path> {
+                        #body
+                    }
+                }
+            })
+            .collect()
+    }
 }
 
 impl ToTokens for InheritedField {
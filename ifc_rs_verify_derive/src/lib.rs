@@ -90,7 +90,10 @@ pub fn ifc_type_builder(item: TokenStream) -> TokenStream {
                             })
                         }
                         IfcTypesTokenType::Inherited => {
-                            Some(FieldType::Inherited(InheritedField { variable_name }))
+                            Some(FieldType::Inherited(InheritedField {
+                                variable_name,
+                                field_type: field.ty.clone(),
+                            }))
                         }
                     }
                 })
@@ -114,6 +117,12 @@ pub fn ifc_type_builder(item: TokenStream) -> TokenStream {
 
     let check_var_functions: Vec<_> = fields.iter().map(|field| field.check_function()).collect();
 
+    // only the first inherited field describes the supertype
+    let accessors = inheriteds
+        .first()
+        .map(|inherited| inherited.accessors())
+        .unwrap_or_default();
+
     TokenStream::from(quote! {
         impl #impls #struct_name #types #where_clause {
             #(
@@ -133,6 +142,8 @@ pub fn ifc_type_builder(item: TokenStream) -> TokenStream {
 
                 Ok(())
             }
+
+            #accessors
         }
     })
 }